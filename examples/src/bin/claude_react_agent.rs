@@ -43,6 +43,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         temperature: Some(0.7),
         max_tokens: Some(1024),
         stop_sequences: vec![],
+        response_format: None,
     });
 
     while let Some(event) = stream.next().await {
@@ -138,6 +138,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 temperature: None,
                 max_tokens: Some(MAX_TOKENS),
                 stop_sequences: vec![],
+                response_format: None,
             };
 
             // Stream the response
@@ -204,6 +205,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 temperature: None,
                 max_tokens: Some(MAX_TOKENS),
                 stop_sequences: vec![],
+                response_format: None,
             };
             let resp = llm.invoke(req).await?;
             cost_summary.add(&resp);
@@ -242,6 +244,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     content: content.into(),
                     tool_call_id: Some(call_id),
                     tool_calls: vec![],
+                    cache_control: None,
                 });
             }
         }
@@ -53,18 +53,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 content: "You are a helpful assistant. Answer questions based on the provided context.".into(),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             },
             Message {
                 role: Role::User,
                 content: prompt.into(),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             },
         ],
         tools: vec![],
         temperature: Some(0.3),
         max_tokens: Some(512),
         stop_sequences: vec![],
+        response_format: None,
     };
 
     use wesichain_core::Runnable;
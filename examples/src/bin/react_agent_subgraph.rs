@@ -143,6 +143,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockAgentLlm {
                     }],
                     usage: None,
                     model: String::new(),
+                    finish_reason: None,
                 })
             }
             Role::Tool => {
@@ -152,6 +153,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockAgentLlm {
                     tool_calls: vec![],
                     usage: None,
                     model: String::new(),
+                    finish_reason: None,
                 })
             }
             _ => {
@@ -161,6 +163,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockAgentLlm {
                     tool_calls: vec![],
                     usage: None,
                     model: String::new(),
+                    finish_reason: None,
                 })
             }
         }
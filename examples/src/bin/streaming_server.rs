@@ -27,11 +27,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             content: "Tell me a short story about a Rust programmer.".into(),
             tool_call_id: None,
             tool_calls: vec![],
+            cache_control: None,
         }],
         tools: vec![],
         temperature: Some(0.8),
         max_tokens: Some(512),
         stop_sequences: vec![],
+        response_format: None,
     };
 
     println!("Streaming response (SSE format):\n");
@@ -0,0 +1,167 @@
+//! `AgentBuilder` — a thin, non-deprecated entry point over [`ReActGraphBuilder`].
+//!
+//! Code that used the old monolithic agents can switch to this type and keep a
+//! single `invoke(user_input)` call; the ReAct loop itself runs as a
+//! `wesichain-graph` [`ExecutableGraph`](wesichain_graph::ExecutableGraph).
+//!
+//! # Example
+//! ```ignore
+//! use wesichain_agent::AgentBuilder;
+//!
+//! let answer = AgentBuilder::new()
+//!     .llm(llm)
+//!     .tools(vec![Arc::new(Calculator)])
+//!     .max_iterations(5)
+//!     .invoke("What is 2 + 2?".to_string())
+//!     .await?;
+//! ```
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use wesichain_core::{
+    HasFinalOutput, HasUserInput, ReActStep, ScratchpadState, Tool, ToolCallingLlm, WesichainError,
+};
+pub use wesichain_graph::react_subgraph::ToolFailurePolicy;
+use wesichain_graph::{ExecutionOptions, GraphState, ReActGraphBuilder, StateSchema};
+
+const DEFAULT_MAX_ITERATIONS: u32 = 10;
+
+/// Builds and runs a ReAct agent graph with a default scratchpad state.
+#[derive(Clone)]
+pub struct AgentBuilder {
+    llm: Option<Arc<dyn ToolCallingLlm>>,
+    tools: Vec<Arc<dyn Tool>>,
+    max_iterations: u32,
+    tool_failure_policy: ToolFailurePolicy,
+}
+
+impl Default for AgentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentBuilder {
+    pub fn new() -> Self {
+        Self {
+            llm: None,
+            tools: Vec::new(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            tool_failure_policy: ToolFailurePolicy::default(),
+        }
+    }
+
+    pub fn llm(mut self, llm: Arc<dyn ToolCallingLlm>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    pub fn tools(mut self, tools: Vec<Arc<dyn Tool>>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Maximum number of LLM turns before the run fails. Values below 1 are treated as 1.
+    pub fn max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations.max(1);
+        self
+    }
+
+    pub fn tool_failure_policy(mut self, policy: ToolFailurePolicy) -> Self {
+        self.tool_failure_policy = policy;
+        self
+    }
+
+    /// Run the agent on `user_input` and return its final answer.
+    pub async fn invoke(&self, user_input: String) -> Result<String, WesichainError> {
+        let llm = self
+            .llm
+            .clone()
+            .ok_or_else(|| WesichainError::InvalidConfig("AgentBuilder: missing LLM".into()))?;
+        let graph = ReActGraphBuilder::new()
+            .llm(llm)
+            .tools(self.tools.clone())
+            .tool_failure_policy(self.tool_failure_policy)
+            .build::<AgentBuilderState>()
+            .map_err(|err| WesichainError::InvalidConfig(err.to_string()))?;
+
+        // One agent visit per iteration plus one tool visit between turns.
+        let options = ExecutionOptions {
+            max_visits: Some(self.max_iterations),
+            max_loop_iterations: Some(self.max_iterations),
+            max_steps: Some(self.max_iterations as usize * 2),
+            ..Default::default()
+        };
+        let state = AgentBuilderState {
+            input: user_input,
+            ..Default::default()
+        };
+        let result = graph
+            .invoke_with_options(GraphState::new(state), options)
+            .await?;
+
+        result.data.final_output.ok_or_else(|| {
+            WesichainError::Custom("agent finished without a final answer".to_string())
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct AgentBuilderState {
+    input: String,
+    scratchpad: Vec<ReActStep>,
+    final_output: Option<String>,
+    iteration_count: u32,
+}
+
+impl StateSchema for AgentBuilderState {
+    type Update = Self;
+
+    fn apply(current: &Self, update: Self) -> Self {
+        let mut next = current.clone();
+        if !update.input.is_empty() {
+            next.input = update.input;
+        }
+        next.scratchpad.extend(update.scratchpad);
+        if update.final_output.is_some() {
+            next.final_output = update.final_output;
+        }
+        next.iteration_count += update.iteration_count;
+        next
+    }
+}
+
+impl ScratchpadState for AgentBuilderState {
+    fn scratchpad(&self) -> &Vec<ReActStep> {
+        &self.scratchpad
+    }
+
+    fn scratchpad_mut(&mut self) -> &mut Vec<ReActStep> {
+        &mut self.scratchpad
+    }
+
+    fn iteration_count(&self) -> u32 {
+        self.iteration_count
+    }
+
+    fn increment_iteration(&mut self) {
+        self.iteration_count += 1;
+    }
+}
+
+impl HasUserInput for AgentBuilderState {
+    fn user_input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl HasFinalOutput for AgentBuilderState {
+    fn final_output(&self) -> Option<&str> {
+        self.final_output.as_deref()
+    }
+
+    fn set_final_output(&mut self, value: String) {
+        self.final_output = Some(value);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod as_tool;
+mod builder;
 pub mod checkpoint;
 mod error;
 mod event;
@@ -26,9 +27,10 @@ pub use runtime::{
 pub use checkpoint::AgentCheckpoint;
 pub use state::AgentState;
 pub use as_tool::AgentAsTool;
+pub use builder::{AgentBuilder, ToolFailurePolicy};
 pub use permission::{PermissionCheck, PermissionPolicy, ToolPermission};
 pub use tooling::{
-    CancellationToken, Tool, ToolCallEnvelope, ToolContext, ToolError, ToolSchema, ToolSet,
-    ToolSetBuildError, TypedTool,
+    validate_args, CancellationToken, Tool, ToolCallEnvelope, ToolContext, ToolError, ToolSchema,
+    ToolSet, ToolSetBuildError, TypedTool,
 };
 pub use validation::{validate_model_action, ModelAction};
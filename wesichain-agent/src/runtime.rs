@@ -427,6 +427,7 @@ mod tests {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         }
     }
 
@@ -440,6 +441,7 @@ mod tests {
             }],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         }
     }
 
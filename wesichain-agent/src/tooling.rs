@@ -110,6 +110,39 @@ impl ToolSet {
             .await
     }
 
+    /// Invoke `name` after checking `args` against its argument schema.
+    ///
+    /// Malformed arguments are rejected with [`ToolError::InvalidInput`]
+    /// naming the offending field, before the tool runs. Use
+    /// [`dispatch`](Self::dispatch) for the unchecked path.
+    pub async fn invoke_validated(
+        &self,
+        name: &str,
+        args: Value,
+        ctx: ToolContext,
+    ) -> Result<Value, ToolError> {
+        let (Some(schema), Some(dispatcher)) =
+            (self.schema_catalog.get(name), self.dispatchers.get(name))
+        else {
+            return Err(ToolError::InvalidInput(format!("unknown tool: {name}")));
+        };
+
+        let schema = serde_json::to_value(&schema.args_schema)?;
+        validate_args(&schema, &args)?;
+
+        dispatcher
+            .dispatch(name, args, String::new(), ctx)
+            .await
+            .map_err(|err| match err {
+                ToolDispatchError::UnknownTool { name, .. } => {
+                    ToolError::InvalidInput(format!("unknown tool: {name}"))
+                }
+                ToolDispatchError::InvalidArgs { source, .. }
+                | ToolDispatchError::Serialization { source, .. } => ToolError::Json(source),
+                ToolDispatchError::Execution { source, .. } => source,
+            })
+    }
+
     /// Dispatch multiple tool calls concurrently via `tokio::spawn`.
     ///
     /// Results are returned in the same order as `envelopes`.
@@ -348,6 +381,112 @@ impl ErasedToolRunner for DynamicToolRunner {
     }
 }
 
+/// Check `args` against a JSON Schema, covering `required` fields and basic
+/// `type` matches for properties and array items. `$ref`s into the root's
+/// `definitions` are followed; other keywords are ignored.
+pub fn validate_args(schema: &Value, args: &Value) -> Result<(), ToolError> {
+    SchemaCheck { root: schema }
+        .check(schema, args, "args")
+        .map_err(ToolError::InvalidInput)
+}
+
+struct SchemaCheck<'a> {
+    root: &'a Value,
+}
+
+impl<'a> SchemaCheck<'a> {
+    fn resolve(&self, schema: &'a Value) -> &'a Value {
+        let mut schema = schema;
+        // Bounded so a self-referential definition cannot loop forever.
+        for _ in 0..16 {
+            let Some(target) = schema
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|r| r.strip_prefix("#/"))
+            else {
+                break;
+            };
+            match self.root.pointer(&format!("/{target}")) {
+                Some(next) => schema = next,
+                None => break,
+            }
+        }
+        schema
+    }
+
+    fn check(&self, schema: &'a Value, value: &Value, path: &str) -> Result<(), String> {
+        let schema = self.resolve(schema);
+
+        if let Some(expected) = schema.get("type") {
+            let allowed: Vec<&str> = match expected {
+                Value::String(ty) => vec![ty.as_str()],
+                Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                _ => Vec::new(),
+            };
+            if !allowed.is_empty() && !allowed.iter().any(|ty| type_matches(ty, value)) {
+                return Err(format!(
+                    "`{path}` expected {}, got {}",
+                    allowed.join(" or "),
+                    type_name(value)
+                ));
+            }
+        }
+
+        if let Value::Object(fields) = value {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for field in required.iter().filter_map(Value::as_str) {
+                    if !fields.contains_key(field) {
+                        return Err(format!("`{path}` is missing required field `{field}`"));
+                    }
+                }
+            }
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (field, field_schema) in properties {
+                    if let Some(field_value) = fields.get(field) {
+                        self.check(field_schema, field_value, &format!("{path}.{field}"))?;
+                    }
+                }
+            }
+        }
+
+        if let (Value::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+            if item_schema.is_object() {
+                for (index, item) in items.iter().enumerate() {
+                    self.check(item_schema, item, &format!("{path}[{index}]"))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ToolSetBuildError {
     InvalidName { name: String },
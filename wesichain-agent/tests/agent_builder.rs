@@ -0,0 +1,174 @@
+use std::sync::{Arc, Mutex};
+
+use futures::stream::{self, BoxStream, StreamExt};
+use serde_json::{json, Value};
+use wesichain_agent::{AgentBuilder, ToolFailurePolicy};
+use wesichain_core::{
+    LlmRequest, LlmResponse, Role, Runnable, StreamEvent, Tool, ToolCall, ToolCallingLlm,
+    ToolError, WesichainError,
+};
+
+struct Calculator;
+
+#[async_trait::async_trait]
+impl Tool for Calculator {
+    fn name(&self) -> &str {
+        "calculator"
+    }
+
+    fn description(&self) -> &str {
+        "Adds two numbers"
+    }
+
+    fn schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": { "a": { "type": "number" }, "b": { "type": "number" } },
+            "required": ["a", "b"]
+        })
+    }
+
+    async fn invoke(&self, args: Value) -> Result<Value, ToolError> {
+        let operand = |key: &str| {
+            args.get(key)
+                .and_then(Value::as_f64)
+                .ok_or_else(|| ToolError::InvalidInput(format!("missing `{key}`")))
+        };
+        Ok(json!(operand("a")? + operand("b")?))
+    }
+}
+
+struct ScriptedLlm {
+    responses: Mutex<Vec<LlmResponse>>,
+    requests: Mutex<Vec<LlmRequest>>,
+}
+
+impl ScriptedLlm {
+    fn new(responses: Vec<LlmResponse>) -> Self {
+        Self {
+            responses: Mutex::new(responses),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, LlmResponse> for ScriptedLlm {
+    async fn invoke(&self, input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        self.requests.lock().unwrap().push(input);
+        let mut responses = self.responses.lock().unwrap();
+        if responses.is_empty() {
+            return Err(WesichainError::Custom("no more scripted responses".into()));
+        }
+        Ok(responses.remove(0))
+    }
+
+    fn stream(&self, _input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::empty().boxed()
+    }
+}
+
+impl ToolCallingLlm for ScriptedLlm {}
+
+fn tool_call(args: Value) -> LlmResponse {
+    LlmResponse {
+        content: String::new(),
+        tool_calls: vec![ToolCall {
+            id: "call_1".to_string(),
+            name: "calculator".to_string(),
+            args,
+        }],
+        usage: None,
+        model: String::new(),
+        finish_reason: None,
+    }
+}
+
+fn answer(text: &str) -> LlmResponse {
+    LlmResponse {
+        content: text.to_string(),
+        tool_calls: vec![],
+        usage: None,
+        model: String::new(),
+        finish_reason: None,
+    }
+}
+
+#[tokio::test]
+async fn agent_builder_runs_tool_and_returns_final_answer() {
+    let llm = Arc::new(ScriptedLlm::new(vec![
+        tool_call(json!({"a": 2, "b": 3})),
+        answer("2 + 3 = 5"),
+    ]));
+
+    let output = AgentBuilder::new()
+        .llm(llm.clone())
+        .tools(vec![Arc::new(Calculator)])
+        .invoke("What is 2 + 3?".to_string())
+        .await
+        .unwrap();
+    assert_eq!(output, "2 + 3 = 5");
+
+    let requests = llm.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].tools[0].name, "calculator");
+    let observation = requests[1]
+        .messages
+        .iter()
+        .find(|message| matches!(message.role, Role::Tool))
+        .expect("tool observation sent back to the llm");
+    assert_eq!(observation.content.to_string(), "5.0");
+}
+
+#[tokio::test]
+async fn agent_builder_fails_after_max_iterations() {
+    let llm = Arc::new(ScriptedLlm::new(
+        (0..5).map(|_| tool_call(json!({"a": 1, "b": 1}))).collect(),
+    ));
+
+    let result = AgentBuilder::new()
+        .llm(llm.clone())
+        .tools(vec![Arc::new(Calculator)])
+        .max_iterations(2)
+        .invoke("loop forever".to_string())
+        .await;
+    assert!(result.is_err());
+    assert_eq!(llm.requests.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn agent_builder_reports_tool_errors_when_configured() {
+    let llm = Arc::new(ScriptedLlm::new(vec![
+        tool_call(json!({"a": 1})),
+        answer("could not add"),
+    ]));
+
+    let output = AgentBuilder::new()
+        .llm(llm.clone())
+        .tools(vec![Arc::new(Calculator)])
+        .tool_failure_policy(ToolFailurePolicy::AppendErrorAndContinue)
+        .invoke("add one".to_string())
+        .await
+        .unwrap();
+    assert_eq!(output, "could not add");
+
+    let requests = llm.requests.lock().unwrap();
+    let observation = requests[1]
+        .messages
+        .iter()
+        .find(|message| matches!(message.role, Role::Tool))
+        .unwrap();
+    assert!(observation
+        .content
+        .to_string()
+        .contains("[TOOL ERROR] calculator"));
+}
+
+#[tokio::test]
+async fn agent_builder_requires_llm() {
+    let err = AgentBuilder::new()
+        .invoke("hello".to_string())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, WesichainError::InvalidConfig(_)));
+}
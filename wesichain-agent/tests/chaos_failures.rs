@@ -39,6 +39,7 @@ fn cancellation_before_tool_dispatch_transitions_to_interrupted() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
     let allowed_tools = vec!["calculator".to_string()];
 
@@ -20,6 +20,7 @@ fn step_started_precedes_terminal_event_for_each_step() {
         tool_calls: Vec::new(),
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let (_, events) = runtime
@@ -43,6 +44,7 @@ fn each_tool_dispatched_has_exactly_one_completion_or_failure_counterpart() {
         }],
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let success_thinking = AgentRuntime::<(), (), NoopPolicy, _>::new().think();
@@ -84,6 +86,7 @@ fn completed_event_is_emitted_only_once() {
         tool_calls: Vec::new(),
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let (_, events) = runtime
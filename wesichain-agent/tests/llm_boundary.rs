@@ -20,6 +20,7 @@ fn runtime_rejects_multi_tool_call_shape_as_invalid_model_action() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let allowed_tools = vec!["calculator".to_string(), "weather_lookup".to_string()];
@@ -56,6 +57,7 @@ fn runtime_maps_unknown_tool_to_invalid_model_action() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let allowed_tools = vec!["calculator".to_string()];
@@ -34,6 +34,7 @@ fn reprompt_consumes_budget_by_default_and_reaches_budget_exceeded() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let runtime = AgentRuntime::<(), (), AlwaysReprompt, Idle>::with_budget(1).think();
@@ -67,6 +68,7 @@ fn final_answer_transitions_to_completed_terminal_state() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let runtime = AgentRuntime::<(), (), AlwaysReprompt, Idle>::with_budget(2).think();
@@ -91,6 +93,7 @@ fn model_error_reprompt_transition_preserves_strategy_metadata() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let runtime = AgentRuntime::<(), (), AlwaysReprompt, Idle>::with_budget(2).think();
@@ -3,7 +3,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use wesichain_agent::{
-    CancellationToken, ToolCallEnvelope, ToolContext, ToolDispatchError, ToolSet, TypedTool,
+    validate_args, CancellationToken, ToolCallEnvelope, ToolContext, ToolDispatchError, ToolError,
+    ToolSet, TypedTool,
 };
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -94,3 +95,114 @@ async fn dispatch_runs_typed_tool_and_serializes_output() {
     let value = toolset.dispatch(envelope, ctx()).await.unwrap();
     assert_eq!(value, json!({"echoed":"hello"}));
 }
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct AddArgs {
+    a: i64,
+    b: i64,
+    #[serde(default)]
+    #[allow(dead_code)]
+    labels: Vec<String>,
+}
+
+struct AddTool;
+
+#[async_trait::async_trait]
+impl TypedTool for AddTool {
+    type Args = AddArgs;
+    type Output = i64;
+
+    const NAME: &'static str = "add";
+
+    async fn run(
+        &self,
+        args: Self::Args,
+        _ctx: ToolContext,
+    ) -> Result<Self::Output, wesichain_agent::ToolError> {
+        Ok(args.a + args.b)
+    }
+}
+
+fn add_toolset() -> ToolSet {
+    ToolSet::new().register_with(AddTool).build().unwrap()
+}
+
+#[tokio::test]
+async fn invoke_validated_runs_tool_with_valid_args() {
+    let value = add_toolset()
+        .invoke_validated("add", json!({"a": 2, "b": 3}), ctx())
+        .await
+        .unwrap();
+    assert_eq!(value, json!(5));
+}
+
+#[tokio::test]
+async fn invoke_validated_rejects_missing_required_field() {
+    let err = add_toolset()
+        .invoke_validated("add", json!({"a": 2}), ctx())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ToolError::InvalidInput(message) if message == "`args` is missing required field `b`"
+    ));
+}
+
+#[tokio::test]
+async fn invoke_validated_rejects_wrong_types() {
+    let err = add_toolset()
+        .invoke_validated("add", json!({"a": "two", "b": 3}), ctx())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ToolError::InvalidInput(message) if message == "`args.a` expected integer, got string"
+    ));
+
+    let err = add_toolset()
+        .invoke_validated("add", json!({"a": 1, "b": 2, "labels": ["x", 3]}), ctx())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ToolError::InvalidInput(message) if message == "`args.labels[1]` expected string, got number"
+    ));
+
+    let err = add_toolset()
+        .invoke_validated("add", json!([1, 2]), ctx())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ToolError::InvalidInput(message) if message.contains("expected object")));
+}
+
+#[tokio::test]
+async fn invoke_validated_rejects_unknown_tool() {
+    let err = add_toolset()
+        .invoke_validated("missing", json!({}), ctx())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ToolError::InvalidInput(message) if message == "unknown tool: missing"));
+}
+
+#[test]
+fn validate_args_follows_definition_refs() {
+    let schema = json!({
+        "type": "object",
+        "required": ["point"],
+        "properties": { "point": { "$ref": "#/definitions/Point" } },
+        "definitions": {
+            "Point": {
+                "type": "object",
+                "required": ["x"],
+                "properties": { "x": { "type": "number" } }
+            }
+        }
+    });
+
+    assert!(validate_args(&schema, &json!({"point": {"x": 1.5}})).is_ok());
+    let err = validate_args(&schema, &json!({"point": {"x": null}})).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid input: `args.point.x` expected number, got null"
+    );
+}
@@ -8,8 +8,8 @@ use std::time::Duration;
 use futures::stream::{self, BoxStream, StreamExt};
 use reqwest::Client;
 use wesichain_core::{
-    LlmRequest, LlmResponse, Message, MessageContent, Role, Runnable, StreamEvent, TokenUsage,
-    ToolCall, ToolSpec, WesichainError,
+    CacheControl, LlmRequest, LlmResponse, Message, MessageContent, ResponseFormat, Role, Runnable,
+    StreamEvent, TokenUsage, ToolCall, ToolSpec, WesichainError,
 };
 
 use crate::{
@@ -36,6 +36,8 @@ pub struct AnthropicClient {
     pub(crate) http: Client,
     /// When set, extended thinking is enabled on every request.
     pub(crate) thinking: Option<ThinkingConfig>,
+    /// Reject requests asking for JSON output instead of ignoring the hint.
+    pub(crate) strict_response_format: bool,
 }
 
 impl AnthropicClient {
@@ -51,6 +53,7 @@ impl AnthropicClient {
             base_url: ANTHROPIC_BASE_URL.to_string(),
             http,
             thinking: None,
+            strict_response_format: false,
         }
     }
 
@@ -70,6 +73,27 @@ impl AnthropicClient {
         self
     }
 
+    /// The Messages API has no JSON mode, so [`LlmRequest::response_format`]
+    /// is ignored by default. When `strict` is set, requests asking for JSON
+    /// output fail with [`WesichainError::InvalidConfig`] instead.
+    pub fn with_strict_response_format(mut self, strict: bool) -> Self {
+        self.strict_response_format = strict;
+        self
+    }
+
+    fn check_response_format(&self, input: &LlmRequest) -> Result<(), WesichainError> {
+        match &input.response_format {
+            Some(ResponseFormat::JsonObject | ResponseFormat::JsonSchema(_))
+                if self.strict_response_format =>
+            {
+                Err(WesichainError::InvalidConfig(
+                    "anthropic does not support JSON response formats".to_string(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn messages_url(&self) -> String {
         format!("{}/v1/messages", self.base_url.trim_end_matches('/'))
     }
@@ -92,17 +116,31 @@ impl AnthropicClient {
 ///
 /// Returns `(system, messages)` where `system` is the concatenated text of all
 /// `Role::System` messages (Anthropic accepts a single top-level system field).
-fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMessage>) {
-    let system_parts: Vec<String> = messages
+/// If any system message carries a cache hint, `system` is instead one text
+/// block per message so the hint can be attached where it was set.
+fn translate_messages(
+    messages: &[Message],
+) -> (Option<AnthropicContent>, Vec<AnthropicMessage>) {
+    let system_messages: Vec<&Message> = messages
         .iter()
         .filter(|m| matches!(m.role, Role::System))
-        .map(|m| m.content.to_text_lossy())
         .collect();
 
-    let system = if system_parts.is_empty() {
+    let system = if system_messages.is_empty() {
         None
+    } else if system_messages.iter().any(|m| m.cache_control.is_some()) {
+        let blocks = system_messages
+            .iter()
+            .map(|m| AnthropicPart::Text {
+                text: m.content.to_text_lossy(),
+                cache_control: m.cache_control,
+            })
+            .collect();
+        Some(AnthropicContent::Parts(blocks))
     } else {
-        Some(system_parts.join("\n"))
+        let texts: Vec<String> =
+            system_messages.iter().map(|m| m.content.to_text_lossy()).collect();
+        Some(AnthropicContent::Text(texts.join("\n")))
     };
 
     let mut anthropic_messages: Vec<AnthropicMessage> = Vec::new();
@@ -119,7 +157,7 @@ fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMes
                         use crate::types::{AnthropicImageSource};
                         use wesichain_core::ContentPart;
                         let a_parts: Vec<AnthropicPart> = parts.iter().map(|p| match p {
-                            ContentPart::Text { text } => AnthropicPart::Text { text: text.clone() },
+                            ContentPart::Text { text } => AnthropicPart::text(text.clone()),
                             ContentPart::ImageUrl { url, .. } => AnthropicPart::Image {
                                 source: AnthropicImageSource {
                                     source_type: "url".to_string(),
@@ -127,6 +165,7 @@ fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMes
                                     data: None,
                                     url: Some(url.clone()),
                                 },
+                                cache_control: None,
                             },
                             ContentPart::ImageData { data, media_type } => AnthropicPart::Image {
                                 source: AnthropicImageSource {
@@ -135,6 +174,7 @@ fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMes
                                     data: Some(data.clone()),
                                     url: None,
                                 },
+                                cache_control: None,
                             },
                         }).collect();
                         AnthropicContent::Parts(a_parts)
@@ -142,16 +182,14 @@ fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMes
                 };
                 anthropic_messages.push(AnthropicMessage {
                     role: "user".to_string(),
-                    content,
+                    content: with_cache_control(content, message.cache_control),
                 });
             }
             Role::Assistant => {
                 let mut parts: Vec<AnthropicPart> = Vec::new();
 
                 if !message.content.is_empty() {
-                    parts.push(AnthropicPart::Text {
-                        text: message.content.to_text_lossy(),
-                    });
+                    parts.push(AnthropicPart::text(message.content.to_text_lossy()));
                 }
 
                 for call in &message.tool_calls {
@@ -159,13 +197,17 @@ fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMes
                         id: call.id.clone(),
                         name: call.name.clone(),
                         input: call.args.clone(),
+                        cache_control: None,
                     });
                 }
 
                 if !parts.is_empty() {
                     anthropic_messages.push(AnthropicMessage {
                         role: "assistant".to_string(),
-                        content: AnthropicContent::Parts(parts),
+                        content: with_cache_control(
+                            AnthropicContent::Parts(parts),
+                            message.cache_control,
+                        ),
                     });
                 }
             }
@@ -180,6 +222,7 @@ fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMes
                     content: AnthropicContent::Parts(vec![AnthropicPart::ToolResult {
                         tool_use_id,
                         content: message.content.to_text_lossy(),
+                        cache_control: message.cache_control,
                     }]),
                 });
             }
@@ -189,6 +232,25 @@ fn translate_messages(messages: &[Message]) -> (Option<String>, Vec<AnthropicMes
     (system, anthropic_messages)
 }
 
+/// Attach a message's cache hint to its last content block. Plain text is
+/// turned into a single text block, since only blocks can carry the marker.
+fn with_cache_control(
+    content: AnthropicContent,
+    cache_control: Option<CacheControl>,
+) -> AnthropicContent {
+    let Some(cache_control) = cache_control else {
+        return content;
+    };
+    let mut parts = match content {
+        AnthropicContent::Text(text) => vec![AnthropicPart::text(text)],
+        AnthropicContent::Parts(parts) => parts,
+    };
+    if let Some(last) = parts.last_mut() {
+        last.set_cache_control(cache_control);
+    }
+    AnthropicContent::Parts(parts)
+}
+
 /// Convert [`ToolSpec`]s to Anthropic's `tools` array format.
 fn translate_tools(tools: &[ToolSpec]) -> Vec<AnthropicTool> {
     tools
@@ -231,6 +293,7 @@ fn content_blocks_to_response(
     blocks: Vec<ResponseContentBlock>,
     usage: TokenUsage,
     model: String,
+    finish_reason: Option<String>,
 ) -> LlmResponse {
     let mut text_parts: Vec<String> = Vec::new();
     let mut tool_calls: Vec<ToolCall> = Vec::new();
@@ -258,6 +321,7 @@ fn content_blocks_to_response(
         tool_calls,
         usage: Some(usage),
         model,
+        finish_reason,
     }
 }
 
@@ -268,6 +332,7 @@ fn content_blocks_to_response(
 #[async_trait::async_trait]
 impl Runnable<LlmRequest, LlmResponse> for AnthropicClient {
     async fn invoke(&self, input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        self.check_response_format(&input)?;
         let mut request = build_request(&input, false, self.thinking.clone());
         // Use the effective model (falling back to the client's default)
         request.model = self.effective_model(&input.model).to_string();
@@ -315,10 +380,18 @@ impl Runnable<LlmRequest, LlmResponse> for AnthropicClient {
                 + anthropic_response.usage.output_tokens,
         };
 
-        Ok(content_blocks_to_response(anthropic_response.content, usage, anthropic_response.model))
+        Ok(content_blocks_to_response(
+            anthropic_response.content,
+            usage,
+            anthropic_response.model,
+            anthropic_response.stop_reason,
+        ))
     }
 
     fn stream(&self, input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        if let Err(err) = self.check_response_format(&input) {
+            return stream::iter(vec![Err(err)]).boxed();
+        }
         let mut request = build_request(&input, true, self.thinking.clone());
         request.model = self.effective_model(&input.model).to_string();
         let has_thinking = self.thinking.is_some();
@@ -382,7 +455,7 @@ mod tests {
     use futures::StreamExt;
     use httpmock::prelude::*;
     use serde_json::json;
-    use wesichain_core::{LlmRequest, Message, Role};
+    use wesichain_core::{FinishReason, LlmRequest, Message, Role};
 
     fn make_client(server: &MockServer) -> AnthropicClient {
         AnthropicClient::new("test-api-key", "claude-3-5-sonnet-20241022")
@@ -397,11 +470,13 @@ mod tests {
                 content: "Hello!".into(),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             }],
             tools: vec![],
             temperature: None,
             max_tokens: None,
             stop_sequences: vec![],
+            response_format: None,
         }
     }
 
@@ -438,6 +513,8 @@ mod tests {
         mock.assert();
         assert_eq!(response.content, "Hello, world!");
         assert!(response.tool_calls.is_empty());
+        assert_eq!(response.finish_reason.as_deref(), Some("end_turn"));
+        assert_eq!(response.finish(), Some(FinishReason::Stop));
 
         let usage = response.usage.unwrap();
         assert_eq!(usage.prompt_tokens, 10);
@@ -479,6 +556,7 @@ mod tests {
 
         assert!(response.content.is_empty());
         assert_eq!(response.tool_calls.len(), 1);
+        assert_eq!(response.finish(), Some(FinishReason::ToolCalls));
 
         let call = &response.tool_calls[0];
         assert_eq!(call.id, "toolu_01");
@@ -520,18 +598,21 @@ mod tests {
                     content: "You are a helpful assistant.".into(),
                     tool_call_id: None,
                     tool_calls: vec![],
+                    cache_control: None,
                 },
                 Message {
                     role: Role::User,
                     content: "Can you help me?".into(),
                     tool_call_id: None,
                     tool_calls: vec![],
+                    cache_control: None,
                 },
             ],
             tools: vec![],
             temperature: None,
             max_tokens: None,
             stop_sequences: vec![],
+            response_format: None,
         };
 
         let response = client.invoke(request).await.unwrap();
@@ -612,4 +693,126 @@ mod tests {
 
         assert_eq!(final_answers, vec!["Hello, world!"]);
     }
+
+    // ------------------------------------------------------------------
+    // Test 5 – truncated response reports max_tokens
+    // ------------------------------------------------------------------
+    #[tokio::test]
+    async fn test_max_tokens_stop_reason_maps_to_length() {
+        let server = MockServer::start();
+
+        server.mock(|when, then| {
+            when.method(POST).path("/v1/messages");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({
+                    "id": "msg_05",
+                    "type": "message",
+                    "role": "assistant",
+                    "content": [{ "type": "text", "text": "Once upon a" }],
+                    "model": "claude-3-5-sonnet-20241022",
+                    "stop_reason": "max_tokens",
+                    "usage": { "input_tokens": 10, "output_tokens": 3 }
+                }));
+        });
+
+        let client = make_client(&server);
+        let response = client.invoke(simple_user_request()).await.unwrap();
+
+        assert_eq!(response.finish_reason.as_deref(), Some("max_tokens"));
+        assert_eq!(response.finish(), Some(FinishReason::Length));
+    }
+
+    // ------------------------------------------------------------------
+    // Test 6 – cache hints become cache_control blocks
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_cache_control_serialized_on_marked_blocks() {
+        let request = LlmRequest {
+            model: String::new(),
+            messages: vec![
+                Message::system("Long shared instructions.")
+                    .with_cache_control(CacheControl::Ephemeral),
+                Message::system("Per-user note."),
+                Message::user("Reference document").with_cache_control(CacheControl::Ephemeral),
+                Message::user("Question?"),
+            ],
+            tools: vec![],
+            temperature: None,
+            max_tokens: None,
+            stop_sequences: vec![],
+            response_format: None,
+        };
+
+        let body = serde_json::to_value(build_request(&request, false, None)).unwrap();
+
+        assert_eq!(
+            body["system"],
+            json!([
+                {
+                    "type": "text",
+                    "text": "Long shared instructions.",
+                    "cache_control": { "type": "ephemeral" }
+                },
+                { "type": "text", "text": "Per-user note." }
+            ])
+        );
+        assert_eq!(
+            body["messages"][0]["content"],
+            json!([{
+                "type": "text",
+                "text": "Reference document",
+                "cache_control": { "type": "ephemeral" }
+            }])
+        );
+        assert_eq!(body["messages"][1]["content"], json!("Question?"));
+    }
+
+    #[test]
+    fn test_messages_without_cache_hints_stay_plain() {
+        let request = LlmRequest {
+            model: String::new(),
+            messages: vec![Message::system("Be brief."), Message::user("Hi")],
+            tools: vec![],
+            temperature: None,
+            max_tokens: None,
+            stop_sequences: vec![],
+            response_format: None,
+        };
+
+        let body = serde_json::to_value(build_request(&request, false, None)).unwrap();
+
+        assert_eq!(body["system"], json!("Be brief."));
+        assert_eq!(body["messages"][0]["content"], json!("Hi"));
+        assert!(!body.to_string().contains("cache_control"));
+    }
+
+    // ------------------------------------------------------------------
+    // Test 7 – JSON response formats are ignored unless strict
+    // ------------------------------------------------------------------
+    #[test]
+    fn test_response_format_is_not_sent() {
+        let mut request = simple_user_request();
+        request.response_format = Some(ResponseFormat::JsonObject);
+
+        let body = serde_json::to_value(build_request(&request, false, None)).unwrap();
+
+        assert!(body.get("response_format").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_strict_response_format_rejects_json_requests() {
+        let client = AnthropicClient::new("test-api-key", "claude-3-5-sonnet-20241022")
+            .with_base_url("http://127.0.0.1:9")
+            .with_strict_response_format(true);
+        let mut request = simple_user_request();
+        request.response_format = Some(ResponseFormat::JsonSchema(json!({"type": "object"})));
+
+        let err = client.invoke(request.clone()).await.unwrap_err();
+        assert!(matches!(err, WesichainError::InvalidConfig(_)), "{err:?}");
+
+        let events: Vec<_> = client.stream(request).collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Err(WesichainError::InvalidConfig(_))));
+    }
 }
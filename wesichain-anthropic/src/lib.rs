@@ -20,11 +20,13 @@
 //!             content: "Hello, Claude!".into(),
 //!             tool_call_id: None,
 //!             tool_calls: vec![],
+//!             cache_control: None,
 //!         }],
 //!         tools: vec![],
 //!         temperature: None,
 //!         max_tokens: None,
 //!         stop_sequences: vec![],  // note: field name matches LlmRequest
+//!         response_format: None,
 //!     };
 //!
 //!     let response = client.invoke(request).await?;
@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use wesichain_core::CacheControl;
 
 // ---------------------------------------------------------------------------
 // Request types
@@ -30,8 +31,10 @@ pub struct AnthropicRequest {
     pub model: String,
     pub max_tokens: u32,
     pub messages: Vec<AnthropicMessage>,
+    /// System prompt; sent as text blocks when any system message carries a
+    /// cache hint.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub system: Option<String>,
+    pub system: Option<AnthropicContent>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Vec<AnthropicTool>>,
     pub stream: bool,
@@ -71,23 +74,52 @@ impl Serialize for AnthropicContent {
 }
 
 /// A single typed part within an Anthropic message.
+///
+/// `cache_control` marks the end of a cacheable prompt prefix.
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AnthropicPart {
     Text {
         text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
     },
     ToolUse {
         id: String,
         name: String,
         input: Value,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
     },
     ToolResult {
         tool_use_id: String,
         content: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
     },
     #[serde(rename = "image")]
-    Image { source: AnthropicImageSource },
+    Image {
+        source: AnthropicImageSource,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
+}
+
+impl AnthropicPart {
+    /// Plain text part without a cache hint.
+    pub fn text(text: impl Into<String>) -> Self {
+        AnthropicPart::Text { text: text.into(), cache_control: None }
+    }
+
+    /// Mark this part as the end of a cacheable prompt prefix.
+    pub fn set_cache_control(&mut self, value: CacheControl) {
+        match self {
+            AnthropicPart::Text { cache_control, .. }
+            | AnthropicPart::ToolUse { cache_control, .. }
+            | AnthropicPart::ToolResult { cache_control, .. }
+            | AnthropicPart::Image { cache_control, .. } => *cache_control = Some(value),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -1,10 +1,11 @@
 use std::convert::TryFrom;
 
 use wesichain_checkpoint_sql::error::CheckpointSqlError;
-use wesichain_checkpoint_sql::migrations::run_migrations;
+use wesichain_checkpoint_sql::migrations::run_migrations_for_table;
 use wesichain_checkpoint_sql::ops::{
-    load_latest_checkpoint, save_checkpoint_with_projections_and_queue,
+    load_latest_checkpoint_for_table, save_checkpoint_with_projections_and_queue_for_table,
 };
+pub use wesichain_checkpoint_sql::schema::TableConfig;
 use wesichain_core::checkpoint::{Checkpoint, Checkpointer};
 use wesichain_core::state::{GraphState, StateSchema};
 use wesichain_core::WesichainError;
@@ -13,6 +14,7 @@ use wesichain_core::WesichainError;
 pub struct PostgresCheckpointer {
     pool: sqlx::PgPool,
     enable_projections: bool,
+    table: TableConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +23,7 @@ pub struct PostgresCheckpointerBuilder {
     max_connections: u32,
     min_connections: u32,
     enable_projections: bool,
+    table: TableConfig,
 }
 
 impl PostgresCheckpointer {
@@ -30,12 +33,17 @@ impl PostgresCheckpointer {
             max_connections: 5,
             min_connections: 0,
             enable_projections: false,
+            table: TableConfig::default(),
         }
     }
 
     pub fn projections_enabled(&self) -> bool {
         self.enable_projections
     }
+
+    pub fn table(&self) -> &TableConfig {
+        &self.table
+    }
 }
 
 impl PostgresCheckpointerBuilder {
@@ -54,6 +62,14 @@ impl PostgresCheckpointerBuilder {
         self
     }
 
+    /// Store checkpoints in `table` instead of `checkpoints`, e.g. so that
+    /// several apps can share one database. Projection tables keep their
+    /// fixed names; see [`TableConfig`].
+    pub fn table(mut self, table: TableConfig) -> Self {
+        self.table = table;
+        self
+    }
+
     pub async fn build(self) -> Result<PostgresCheckpointer, CheckpointSqlError> {
         let pool = sqlx::postgres::PgPoolOptions::new()
             .max_connections(self.max_connections)
@@ -62,11 +78,12 @@ impl PostgresCheckpointerBuilder {
             .await
             .map_err(CheckpointSqlError::Connection)?;
 
-        run_migrations(&pool).await?;
+        run_migrations_for_table(&pool, &self.table).await?;
 
         Ok(PostgresCheckpointer {
             pool,
             enable_projections: self.enable_projections,
+            table: self.table,
         })
     }
 }
@@ -95,8 +112,9 @@ impl<S: StateSchema> Checkpointer<S> for PostgresCheckpointer {
             let step = i64::try_from(checkpoint.step)
                 .map_err(|_| graph_checkpoint_error("checkpoint step does not fit into i64"))?;
 
-            save_checkpoint_with_projections_and_queue(
+            save_checkpoint_with_projections_and_queue_for_table(
                 &self.pool,
+                &self.table,
                 &checkpoint.thread_id,
                 &checkpoint.node,
                 step,
@@ -128,7 +146,7 @@ impl<S: StateSchema> Checkpointer<S> for PostgresCheckpointer {
         Self: 'async_trait,
     {
         Box::pin(async move {
-            let stored = load_latest_checkpoint(&self.pool, thread_id)
+            let stored = load_latest_checkpoint_for_table(&self.pool, &self.table, thread_id)
                 .await
                 .map_err(map_sql_error)?;
 
@@ -12,6 +12,8 @@ pub enum CheckpointSqlError {
     Query(#[source] sqlx::Error),
     #[error("checkpoint SQL projection error: {0}")]
     Projection(String),
+    #[error("invalid checkpoint SQL identifier '{0}'")]
+    InvalidIdentifier(String),
     #[error("SQL checkpoint operation is not implemented")]
     NotImplemented,
 }
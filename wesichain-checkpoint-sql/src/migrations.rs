@@ -1,5 +1,5 @@
 use crate::error::CheckpointSqlError;
-use crate::schema::{ADD_CHECKPOINT_QUEUE_COLUMN_SQL, MIGRATION_STATEMENTS_SQL};
+use crate::schema::{TableConfig, PROJECTION_TABLES_SQL};
 use sqlx::{Database, Pool};
 
 fn is_duplicate_column_error(error: &sqlx::Error) -> bool {
@@ -11,17 +11,27 @@ fn is_duplicate_column_error(error: &sqlx::Error) -> bool {
     message.contains("duplicate column") || message.contains("already exists")
 }
 
-pub async fn run_migrations_with_connection<DB>(
+/// Create the tables in
+/// [`MIGRATION_STATEMENTS_SQL`](crate::schema::MIGRATION_STATEMENTS_SQL), with
+/// the checkpoints table named by `table`.
+pub async fn run_migrations_with_connection_for_table<DB>(
     conn: &mut DB::Connection,
+    table: &TableConfig,
 ) -> Result<(), CheckpointSqlError>
 where
     DB: Database,
     for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
     for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
 {
-    for statement in MIGRATION_STATEMENTS_SQL {
+    let create_table = table.create_table_sql();
+    let add_queue_column = table.add_queue_column_sql();
+    let statements = [create_table.as_str(), add_queue_column.as_str()]
+        .into_iter()
+        .chain(PROJECTION_TABLES_SQL);
+
+    for statement in statements {
         if let Err(error) = sqlx::query::<DB>(statement).execute(&mut *conn).await {
-            if statement == ADD_CHECKPOINT_QUEUE_COLUMN_SQL && is_duplicate_column_error(&error) {
+            if statement == add_queue_column && is_duplicate_column_error(&error) {
                 continue;
             }
             return Err(CheckpointSqlError::Migration(error));
@@ -31,18 +41,22 @@ where
     Ok(())
 }
 
-pub async fn run_migrations_in_transaction<DB>(
+pub async fn run_migrations_in_transaction_for_table<DB>(
     tx: &mut sqlx::Transaction<'_, DB>,
+    table: &TableConfig,
 ) -> Result<(), CheckpointSqlError>
 where
     DB: Database,
     for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
     for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
 {
-    run_migrations_with_connection(tx.as_mut()).await
+    run_migrations_with_connection_for_table(tx.as_mut(), table).await
 }
 
-pub async fn run_migrations<DB>(executor: &Pool<DB>) -> Result<(), CheckpointSqlError>
+pub async fn run_migrations_for_table<DB>(
+    executor: &Pool<DB>,
+    table: &TableConfig,
+) -> Result<(), CheckpointSqlError>
 where
     DB: Database,
     for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
@@ -53,5 +67,39 @@ where
         .await
         .map_err(CheckpointSqlError::Migration)?;
 
-    run_migrations_with_connection(conn.as_mut()).await
+    run_migrations_with_connection_for_table(conn.as_mut(), table).await
+}
+
+/// [`run_migrations_with_connection_for_table`] for the default `checkpoints` table.
+pub async fn run_migrations_with_connection<DB>(
+    conn: &mut DB::Connection,
+) -> Result<(), CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+{
+    run_migrations_with_connection_for_table(conn, &TableConfig::default()).await
+}
+
+/// [`run_migrations_in_transaction_for_table`] for the default `checkpoints` table.
+pub async fn run_migrations_in_transaction<DB>(
+    tx: &mut sqlx::Transaction<'_, DB>,
+) -> Result<(), CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+{
+    run_migrations_in_transaction_for_table(tx, &TableConfig::default()).await
+}
+
+/// [`run_migrations_for_table`] for the default `checkpoints` table.
+pub async fn run_migrations<DB>(executor: &Pool<DB>) -> Result<(), CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+{
+    run_migrations_for_table(executor, &TableConfig::default()).await
 }
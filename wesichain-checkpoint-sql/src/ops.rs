@@ -1,5 +1,6 @@
 use crate::error::CheckpointSqlError;
 use crate::projection::{apply_projection_rows_in_transaction, map_state_to_projection_rows};
+use crate::schema::TableConfig;
 use serde::Serialize;
 use serde_json::Value;
 use sqlx::Row;
@@ -22,8 +23,9 @@ fn should_retry_sequence_write(error: &sqlx::Error) -> bool {
     false
 }
 
-pub async fn next_checkpoint_seq_in_transaction<DB>(
+pub async fn next_checkpoint_seq_in_transaction_for_table<DB>(
     tx: &mut sqlx::Transaction<'_, DB>,
+    table: &TableConfig,
     thread_id: &str,
 ) -> Result<i64, CheckpointSqlError>
 where
@@ -35,9 +37,10 @@ where
     usize: ColumnIndex<DB::Row>,
 {
     let seq_sql = {
-        let mut query = QueryBuilder::<DB>::new(
-            "SELECT COALESCE(MAX(seq), 0) + 1 FROM checkpoints WHERE thread_id = ",
-        );
+        let mut query = QueryBuilder::<DB>::new(format!(
+            "SELECT COALESCE(MAX(seq), 0) + 1 FROM {} WHERE thread_id = ",
+            table.qualified_name()
+        ));
         query.push_bind(thread_id);
         query.sql().to_owned()
     };
@@ -49,9 +52,26 @@ where
         .map_err(CheckpointSqlError::Query)
 }
 
+/// [`next_checkpoint_seq_in_transaction_for_table`] for the default `checkpoints` table.
+pub async fn next_checkpoint_seq_in_transaction<DB>(
+    tx: &mut sqlx::Transaction<'_, DB>,
+    thread_id: &str,
+) -> Result<i64, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    usize: ColumnIndex<DB::Row>,
+{
+    next_checkpoint_seq_in_transaction_for_table(tx, &TableConfig::default(), thread_id).await
+}
+
 #[allow(clippy::too_many_arguments)]
-pub async fn insert_checkpoint_in_transaction<DB>(
+pub async fn insert_checkpoint_in_transaction_for_table<DB>(
     tx: &mut sqlx::Transaction<'_, DB>,
+    table: &TableConfig,
     thread_id: &str,
     seq: i64,
     created_at: &str,
@@ -68,9 +88,10 @@ where
     for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
 {
     let insert_sql = {
-        let mut query = QueryBuilder::<DB>::new(
-            "INSERT INTO checkpoints (thread_id, seq, created_at, node, step, state_json, queue_json) VALUES (",
-        );
+        let mut query = QueryBuilder::<DB>::new(format!(
+            "INSERT INTO {} (thread_id, seq, created_at, node, step, state_json, queue_json) VALUES (",
+            table.qualified_name()
+        ));
         query
             .push_bind(thread_id)
             .push(", ")
@@ -104,8 +125,43 @@ where
     Ok(())
 }
 
-pub async fn save_checkpoint_in_transaction_with_queue<DB, S, Q>(
+/// [`insert_checkpoint_in_transaction_for_table`] for the default `checkpoints` table.
+#[allow(clippy::too_many_arguments)]
+pub async fn insert_checkpoint_in_transaction<DB>(
+    tx: &mut sqlx::Transaction<'_, DB>,
+    thread_id: &str,
+    seq: i64,
+    created_at: &str,
+    node: &str,
+    step: i64,
+    state_json: &str,
+    queue_json: &str,
+) -> Result<(), CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> i64: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+{
+    insert_checkpoint_in_transaction_for_table(
+        tx,
+        &TableConfig::default(),
+        thread_id,
+        seq,
+        created_at,
+        node,
+        step,
+        state_json,
+        queue_json,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn save_checkpoint_in_transaction_with_queue_for_table<DB, S, Q>(
     tx: &mut sqlx::Transaction<'_, DB>,
+    table: &TableConfig,
     thread_id: &str,
     node: &str,
     step: i64,
@@ -126,10 +182,11 @@ where
 {
     let state_json = serde_json::to_string(state).map_err(CheckpointSqlError::Serialization)?;
     let queue_json = serde_json::to_string(queue).map_err(CheckpointSqlError::Serialization)?;
-    let seq = next_checkpoint_seq_in_transaction(tx, thread_id).await?;
+    let seq = next_checkpoint_seq_in_transaction_for_table(tx, table, thread_id).await?;
 
-    insert_checkpoint_in_transaction(
+    insert_checkpoint_in_transaction_for_table(
         tx,
+        table,
         thread_id,
         seq,
         created_at,
@@ -143,8 +200,43 @@ where
     Ok(seq)
 }
 
-pub async fn save_checkpoint_in_transaction<DB, S>(
+/// [`save_checkpoint_in_transaction_with_queue_for_table`] for the default `checkpoints` table.
+pub async fn save_checkpoint_in_transaction_with_queue<DB, S, Q>(
+    tx: &mut sqlx::Transaction<'_, DB>,
+    thread_id: &str,
+    node: &str,
+    step: i64,
+    created_at: &str,
+    state: &S,
+    queue: &Q,
+) -> Result<i64, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> i64: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    usize: ColumnIndex<DB::Row>,
+    S: Serialize + ?Sized,
+    Q: Serialize + ?Sized,
+{
+    save_checkpoint_in_transaction_with_queue_for_table(
+        tx,
+        &TableConfig::default(),
+        thread_id,
+        node,
+        step,
+        created_at,
+        state,
+        queue,
+    )
+    .await
+}
+
+pub async fn save_checkpoint_in_transaction_for_table<DB, S>(
     tx: &mut sqlx::Transaction<'_, DB>,
+    table: &TableConfig,
     thread_id: &str,
     node: &str,
     step: i64,
@@ -161,8 +253,9 @@ where
     usize: ColumnIndex<DB::Row>,
     S: Serialize + ?Sized,
 {
-    save_checkpoint_in_transaction_with_queue(
+    save_checkpoint_in_transaction_with_queue_for_table(
         tx,
+        table,
         thread_id,
         node,
         step,
@@ -173,6 +266,37 @@ where
     .await
 }
 
+/// [`save_checkpoint_in_transaction_for_table`] for the default `checkpoints` table.
+pub async fn save_checkpoint_in_transaction<DB, S>(
+    tx: &mut sqlx::Transaction<'_, DB>,
+    thread_id: &str,
+    node: &str,
+    step: i64,
+    created_at: &str,
+    state: &S,
+) -> Result<i64, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> i64: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    usize: ColumnIndex<DB::Row>,
+    S: Serialize + ?Sized,
+{
+    save_checkpoint_in_transaction_for_table(
+        tx,
+        &TableConfig::default(),
+        thread_id,
+        node,
+        step,
+        created_at,
+        state,
+    )
+    .await
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct StoredCheckpoint {
     pub thread_id: String,
@@ -184,8 +308,10 @@ pub struct StoredCheckpoint {
     pub queue_json: Value,
 }
 
-pub async fn save_checkpoint_with_queue<DB, S, Q>(
+#[allow(clippy::too_many_arguments)]
+pub async fn save_checkpoint_with_queue_for_table<DB, S, Q>(
     pool: &Pool<DB>,
+    table: &TableConfig,
     thread_id: &str,
     node: &str,
     step: i64,
@@ -210,8 +336,8 @@ where
 
     for _attempt in 0..SAVE_RETRY_LIMIT {
         let mut tx = pool.begin().await.map_err(CheckpointSqlError::Query)?;
-        match save_checkpoint_in_transaction_with_queue(
-            &mut tx, thread_id, node, step, created_at, state, queue,
+        match save_checkpoint_in_transaction_with_queue_for_table(
+            &mut tx, table, thread_id, node, step, created_at, state, queue,
         )
         .await
         {
@@ -244,8 +370,45 @@ where
     Err(last_error.unwrap_or(CheckpointSqlError::NotImplemented))
 }
 
-pub async fn save_checkpoint<DB, S>(
+/// [`save_checkpoint_with_queue_for_table`] for the default `checkpoints` table.
+pub async fn save_checkpoint_with_queue<DB, S, Q>(
+    pool: &Pool<DB>,
+    thread_id: &str,
+    node: &str,
+    step: i64,
+    created_at: &str,
+    state: &S,
+    queue: &Q,
+) -> Result<i64, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> i64: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> String: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c Pool<DB>: sqlx::Executor<'c, Database = DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+    S: Serialize + ?Sized,
+    Q: Serialize + ?Sized,
+{
+    save_checkpoint_with_queue_for_table(
+        pool,
+        &TableConfig::default(),
+        thread_id,
+        node,
+        step,
+        created_at,
+        state,
+        queue,
+    )
+    .await
+}
+
+pub async fn save_checkpoint_for_table<DB, S>(
     pool: &Pool<DB>,
+    table: &TableConfig,
     thread_id: &str,
     node: &str,
     step: i64,
@@ -264,8 +427,9 @@ where
     usize: ColumnIndex<DB::Row>,
     S: Serialize + ?Sized,
 {
-    save_checkpoint_with_queue(
+    save_checkpoint_with_queue_for_table(
         pool,
+        table,
         thread_id,
         node,
         step,
@@ -276,9 +440,43 @@ where
     .await
 }
 
+/// [`save_checkpoint_for_table`] for the default `checkpoints` table.
+pub async fn save_checkpoint<DB, S>(
+    pool: &Pool<DB>,
+    thread_id: &str,
+    node: &str,
+    step: i64,
+    created_at: &str,
+    state: &S,
+) -> Result<i64, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> i64: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> String: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c Pool<DB>: sqlx::Executor<'c, Database = DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+    S: Serialize + ?Sized,
+{
+    save_checkpoint_for_table(
+        pool,
+        &TableConfig::default(),
+        thread_id,
+        node,
+        step,
+        created_at,
+        state,
+    )
+    .await
+}
+
 #[allow(clippy::too_many_arguments)]
-pub async fn save_checkpoint_with_projections_and_queue<DB, S, Q>(
+pub async fn save_checkpoint_with_projections_and_queue_for_table<DB, S, Q>(
     pool: &Pool<DB>,
+    table: &TableConfig,
     thread_id: &str,
     node: &str,
     step: i64,
@@ -305,8 +503,8 @@ where
 
     for _attempt in 0..SAVE_RETRY_LIMIT {
         let mut tx = pool.begin().await.map_err(CheckpointSqlError::Query)?;
-        match save_checkpoint_in_transaction_with_queue(
-            &mut tx, thread_id, node, step, created_at, state, queue,
+        match save_checkpoint_in_transaction_with_queue_for_table(
+            &mut tx, table, thread_id, node, step, created_at, state, queue,
         )
         .await
         {
@@ -353,8 +551,50 @@ where
     Err(last_error.unwrap_or(CheckpointSqlError::NotImplemented))
 }
 
-pub async fn save_checkpoint_with_projections<DB, S>(
+/// [`save_checkpoint_with_projections_and_queue_for_table`] for the default `checkpoints` table.
+#[allow(clippy::too_many_arguments)]
+pub async fn save_checkpoint_with_projections_and_queue<DB, S, Q>(
+    pool: &Pool<DB>,
+    thread_id: &str,
+    node: &str,
+    step: i64,
+    created_at: &str,
+    state: &S,
+    queue: &Q,
+    enable_projections: bool,
+) -> Result<i64, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> i64: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> String: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> Option<String>: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c Pool<DB>: sqlx::Executor<'c, Database = DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+    S: Serialize + ?Sized,
+    Q: Serialize + ?Sized,
+{
+    save_checkpoint_with_projections_and_queue_for_table(
+        pool,
+        &TableConfig::default(),
+        thread_id,
+        node,
+        step,
+        created_at,
+        state,
+        queue,
+        enable_projections,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn save_checkpoint_with_projections_for_table<DB, S>(
     pool: &Pool<DB>,
+    table: &TableConfig,
     thread_id: &str,
     node: &str,
     step: i64,
@@ -375,8 +615,9 @@ where
     usize: ColumnIndex<DB::Row>,
     S: Serialize + ?Sized,
 {
-    save_checkpoint_with_projections_and_queue(
+    save_checkpoint_with_projections_and_queue_for_table(
         pool,
+        table,
         thread_id,
         node,
         step,
@@ -388,8 +629,45 @@ where
     .await
 }
 
-pub async fn load_latest_checkpoint<DB>(
+/// [`save_checkpoint_with_projections_for_table`] for the default `checkpoints` table.
+pub async fn save_checkpoint_with_projections<DB, S>(
+    pool: &Pool<DB>,
+    thread_id: &str,
+    node: &str,
+    step: i64,
+    created_at: &str,
+    state: &S,
+    enable_projections: bool,
+) -> Result<i64, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> i64: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> String: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> Option<String>: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c Pool<DB>: sqlx::Executor<'c, Database = DB>,
+    for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    usize: ColumnIndex<DB::Row>,
+    S: Serialize + ?Sized,
+{
+    save_checkpoint_with_projections_for_table(
+        pool,
+        &TableConfig::default(),
+        thread_id,
+        node,
+        step,
+        created_at,
+        state,
+        enable_projections,
+    )
+    .await
+}
+
+pub async fn load_latest_checkpoint_for_table<DB>(
     pool: &Pool<DB>,
+    table: &TableConfig,
     thread_id: &str,
 ) -> Result<Option<StoredCheckpoint>, CheckpointSqlError>
 where
@@ -404,9 +682,10 @@ where
     for<'r> Option<i64>: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
 {
     let select_sql = {
-        let mut query = QueryBuilder::<DB>::new(
-            "SELECT thread_id, seq, created_at, node, step, state_json, queue_json FROM checkpoints WHERE thread_id = ",
-        );
+        let mut query = QueryBuilder::<DB>::new(format!(
+            "SELECT thread_id, seq, created_at, node, step, state_json, queue_json FROM {} WHERE thread_id = ",
+            table.qualified_name()
+        ));
         query
             .push_bind(thread_id)
             .push(" ORDER BY seq DESC LIMIT 1");
@@ -441,8 +720,28 @@ where
     }))
 }
 
-pub async fn load_checkpoint<DB>(
+/// [`load_latest_checkpoint_for_table`] for the default `checkpoints` table.
+pub async fn load_latest_checkpoint<DB>(
+    pool: &Pool<DB>,
+    thread_id: &str,
+) -> Result<Option<StoredCheckpoint>, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c Pool<DB>: sqlx::Executor<'c, Database = DB>,
+    &'static str: ColumnIndex<DB::Row>,
+    for<'r> String: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'r> Option<String>: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'r> Option<i64>: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+{
+    load_latest_checkpoint_for_table(pool, &TableConfig::default(), thread_id).await
+}
+
+pub async fn load_checkpoint_for_table<DB>(
     pool: &Pool<DB>,
+    table: &TableConfig,
     thread_id: &str,
 ) -> Result<Option<Value>, CheckpointSqlError>
 where
@@ -456,7 +755,26 @@ where
     for<'r> Option<String>: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
     for<'r> Option<i64>: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
 {
-    Ok(load_latest_checkpoint(pool, thread_id)
+    Ok(load_latest_checkpoint_for_table(pool, table, thread_id)
         .await?
         .map(|checkpoint| checkpoint.state_json))
 }
+
+/// [`load_checkpoint_for_table`] for the default `checkpoints` table.
+pub async fn load_checkpoint<DB>(
+    pool: &Pool<DB>,
+    thread_id: &str,
+) -> Result<Option<Value>, CheckpointSqlError>
+where
+    DB: Database,
+    for<'q> &'q str: sqlx::Encode<'q, DB> + sqlx::Type<DB>,
+    for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    for<'c> &'c Pool<DB>: sqlx::Executor<'c, Database = DB>,
+    &'static str: ColumnIndex<DB::Row>,
+    for<'r> String: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'r> i64: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'r> Option<String>: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+    for<'r> Option<i64>: sqlx::Decode<'r, DB> + sqlx::Type<DB>,
+{
+    load_checkpoint_for_table(pool, &TableConfig::default(), thread_id).await
+}
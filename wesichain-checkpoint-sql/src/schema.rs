@@ -1,3 +1,9 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::error::CheckpointSqlError;
+
 pub const CHECKPOINTS_TABLE: &str = "checkpoints";
 pub const SESSIONS_TABLE: &str = "sessions";
 pub const MESSAGES_TABLE: &str = "messages";
@@ -48,3 +54,88 @@ pub const MIGRATION_STATEMENTS_SQL: [&str; 5] = [
     CREATE_MESSAGES_TABLE_SQL,
     CREATE_GRAPH_TRIPLES_TABLE_SQL,
 ];
+
+/// Statements creating the projection tables. Their names are fixed; only the
+/// checkpoints table follows [`TableConfig`].
+pub const PROJECTION_TABLES_SQL: [&str; 3] = [
+    CREATE_SESSIONS_TABLE_SQL,
+    CREATE_MESSAGES_TABLE_SQL,
+    CREATE_GRAPH_TRIPLES_TABLE_SQL,
+];
+
+/// Name of the checkpoints table, optionally qualified by a schema.
+///
+/// Both parts are interpolated into SQL, so they must be plain identifiers:
+/// an ASCII letter or underscore followed by up to 62 letters, digits or
+/// underscores. The schema is not created by migrations.
+///
+/// Only the checkpoints table is renamed. The projection tables
+/// ([`SESSIONS_TABLE`], [`MESSAGES_TABLE`], [`GRAPH_TRIPLES_TABLE`]) keep
+/// their fixed names in the default schema, so apps sharing a database must
+/// not both enable projections.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TableConfig {
+    schema: Option<String>,
+    table: String,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            table: CHECKPOINTS_TABLE.to_string(),
+        }
+    }
+}
+
+impl TableConfig {
+    pub fn new(table: impl Into<String>) -> Result<Self, CheckpointSqlError> {
+        Ok(Self {
+            schema: None,
+            table: validate_identifier(table.into())?,
+        })
+    }
+
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Result<Self, CheckpointSqlError> {
+        self.schema = Some(validate_identifier(schema.into())?);
+        Ok(self)
+    }
+
+    pub fn table(&self) -> &str {
+        &self.table
+    }
+
+    pub fn schema(&self) -> Option<&str> {
+        self.schema.as_deref()
+    }
+
+    /// The name to use in SQL, e.g. `checkpoints` or `app.checkpoints`.
+    pub fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{schema}.{}", self.table),
+            None => self.table.clone(),
+        }
+    }
+
+    /// [`CREATE_CHECKPOINTS_TABLE_SQL`] for this table.
+    pub fn create_table_sql(&self) -> String {
+        CREATE_CHECKPOINTS_TABLE_SQL.replacen(CHECKPOINTS_TABLE, &self.qualified_name(), 1)
+    }
+
+    /// [`ADD_CHECKPOINT_QUEUE_COLUMN_SQL`] for this table.
+    pub fn add_queue_column_sql(&self) -> String {
+        ADD_CHECKPOINT_QUEUE_COLUMN_SQL.replacen(CHECKPOINTS_TABLE, &self.qualified_name(), 1)
+    }
+}
+
+fn validate_identifier(identifier: String) -> Result<String, CheckpointSqlError> {
+    static IDENTIFIER: OnceLock<Regex> = OnceLock::new();
+    let pattern = IDENTIFIER.get_or_init(|| {
+        Regex::new("^[A-Za-z_][A-Za-z0-9_]{0,62}$").expect("valid identifier regex")
+    });
+    if pattern.is_match(&identifier) {
+        Ok(identifier)
+    } else {
+        Err(CheckpointSqlError::InvalidIdentifier(identifier))
+    }
+}
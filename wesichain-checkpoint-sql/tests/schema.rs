@@ -1,7 +1,7 @@
 use sqlx::Connection;
 use wesichain_checkpoint_sql::error::CheckpointSqlError;
 use wesichain_checkpoint_sql::schema::{
-    ADD_CHECKPOINT_QUEUE_COLUMN_SQL, CHECKPOINTS_TABLE, CREATE_CHECKPOINTS_TABLE_SQL,
+    TableConfig, ADD_CHECKPOINT_QUEUE_COLUMN_SQL, CHECKPOINTS_TABLE, CREATE_CHECKPOINTS_TABLE_SQL,
     CREATE_GRAPH_TRIPLES_TABLE_SQL, CREATE_MESSAGES_TABLE_SQL, CREATE_SESSIONS_TABLE_SQL,
     GRAPH_TRIPLES_TABLE, MESSAGES_TABLE, MIGRATION_STATEMENTS_SQL, SCHEMA_VERSION, SESSIONS_TABLE,
 };
@@ -107,3 +107,40 @@ async fn schema_migrations_execute_on_in_memory_sqlite() {
             .expect("migration statement should execute");
     }
 }
+
+#[test]
+fn table_config_qualifies_and_validates_names() {
+    let default = TableConfig::default();
+    assert_eq!(default.qualified_name(), CHECKPOINTS_TABLE);
+    assert_eq!(default.create_table_sql(), CREATE_CHECKPOINTS_TABLE_SQL);
+    assert_eq!(
+        default.add_queue_column_sql(),
+        ADD_CHECKPOINT_QUEUE_COLUMN_SQL
+    );
+
+    let custom = TableConfig::new("app_checkpoints")
+        .and_then(|table| table.with_schema("agents"))
+        .expect("plain identifiers should be accepted");
+    assert_eq!(custom.qualified_name(), "agents.app_checkpoints");
+    assert!(custom
+        .create_table_sql()
+        .starts_with("CREATE TABLE IF NOT EXISTS agents.app_checkpoints ("));
+
+    for invalid in [
+        "",
+        "1table",
+        "checkpoints; DROP TABLE x",
+        "a.b",
+        "tab le",
+        "\"x\"",
+    ] {
+        assert!(
+            matches!(
+                TableConfig::new(invalid),
+                Err(CheckpointSqlError::InvalidIdentifier(name)) if name == invalid
+            ),
+            "{invalid:?} should be rejected"
+        );
+    }
+    assert!(TableConfig::default().with_schema("public;").is_err());
+}
@@ -1,10 +1,11 @@
 use std::convert::TryFrom;
 
 use wesichain_checkpoint_sql::error::CheckpointSqlError;
-use wesichain_checkpoint_sql::migrations::run_migrations;
+use wesichain_checkpoint_sql::migrations::run_migrations_for_table;
 use wesichain_checkpoint_sql::ops::{
-    load_latest_checkpoint, save_checkpoint_with_projections_and_queue,
+    load_latest_checkpoint_for_table, save_checkpoint_with_projections_and_queue_for_table,
 };
+pub use wesichain_checkpoint_sql::schema::TableConfig;
 use wesichain_core::checkpoint::{Checkpoint, Checkpointer};
 use wesichain_core::state::{GraphState, StateSchema};
 use wesichain_core::WesichainError;
@@ -13,6 +14,7 @@ use wesichain_core::WesichainError;
 pub struct SqliteCheckpointer {
     pool: sqlx::SqlitePool,
     enable_projections: bool,
+    table: TableConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +22,8 @@ pub struct SqliteCheckpointerBuilder {
     database_url: String,
     max_connections: u32,
     enable_projections: bool,
+    shared_memory: Option<String>,
+    table: TableConfig,
 }
 
 impl SqliteCheckpointer {
@@ -28,12 +32,18 @@ impl SqliteCheckpointer {
             database_url: database_url.into(),
             max_connections: 1,
             enable_projections: false,
+            shared_memory: None,
+            table: TableConfig::default(),
         }
     }
 
     pub fn projections_enabled(&self) -> bool {
         self.enable_projections
     }
+
+    pub fn table(&self) -> &TableConfig {
+        &self.table
+    }
 }
 
 impl SqliteCheckpointerBuilder {
@@ -47,18 +57,53 @@ impl SqliteCheckpointerBuilder {
         self
     }
 
+    /// Store checkpoints in `table` instead of `checkpoints`, e.g. so that
+    /// several apps can share one database. Projection tables keep their
+    /// fixed names; see [`TableConfig`].
+    pub fn table(mut self, table: TableConfig) -> Self {
+        self.table = table;
+        self
+    }
+
+    /// Connect to the named shared-cache in-memory database
+    /// (`file:<name>?mode=memory&cache=shared`) instead of the builder URL.
+    ///
+    /// Unlike `sqlite::memory:`, every connection in the pool — and every
+    /// checkpointer built with the same `name` in this process — sees the same
+    /// database, so `max_connections` can be raised above 1. The pool keeps one
+    /// connection open for its whole lifetime; the database vanishes once the
+    /// last connection to it closes, i.e. when every such checkpointer is dropped.
+    pub fn shared_memory(mut self, name: &str) -> Self {
+        self.shared_memory = Some(name.to_string());
+        self
+    }
+
     pub async fn build(self) -> Result<SqliteCheckpointer, CheckpointSqlError> {
-        let pool = sqlx::sqlite::SqlitePoolOptions::new()
-            .max_connections(self.max_connections)
-            .connect(&self.database_url)
-            .await
-            .map_err(CheckpointSqlError::Connection)?;
+        let pool_options =
+            sqlx::sqlite::SqlitePoolOptions::new().max_connections(self.max_connections);
+        let pool = match &self.shared_memory {
+            Some(name) => {
+                let options = sqlx::sqlite::SqliteConnectOptions::new()
+                    .filename(format!("file:{name}"))
+                    .in_memory(true)
+                    .shared_cache(true);
+                pool_options
+                    .min_connections(1)
+                    .idle_timeout(None)
+                    .max_lifetime(None)
+                    .connect_with(options)
+                    .await
+            }
+            None => pool_options.connect(&self.database_url).await,
+        }
+        .map_err(CheckpointSqlError::Connection)?;
 
-        run_migrations(&pool).await?;
+        run_migrations_for_table(&pool, &self.table).await?;
 
         Ok(SqliteCheckpointer {
             pool,
             enable_projections: self.enable_projections,
+            table: self.table,
         })
     }
 }
@@ -87,8 +132,9 @@ impl<S: StateSchema> Checkpointer<S> for SqliteCheckpointer {
             let step = i64::try_from(checkpoint.step)
                 .map_err(|_| graph_checkpoint_error("checkpoint step does not fit into i64"))?;
 
-            save_checkpoint_with_projections_and_queue(
+            save_checkpoint_with_projections_and_queue_for_table(
                 &self.pool,
+                &self.table,
                 &checkpoint.thread_id,
                 &checkpoint.node,
                 step,
@@ -120,7 +166,7 @@ impl<S: StateSchema> Checkpointer<S> for SqliteCheckpointer {
         Self: 'async_trait,
     {
         Box::pin(async move {
-            let stored = load_latest_checkpoint(&self.pool, thread_id)
+            let stored = load_latest_checkpoint_for_table(&self.pool, &self.table, thread_id)
                 .await
                 .map_err(map_sql_error)?;
 
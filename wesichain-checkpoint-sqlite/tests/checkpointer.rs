@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 use wesichain_graph::{Checkpoint, Checkpointer, GraphState, StateSchema};
 
-use wesichain_checkpoint_sqlite::SqliteCheckpointer;
+use wesichain_checkpoint_sqlite::{SqliteCheckpointer, TableConfig};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
 struct DemoState {
@@ -104,3 +104,114 @@ async fn checkpointer_load_fails_when_required_columns_are_null() {
     drop(pool);
     let _ = std::fs::remove_file(db_path);
 }
+
+#[tokio::test]
+async fn shared_memory_checkpointers_see_the_same_database() {
+    let name = format!(
+        "shared-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time should move forward")
+            .as_nanos()
+    );
+    let first = SqliteCheckpointer::builder("sqlite::memory:")
+        .shared_memory(&name)
+        .max_connections(4)
+        .build()
+        .await
+        .expect("first shared-memory checkpointer should build");
+    let second = SqliteCheckpointer::builder("sqlite::memory:")
+        .shared_memory(&name)
+        .max_connections(4)
+        .build()
+        .await
+        .expect("second shared-memory checkpointer should build");
+
+    let saves = (0..4).map(|i| {
+        let checkpointer = if i % 2 == 0 { &first } else { &second };
+        let checkpoint = Checkpoint::new(
+            format!("thread-{i}"),
+            GraphState::new(DemoState { count: i }),
+            1,
+            "node".to_string(),
+            Vec::new(),
+        );
+        async move { checkpointer.save(&checkpoint).await }
+    });
+    for result in futures::future::join_all(saves).await {
+        result.expect("concurrent save should succeed");
+    }
+
+    for i in 0..4 {
+        let reader = if i % 2 == 0 { &second } else { &first };
+        let loaded: Checkpoint<DemoState> = reader
+            .load(&format!("thread-{i}"))
+            .await
+            .expect("checkpoint should load")
+            .expect("checkpoint written by the other handle should be visible");
+        assert_eq!(loaded.state.data.count, i);
+    }
+}
+
+#[tokio::test]
+async fn checkpointer_uses_configured_table_name() {
+    let name = format!(
+        "custom-table-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time should move forward")
+            .as_nanos()
+    );
+    let custom = SqliteCheckpointer::builder("sqlite::memory:")
+        .shared_memory(&name)
+        .table(TableConfig::new("app_checkpoints").expect("valid table name"))
+        .build()
+        .await
+        .expect("custom-table checkpointer should build");
+    let default = SqliteCheckpointer::builder("sqlite::memory:")
+        .shared_memory(&name)
+        .build()
+        .await
+        .expect("default checkpointer should build");
+    assert_eq!(custom.table().table(), "app_checkpoints");
+
+    let checkpoint = Checkpoint::new(
+        "thread-1".to_string(),
+        GraphState::new(DemoState { count: 5 }),
+        2,
+        "node-a".to_string(),
+        Vec::new(),
+    );
+    custom
+        .save(&checkpoint)
+        .await
+        .expect("checkpoint should save");
+
+    let loaded: Checkpoint<DemoState> = custom
+        .load("thread-1")
+        .await
+        .expect("checkpoint should load")
+        .expect("checkpoint should exist");
+    assert_eq!(loaded.state.data.count, 5);
+
+    let from_default: Option<Checkpoint<DemoState>> = default
+        .load("thread-1")
+        .await
+        .expect("default table should be readable");
+    assert!(from_default.is_none());
+
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .connect_with(
+            sqlx::sqlite::SqliteConnectOptions::new()
+                .filename(format!("file:{name}"))
+                .in_memory(true)
+                .shared_cache(true),
+        )
+        .await
+        .expect("direct shared-memory pool should connect");
+    let rows: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM app_checkpoints")
+        .fetch_one(&pool)
+        .await
+        .expect("custom table should exist");
+    assert_eq!(rows, 1);
+}
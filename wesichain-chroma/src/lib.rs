@@ -2,6 +2,8 @@
 
 use std::collections::HashMap;
 
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use chroma::client::{ChromaAuthMethod, ChromaHttpClientError, ChromaHttpClientOptions};
 use chroma::types::{
     BooleanOperator, CompositeExpression, IncludeList, MetadataComparison, MetadataExpression,
@@ -10,7 +12,10 @@ use chroma::types::{
 };
 use chroma::{ChromaCollection, ChromaHttpClient};
 use thiserror::Error;
-use wesichain_core::{Document, MetadataFilter, SearchResult, StoreError, Value, VectorStore};
+use wesichain_core::{
+    find_duplicate_id, Document, MetadataFilter, SearchResult, StoreError, Value, VectorStore,
+    WriteMode,
+};
 
 #[derive(Debug, Error)]
 pub enum ChromaStoreError {
@@ -26,6 +31,8 @@ pub enum ChromaStoreError {
     UnsupportedMetadataValue { key: String, reason: String },
     #[error("unsupported metadata filter: {0}")]
     UnsupportedFilter(String),
+    #[error("invalid credentials: {0}")]
+    InvalidCredentials(String),
 }
 
 impl From<ChromaStoreError> for StoreError {
@@ -39,33 +46,239 @@ impl From<ChromaStoreError> for StoreError {
     }
 }
 
-pub struct ChromaVectorStore {
-    collection: ChromaCollection,
+/// Distance function a Chroma collection was created with (`hnsw:space`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DistanceSpace {
+    /// Squared euclidean distance; Chroma's default.
+    #[default]
+    L2,
+    /// Cosine distance, `1 - cos(a, b)`.
+    Cosine,
+    /// Inner product distance, `1 - a·b`.
+    InnerProduct,
 }
 
-impl ChromaVectorStore {
-    pub async fn new(
-        endpoint: impl AsRef<str>,
-        collection_name: impl Into<String>,
-    ) -> Result<Self, ChromaStoreError> {
+/// Collection metadata key holding the collection's [`DistanceSpace`].
+pub const HNSW_SPACE_KEY: &str = "hnsw:space";
+
+impl DistanceSpace {
+    /// Parse an `hnsw:space` value: `l2`, `cosine` or `ip`.
+    pub fn from_hnsw_space(space: &str) -> Option<Self> {
+        match space {
+            "l2" => Some(DistanceSpace::L2),
+            "cosine" => Some(DistanceSpace::Cosine),
+            "ip" => Some(DistanceSpace::InnerProduct),
+            _ => None,
+        }
+    }
+}
+
+/// How raw Chroma distances are turned into [`SearchResult::score`] values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScoreMode {
+    /// `-distance`. Higher is better, but scores are negative and depend on the space.
+    #[default]
+    RawNegDistance,
+    /// Cosine similarity in `[-1, 1]`. For `l2` and `ip` spaces this assumes
+    /// unit-normalized embeddings.
+    CosineSimilarity,
+    /// Similarity squashed into `[0, 1]`, comparable across spaces and stores.
+    NormalizedZeroToOne,
+}
+
+impl ScoreMode {
+    /// Convert a distance reported by a collection using `space` into a score.
+    pub fn score(self, space: DistanceSpace, distance: f32) -> f32 {
+        match self {
+            ScoreMode::RawNegDistance => -distance,
+            ScoreMode::CosineSimilarity => cosine_similarity(space, distance),
+            ScoreMode::NormalizedZeroToOne => match space {
+                DistanceSpace::L2 => 1.0 / (1.0 + distance.max(0.0)),
+                DistanceSpace::Cosine | DistanceSpace::InnerProduct => {
+                    ((1.0 + cosine_similarity(space, distance)) / 2.0).clamp(0.0, 1.0)
+                }
+            },
+        }
+    }
+}
+
+fn cosine_similarity(space: DistanceSpace, distance: f32) -> f32 {
+    let similarity = match space {
+        // For unit vectors ||a - b||^2 = 2 - 2cos(a, b).
+        DistanceSpace::L2 => 1.0 - distance / 2.0,
+        DistanceSpace::Cosine | DistanceSpace::InnerProduct => 1.0 - distance,
+    };
+    similarity.clamp(-1.0, 1.0)
+}
+
+/// Credentials sent with every request to the Chroma server.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub enum ChromaAuth {
+    #[default]
+    None,
+    /// API token sent as `x-chroma-token`, as used by Chroma Cloud and
+    /// token-authenticated servers.
+    Token(String),
+    /// HTTP basic auth, e.g. for a reverse-proxied instance.
+    Basic { username: String, password: String },
+}
+
+impl ChromaAuth {
+    /// Token credentials from a `CHROMA_API_KEY` value; unset or empty means
+    /// no credentials.
+    pub fn from_env_value(api_key: Option<String>) -> Self {
+        api_key
+            .filter(|key| !key.is_empty())
+            .map(ChromaAuth::Token)
+            .unwrap_or_default()
+    }
+
+    /// The header name and value carrying these credentials, if any.
+    pub fn header(&self) -> Option<(&'static str, String)> {
+        match self {
+            ChromaAuth::None => None,
+            ChromaAuth::Token(token) => Some(("x-chroma-token", token.clone())),
+            ChromaAuth::Basic { username, password } => Some((
+                "authorization",
+                format!(
+                    "Basic {}",
+                    STANDARD.encode(format!("{username}:{password}"))
+                ),
+            )),
+        }
+    }
+
+    fn auth_method(&self) -> Result<ChromaAuthMethod, ChromaStoreError> {
+        let Some((name, value)) = self.header() else {
+            return Ok(ChromaAuthMethod::None);
+        };
+        let mut value = reqwest::header::HeaderValue::from_str(&value)
+            .map_err(|err| ChromaStoreError::InvalidCredentials(err.to_string()))?;
+        value.set_sensitive(true);
+        Ok(ChromaAuthMethod::HeaderAuth {
+            header: reqwest::header::HeaderName::from_static(name),
+            value,
+        })
+    }
+}
+
+impl std::fmt::Debug for ChromaAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChromaAuth::None => f.write_str("None"),
+            ChromaAuth::Token(_) => f.debug_tuple("Token").field(&"<redacted>").finish(),
+            ChromaAuth::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+        }
+    }
+}
+
+/// Builder for [`ChromaVectorStore`] connections.
+///
+/// Without explicit credentials, a `CHROMA_API_KEY` environment variable is
+/// used as a token. Tenant and database default to the `CHROMA_TENANT` and
+/// `CHROMA_DATABASE` environment variables, then Chroma's defaults.
+#[derive(Debug, Clone)]
+pub struct ChromaStoreBuilder {
+    endpoint: String,
+    collection_name: String,
+    auth: Option<ChromaAuth>,
+}
+
+impl ChromaStoreBuilder {
+    pub fn new(endpoint: impl Into<String>, collection_name: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            collection_name: collection_name.into(),
+            auth: None,
+        }
+    }
+
+    /// Authenticate with an API token.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.auth = Some(ChromaAuth::Token(token.into()));
+        self
+    }
+
+    /// Authenticate with HTTP basic auth.
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.auth = Some(ChromaAuth::Basic {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Connect without credentials, ignoring `CHROMA_API_KEY`.
+    pub fn without_auth(mut self) -> Self {
+        self.auth = Some(ChromaAuth::None);
+        self
+    }
+
+    /// Credentials the store will connect with, after the environment fallback.
+    pub fn auth(&self) -> ChromaAuth {
+        match &self.auth {
+            Some(auth) => auth.clone(),
+            None => ChromaAuth::from_env_value(std::env::var("CHROMA_API_KEY").ok()),
+        }
+    }
+
+    /// Client options for the configured endpoint and credentials.
+    pub fn client_options(&self) -> Result<ChromaHttpClientOptions, ChromaStoreError> {
         let tenant_id =
             std::env::var("CHROMA_TENANT").unwrap_or_else(|_| "default_tenant".to_string());
         let database_name =
             std::env::var("CHROMA_DATABASE").unwrap_or_else(|_| "default_database".to_string());
 
-        let options = ChromaHttpClientOptions {
-            endpoint: endpoint
-                .as_ref()
+        Ok(ChromaHttpClientOptions {
+            endpoint: self
+                .endpoint
                 .parse::<reqwest::Url>()
                 .map_err(|err| ChromaStoreError::InvalidEndpoint(err.to_string()))?,
-            auth_method: ChromaAuthMethod::None,
+            auth_method: self.auth().auth_method()?,
             tenant_id: Some(tenant_id),
             database_name: Some(database_name),
             ..Default::default()
-        };
+        })
+    }
+
+    pub async fn build(self) -> Result<ChromaVectorStore, ChromaStoreError> {
+        let client = ChromaHttpClient::new(self.client_options()?);
+        ChromaVectorStore::with_client(client, self.collection_name).await
+    }
+}
+
+pub struct ChromaVectorStore {
+    collection: ChromaCollection,
+    score_mode: ScoreMode,
+    distance_space: DistanceSpace,
+}
+
+impl ChromaVectorStore {
+    /// Connect without authentication. Use [`ChromaVectorStore::builder`] for
+    /// secured servers.
+    pub async fn new(
+        endpoint: impl AsRef<str>,
+        collection_name: impl Into<String>,
+    ) -> Result<Self, ChromaStoreError> {
+        ChromaStoreBuilder::new(endpoint.as_ref(), collection_name)
+            .without_auth()
+            .build()
+            .await
+    }
 
-        let client = ChromaHttpClient::new(options);
-        Self::with_client(client, collection_name).await
+    pub fn builder(
+        endpoint: impl Into<String>,
+        collection_name: impl Into<String>,
+    ) -> ChromaStoreBuilder {
+        ChromaStoreBuilder::new(endpoint, collection_name)
     }
 
     pub async fn with_client(
@@ -75,12 +288,48 @@ impl ChromaVectorStore {
         let collection = client
             .get_or_create_collection(collection_name.into(), None, None)
             .await?;
-        Ok(Self { collection })
+        let distance_space = collection
+            .metadata()
+            .as_ref()
+            .and_then(|metadata| metadata.get(HNSW_SPACE_KEY))
+            .and_then(|value| match value {
+                MetadataValue::Str(space) => DistanceSpace::from_hnsw_space(space),
+                _ => None,
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            collection,
+            score_mode: ScoreMode::default(),
+            distance_space,
+        })
+    }
+
+    /// Select how distances are converted into scores. Defaults to
+    /// [`ScoreMode::RawNegDistance`].
+    pub fn with_score_mode(mut self, score_mode: ScoreMode) -> Self {
+        self.score_mode = score_mode;
+        self
+    }
+
+    /// Override the distance space used to convert scores. Defaults to the
+    /// collection's `hnsw:space` metadata, or [`DistanceSpace::L2`] (Chroma's
+    /// default) when the collection doesn't set it there.
+    pub fn with_distance_space(mut self, distance_space: DistanceSpace) -> Self {
+        self.distance_space = distance_space;
+        self
     }
 
     pub fn collection_name(&self) -> &str {
         self.collection.name()
     }
+
+    pub fn score_mode(&self) -> ScoreMode {
+        self.score_mode
+    }
+
+    pub fn distance_space(&self) -> DistanceSpace {
+        self.distance_space
+    }
 }
 
 #[async_trait::async_trait]
@@ -134,6 +383,27 @@ impl VectorStore for ChromaVectorStore {
             .map(|_| ())
     }
 
+    async fn add_mode(&self, docs: Vec<Document>, mode: WriteMode) -> Result<(), StoreError> {
+        if mode == WriteMode::InsertOnly && !docs.is_empty() {
+            if let Some(id) = find_duplicate_id(&docs) {
+                return Err(StoreError::DuplicateId(id.to_string()));
+            }
+
+            let ids = docs.iter().map(|doc| doc.id.clone()).collect::<Vec<_>>();
+            let existing = self
+                .collection
+                .get(Some(ids), None, None, None, Some(IncludeList::empty()))
+                .await
+                .map_err(ChromaStoreError::from)
+                .map_err(StoreError::from)?;
+            if let Some(id) = existing.ids.into_iter().next() {
+                return Err(StoreError::DuplicateId(id));
+            }
+        }
+
+        self.add(docs).await
+    }
+
     async fn search(
         &self,
         query_embedding: &[f32],
@@ -164,7 +434,11 @@ impl VectorStore for ChromaVectorStore {
             .map_err(ChromaStoreError::from)
             .map_err(StoreError::from)?;
 
-        Ok(query_response_to_results(response))
+        Ok(query_response_to_results(
+            response,
+            self.score_mode,
+            self.distance_space,
+        ))
     }
 
     async fn delete(&self, ids: &[String]) -> Result<(), StoreError> {
@@ -181,7 +455,13 @@ impl VectorStore for ChromaVectorStore {
     }
 }
 
-fn query_response_to_results(response: QueryResponse) -> Vec<SearchResult> {
+/// Map the first query's hits to search results, converting each distance
+/// with `score_mode`. Hits without a distance score `0.0`.
+pub fn query_response_to_results(
+    response: QueryResponse,
+    score_mode: ScoreMode,
+    distance_space: DistanceSpace,
+) -> Vec<SearchResult> {
     let ids = response.ids.into_iter().next().unwrap_or_default();
     let documents = response
         .documents
@@ -217,7 +497,7 @@ fn query_response_to_results(response: QueryResponse) -> Vec<SearchResult> {
                 .get(idx)
                 .copied()
                 .flatten()
-                .map(|distance| -distance)
+                .map(|distance| score_mode.score(distance_space, distance))
                 .unwrap_or(0.0);
 
             SearchResult {
@@ -233,6 +513,14 @@ fn query_response_to_results(response: QueryResponse) -> Vec<SearchResult> {
         .collect()
 }
 
+/// Translate a [`MetadataFilter`] into the `where` clause sent with queries.
+///
+/// [`MetadataFilter::Raw`] values are parsed as Chroma's JSON `where` syntax
+/// (including `#document` conditions) without further checks.
+pub fn to_chroma_where(filter: &MetadataFilter) -> Result<Where, ChromaStoreError> {
+    filter_to_where(filter)
+}
+
 fn filter_to_where(filter: &MetadataFilter) -> Result<Where, ChromaStoreError> {
     match filter {
         MetadataFilter::Eq(key, value) => Ok(Where::Metadata(MetadataExpression {
@@ -314,6 +602,16 @@ fn filter_to_where(filter: &MetadataFilter) -> Result<Where, ChromaStoreError> {
                 children,
             }))
         }
+        MetadataFilter::Exists(key) | MetadataFilter::NotExists(key) => {
+            Err(ChromaStoreError::UnsupportedFilter(format!(
+                "key presence filter for '{key}' is not supported by Chroma"
+            )))
+        }
+        MetadataFilter::Raw(value) => serde_json::from_value(value.clone()).map_err(|err| {
+            ChromaStoreError::UnsupportedFilter(format!(
+                "raw filter is not a valid where clause: {err}"
+            ))
+        }),
     }
 }
 
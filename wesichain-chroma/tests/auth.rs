@@ -0,0 +1,76 @@
+use wesichain_chroma::{ChromaAuth, ChromaStoreBuilder, ChromaVectorStore};
+
+#[test]
+fn token_auth_sends_chroma_token_header() {
+    let builder = ChromaVectorStore::builder("http://localhost:8000", "docs").with_token("ck-123");
+
+    assert_eq!(builder.auth(), ChromaAuth::Token("ck-123".to_string()));
+    assert_eq!(
+        builder.auth().header(),
+        Some(("x-chroma-token", "ck-123".to_string()))
+    );
+    builder.client_options().expect("valid options");
+}
+
+#[test]
+fn basic_auth_sends_encoded_authorization_header() {
+    let builder =
+        ChromaStoreBuilder::new("http://localhost:8000", "docs").with_basic_auth("admin", "s3cret");
+
+    assert_eq!(
+        builder.auth(),
+        ChromaAuth::Basic {
+            username: "admin".to_string(),
+            password: "s3cret".to_string(),
+        }
+    );
+    assert_eq!(
+        builder.auth().header(),
+        Some(("authorization", "Basic YWRtaW46czNjcmV0".to_string()))
+    );
+    builder.client_options().expect("valid options");
+}
+
+#[test]
+fn api_key_env_is_a_fallback_for_unset_auth() {
+    assert_eq!(
+        ChromaAuth::from_env_value(Some("from-env".to_string())),
+        ChromaAuth::Token("from-env".to_string())
+    );
+    assert_eq!(
+        ChromaAuth::from_env_value(Some(String::new())),
+        ChromaAuth::None
+    );
+    assert_eq!(ChromaAuth::from_env_value(None), ChromaAuth::None);
+
+    let builder = ChromaStoreBuilder::new("http://localhost:8000", "docs");
+    let explicit = builder.clone().with_token("explicit");
+    let disabled = builder.without_auth();
+
+    assert_eq!(explicit.auth(), ChromaAuth::Token("explicit".to_string()));
+    assert_eq!(disabled.auth(), ChromaAuth::None);
+    assert_eq!(disabled.auth().header(), None);
+}
+
+#[test]
+fn debug_output_redacts_secrets() {
+    let auth = ChromaAuth::Basic {
+        username: "admin".to_string(),
+        password: "s3cret".to_string(),
+    };
+    let debug = format!("{auth:?}");
+
+    assert!(debug.contains("admin"));
+    assert!(!debug.contains("s3cret"));
+    assert!(!format!("{:?}", ChromaAuth::Token("ck-123".to_string())).contains("ck-123"));
+}
+
+#[test]
+fn invalid_endpoint_is_rejected() {
+    let err = ChromaStoreBuilder::new("not a url", "docs")
+        .client_options()
+        .err()
+        .expect("endpoint should be rejected");
+
+    assert!(err.to_string().contains("invalid endpoint URL"));
+}
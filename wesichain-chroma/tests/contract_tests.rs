@@ -5,7 +5,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde_json::json;
 use wesichain_chroma::ChromaVectorStore;
-use wesichain_core::{Document, Value, VectorStore};
+use wesichain_core::{Document, StoreError, Value, VectorStore, WriteMode};
 use wesichain_retrieval::InMemoryVectorStore;
 
 static COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -161,6 +161,32 @@ async fn contract_duplicate_id_overwrites(store: &dyn VectorStore) {
     assert_eq!(results[0].document.content, "new");
 }
 
+async fn contract_insert_only_rejects_existing_id(store: &dyn VectorStore) {
+    store
+        .add(vec![build_doc("doc1", "old", vec![1.0, 0.0], json!({}))])
+        .await
+        .expect("first add should succeed");
+
+    let err = store
+        .add_mode(
+            vec![
+                build_doc("doc2", "fresh", vec![0.5, 0.5], json!({})),
+                build_doc("doc1", "new", vec![0.0, 1.0], json!({})),
+            ],
+            WriteMode::InsertOnly,
+        )
+        .await
+        .expect_err("insert-only add of an existing id should fail");
+    assert!(matches!(err, StoreError::DuplicateId(ref id) if id == "doc1"));
+
+    let results = store
+        .search(&[1.0, 0.0], 5, None)
+        .await
+        .expect("search should succeed");
+    assert_eq!(results.len(), 1, "conflicting batch must not be written");
+    assert_eq!(results[0].document.content, "old");
+}
+
 async fn contract_metadata_preserved(store: &dyn VectorStore) {
     store
         .add(vec![build_doc(
@@ -221,6 +247,15 @@ async fn contract_chroma_duplicate_id_overwrites() {
     contract_duplicate_id_overwrites(store.as_ref()).await;
 }
 
+#[tokio::test]
+async fn contract_chroma_insert_only_rejects_existing_id() {
+    if !chroma_contract_enabled() {
+        return;
+    }
+    let store = make_chroma_store("insert_only").await;
+    contract_insert_only_rejects_existing_id(store.as_ref()).await;
+}
+
 #[tokio::test]
 async fn contract_chroma_metadata_preserved() {
     if !chroma_contract_enabled() {
@@ -254,6 +289,12 @@ async fn contract_inmemory_duplicate_id_overwrites() {
     contract_duplicate_id_overwrites(store.as_ref()).await;
 }
 
+#[tokio::test]
+async fn contract_inmemory_insert_only_rejects_existing_id() {
+    let store = make_in_memory_store();
+    contract_insert_only_rejects_existing_id(store.as_ref()).await;
+}
+
 #[tokio::test]
 async fn contract_inmemory_metadata_preserved() {
     let store = make_in_memory_store();
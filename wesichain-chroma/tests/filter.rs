@@ -0,0 +1,20 @@
+use serde_json::json;
+use wesichain_chroma::{to_chroma_where, ChromaStoreError};
+use wesichain_core::MetadataFilter;
+
+#[test]
+fn raw_filter_round_trips_unchanged() {
+    let raw = json!({"source": {"$ne": "draft"}});
+
+    let clause = to_chroma_where(&MetadataFilter::Raw(raw.clone())).expect("raw filter is valid");
+
+    assert_eq!(serde_json::to_value(clause).unwrap(), raw);
+}
+
+#[test]
+fn rejects_malformed_raw_filter() {
+    let err = to_chroma_where(&MetadataFilter::Raw(json!(["not", "a", "clause"])))
+        .expect_err("arrays are not where clauses");
+
+    assert!(matches!(err, ChromaStoreError::UnsupportedFilter(_)));
+}
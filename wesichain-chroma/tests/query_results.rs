@@ -0,0 +1,74 @@
+use chroma::types::QueryResponse;
+use serde_json::json;
+use wesichain_chroma::{query_response_to_results, DistanceSpace, ScoreMode};
+
+fn response(value: serde_json::Value) -> QueryResponse {
+    serde_json::from_value(value).expect("query response should deserialize")
+}
+
+#[test]
+fn maps_ids_documents_metadata_and_scores() {
+    let response = response(json!({
+        "ids": [["a", "b"]],
+        "documents": [["alpha", null]],
+        "metadatas": [[{"source": "wiki", "page": 3}, null]],
+        "distances": [[0.2, 1.0]],
+        "include": ["documents", "metadatas", "distances"]
+    }));
+
+    let results =
+        query_response_to_results(response, ScoreMode::CosineSimilarity, DistanceSpace::Cosine);
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].document.id, "a");
+    assert_eq!(results[0].document.content, "alpha");
+    assert_eq!(results[0].document.metadata["source"], json!("wiki"));
+    assert_eq!(results[0].document.metadata["page"], json!(3));
+    assert!(results[0].document.embedding.is_none());
+    assert!((results[0].score - 0.8).abs() < 1e-6);
+
+    assert_eq!(results[1].document.id, "b");
+    assert_eq!(results[1].document.content, "");
+    assert!(results[1].document.metadata.is_empty());
+    assert!(results[1].score.abs() < 1e-6);
+}
+
+#[test]
+fn missing_distances_score_zero() {
+    let response = response(json!({
+        "ids": [["a"]],
+        "documents": [["alpha"]],
+        "include": ["documents"]
+    }));
+
+    let results = query_response_to_results(response, ScoreMode::RawNegDistance, DistanceSpace::L2);
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].score, 0.0);
+}
+
+#[test]
+fn empty_response_has_no_results() {
+    let response = response(json!({"ids": [], "include": []}));
+
+    let results = query_response_to_results(response, ScoreMode::RawNegDistance, DistanceSpace::L2);
+
+    assert!(results.is_empty());
+}
+
+#[test]
+fn parses_hnsw_space_values() {
+    assert_eq!(
+        DistanceSpace::from_hnsw_space("l2"),
+        Some(DistanceSpace::L2)
+    );
+    assert_eq!(
+        DistanceSpace::from_hnsw_space("cosine"),
+        Some(DistanceSpace::Cosine)
+    );
+    assert_eq!(
+        DistanceSpace::from_hnsw_space("ip"),
+        Some(DistanceSpace::InnerProduct)
+    );
+    assert_eq!(DistanceSpace::from_hnsw_space("manhattan"), None);
+}
@@ -0,0 +1,82 @@
+use wesichain_chroma::{DistanceSpace, ScoreMode};
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn default_mode_keeps_negated_distance() {
+    assert_eq!(ScoreMode::default(), ScoreMode::RawNegDistance);
+    assert_eq!(DistanceSpace::default(), DistanceSpace::L2);
+
+    for space in [
+        DistanceSpace::L2,
+        DistanceSpace::Cosine,
+        DistanceSpace::InnerProduct,
+    ] {
+        assert_close(ScoreMode::RawNegDistance.score(space, 0.25), -0.25);
+        assert_close(ScoreMode::RawNegDistance.score(space, 1.5), -1.5);
+    }
+}
+
+#[test]
+fn cosine_similarity_mode_converts_each_space() {
+    let mode = ScoreMode::CosineSimilarity;
+
+    assert_close(mode.score(DistanceSpace::Cosine, 0.0), 1.0);
+    assert_close(mode.score(DistanceSpace::Cosine, 0.4), 0.6);
+    assert_close(mode.score(DistanceSpace::Cosine, 2.0), -1.0);
+
+    assert_close(mode.score(DistanceSpace::InnerProduct, 0.1), 0.9);
+
+    // Unit vectors: squared L2 of 1.0 corresponds to a 60 degree angle.
+    assert_close(mode.score(DistanceSpace::L2, 0.0), 1.0);
+    assert_close(mode.score(DistanceSpace::L2, 1.0), 0.5);
+    assert_close(mode.score(DistanceSpace::L2, 4.0), -1.0);
+}
+
+#[test]
+fn normalized_mode_stays_within_unit_interval() {
+    let mode = ScoreMode::NormalizedZeroToOne;
+
+    assert_close(mode.score(DistanceSpace::Cosine, 0.0), 1.0);
+    assert_close(mode.score(DistanceSpace::Cosine, 1.0), 0.5);
+    assert_close(mode.score(DistanceSpace::Cosine, 2.0), 0.0);
+
+    assert_close(mode.score(DistanceSpace::L2, 0.0), 1.0);
+    assert_close(mode.score(DistanceSpace::L2, 3.0), 0.25);
+
+    // Inner product distances can fall outside [0, 2] for non-normalized vectors.
+    assert_close(mode.score(DistanceSpace::InnerProduct, -5.0), 1.0);
+    assert_close(mode.score(DistanceSpace::InnerProduct, 5.0), 0.0);
+
+    for distance in [0.0_f32, 0.3, 0.9, 1.7] {
+        for space in [
+            DistanceSpace::L2,
+            DistanceSpace::Cosine,
+            DistanceSpace::InnerProduct,
+        ] {
+            let score = mode.score(space, distance);
+            assert!(
+                (0.0..=1.0).contains(&score),
+                "{space:?} {distance} -> {score}"
+            );
+        }
+    }
+}
+
+#[test]
+fn normalized_scores_preserve_distance_ordering() {
+    for space in [
+        DistanceSpace::L2,
+        DistanceSpace::Cosine,
+        DistanceSpace::InnerProduct,
+    ] {
+        let near = ScoreMode::NormalizedZeroToOne.score(space, 0.2);
+        let far = ScoreMode::NormalizedZeroToOne.score(space, 0.8);
+        assert!(near > far, "{space:?}: {near} <= {far}");
+    }
+}
@@ -421,6 +421,7 @@ async fn main() -> anyhow::Result<()> {
         temperature: None,
         max_tokens: Some(256),
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let resp = llm.invoke(req).await?;
@@ -42,6 +42,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockLlm {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
     }
 
@@ -98,6 +99,7 @@ async fn end_to_end_flow() {
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     // Bind tools to the request (simulating llm.bind(tools))
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data")]
@@ -8,6 +9,10 @@ pub enum AgentEvent {
         message: String,
         step: usize,
         thread_id: String,
+        /// Trace of the run that emitted this event, when callbacks are
+        /// configured, for linking the event to its trace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        trace_id: Option<Uuid>,
     },
     Thought {
         content: String,
@@ -19,22 +24,49 @@ pub enum AgentEvent {
         tool_name: String,
         input: serde_json::Value,
         step: usize,
+        /// Trace of the run that emitted this event, when callbacks are
+        /// configured, for linking the event to its trace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        trace_id: Option<Uuid>,
     },
     Observation {
         id: String,
         tool_name: String,
         output: serde_json::Value,
         step: usize,
+        /// Trace of the run that emitted this event, when callbacks are
+        /// configured, for linking the event to its trace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        trace_id: Option<Uuid>,
+    },
+    /// Incremental answer content, emitted as the model streams it. The
+    /// concatenated tokens of a run equal the following `Final` content.
+    Token {
+        content: String,
+        step: usize,
+        thread_id: String,
+        /// Trace of the run that emitted this event, when callbacks are
+        /// configured, for linking the event to its trace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        trace_id: Option<Uuid>,
     },
     Final {
         content: String,
         step: usize,
+        /// Trace of the run that emitted this event, when callbacks are
+        /// configured, for linking the event to its trace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        trace_id: Option<Uuid>,
     },
     Error {
         message: String,
         step: usize,
         recoverable: bool,
         source: Option<String>,
+        /// Trace of the run that emitted this event, when callbacks are
+        /// configured, for linking the event to its trace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        trace_id: Option<Uuid>,
     },
     Metadata {
         key: String,
@@ -49,6 +81,7 @@ impl AgentEvent {
             | Self::Thought { step, .. }
             | Self::ToolCall { step, .. }
             | Self::Observation { step, .. }
+            | Self::Token { step, .. }
             | Self::Final { step, .. }
             | Self::Error { step, .. } => Some(*step),
             Self::Metadata { .. } => None,
@@ -57,8 +90,22 @@ impl AgentEvent {
 
     pub fn thread_id(&self) -> Option<&str> {
         match self {
-            Self::Status { thread_id, .. } => Some(thread_id.as_str()),
+            Self::Status { thread_id, .. } | Self::Token { thread_id, .. } => {
+                Some(thread_id.as_str())
+            }
             _ => None,
         }
     }
+
+    pub fn trace_id(&self) -> Option<Uuid> {
+        match self {
+            Self::Status { trace_id, .. }
+            | Self::ToolCall { trace_id, .. }
+            | Self::Observation { trace_id, .. }
+            | Self::Token { trace_id, .. }
+            | Self::Final { trace_id, .. }
+            | Self::Error { trace_id, .. } => *trace_id,
+            Self::Thought { .. } | Self::Metadata { .. } => None,
+        }
+    }
 }
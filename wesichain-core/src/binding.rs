@@ -13,6 +13,42 @@ pub trait Bindable: Sized + Send + Sync + 'static {
     fn bind(&mut self, args: crate::Value) -> Result<(), WesichainError>;
 }
 
+/// Merges the bound keys into an object input. Arrays present in both are
+/// concatenated, so bound `tools` extend those of a serialized
+/// [`LlmRequest`](crate::LlmRequest); other bound keys overwrite the input's.
+/// Non-object inputs or arguments are an error.
+impl Bindable for crate::Value {
+    fn bind(&mut self, args: crate::Value) -> Result<(), WesichainError> {
+        let bound = match args {
+            crate::Value::Object(bound) => bound,
+            other => {
+                return Err(WesichainError::Custom(format!(
+                    "bound arguments must be a JSON object, got {other}"
+                )))
+            }
+        };
+        let fields = match self {
+            crate::Value::Object(fields) => fields,
+            other => {
+                return Err(WesichainError::Custom(format!(
+                    "cannot bind arguments to a non-object input, got {other}"
+                )))
+            }
+        };
+        for (key, value) in bound {
+            match (fields.get_mut(&key), value) {
+                (Some(crate::Value::Array(existing)), crate::Value::Array(extra)) => {
+                    existing.extend(extra)
+                }
+                (_, value) => {
+                    fields.insert(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 /// A Runnable that has arguments bound to it.
 pub struct RunnableBinding<R, Input, Output> {
     pub(crate) bound: R,
@@ -59,4 +95,11 @@ where
 
         self.bound.stream(input)
     }
+
+    fn to_serializable(&self) -> Option<crate::serde::SerializableRunnable> {
+        Some(crate::serde::SerializableRunnable::Binding {
+            inner: Box::new(self.bound.to_serializable()?),
+            bound: self.args.clone(),
+        })
+    }
 }
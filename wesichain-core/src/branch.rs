@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use crate::serde::{SerializableBranch, SerializableRunnable};
+use crate::{Runnable, StreamEvent, WesichainError};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+
+type Predicate<Input> = Box<dyn Fn(&Input) -> bool + Send + Sync>;
+
+struct Branch<Input, Output> {
+    label: String,
+    predicate: Predicate<Input>,
+    runnable: Arc<dyn Runnable<Input, Output> + Send + Sync>,
+}
+
+/// Runnable that routes each input to the first branch whose predicate
+/// matches it, or to the default runnable when none do.
+///
+/// Predicates are tried in the order branches were added. Only the chosen
+/// runnable is invoked or streamed.
+pub struct RunnableBranch<Input, Output> {
+    branches: Vec<Branch<Input, Output>>,
+    default: Arc<dyn Runnable<Input, Output> + Send + Sync>,
+}
+
+impl<Input, Output> RunnableBranch<Input, Output> {
+    pub fn new(default: Arc<dyn Runnable<Input, Output> + Send + Sync>) -> Self {
+        Self {
+            branches: Vec::new(),
+            default,
+        }
+    }
+
+    /// Add a branch, tried after those already added.
+    ///
+    /// Predicates can't be serialized, so `label` stands in for this one in
+    /// [`SerializableRunnable::Branch`].
+    pub fn branch<F>(
+        mut self,
+        label: impl Into<String>,
+        predicate: F,
+        runnable: Arc<dyn Runnable<Input, Output> + Send + Sync>,
+    ) -> Self
+    where
+        F: Fn(&Input) -> bool + Send + Sync + 'static,
+    {
+        self.branches.push(Branch {
+            label: label.into(),
+            predicate: Box::new(predicate),
+            runnable,
+        });
+        self
+    }
+
+    fn select(&self, input: &Input) -> &Arc<dyn Runnable<Input, Output> + Send + Sync> {
+        self.branches
+            .iter()
+            .find(|branch| (branch.predicate)(input))
+            .map_or(&self.default, |branch| &branch.runnable)
+    }
+}
+
+#[async_trait]
+impl<Input, Output> Runnable<Input, Output> for RunnableBranch<Input, Output>
+where
+    Input: Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    async fn invoke(&self, input: Input) -> Result<Output, WesichainError> {
+        self.select(&input).invoke(input).await
+    }
+
+    fn stream(&self, input: Input) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        self.select(&input).stream(input)
+    }
+
+    fn to_serializable(&self) -> Option<SerializableRunnable> {
+        let mut branches = Vec::with_capacity(self.branches.len());
+        for branch in &self.branches {
+            branches.push(SerializableBranch {
+                label: branch.label.clone(),
+                runnable: branch.runnable.to_serializable()?,
+            });
+        }
+        Some(SerializableRunnable::Branch {
+            branches,
+            default: Box::new(self.default.to_serializable()?),
+        })
+    }
+}
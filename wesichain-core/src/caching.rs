@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::stream::BoxStream;
+use serde::Serialize;
+use tokio::time::Instant;
+
+use crate::{Runnable, StreamEvent, WesichainError};
+
+type ShouldCacheFn<Input> = dyn Fn(&Input) -> bool + Send + Sync;
+
+struct CacheEntry<Output> {
+    output: Output,
+    inserted: Instant,
+    last_used: u64,
+}
+
+struct LruCache<Output> {
+    entries: HashMap<String, CacheEntry<Output>>,
+    tick: u64,
+}
+
+/// Memoizes a runnable's `invoke` results in a bounded in-memory LRU. Built
+/// with [`RunnableExt::with_cache`](crate::RunnableExt::with_cache).
+///
+/// Entries are keyed on the input's JSON serialization. Inputs that
+/// fail to serialize, or that [`with_should_cache`](Self::with_should_cache)
+/// rejects, go straight to the inner runnable. Errors are never cached, and
+/// concurrent misses for the same input each call the inner runnable.
+/// `stream` always passes through.
+pub struct CachingRunnable<R, Input, Output> {
+    inner: R,
+    capacity: usize,
+    ttl: Option<Duration>,
+    should_cache: Option<Box<ShouldCacheFn<Input>>>,
+    cache: Mutex<LruCache<Output>>,
+    _marker: PhantomData<fn(Input)>,
+}
+
+impl<R, Input, Output> CachingRunnable<R, Input, Output> {
+    /// Keep at most `capacity` outputs, evicting the least recently used.
+    /// `0` is treated as `1`.
+    pub fn new(inner: R, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity: capacity.max(1),
+            ttl: None,
+            should_cache: None,
+            cache: Mutex::new(LruCache {
+                entries: HashMap::new(),
+                tick: 0,
+            }),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Expire entries `ttl` after they were stored. Entries never expire by
+    /// default.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Only cache inputs for which `should_cache` returns true, e.g. to skip
+    /// LLM requests with a non-zero temperature.
+    pub fn with_should_cache<F>(mut self, should_cache: F) -> Self
+    where
+        F: Fn(&Input) -> bool + Send + Sync + 'static,
+    {
+        self.should_cache = Some(Box::new(should_cache));
+        self
+    }
+
+    /// Number of cached outputs, including expired ones not yet evicted.
+    pub fn len(&self) -> usize {
+        self.cache.lock().expect("cache lock").entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.cache.lock().expect("cache lock").entries.clear();
+    }
+}
+
+impl<R, Input, Output> CachingRunnable<R, Input, Output>
+where
+    Input: Serialize,
+    Output: Clone,
+{
+    fn key(&self, input: &Input) -> Option<String> {
+        if let Some(should_cache) = &self.should_cache {
+            if !should_cache(input) {
+                return None;
+            }
+        }
+        serde_json::to_string(input).ok()
+    }
+
+    fn get(&self, key: &str) -> Option<Output> {
+        let mut cache = self.cache.lock().expect("cache lock");
+        cache.tick += 1;
+        let tick = cache.tick;
+        let expired = cache
+            .entries
+            .get(key)
+            .map(|entry| self.ttl.is_some_and(|ttl| entry.inserted.elapsed() >= ttl))?;
+        if expired {
+            cache.entries.remove(key);
+            return None;
+        }
+        let entry = cache.entries.get_mut(key)?;
+        entry.last_used = tick;
+        Some(entry.output.clone())
+    }
+
+    fn put(&self, key: String, output: Output) {
+        let mut cache = self.cache.lock().expect("cache lock");
+        cache.tick += 1;
+        let tick = cache.tick;
+        if !cache.entries.contains_key(&key) && cache.entries.len() >= self.capacity {
+            let oldest = cache
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                cache.entries.remove(&oldest);
+            }
+        }
+        cache.entries.insert(
+            key,
+            CacheEntry {
+                output,
+                inserted: Instant::now(),
+                last_used: tick,
+            },
+        );
+    }
+}
+
+#[async_trait::async_trait]
+impl<Input, Output, R> Runnable<Input, Output> for CachingRunnable<R, Input, Output>
+where
+    Input: Serialize + Send + 'static,
+    Output: Clone + Send + 'static,
+    R: Runnable<Input, Output> + Send + Sync,
+{
+    async fn invoke(&self, input: Input) -> Result<Output, WesichainError> {
+        let Some(key) = self.key(&input) else {
+            return self.inner.invoke(input).await;
+        };
+        if let Some(output) = self.get(&key) {
+            return Ok(output);
+        }
+        let output = self.inner.invoke(input).await?;
+        self.put(key, output.clone());
+        Ok(output)
+    }
+
+    fn stream<'a>(&'a self, input: Input) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
+        self.inner.stream(input)
+    }
+}
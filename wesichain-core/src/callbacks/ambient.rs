@@ -0,0 +1,53 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{BoxStream, Stream, StreamExt};
+
+use crate::callbacks::{CallbackManager, RunContext};
+
+tokio::task_local! {
+    static CURRENT_RUN: (CallbackManager, RunContext);
+}
+
+/// Drive `future` with `ctx` as the ambient run, so runnables invoked inside
+/// it (e.g. [`RunnableWithConfig`](crate::RunnableWithConfig)) can report
+/// child runs to `manager` without it being passed down explicitly.
+pub async fn with_run_context<F: Future>(
+    manager: CallbackManager,
+    ctx: RunContext,
+    future: F,
+) -> F::Output {
+    CURRENT_RUN.scope((manager, ctx), future).await
+}
+
+/// Stream counterpart of [`with_run_context`]: `make_stream` is called, and
+/// the stream it returns is polled, with `ctx` as the ambient run.
+pub fn with_run_context_stream<'a, T: 'a>(
+    manager: CallbackManager,
+    ctx: RunContext,
+    make_stream: impl FnOnce() -> BoxStream<'a, T>,
+) -> BoxStream<'a, T> {
+    let run = (manager, ctx);
+    let stream = CURRENT_RUN.sync_scope(run.clone(), make_stream);
+    RunScopedStream { run, stream }.boxed()
+}
+
+/// The innermost ambient run set by [`with_run_context`], if any.
+pub fn current_run_context() -> Option<(CallbackManager, RunContext)> {
+    CURRENT_RUN.try_with(Clone::clone).ok()
+}
+
+struct RunScopedStream<'a, T> {
+    run: (CallbackManager, RunContext),
+    stream: BoxStream<'a, T>,
+}
+
+impl<T> Stream for RunScopedStream<'_, T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        CURRENT_RUN.sync_scope(this.run.clone(), || this.stream.poll_next_unpin(cx))
+    }
+}
@@ -3,6 +3,8 @@
 //! These types capture LLM-specific inputs and outputs for cost tracking,
 //! prompt debugging, and performance analysis.
 
+use crate::{LlmRequest, LlmResponse, Role};
+
 /// Token consumption for cost tracking and optimization.
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct TokenUsage {
@@ -31,3 +33,41 @@ pub struct LlmResult {
     /// Rendered output strings (one per generation)
     pub generations: Vec<String>,
 }
+
+impl From<&LlmRequest> for LlmInput {
+    /// Renders each message as a `role: text` line.
+    fn from(request: &LlmRequest) -> Self {
+        let prompt = request
+            .messages
+            .iter()
+            .map(|message| {
+                let role = match message.role {
+                    Role::System => "system",
+                    Role::User => "user",
+                    Role::Assistant => "assistant",
+                    Role::Tool => "tool",
+                };
+                format!("{role}: {}", message.content.to_text_lossy())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Self {
+            model: request.model.clone(),
+            prompt,
+            temperature: request.temperature,
+            max_tokens: request.max_tokens,
+            stop_sequences: request.stop_sequences.clone(),
+        }
+    }
+}
+
+impl From<&LlmResponse> for LlmResult {
+    fn from(response: &LlmResponse) -> Self {
+        Self {
+            token_usage: response.usage.clone(),
+            model: response.model.clone(),
+            finish_reason: response.finish_reason.clone(),
+            generations: vec![response.content.clone()],
+        }
+    }
+}
@@ -7,12 +7,14 @@ use uuid::Uuid;
 
 use crate::Value;
 
+mod ambient;
 mod llm;
 mod wrappers;
 
+pub use ambient::{current_run_context, with_run_context, with_run_context_stream};
 pub use llm::{LlmInput, LlmResult, TokenUsage};
 
-pub use wrappers::TracedRunnable;
+pub use wrappers::{TracedRunnable, MESSAGE_COUNT_METADATA_KEY};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RunType {
@@ -108,6 +110,23 @@ pub trait CallbackHandler: Send + Sync {
         .await
     }
 
+    /// Called before a tool is invoked. Default implementation does nothing.
+    async fn on_tool_start(&self, _ctx: &RunContext, _tool_name: &str, _args: &Value) {}
+
+    /// Called after a tool returns successfully. Default implementation does nothing.
+    async fn on_tool_end(
+        &self,
+        _ctx: &RunContext,
+        _tool_name: &str,
+        _result: &Value,
+        _duration_ms: u128,
+    ) {
+    }
+
+    /// Called when a failed attempt is about to be retried. `attempt` is the
+    /// 1-based number of the attempt that failed. Default implementation does nothing.
+    async fn on_retry(&self, _ctx: &RunContext, _attempt: usize, _error: &Value) {}
+
     /// Generic event hook for custom events (e.g. checkpoint saved)
     async fn on_event(&self, _ctx: &RunContext, _event: &str, _data: &Value) {}
 }
@@ -178,6 +197,32 @@ impl CallbackManager {
         }
     }
 
+    pub async fn on_tool_start(&self, ctx: &RunContext, tool_name: &str, args: &Value) {
+        for handler in &self.handlers {
+            handler.on_tool_start(ctx, tool_name, args).await;
+        }
+    }
+
+    pub async fn on_tool_end(
+        &self,
+        ctx: &RunContext,
+        tool_name: &str,
+        result: &Value,
+        duration_ms: u128,
+    ) {
+        for handler in &self.handlers {
+            handler
+                .on_tool_end(ctx, tool_name, result, duration_ms)
+                .await;
+        }
+    }
+
+    pub async fn on_retry(&self, ctx: &RunContext, attempt: usize, error: &Value) {
+        for handler in &self.handlers {
+            handler.on_retry(ctx, attempt, error).await;
+        }
+    }
+
     pub async fn on_event(&self, ctx: &RunContext, event: &str, data: &Value) {
         for handler in &self.handlers {
             handler.on_event(ctx, event, data).await;
@@ -1,10 +1,25 @@
+use std::any::Any;
+
 use futures::stream::BoxStream;
 
 use crate::callbacks::{
-    ensure_object, CallbackManager, RunContext, RunType, ToTraceInput, ToTraceOutput,
+    ensure_object, with_run_context, with_run_context_stream, CallbackManager, LlmInput, LlmResult,
+    RunContext, RunType, ToTraceInput, ToTraceOutput, TokenUsage,
 };
-use crate::{Runnable, StreamEvent, Value, WesichainError};
+use crate::{LlmRequest, LlmResponse, Runnable, StreamEvent, Value, WesichainError};
+
+/// Run metadata key holding the number of messages sent in a traced LLM run.
+pub const MESSAGE_COUNT_METADATA_KEY: &str = "message_count";
 
+/// Wraps a runnable so each run reports to a [`CallbackManager`].
+///
+/// Runs are reported through `on_start`/`on_end`/`on_error`. For
+/// [`RunType::Llm`] runs whose input is an [`LlmRequest`], both `invoke` and
+/// `stream` report through `on_llm_start`/`on_llm_end` instead, so handlers
+/// see the model name and token usage. A streamed run's result is built from
+/// its `FinalAnswer` and `UsageUpdate` events. The number of request
+/// messages is recorded in the run's metadata under
+/// [`MESSAGE_COUNT_METADATA_KEY`].
 #[allow(dead_code)]
 pub struct TracedRunnable<R> {
     inner: R,
@@ -31,6 +46,38 @@ impl<R> TracedRunnable<R> {
             name,
         }
     }
+
+    /// The typed LLM input of this run, recording the message count in `ctx`.
+    fn llm_input<Input: 'static>(&self, input: &Input, ctx: &mut RunContext) -> Option<LlmInput> {
+        if self.run_type != RunType::Llm {
+            return None;
+        }
+        let request = (input as &dyn Any).downcast_ref::<LlmRequest>()?;
+        ctx.metadata.insert(
+            MESSAGE_COUNT_METADATA_KEY.to_string(),
+            Value::from(request.messages.len()),
+        );
+        Some(LlmInput::from(request))
+    }
+}
+
+/// Outputs that are not an [`LlmResponse`] are reported as a single
+/// generation so a run started with `on_llm_start` still ends with
+/// `on_llm_end`.
+fn llm_result<Output: ToTraceOutput + 'static>(llm_input: &LlmInput, output: &Output) -> LlmResult {
+    if let Some(response) = (output as &dyn Any).downcast_ref::<LlmResponse>() {
+        return LlmResult::from(response);
+    }
+    let generation = match output.to_trace_output() {
+        Value::String(text) => text,
+        other => other.to_string(),
+    };
+    LlmResult {
+        token_usage: None,
+        model: llm_input.model.clone(),
+        finish_reason: None,
+        generations: vec![generation],
+    }
 }
 
 #[async_trait::async_trait]
@@ -45,18 +92,33 @@ where
             return self.inner.invoke(input).await;
         }
 
-        let ctx = self.parent.child(self.run_type.clone(), self.name.clone());
-        let inputs = ensure_object(input.to_trace_input());
-        self.manager.on_start(&ctx, &inputs).await;
+        let mut ctx = self.parent.child(self.run_type.clone(), self.name.clone());
+        let llm_input = self.llm_input(&input, &mut ctx);
+        match &llm_input {
+            Some(llm_input) => self.manager.on_llm_start(&ctx, llm_input).await,
+            None => {
+                let inputs = ensure_object(input.to_trace_input());
+                self.manager.on_start(&ctx, &inputs).await;
+            }
+        }
 
-        let result = self.inner.invoke(input).await;
+        let result =
+            with_run_context(self.manager.clone(), ctx.clone(), self.inner.invoke(input)).await;
         let duration_ms = ctx.start_instant.elapsed().as_millis();
 
         match &result {
-            Ok(output) => {
-                let outputs = ensure_object(output.to_trace_output());
-                self.manager.on_end(&ctx, &outputs, duration_ms).await;
-            }
+            Ok(output) => match &llm_input {
+                Some(llm_input) => {
+                    let llm_result = llm_result(llm_input, output);
+                    self.manager
+                        .on_llm_end(&ctx, &llm_result, duration_ms)
+                        .await
+                }
+                None => {
+                    let outputs = ensure_object(output.to_trace_output());
+                    self.manager.on_end(&ctx, &outputs, duration_ms).await;
+                }
+            },
             Err(err) => {
                 let error = ensure_object(err.to_string().to_trace_output());
                 self.manager.on_error(&ctx, &error, duration_ms).await;
@@ -72,17 +134,25 @@ where
         }
 
         let manager = self.manager.clone();
-        let parent = self.parent.clone();
-        let run_type = self.run_type.clone();
-        let name = self.name.clone();
-        let inner_stream = self.inner.stream(input.clone());
+        let mut ctx = self.parent.child(self.run_type.clone(), self.name.clone());
+        let llm_input = self.llm_input(&input, &mut ctx);
+        let inputs = llm_input
+            .is_none()
+            .then(|| ensure_object(input.to_trace_input()));
+        let inner_stream =
+            with_run_context_stream(manager.clone(), ctx.clone(), || self.inner.stream(input));
 
         Box::pin(async_stream::stream! {
-            let ctx = parent.child(run_type, name);
-            let inputs = ensure_object(input.to_trace_input());
-            manager.on_start(&ctx, &inputs).await;
+            if let Some(llm_input) = &llm_input {
+                manager.on_llm_start(&ctx, llm_input).await;
+            } else if let Some(inputs) = &inputs {
+                manager.on_start(&ctx, inputs).await;
+            }
 
             let mut got_final_answer = false;
+            let mut failed = false;
+            let mut final_answer = None;
+            let mut token_usage = None;
 
             for await event in inner_stream {
                 match &event {
@@ -93,16 +163,28 @@ where
                     Ok(StreamEvent::ToolCallDelta { id: _, delta }) => {
                         manager.on_stream_chunk(&ctx, delta).await;
                     }
-                    Ok(StreamEvent::FinalAnswer(_)) => {
+                    Ok(StreamEvent::FinalAnswer(text)) => {
                         got_final_answer = true;
-                        let outputs = ensure_object(Value::String("final_answer".to_string()));
-                        let duration_ms = ctx.start_instant.elapsed().as_millis();
-                        manager.on_end(&ctx, &outputs, duration_ms).await;
+                        if llm_input.is_some() {
+                            final_answer = Some(text.clone());
+                        } else {
+                            let outputs = ensure_object(Value::String("final_answer".to_string()));
+                            let duration_ms = ctx.start_instant.elapsed().as_millis();
+                            manager.on_end(&ctx, &outputs, duration_ms).await;
+                        }
+                    }
+                    Ok(StreamEvent::UsageUpdate { input_tokens, output_tokens, .. }) => {
+                        token_usage = Some(TokenUsage {
+                            prompt_tokens: *input_tokens,
+                            completion_tokens: *output_tokens,
+                            total_tokens: input_tokens.saturating_add(*output_tokens),
+                        });
                     }
                     Ok(_) => {
                         // Other variants don't trigger specific callbacks
                     }
                     Err(err) => {
+                        failed = true;
                         let error = ensure_object(err.to_string().to_trace_output());
                         let duration_ms = ctx.start_instant.elapsed().as_millis();
                         manager.on_error(&ctx, &error, duration_ms).await;
@@ -111,11 +193,25 @@ where
                 yield event;
             }
 
-            // If stream ended without FinalAnswer, call on_end
-            if !got_final_answer {
-                let outputs = ensure_object(Value::Object(serde_json::Map::new()));
-                let duration_ms = ctx.start_instant.elapsed().as_millis();
-                manager.on_end(&ctx, &outputs, duration_ms).await;
+            let duration_ms = ctx.start_instant.elapsed().as_millis();
+            match &llm_input {
+                // A failed LLM run has already ended with `on_error`.
+                Some(_) if failed => {}
+                Some(llm_input) => {
+                    let llm_result = LlmResult {
+                        token_usage,
+                        model: llm_input.model.clone(),
+                        finish_reason: None,
+                        generations: final_answer.into_iter().collect(),
+                    };
+                    manager.on_llm_end(&ctx, &llm_result, duration_ms).await;
+                }
+                // If stream ended without FinalAnswer, call on_end
+                None if !got_final_answer => {
+                    let outputs = ensure_object(Value::Object(serde_json::Map::new()));
+                    manager.on_end(&ctx, &outputs, duration_ms).await;
+                }
+                None => {}
             }
         })
     }
@@ -123,6 +123,60 @@ pub trait RunnableExt<Input: Send + 'static, Output: Send + 'static>:
     {
         crate::RateLimited::new(self, requests_per_minute)
     }
+
+    /// Memoize `invoke` results in an LRU of at most `capacity` entries. See
+    /// [`CachingRunnable`](crate::CachingRunnable) for TTL and predicates.
+    fn with_cache(self, capacity: usize) -> crate::CachingRunnable<Self, Input, Output>
+    where
+        Self: Send + Sync,
+        Input: serde::Serialize,
+        Output: Clone,
+    {
+        crate::CachingRunnable::new(self, capacity)
+    }
+
+    /// Transform the output with a fallible function, e.g.
+    /// `llm.map(|r| Ok(r.content))`. Streaming passes through unchanged.
+    fn map<NewOutput, F>(self, f: F) -> crate::Mapped<Self, F, Output>
+    where
+        Self: Send + Sync,
+        F: Fn(Output) -> Result<NewOutput, WesichainError> + Send + Sync,
+        NewOutput: Send + 'static,
+    {
+        crate::Mapped::new(self, f)
+    }
+
+    /// Transform the output with an infallible function. Streaming passes
+    /// through unchanged.
+    fn map_ok<NewOutput, F>(self, f: F) -> crate::MappedOk<Self, F, Output>
+    where
+        Self: Send + Sync,
+        F: Fn(Output) -> NewOutput + Send + Sync,
+        NewOutput: Send + 'static,
+    {
+        crate::MappedOk::new(self, f)
+    }
+
+    /// Label this runnable's runs with `tags`, e.g. `llm.with_tags(vec!["rag".into()])`.
+    /// See [`RunnableWithConfig`](crate::RunnableWithConfig).
+    fn with_tags(self, tags: Vec<String>) -> crate::RunnableWithConfig<Self>
+    where
+        Self: Send + Sync,
+    {
+        crate::RunnableWithConfig::new(self).with_tags(tags)
+    }
+
+    /// Attach `metadata` to this runnable's runs. See
+    /// [`RunnableWithConfig`](crate::RunnableWithConfig).
+    fn with_metadata(
+        self,
+        metadata: std::collections::BTreeMap<String, crate::Value>,
+    ) -> crate::RunnableWithConfig<Self>
+    where
+        Self: Send + Sync,
+    {
+        crate::RunnableWithConfig::new(self).with_metadata(metadata)
+    }
 }
 
 impl<Input: Send + 'static, Output: Send + 'static, T> RunnableExt<Input, Output> for T where
@@ -130,7 +184,10 @@ impl<Input: Send + 'static, Output: Send + 'static, T> RunnableExt<Input, Output
 {
 }
 
-use crate::Value;
+use crate::{
+    current_run_context, ensure_object, with_run_context, with_run_context_stream, CallbackManager,
+    RunConfig, RunContext, RunType, ToTraceInput, ToTraceOutput, Value,
+};
 use std::sync::Arc;
 
 /// A runtime-constructed chain that operates on `Value`.
@@ -143,41 +200,189 @@ impl RuntimeChain {
     pub fn new(steps: Vec<Arc<dyn Runnable<Value, Value>>>) -> Self {
         Self { steps }
     }
-}
 
-#[async_trait]
-impl Runnable<Value, Value> for RuntimeChain {
-    async fn invoke(&self, input: Value) -> Result<Value, WesichainError> {
-        let mut current = input;
-        for step in &self.steps {
-            current = step.invoke(current).await?;
-        }
-        Ok(current)
+    /// Runs the chain, reporting a root run plus one child run per step to
+    /// `config.callbacks`. Without callbacks, a chain invoked under an ambient
+    /// run (see [`with_run_context`]) reports a child of that run instead.
+    pub async fn invoke_with_config(
+        &self,
+        input: Value,
+        config: RunConfig,
+    ) -> Result<Value, WesichainError> {
+        let Some((manager, root)) = Self::start_run(&config, &input).await else {
+            return self.invoke_steps(input, None).await;
+        };
+
+        let result = self.invoke_steps(input, Some((&manager, &root))).await;
+        Self::finish(&manager, &root, &result).await;
+        result
     }
 
-    fn stream<'a>(&'a self, input: Value) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
-        if self.steps.is_empty() {
+    /// Streaming counterpart of [`invoke_with_config`](Self::invoke_with_config).
+    /// Every step but the last is invoked; the last step's stream is forwarded.
+    pub fn stream_with_config(
+        &self,
+        input: Value,
+        config: RunConfig,
+    ) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        let Some(last) = self.steps.last() else {
             return stream::empty().boxed();
-        }
+        };
+        let head = &self.steps[..self.steps.len() - 1];
 
-        let steps = &self.steps;
+        Box::pin(async_stream::stream! {
+            let traced = Self::start_run(&config, &input).await;
+            let parent = traced.as_ref().map(|(manager, root)| (manager, root));
 
-        let s = async move {
             let mut current = input;
-            let last_idx = steps.len() - 1;
-            for (i, step) in steps.iter().enumerate() {
-                if i == last_idx {
-                    break;
+            for (index, step) in head.iter().enumerate() {
+                match invoke_step(step.as_ref(), index, current, parent).await {
+                    Ok(value) => current = value,
+                    Err(err) => {
+                        if let Some((manager, root)) = parent {
+                            Self::fail(manager, root, &err.to_string()).await;
+                        }
+                        yield Err(err);
+                        return;
+                    }
                 }
-                current = step.invoke(current).await?;
             }
-            Ok::<Value, WesichainError>(current)
+
+            let step_ctx = match parent {
+                Some((manager, root)) => {
+                    let ctx = root.child(RunType::Runnable, step_name(head.len()));
+                    manager.on_start(&ctx, &ensure_object(current.to_trace_input())).await;
+                    Some(ctx)
+                }
+                None => None,
+            };
+
+            let last_stream = match (parent, &step_ctx) {
+                (Some((manager, _)), Some(ctx)) => {
+                    with_run_context_stream(manager.clone(), ctx.clone(), || last.stream(current))
+                }
+                _ => last.stream(current),
+            };
+
+            let mut failure = None;
+            for await event in last_stream {
+                if let Err(err) = &event {
+                    failure.get_or_insert_with(|| err.to_string());
+                }
+                yield event;
+            }
+
+            if let Some((manager, root)) = parent {
+                let output = Value::Object(Default::default());
+                for ctx in step_ctx.iter().chain([root]) {
+                    match &failure {
+                        Some(message) => Self::fail(manager, ctx, message).await,
+                        None => Self::finish(manager, ctx, &Ok(output.clone())).await,
+                    }
+                }
+            }
+        })
+    }
+
+    async fn invoke_steps(
+        &self,
+        input: Value,
+        parent: Option<(&CallbackManager, &RunContext)>,
+    ) -> Result<Value, WesichainError> {
+        let mut current = input;
+        for (index, step) in self.steps.iter().enumerate() {
+            current = invoke_step(step.as_ref(), index, current, parent).await?;
+        }
+        Ok(current)
+    }
+
+    async fn start_run(config: &RunConfig, input: &Value) -> Option<(CallbackManager, RunContext)> {
+        let name = config
+            .name_override
+            .clone()
+            .unwrap_or_else(|| "RuntimeChain".to_string());
+        let (manager, run) = match config.callbacks.clone().filter(|m| !m.is_noop()) {
+            Some(manager) => {
+                let root = RunContext::root(
+                    RunType::Chain,
+                    name,
+                    config.tags.clone(),
+                    config.metadata.clone(),
+                );
+                (manager, root)
+            }
+            None => {
+                let (manager, parent) = current_run_context().filter(|(m, _)| !m.is_noop())?;
+                let mut child = parent.child(RunType::Chain, name);
+                for tag in &config.tags {
+                    if !child.tags.contains(tag) {
+                        child.tags.push(tag.clone());
+                    }
+                }
+                child.metadata.extend(config.metadata.clone());
+                (manager, child)
+            }
         };
+        manager
+            .on_start(&run, &ensure_object(input.to_trace_input()))
+            .await;
+        Some((manager, run))
+    }
+
+    async fn finish(
+        manager: &CallbackManager,
+        ctx: &RunContext,
+        result: &Result<Value, WesichainError>,
+    ) {
+        match result {
+            Ok(output) => {
+                let duration_ms = ctx.start_instant.elapsed().as_millis();
+                manager
+                    .on_end(ctx, &ensure_object(output.to_trace_output()), duration_ms)
+                    .await
+            }
+            Err(err) => Self::fail(manager, ctx, &err.to_string()).await,
+        }
+    }
+
+    async fn fail(manager: &CallbackManager, ctx: &RunContext, message: &str) {
+        let duration_ms = ctx.start_instant.elapsed().as_millis();
+        let error = ensure_object(message.to_string().to_trace_output());
+        manager.on_error(ctx, &error, duration_ms).await;
+    }
+}
+
+fn step_name(index: usize) -> String {
+    format!("step_{index}")
+}
 
-        stream::once(s)
-            .map_ok(move |val| steps.last().unwrap().stream(val))
-            .try_flatten()
-            .boxed()
+async fn invoke_step(
+    step: &dyn Runnable<Value, Value>,
+    index: usize,
+    input: Value,
+    parent: Option<(&CallbackManager, &RunContext)>,
+) -> Result<Value, WesichainError> {
+    let Some((manager, root)) = parent else {
+        return step.invoke(input).await;
+    };
+
+    let ctx = root.child(RunType::Runnable, step_name(index));
+    manager
+        .on_start(&ctx, &ensure_object(input.to_trace_input()))
+        .await;
+    let result = with_run_context(manager.clone(), ctx.clone(), step.invoke(input)).await;
+    RuntimeChain::finish(manager, &ctx, &result).await;
+    result
+}
+
+#[async_trait]
+impl Runnable<Value, Value> for RuntimeChain {
+    async fn invoke(&self, input: Value) -> Result<Value, WesichainError> {
+        self.invoke_with_config(input, RunConfig::default()).await
+    }
+
+    fn stream<'a>(&'a self, input: Value) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
+        self.stream_with_config(input, RunConfig::default())
     }
 
     fn to_serializable(&self) -> Option<crate::serde::SerializableRunnable> {
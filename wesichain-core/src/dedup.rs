@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use crate::{Document, SearchResult};
+
+/// What makes two documents duplicates in [`dedup_documents`] and
+/// [`dedup_search_results`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DedupKey {
+    /// Same document ID.
+    #[default]
+    Id,
+    /// Identical content, regardless of ID.
+    ContentHash,
+    /// Same ID or identical content.
+    Both,
+}
+
+/// Keys of the documents kept so far, mapped to their position in the output.
+struct Seen {
+    by: DedupKey,
+    ids: HashMap<String, usize>,
+    contents: HashMap<String, usize>,
+}
+
+impl Seen {
+    fn new(by: DedupKey) -> Self {
+        Self {
+            by,
+            ids: HashMap::new(),
+            contents: HashMap::new(),
+        }
+    }
+
+    /// Position of an earlier document `doc` duplicates.
+    fn find(&self, doc: &Document) -> Option<usize> {
+        let by_id = || self.ids.get(&doc.id).copied();
+        let by_content = || self.contents.get(&doc.content).copied();
+        match self.by {
+            DedupKey::Id => by_id(),
+            DedupKey::ContentHash => by_content(),
+            DedupKey::Both => by_id().or_else(by_content),
+        }
+    }
+
+    fn record(&mut self, doc: &Document, index: usize) {
+        if self.by != DedupKey::ContentHash {
+            self.ids.insert(doc.id.clone(), index);
+        }
+        if self.by != DedupKey::Id {
+            self.contents.insert(doc.content.clone(), index);
+        }
+    }
+
+    /// Replace the document kept at `index` with `new`, dropping the keys of
+    /// `old` so they no longer match.
+    fn replace(&mut self, old: &Document, new: &Document, index: usize) {
+        if self.ids.get(&old.id) == Some(&index) {
+            self.ids.remove(&old.id);
+        }
+        if self.contents.get(&old.content) == Some(&index) {
+            self.contents.remove(&old.content);
+        }
+        self.record(new, index);
+    }
+}
+
+/// Drops documents that duplicate an earlier one, keeping the first
+/// occurrence and the original order.
+pub fn dedup_documents(docs: Vec<Document>, by: DedupKey) -> Vec<Document> {
+    let mut seen = Seen::new(by);
+    let mut kept: Vec<Document> = Vec::with_capacity(docs.len());
+    for doc in docs {
+        if seen.find(&doc).is_none() {
+            seen.record(&doc, kept.len());
+            kept.push(doc);
+        }
+    }
+    kept
+}
+
+/// Drops results that duplicate an earlier one, keeping the highest-scoring
+/// result of each group at the position its group was first seen.
+pub fn dedup_search_results(results: Vec<SearchResult>, by: DedupKey) -> Vec<SearchResult> {
+    let mut seen = Seen::new(by);
+    let mut kept: Vec<SearchResult> = Vec::with_capacity(results.len());
+    for result in results {
+        match seen.find(&result.document) {
+            Some(index) => {
+                if result.score > kept[index].score {
+                    seen.replace(&kept[index].document, &result.document, index);
+                    kept[index] = result;
+                }
+            }
+            None => {
+                seen.record(&result.document, kept.len());
+                kept.push(result);
+            }
+        }
+    }
+    kept
+}
@@ -8,6 +8,19 @@ pub trait Embedding: Send + Sync {
 
     async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError>;
 
+    /// Embed a search query. Defaults to [`embed`](Self::embed); embedders
+    /// for instruction-tuned models override it to add their query prefix.
+    async fn embed_query(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.embed(text).await
+    }
+
+    /// Embed documents for indexing. Defaults to
+    /// [`embed_batch`](Self::embed_batch); override alongside
+    /// [`embed_query`](Self::embed_query) to add a document prefix.
+    async fn embed_documents(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        self.embed_batch(texts).await
+    }
+
     async fn embed_batch_strs(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, EmbeddingError>
     where
         Self: Sized,
@@ -38,6 +51,14 @@ impl<T: Embedding + ?Sized> Embedding for std::sync::Arc<T> {
         self.as_ref().embed_batch(texts).await
     }
 
+    async fn embed_query(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.as_ref().embed_query(text).await
+    }
+
+    async fn embed_documents(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        self.as_ref().embed_documents(texts).await
+    }
+
     fn dimension(&self) -> usize {
         self.as_ref().dimension()
     }
@@ -32,6 +32,28 @@ pub enum WesichainError {
     ContextWindowExceeded { limit: usize, actual: usize },
     #[error("content policy violation: {reason}")]
     ContentPolicyViolation { reason: String },
+    /// A graph execution error kept as-is so callers can downcast it (e.g. to
+    /// `wesichain_graph::GraphError`) instead of parsing the message.
+    #[error("{0}")]
+    Graph(Box<dyn StdError + Send + Sync>),
+}
+
+impl WesichainError {
+    /// Whether retrying the same request may succeed.
+    ///
+    /// Transient failures are retryable: provider and transport errors,
+    /// timeouts, rate limits and tool call failures. Deterministic failures,
+    /// such as parse, serialization, configuration or authentication errors,
+    /// are not.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            WesichainError::LlmProvider(_)
+                | WesichainError::ToolCallFailed { .. }
+                | WesichainError::Timeout(_)
+                | WesichainError::RateLimitExceeded { .. }
+        )
+    }
 }
 
 impl From<EmbeddingError> for WesichainError {
@@ -49,12 +71,36 @@ impl From<StoreError> for WesichainError {
 #[derive(Debug)]
 pub enum EmbeddingError {
     InvalidResponse(String),
-    RateLimited { retry_after: Option<Duration> },
+    RateLimited {
+        retry_after: Option<Duration>,
+    },
     Timeout(Duration),
+    /// The provider rejected the credentials (HTTP 401/403).
+    Auth(String),
+    /// The request did not complete: connection failures and 5xx responses.
+    Transport(String),
+    /// The provider returned vectors of an unexpected size.
+    DimensionMismatch {
+        expected: usize,
+        got: usize,
+    },
     Provider(String),
     Other(Box<dyn StdError + Send + Sync>),
 }
 
+impl EmbeddingError {
+    /// Whether retrying the same request, possibly against another backend,
+    /// may succeed: rate limits, timeouts and transport failures.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            EmbeddingError::RateLimited { .. }
+                | EmbeddingError::Timeout(_)
+                | EmbeddingError::Transport(_)
+        )
+    }
+}
+
 impl fmt::Display for EmbeddingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -66,6 +112,16 @@ impl fmt::Display for EmbeddingError {
                 None => write!(f, "Embedding rate limited (retry_after=unknown)"),
             },
             EmbeddingError::Timeout(duration) => write!(f, "Embedding timeout after {duration:?}"),
+            EmbeddingError::Auth(message) => {
+                write!(f, "Embedding authentication failed: {message}")
+            }
+            EmbeddingError::Transport(message) => write!(f, "Embedding transport error: {message}"),
+            EmbeddingError::DimensionMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Embedding dimension mismatch: expected {expected}, got {got}"
+                )
+            }
             EmbeddingError::Provider(message) => write!(f, "Embedding provider error: {message}"),
             EmbeddingError::Other(error) => write!(f, "Embedding error: {error}"),
         }
@@ -87,6 +143,10 @@ pub enum StoreError {
     DimensionMismatch { expected: usize, got: usize },
     #[error("invalid document id: {0}")]
     InvalidId(String),
+    #[error("document id already exists: {0}")]
+    DuplicateId(String),
+    #[error("unsupported metadata filter: {0}")]
+    UnsupportedFilter(String),
     #[error("Store error: {0}")]
     Internal(#[source] Box<dyn StdError + Send + Sync>),
 }
@@ -2,11 +2,20 @@ use std::sync::Arc;
 
 use crate::{Runnable, StreamEvent, WesichainError};
 use async_trait::async_trait;
-use futures::stream::BoxStream;
+use futures::stream::{BoxStream, StreamExt};
 
+/// Runs `primary`, trying each fallback in order when it fails.
+///
+/// `invoke` returns the first success, or the last error if every runnable
+/// fails. `stream` only falls back when a runnable errors before emitting any
+/// event: once events have reached the caller, switching runnables would
+/// replay output from scratch, so the error is surfaced instead. Set
+/// [`with_fallback_on_mid_stream`](Self::with_fallback_on_mid_stream) to
+/// fall back anyway.
 pub struct RunnableWithFallbacks<Input, Output> {
     primary: Arc<dyn Runnable<Input, Output> + Send + Sync>,
     fallbacks: Vec<Arc<dyn Runnable<Input, Output> + Send + Sync>>,
+    fallback_on_mid_stream: bool,
 }
 
 impl<Input, Output> RunnableWithFallbacks<Input, Output> {
@@ -14,7 +23,19 @@ impl<Input, Output> RunnableWithFallbacks<Input, Output> {
         primary: Arc<dyn Runnable<Input, Output> + Send + Sync>,
         fallbacks: Vec<Arc<dyn Runnable<Input, Output> + Send + Sync>>,
     ) -> Self {
-        Self { primary, fallbacks }
+        Self {
+            primary,
+            fallbacks,
+            fallback_on_mid_stream: false,
+        }
+    }
+
+    /// Also fall back when a stream fails after emitting events. The
+    /// fallback's stream then follows the events already emitted, so callers
+    /// must be prepared to discard partial output.
+    pub fn with_fallback_on_mid_stream(mut self, fallback_on_mid_stream: bool) -> Self {
+        self.fallback_on_mid_stream = fallback_on_mid_stream;
+        self
     }
 }
 
@@ -41,11 +62,36 @@ where
     }
 
     fn stream(&self, input: Input) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
-        // Streaming support for fallbacks is tricky (state reset).
-        // Delegating to primary stream for now, but ignoring fallbacks in stream mode?
-        // Or if primary fails stream, we can't easily switch to fallback stream mid-stream.
-        // So we just stream primary.
-        self.primary.stream(input)
+        Box::pin(async_stream::stream! {
+            let runnables = std::iter::once(&self.primary).chain(&self.fallbacks);
+            let last = self.fallbacks.len();
+            for (index, runnable) in runnables.enumerate() {
+                let mut events = runnable.stream(input.clone());
+                let mut emitted = false;
+                let mut error = None;
+                while let Some(event) = events.next().await {
+                    match event {
+                        Ok(event) => {
+                            emitted = true;
+                            yield Ok(event);
+                        }
+                        Err(err) => {
+                            error = Some(err);
+                            break;
+                        }
+                    }
+                }
+
+                match error {
+                    None => return,
+                    Some(err) if index == last || (emitted && !self.fallback_on_mid_stream) => {
+                        yield Err(err);
+                        return;
+                    }
+                    Some(_) => {}
+                }
+            }
+        })
     }
 
     fn to_serializable(&self) -> Option<crate::serde::SerializableRunnable> {
@@ -0,0 +1,74 @@
+use crate::serde::SerializableRunnable;
+use crate::{value_get_path, Runnable, Value, WesichainError};
+
+/// Runnable that plucks a nested field out of a JSON value, e.g. from an
+/// LLM's structured output before the next step.
+///
+/// Paths are dotted, with optional brackets and a leading `$`, so
+/// `"data.items.0.name"`, `"data.items[0].name"` and `"$.data.items[0].name"`
+/// are equivalent. Lookup follows [`value_get_path`]. A missing path is an
+/// error unless [`with_lenient`](Self::with_lenient) is set, in which case it
+/// yields `Null`.
+#[derive(Clone, Debug)]
+pub struct JsonPathRunnable {
+    path: String,
+    dotted: String,
+    lenient: bool,
+}
+
+impl JsonPathRunnable {
+    pub fn new(path: impl Into<String>) -> Self {
+        let path = path.into();
+        let dotted = to_dotted(&path);
+        Self {
+            path,
+            dotted,
+            lenient: false,
+        }
+    }
+
+    /// Return `Null` instead of an error when the path is absent.
+    pub fn with_lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Extract the sub-value at this runnable's path.
+    pub fn extract(&self, value: &Value) -> Result<Value, WesichainError> {
+        match value_get_path(value, &self.dotted) {
+            Some(found) => Ok(found.clone()),
+            None if self.lenient => Ok(Value::Null),
+            None => Err(WesichainError::Custom(format!(
+                "JSON path '{}' not found",
+                self.path
+            ))),
+        }
+    }
+}
+
+/// `$.items[0].name` -> `items.0.name`
+fn to_dotted(path: &str) -> String {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    path.replace('[', ".")
+        .replace(']', "")
+        .trim_start_matches('.')
+        .to_string()
+}
+
+#[async_trait::async_trait]
+impl Runnable<Value, Value> for JsonPathRunnable {
+    async fn invoke(&self, input: Value) -> Result<Value, WesichainError> {
+        self.extract(&input)
+    }
+
+    fn to_serializable(&self) -> Option<SerializableRunnable> {
+        Some(SerializableRunnable::JsonPath {
+            path: self.path.clone(),
+            lenient: self.lenient,
+        })
+    }
+}
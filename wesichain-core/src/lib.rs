@@ -1,16 +1,20 @@
-pub mod approval;
-pub mod capability;
-pub mod token_budget;
 mod agent_event;
+pub mod approval;
 mod binding;
+mod branch;
+mod caching;
 mod callbacks;
+pub mod capability;
 mod chain;
 pub mod checkpoint;
+mod dedup;
 mod document;
 mod embedding;
 mod error;
 mod fallbacks;
+mod json_path;
 mod llm;
+mod mapped;
 mod metadata_filter;
 mod output_parsers;
 pub mod persistence;
@@ -18,47 +22,77 @@ pub mod prelude;
 mod rate_limiter;
 mod react;
 pub mod registry;
+mod reranker;
 mod retrieval_state;
 mod retry;
 pub mod runnable;
+mod runnable_assign;
 mod runnable_parallel;
 pub mod serde;
+pub mod similarity;
 pub mod state;
+mod stream_buffer;
+mod stream_collect;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 mod time_limited;
+pub mod token_budget;
 mod tool;
+mod tool_call_assembler;
+mod usage;
 mod value;
 mod vector_store;
+mod with_config;
 
 pub use agent_event::AgentEvent;
 pub use approval::{ApprovalChannel, ApprovalDecision, ApprovalDefault, ApprovalRequest};
 pub use binding::{Bindable, RunnableBinding};
+pub use branch::RunnableBranch;
+pub use caching::CachingRunnable;
 pub use callbacks::{
-    ensure_object, CallbackHandler, CallbackManager, LlmInput, LlmResult, RunConfig, RunContext,
-    RunType, ToTraceInput, ToTraceOutput, TokenUsage, TracedRunnable,
+    current_run_context, ensure_object, with_run_context, with_run_context_stream, CallbackHandler,
+    CallbackManager, LlmInput, LlmResult, RunConfig, RunContext, RunType, ToTraceInput,
+    ToTraceOutput, TokenUsage, TracedRunnable, MESSAGE_COUNT_METADATA_KEY,
 };
 pub use chain::{Chain, RunnableExt, RuntimeChain};
+pub use dedup::{dedup_documents, dedup_search_results, DedupKey};
 pub use document::Document;
 pub use embedding::{embed_batch_ref_dyn, embed_batch_strs_dyn, Embedding};
 pub use error::{EmbeddingError, StoreError, WesichainError};
 pub use fallbacks::RunnableWithFallbacks;
+pub use json_path::JsonPathRunnable;
 pub use llm::{
-    ContentPart, LlmRequest, LlmResponse, Message, MessageContent, Role, ToolCall, ToolCallingLlm,
-    ToolCallingLlmExt, ToolSpec,
+    CacheControl, ContentPart, FinishReason, LlmRequest, LlmRequestBuilder, LlmResponse, Message,
+    MessageContent, ResponseFormat, Role, ToolCall, ToolCallingLlm, ToolCallingLlmExt, ToolSpec,
 };
-pub use rate_limiter::RateLimited;
-pub use time_limited::TimeLimited;
+pub use mapped::{Mapped, MappedOk};
 pub use metadata_filter::MetadataFilter;
 pub use output_parsers::{
-    BaseOutputParser, JsonOutputParser, OutputFixingParser, StrOutputParser, StructuredOutputParser,
+    extract_json, BaseOutputParser, ConfiguredStrOutputParser, JsonOutputParser,
+    OutputFixingParser, StrOutputParser, StrOutputParserBuilder, StructuredOutputParser,
+    ToolCallPolicy,
+};
+pub use persistence::{
+    load_runnable, parse_persisted, reconstruct, save_runnable, PERSISTENCE_VERSION,
 };
-pub use persistence::{load_runnable, reconstruct, save_runnable};
+pub use rate_limiter::RateLimited;
 pub use react::{HasFinalOutput, HasUserInput, ReActStep, ScratchpadState};
 pub use registry::RunnableRegistry;
-pub use retrieval_state::{HasMetadataFilter, HasQuery, HasRetrievedDocs};
+pub use reranker::{LocalScoreReranker, Reranker};
+pub use retrieval_state::{HasMetadataFilter, HasQuery, HasRetrievedDocs, ReadRetrievedDocs};
 pub use retry::Retrying;
-pub use runnable::{Runnable, StreamEvent};
+pub use runnable::{BatchConfig, Runnable, StreamEvent};
+pub use runnable_assign::RunnableAssign;
 pub use runnable_parallel::RunnableParallel;
-pub use serde::SerializableRunnable;
+pub use serde::{SerializableBranch, SerializableRunnable};
+pub use stream_buffer::buffer_stream;
+pub use stream_collect::collect_stream;
+pub use time_limited::TimeLimited;
 pub use tool::{CancellationToken, Tool, ToolContext, ToolError, TypedTool};
-pub use value::{IntoValue, TryFromValue, Value};
-pub use vector_store::{delete_ref_dyn, delete_strs_dyn, SearchResult, VectorStore};
+pub use tool_call_assembler::ToolCallAssembler;
+pub use usage::UsageAccumulator;
+pub use value::{value_get_path, value_set_path, IntoValue, TryFromValue, Value};
+pub use vector_store::{
+    delete_ref_dyn, delete_strs_dyn, find_duplicate_id, SearchResult, VectorStore, WriteMode,
+};
+pub use with_config::RunnableWithConfig;
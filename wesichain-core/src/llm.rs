@@ -84,6 +84,18 @@ impl MessageContent {
     }
 }
 
+/// Prompt-caching hint marking a message as the end of a cacheable prefix.
+///
+/// Providers that support caching send it as a `cache_control` block on the
+/// message's last content part; the rest drop it.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CacheControl {
+    /// Short-lived cache entry, refreshed each time the prefix is reused.
+    #[default]
+    Ephemeral,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct Message {
     pub role: Role,
@@ -92,19 +104,38 @@ pub struct Message {
     pub tool_call_id: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tool_calls: Vec<ToolCall>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
 }
 
 impl Message {
     pub fn user(content: impl Into<MessageContent>) -> Self {
-        Self { role: Role::User, content: content.into(), tool_call_id: None, tool_calls: vec![] }
+        Self { role: Role::User, content: content.into(), tool_call_id: None, tool_calls: vec![], cache_control: None }
     }
 
     pub fn system(content: impl Into<MessageContent>) -> Self {
-        Self { role: Role::System, content: content.into(), tool_call_id: None, tool_calls: vec![] }
+        Self { role: Role::System, content: content.into(), tool_call_id: None, tool_calls: vec![], cache_control: None }
     }
 
     pub fn assistant(content: impl Into<MessageContent>) -> Self {
-        Self { role: Role::Assistant, content: content.into(), tool_call_id: None, tool_calls: vec![] }
+        Self { role: Role::Assistant, content: content.into(), tool_call_id: None, tool_calls: vec![], cache_control: None }
+    }
+
+    /// Tool output answering the assistant's call with id `call_id`.
+    pub fn tool_result(call_id: impl Into<String>, content: impl Into<MessageContent>) -> Self {
+        Self {
+            role: Role::Tool,
+            content: content.into(),
+            tool_call_id: Some(call_id.into()),
+            tool_calls: vec![],
+            cache_control: None,
+        }
+    }
+
+    /// Mark this message as the end of a prefix the provider may cache.
+    pub fn with_cache_control(mut self, cache_control: CacheControl) -> Self {
+        self.cache_control = Some(cache_control);
+        self
     }
 
     pub fn with_image_url(mut self, url: impl Into<String>, detail: Option<String>) -> Self {
@@ -154,6 +185,22 @@ pub struct ToolCall {
     pub args: Value,
 }
 
+/// Output format a provider should constrain generation to.
+///
+/// Providers map this onto their native option (`response_format` for
+/// OpenAI-compatible APIs, `responseMimeType`/`responseSchema` for Google).
+/// Providers without such an option ignore it unless configured to reject it.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", content = "schema", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    /// Free-form text; the provider default.
+    Text,
+    /// Any syntactically valid JSON object.
+    JsonObject,
+    /// JSON conforming to the given JSON Schema.
+    JsonSchema(Value),
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct LlmRequest {
     pub model: String,
@@ -166,6 +213,96 @@ pub struct LlmRequest {
     pub max_tokens: Option<u32>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub stop_sequences: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+}
+
+impl LlmRequest {
+    pub fn builder() -> LlmRequestBuilder {
+        LlmRequestBuilder::default()
+    }
+}
+
+/// Incremental constructor for [`LlmRequest`]; messages keep call order.
+#[derive(Clone, Debug, Default)]
+pub struct LlmRequestBuilder {
+    model: String,
+    messages: Vec<Message>,
+    tools: Vec<ToolSpec>,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    stop_sequences: Vec<String>,
+    response_format: Option<ResponseFormat>,
+}
+
+impl LlmRequestBuilder {
+    /// Model name; left empty, providers fall back to their configured default.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    pub fn system(self, content: impl Into<MessageContent>) -> Self {
+        self.message(Message::system(content))
+    }
+
+    pub fn user(self, content: impl Into<MessageContent>) -> Self {
+        self.message(Message::user(content))
+    }
+
+    pub fn assistant(self, content: impl Into<MessageContent>) -> Self {
+        self.message(Message::assistant(content))
+    }
+
+    pub fn tool_result(
+        self,
+        call_id: impl Into<String>,
+        content: impl Into<MessageContent>,
+    ) -> Self {
+        self.message(Message::tool_result(call_id, content))
+    }
+
+    pub fn message(mut self, message: Message) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    pub fn tool(mut self, tool: ToolSpec) -> Self {
+        self.tools.push(tool);
+        self
+    }
+
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    pub fn stop_sequence(mut self, stop: impl Into<String>) -> Self {
+        self.stop_sequences.push(stop.into());
+        self
+    }
+
+    pub fn response_format(mut self, response_format: ResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
+    pub fn build(self) -> LlmRequest {
+        LlmRequest {
+            model: self.model,
+            messages: self.messages,
+            tools: self.tools,
+            temperature: self.temperature,
+            max_tokens: self.max_tokens,
+            stop_sequences: self.stop_sequences,
+            response_format: self.response_format,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
@@ -179,6 +316,67 @@ pub struct LlmResponse {
     /// Empty string when the provider did not return a model name.
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub model: String,
+    /// Raw stop reason reported by the provider (e.g. `"stop"`, `"end_turn"`,
+    /// `"MAX_TOKENS"`). Use [`LlmResponse::finish`] for a normalized view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finish_reason: Option<String>,
+}
+
+impl LlmResponse {
+    /// Normalized form of [`finish_reason`](Self::finish_reason), if the
+    /// provider reported one.
+    pub fn finish(&self) -> Option<FinishReason> {
+        self.finish_reason.as_deref().map(FinishReason::from_raw)
+    }
+}
+
+/// Why a provider stopped generating, normalized across providers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FinishReason {
+    /// Natural end of the message or a stop sequence was hit.
+    Stop,
+    /// Generation was cut off by `max_tokens` or the context window.
+    Length,
+    /// The model stopped to request one or more tool calls.
+    ToolCalls,
+    /// Output was withheld or truncated by a safety filter.
+    ContentFilter,
+    /// Any provider-specific reason without a normalized equivalent.
+    Other(String),
+}
+
+impl FinishReason {
+    /// Map a provider's native stop reason onto the normalized variants.
+    ///
+    /// Matching is case-insensitive so OpenAI (`"length"`), Anthropic
+    /// (`"max_tokens"`) and Gemini (`"MAX_TOKENS"`) spellings all agree.
+    pub fn from_raw(raw: &str) -> Self {
+        match raw.to_ascii_lowercase().as_str() {
+            "stop" | "end_turn" | "stop_sequence" => Self::Stop,
+            "length" | "max_tokens" => Self::Length,
+            "tool_calls" | "tool_use" | "function_call" => Self::ToolCalls,
+            "content_filter" | "safety" | "recitation" | "blocklist" | "prohibited_content"
+            | "spii" | "refusal" => Self::ContentFilter,
+            _ => Self::Other(raw.to_string()),
+        }
+    }
+
+    /// Canonical lowercase name of the variant; `Other` returns the raw value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Stop => "stop",
+            Self::Length => "length",
+            Self::ToolCalls => "tool_calls",
+            Self::ContentFilter => "content_filter",
+            Self::Other(raw) => raw,
+        }
+    }
+}
+
+impl std::fmt::Display for FinishReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[async_trait]
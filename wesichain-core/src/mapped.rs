@@ -0,0 +1,84 @@
+use std::marker::PhantomData;
+
+use futures::stream::BoxStream;
+
+use crate::{Runnable, StreamEvent, WesichainError};
+
+/// Runnable that applies a fallible function to another runnable's output.
+///
+/// Built with [`RunnableExt::map`](crate::RunnableExt::map). Only `invoke`
+/// is transformed: stream events carry no typed output, so `stream` yields
+/// the inner runnable's events unchanged.
+pub struct Mapped<R, F, Mid> {
+    inner: R,
+    f: F,
+    _marker: PhantomData<fn() -> Mid>,
+}
+
+impl<R, F, Mid> Mapped<R, F, Mid> {
+    pub fn new(inner: R, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<Input, Mid, Output, R, F> Runnable<Input, Output> for Mapped<R, F, Mid>
+where
+    Input: Send + 'static,
+    Mid: Send + 'static,
+    Output: Send + 'static,
+    R: Runnable<Input, Mid> + Send + Sync,
+    F: Fn(Mid) -> Result<Output, WesichainError> + Send + Sync,
+{
+    async fn invoke(&self, input: Input) -> Result<Output, WesichainError> {
+        let mid = self.inner.invoke(input).await?;
+        (self.f)(mid)
+    }
+
+    fn stream(&self, input: Input) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        self.inner.stream(input)
+    }
+}
+
+/// Runnable that applies an infallible function to another runnable's output.
+///
+/// Built with [`RunnableExt::map_ok`](crate::RunnableExt::map_ok); streams
+/// pass through unchanged, as with [`Mapped`].
+pub struct MappedOk<R, F, Mid> {
+    inner: R,
+    f: F,
+    _marker: PhantomData<fn() -> Mid>,
+}
+
+impl<R, F, Mid> MappedOk<R, F, Mid> {
+    pub fn new(inner: R, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<Input, Mid, Output, R, F> Runnable<Input, Output> for MappedOk<R, F, Mid>
+where
+    Input: Send + 'static,
+    Mid: Send + 'static,
+    Output: Send + 'static,
+    R: Runnable<Input, Mid> + Send + Sync,
+    F: Fn(Mid) -> Output + Send + Sync,
+{
+    async fn invoke(&self, input: Input) -> Result<Output, WesichainError> {
+        let mid = self.inner.invoke(input).await?;
+        Ok((self.f)(mid))
+    }
+
+    fn stream(&self, input: Input) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        self.inner.stream(input)
+    }
+}
@@ -13,4 +13,19 @@ pub enum MetadataFilter {
     },
     All(Vec<MetadataFilter>),
     Any(Vec<MetadataFilter>),
+    /// Matches documents whose metadata has a non-null value for the key.
+    ///
+    /// A null value counts as missing, as it does for Weaviate's `IsNull`.
+    /// Qdrant's `is_empty` also treats an empty array as missing.
+    Exists(String),
+    /// Matches documents whose metadata lacks the key or holds null for it.
+    NotExists(String),
+    /// Backend-native filter passed through verbatim, for operators the typed
+    /// variants can't express (Qdrant geo conditions, Weaviate
+    /// `valueGeoRange`, Chroma document filters, ...).
+    ///
+    /// Not portable: the value is only meaningful to the store it was written
+    /// for, skips all validation, and stores without a native filter language
+    /// reject it with [`StoreError::UnsupportedFilter`](crate::StoreError::UnsupportedFilter).
+    Raw(Value),
 }
@@ -17,9 +17,19 @@ pub trait BaseOutputParser<Input: Send + Sync + 'static, Output: Send + Sync + '
 
 /// A parser that converts `LlmResponse` or `String` into a `String`.
 /// If input is `LlmResponse`, it extracts the `content`.
-#[derive(Clone, Default)]
+///
+/// Returns the content unchanged and ignores any tool calls; use
+/// [`builder`](Self::builder) for a parser that trims the text or rejects
+/// responses that carry tool calls.
+#[derive(Clone, Debug, Default)]
 pub struct StrOutputParser;
 
+impl StrOutputParser {
+    pub fn builder() -> StrOutputParserBuilder {
+        StrOutputParserBuilder::default()
+    }
+}
+
 #[async_trait]
 impl Runnable<LlmResponse, String> for StrOutputParser {
     async fn invoke(&self, input: LlmResponse) -> Result<String, WesichainError> {
@@ -49,18 +59,214 @@ impl Runnable<String, String> for StrOutputParser {
     }
 }
 
+/// What a [`ConfiguredStrOutputParser`] does with an `LlmResponse` that has
+/// tool calls.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToolCallPolicy {
+    /// Return the text content and drop the tool calls.
+    #[default]
+    Ignore,
+    /// Fail with [`WesichainError::ParseFailed`].
+    Error,
+}
+
+/// Configures a [`ConfiguredStrOutputParser`]. Created with
+/// [`StrOutputParser::builder`].
+#[derive(Clone, Debug, Default)]
+pub struct StrOutputParserBuilder {
+    trim: bool,
+    on_tool_calls: ToolCallPolicy,
+}
+
+impl StrOutputParserBuilder {
+    /// Strip leading and trailing whitespace from the output.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    pub fn on_tool_calls(mut self, policy: ToolCallPolicy) -> Self {
+        self.on_tool_calls = policy;
+        self
+    }
+
+    pub fn build(self) -> ConfiguredStrOutputParser {
+        ConfiguredStrOutputParser {
+            trim: self.trim,
+            on_tool_calls: self.on_tool_calls,
+        }
+    }
+}
+
+/// A [`StrOutputParser`] with trimming and a [`ToolCallPolicy`].
+///
+/// Not serializable: `save_runnable` rejects it rather than persisting a
+/// plain `StrOutputParser` that would lose the configuration.
+#[derive(Clone, Debug)]
+pub struct ConfiguredStrOutputParser {
+    trim: bool,
+    on_tool_calls: ToolCallPolicy,
+}
+
+impl ConfiguredStrOutputParser {
+    fn parse_text(&self, text: String) -> String {
+        if self.trim {
+            text.trim().to_string()
+        } else {
+            text
+        }
+    }
+
+    fn parse_response(&self, response: LlmResponse) -> Result<String, WesichainError> {
+        if self.on_tool_calls == ToolCallPolicy::Error && !response.tool_calls.is_empty() {
+            let names: Vec<&str> = response
+                .tool_calls
+                .iter()
+                .map(|call| call.name.as_str())
+                .collect();
+            return Err(WesichainError::ParseFailed {
+                reason: format!("expected text but got tool calls: {}", names.join(", ")),
+                output: response.content,
+            });
+        }
+        Ok(self.parse_text(response.content))
+    }
+}
+
+#[async_trait]
+impl Runnable<LlmResponse, String> for ConfiguredStrOutputParser {
+    async fn invoke(&self, input: LlmResponse) -> Result<String, WesichainError> {
+        self.parse_response(input)
+    }
+
+    fn stream(&self, input: LlmResponse) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        let output = self.parse_response(input);
+        futures::stream::once(async move { output.map(StreamEvent::ContentChunk) }).boxed()
+    }
+}
+
+#[async_trait]
+impl Runnable<String, String> for ConfiguredStrOutputParser {
+    async fn invoke(&self, input: String) -> Result<String, WesichainError> {
+        Ok(self.parse_text(input))
+    }
+
+    fn stream(&self, input: String) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        let output = self.parse_text(input);
+        futures::stream::once(async move { Ok(StreamEvent::ContentChunk(output)) }).boxed()
+    }
+}
+
 /// A parser that parses a JSON string (or LlmResponse content) into a structured type or Value.
+///
+/// By default the JSON may be wrapped in a markdown code fence or surrounded
+/// by prose; see [`extract_json`]. Use [`strict`](Self::strict) to require the
+/// whole input to be JSON.
 #[derive(Clone, Default)]
 pub struct JsonOutputParser<T = Value> {
+    strict: bool,
     _marker: PhantomData<T>,
 }
 
 impl<T> JsonOutputParser<T> {
     pub fn new() -> Self {
         Self {
+            strict: false,
             _marker: PhantomData,
         }
     }
+
+    /// Reject input that is not JSON as a whole instead of extracting the
+    /// JSON from fences or surrounding text.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+}
+
+/// Locate the JSON payload in free-form model output.
+///
+/// Returns the trimmed text if it already parses. Otherwise looks inside the
+/// first markdown code fence (` ```json `, ` ``` ` or inline backticks), then
+/// for the first balanced `{...}` or `[...]` span that parses. Falls back to
+/// the trimmed text so the caller reports the original parse error.
+pub fn extract_json(text: &str) -> &str {
+    let text = text.trim();
+    if is_json(text) {
+        return text;
+    }
+
+    let body = fenced_block(text).unwrap_or(text);
+    if is_json(body) {
+        return body;
+    }
+
+    first_json_span(body).unwrap_or(text)
+}
+
+fn is_json(text: &str) -> bool {
+    serde_json::from_str::<serde::de::IgnoredAny>(text).is_ok()
+}
+
+/// Body of the first code fence, without its language tag.
+fn fenced_block(text: &str) -> Option<&str> {
+    let start = text.find("```")?;
+    let after = &text[start + 3..];
+    let body = match after.find('\n') {
+        Some(newline)
+            if after[..newline]
+                .trim()
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+        {
+            &after[newline + 1..]
+        }
+        _ => after,
+    };
+    let end = body.find("```").unwrap_or(body.len());
+    Some(body[..end].trim())
+}
+
+/// First span starting at `{` or `[` whose brackets balance and which parses
+/// as JSON. Brackets inside string literals are ignored.
+fn first_json_span(text: &str) -> Option<&str> {
+    text.char_indices()
+        .filter(|(_, c)| matches!(c, '{' | '['))
+        .filter_map(|(start, _)| balanced_end(&text[start..]).map(|end| &text[start..start + end]))
+        .find(|candidate| is_json(candidate))
+}
+
+fn balanced_end(text: &str) -> Option<usize> {
+    let mut closers = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => closers.push('}'),
+            '[' => closers.push(']'),
+            '}' | ']' => {
+                if closers.pop() != Some(c) {
+                    return None;
+                }
+                if closers.is_empty() {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
 }
 
 #[async_trait]
@@ -68,20 +274,10 @@ impl<T: DeserializeOwned + serde::Serialize + Send + Sync + 'static> Runnable<St
     for JsonOutputParser<T>
 {
     async fn invoke(&self, input: String) -> Result<T, WesichainError> {
-        // Basic cleanup of markdown code blocks if present
-        let cleaned = input.trim();
-        let cleaned = if cleaned.starts_with("```json") {
-            cleaned
-                .trim_start_matches("```json")
-                .trim_end_matches("```")
-                .trim()
-        } else if cleaned.starts_with("```") {
-            cleaned
-                .trim_start_matches("```")
-                .trim_end_matches("```")
-                .trim()
+        let cleaned = if self.strict {
+            input.trim()
         } else {
-            cleaned
+            extract_json(&input)
         };
 
         serde_json::from_str(cleaned).map_err(WesichainError::Serde)
@@ -138,6 +334,193 @@ impl<T> StructuredOutputParser<T> {
     }
 }
 
+impl<T: schemars::JsonSchema> StructuredOutputParser<T> {
+    /// Prompt text describing the JSON shape of `T`, derived from its JSON
+    /// Schema: one line per field (nested fields use dotted paths) followed by
+    /// an example value. Embed it in the prompt so the model emits output this
+    /// parser accepts.
+    pub fn format_instructions(&self) -> String {
+        let schema = serde_json::to_value(schemars::schema_for!(T)).unwrap_or(Value::Null);
+        let definitions = schema.get("definitions").cloned().unwrap_or(Value::Null);
+
+        let mut lines = vec![
+            "Respond with a single JSON value matching the schema below, with no other text."
+                .to_string(),
+        ];
+        describe_fields(&schema, &definitions, "", &mut lines, 0);
+        let example = example_value(&schema, &definitions, 0);
+        let example = serde_json::to_string_pretty(&example).unwrap_or_default();
+
+        format!(
+            "{}\n\nExample:\n```json\n{}\n```",
+            lines.join("\n"),
+            example
+        )
+    }
+}
+
+/// Deepest level of nested objects described or exemplified.
+const MAX_SCHEMA_DEPTH: usize = 4;
+
+/// Follows `$ref`s into `definitions` and unwraps the single-entry `allOf`
+/// schemars emits for documented references.
+fn resolve_schema<'a>(schema: &'a Value, definitions: &'a Value) -> &'a Value {
+    if let Some(name) = schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix("#/definitions/"))
+    {
+        if let Some(target) = definitions.get(name) {
+            return resolve_schema(target, definitions);
+        }
+    }
+    match schema.get("allOf").and_then(Value::as_array) {
+        Some(all) if all.len() == 1 => resolve_schema(&all[0], definitions),
+        _ => schema,
+    }
+}
+
+fn describe_type(schema: &Value, definitions: &Value) -> String {
+    let schema = resolve_schema(schema, definitions);
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        let values: Vec<String> = values.iter().map(Value::to_string).collect();
+        return format!("one of {}", values.join(", "));
+    }
+    if let Some(variants) = schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(Value::as_array)
+    {
+        let variants: Vec<String> = variants
+            .iter()
+            .map(|variant| describe_type(variant, definitions))
+            .collect();
+        return variants.join(" or ");
+    }
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(kind)) => vec![kind.as_str()],
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if types.is_empty() {
+        return "any".to_string();
+    }
+    types
+        .into_iter()
+        .map(|kind| match (kind, schema.get("items")) {
+            ("array", Some(items)) => format!("array of {}", describe_type(items, definitions)),
+            (kind, _) => kind.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+fn describe_fields(
+    schema: &Value,
+    definitions: &Value,
+    prefix: &str,
+    lines: &mut Vec<String>,
+    depth: usize,
+) {
+    let schema = resolve_schema(schema, definitions);
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    for (name, property) in properties {
+        let path = format!("{prefix}{name}");
+        let resolved = resolve_schema(property, definitions);
+        let presence = if required.contains(&name.as_str()) {
+            "required"
+        } else {
+            "optional"
+        };
+        let mut line = format!(
+            "- `{path}` ({}, {presence})",
+            describe_type(property, definitions)
+        );
+        if let Some(description) = property
+            .get("description")
+            .or_else(|| resolved.get("description"))
+            .and_then(Value::as_str)
+        {
+            line.push_str(": ");
+            line.push_str(description);
+        }
+        lines.push(line);
+
+        if depth < MAX_SCHEMA_DEPTH {
+            describe_fields(property, definitions, &format!("{path}."), lines, depth + 1);
+        }
+    }
+}
+
+fn example_value(schema: &Value, definitions: &Value, depth: usize) -> Value {
+    let schema = resolve_schema(schema, definitions);
+    if let Some(first) = schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|values| values.first())
+    {
+        return first.clone();
+    }
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        if depth >= MAX_SCHEMA_DEPTH {
+            return Value::Object(Default::default());
+        }
+        return Value::Object(
+            properties
+                .iter()
+                .map(|(name, property)| {
+                    (
+                        name.clone(),
+                        example_value(property, definitions, depth + 1),
+                    )
+                })
+                .collect(),
+        );
+    }
+    if let Some(variants) = schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(Value::as_array)
+    {
+        return variants
+            .iter()
+            .map(|variant| example_value(variant, definitions, depth))
+            .find(|value| !value.is_null())
+            .unwrap_or(Value::Null);
+    }
+    let kind = match schema.get("type") {
+        Some(Value::String(kind)) => kind.as_str(),
+        Some(Value::Array(kinds)) => kinds
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|kind| *kind != "null")
+            .unwrap_or("null"),
+        _ => "null",
+    };
+    match kind {
+        "string" => Value::String("string".to_string()),
+        "integer" => Value::from(0),
+        "number" => Value::from(0.0),
+        "boolean" => Value::Bool(true),
+        "array" => match schema.get("items") {
+            Some(items) if depth < MAX_SCHEMA_DEPTH => {
+                Value::Array(vec![example_value(items, definitions, depth + 1)])
+            }
+            _ => Value::Array(Vec::new()),
+        },
+        "object" => Value::Object(Default::default()),
+        _ => Value::Null,
+    }
+}
+
 #[async_trait]
 impl<T: DeserializeOwned + serde::Serialize + Send + Sync + 'static> Runnable<LlmResponse, T>
     for StructuredOutputParser<T>
@@ -150,20 +533,7 @@ impl<T: DeserializeOwned + serde::Serialize + Send + Sync + 'static> Runnable<Ll
         }
 
         // 2. Fallback to content parsing (reuse logic from JsonOutputParser)
-        let content = input.content.trim();
-        let cleaned = if content.starts_with("```json") {
-            content
-                .trim_start_matches("```json")
-                .trim_end_matches("```")
-                .trim()
-        } else if content.starts_with("```") {
-            content
-                .trim_start_matches("```")
-                .trim_end_matches("```")
-                .trim()
-        } else {
-            content
-        };
+        let cleaned = extract_json(&input.content);
 
         if cleaned.is_empty() {
             return Err(WesichainError::Custom(
@@ -240,6 +610,7 @@ where
                         content: response.content.into(),
                         tool_call_id: None,
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                     current_request.messages.push(Message {
                         role: Role::User,
@@ -249,6 +620,7 @@ where
                         ).into(),
                         tool_call_id: None,
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                 }
             }
@@ -9,7 +9,22 @@ use std::fs;
 use std::path::Path;
 use std::sync::Arc;
 
+/// Persistence format version written by [`save_runnable`], as `major.minor`.
+///
+/// Files from an older minor of the same major still load; files from another
+/// major, or a newer minor, are rejected.
+pub const PERSISTENCE_VERSION: &str = "0.3";
+
+#[derive(serde::Serialize)]
+struct PersistedRunnableRef<'a> {
+    wesichain_version: &'a str,
+    runnable: &'a SerializableRunnable,
+}
+
 /// Save a Runnable to a JSON file.
+///
+/// The runnable is wrapped in a `{ "wesichain_version": ..., "runnable": ... }`
+/// envelope so later versions can tell which format they are reading.
 pub fn save_runnable<Input, Output>(
     path: impl AsRef<Path>,
     runnable: &dyn Runnable<Input, Output>,
@@ -21,12 +36,71 @@ where
     let serializable = runnable
         .to_serializable()
         .ok_or_else(|| WesichainError::Custom("Runnable is not serializable".to_string()))?;
-    let serialized = serde_json::to_string_pretty(&serializable).map_err(WesichainError::Serde)?;
+    let envelope = PersistedRunnableRef {
+        wesichain_version: PERSISTENCE_VERSION,
+        runnable: &serializable,
+    };
+    let serialized = serde_json::to_string_pretty(&envelope).map_err(WesichainError::Serde)?;
     fs::write(path, serialized)
         .map_err(|e| WesichainError::Custom(format!("Failed to write file: {}", e)))?;
     Ok(())
 }
 
+/// Parse the contents of a file written by [`save_runnable`].
+///
+/// Files without a version envelope predate it and are read as the current
+/// version, with a warning.
+pub fn parse_persisted(content: &str) -> Result<SerializableRunnable, WesichainError> {
+    let mut value: Value = serde_json::from_str(content).map_err(WesichainError::Serde)?;
+    let Some(version) = value.get("wesichain_version") else {
+        tracing::warn!(
+            "persisted runnable has no wesichain_version; assuming {}",
+            PERSISTENCE_VERSION
+        );
+        return serde_json::from_value(value).map_err(WesichainError::Serde);
+    };
+    let version = version.as_str().ok_or_else(|| {
+        WesichainError::Custom(format!("wesichain_version must be a string, got {version}"))
+    })?;
+    check_version(version)?;
+    let runnable = value
+        .get_mut("runnable")
+        .map(Value::take)
+        .ok_or_else(|| WesichainError::Custom("persisted file has no 'runnable'".to_string()))?;
+    serde_json::from_value(runnable).map_err(WesichainError::Serde)
+}
+
+fn parse_version(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+    Some((major, minor))
+}
+
+fn check_version(version: &str) -> Result<(), WesichainError> {
+    let (major, minor) = parse_version(version)
+        .ok_or_else(|| WesichainError::Custom(format!("invalid wesichain_version '{version}'")))?;
+    let (current_major, current_minor) =
+        parse_version(PERSISTENCE_VERSION).expect("PERSISTENCE_VERSION is major.minor");
+    if major != current_major {
+        return Err(WesichainError::Custom(format!(
+            "persisted runnable version {version} is incompatible with {PERSISTENCE_VERSION}"
+        )));
+    }
+    if minor > current_minor {
+        return Err(WesichainError::Custom(format!(
+            "persisted runnable version {version} is newer than supported {PERSISTENCE_VERSION}"
+        )));
+    }
+    Ok(())
+}
+
+fn read_persisted(path: impl AsRef<Path>) -> Result<SerializableRunnable, WesichainError> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| WesichainError::Custom(format!("Failed to read file: {}", e)))?;
+    parse_persisted(&content)
+}
+
 use crate::registry::RunnableRegistry;
 
 use crate::{IntoValue, TryFromValue};
@@ -71,10 +145,7 @@ where
     Input: IntoValue + TryFromValue + Send + Sync + 'static,
     Output: IntoValue + TryFromValue + Send + Sync + 'static,
 {
-    let content = fs::read_to_string(path)
-        .map_err(|e| WesichainError::Custom(format!("Failed to read file: {}", e)))?;
-    let serializable: SerializableRunnable =
-        serde_json::from_str(&content).map_err(WesichainError::Serde)?;
+    let serializable = read_persisted(path)?;
 
     let arc = reconstruct(serializable, registry)?;
     // Wrap Arc in Box to match return type.
@@ -333,11 +404,15 @@ where
         }
         SerializableRunnable::Tool {
             name,
-            schema: _,
-            description: _,
+            schema,
+            description,
         } => {
             if let Some(reg) = registry {
-                let tool = reg.lookup_tool(&name, serde_json::json!({}))?;
+                let config = serde_json::json!({
+                    "description": description,
+                    "schema": schema,
+                });
+                let tool = reg.lookup_tool(&name, config)?;
                 struct ToolWrapper(Arc<dyn crate::Tool>);
                 #[async_trait::async_trait]
                 impl Runnable<Value, Value> for ToolWrapper {
@@ -382,15 +457,48 @@ where
                 ))
             }
         }
+        SerializableRunnable::Binding { inner, bound } => {
+            let inner: Arc<dyn Runnable<Value, Value> + Send + Sync> =
+                reconstruct(*inner, registry)?;
+            Ok(Arc::new(RuntimeChainAdapter {
+                inner: crate::chain::RuntimeChain::new(vec![Arc::new(
+                    crate::RunnableBinding::new(inner, bound),
+                )]),
+                _marker: PhantomData,
+            }))
+        }
+        SerializableRunnable::JsonPath { path, lenient } => Ok(Arc::new(RuntimeChainAdapter {
+            inner: crate::chain::RuntimeChain::new(vec![Arc::new(
+                crate::JsonPathRunnable::new(path).with_lenient(lenient),
+            )]),
+            _marker: PhantomData,
+        })),
+        SerializableRunnable::Assign { steps } => {
+            let mut runtime_steps = BTreeMap::new();
+            for (key, val) in steps {
+                let runnable = reconstruct(val, registry)?;
+                runtime_steps.insert(key, runnable as Arc<dyn Runnable<Value, Value>>);
+            }
+            Ok(Arc::new(RuntimeChainAdapter {
+                inner: crate::chain::RuntimeChain::new(vec![Arc::new(crate::RunnableAssign::new(
+                    runtime_steps,
+                ))]),
+                _marker: PhantomData,
+            }))
+        }
+        SerializableRunnable::Branch { branches, .. } => {
+            let labels: Vec<_> = branches.into_iter().map(|branch| branch.label).collect();
+            Err(WesichainError::Custom(format!(
+                "cannot reconstruct branch predicates ({}); rebuild the RunnableBranch in code",
+                labels.join(", ")
+            )))
+        }
     }
 }
 
 // Helper to load specific known types for testing
 pub fn load_str_parser(path: impl AsRef<Path>) -> Result<StrOutputParser, WesichainError> {
-    let content = fs::read_to_string(path)
-        .map_err(|e| WesichainError::Custom(format!("Failed to read file: {}", e)))?;
-    let ser: SerializableRunnable =
-        serde_json::from_str(&content).map_err(WesichainError::Serde)?;
+    let ser = read_persisted(path)?;
     if let SerializableRunnable::Parser { kind, .. } = ser {
         if kind == "str" {
             Ok(StrOutputParser)
@@ -403,10 +511,7 @@ pub fn load_str_parser(path: impl AsRef<Path>) -> Result<StrOutputParser, Wesich
 }
 
 pub fn load_json_parser<T>(path: impl AsRef<Path>) -> Result<JsonOutputParser<T>, WesichainError> {
-    let content = fs::read_to_string(path)
-        .map_err(|e| WesichainError::Custom(format!("Failed to read file: {}", e)))?;
-    let ser: SerializableRunnable =
-        serde_json::from_str(&content).map_err(WesichainError::Serde)?;
+    let ser = read_persisted(path)?;
     if let SerializableRunnable::Parser { kind, .. } = ser {
         if kind == "json" {
             Ok(JsonOutputParser::new())
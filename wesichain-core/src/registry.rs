@@ -1,3 +1,18 @@
+//! Factories for components that can't be rebuilt from their saved form
+//! alone.
+//!
+//! [`load_runnable`](crate::load_runnable) and [`reconstruct`](crate::reconstruct)
+//! rebuild chains, parsers and passthroughs on their own, but an LLM, tool or
+//! prompt is saved only by name and settings. Register a factory for each one a
+//! saved chain refers to and pass the registry when loading:
+//!
+//! ```ignore
+//! let mut registry = RunnableRegistry::new();
+//! registry.register_llm("gpt-4o-mini", |params| Ok(Arc::new(MyLlm::from_params(params)?)));
+//! registry.register_tool("search", |config| Ok(Arc::new(SearchTool::new())));
+//! let chain: Box<dyn Runnable<Value, Value>> = load_runnable(path, Some(&registry))?;
+//! ```
+
 use crate::tool::Tool;
 use crate::{Runnable, WesichainError};
 use serde_json::Value;
@@ -19,6 +34,7 @@ type PromptFactory = Box<
         + Sync,
 >;
 
+/// Named factories consulted when reconstructing saved runnables.
 #[derive(Default)]
 pub struct RunnableRegistry {
     tool_factories: HashMap<String, ToolFactory>,
@@ -31,6 +47,10 @@ impl RunnableRegistry {
         Self::default()
     }
 
+    /// Register the factory for tools saved under `name`.
+    ///
+    /// The factory receives the saved `description` and `schema` as a JSON
+    /// object; either is `null` when it was not saved.
     pub fn register_tool<F>(&mut self, name: &str, factory: F)
     where
         F: Fn(Value) -> Result<Arc<dyn Tool>, WesichainError> + Send + Sync + 'static,
@@ -39,6 +59,8 @@ impl RunnableRegistry {
             .insert(name.to_string(), Box::new(factory));
     }
 
+    /// Register the factory for LLMs saved with model `name`. The factory
+    /// receives the saved `params`.
     pub fn register_llm<F>(&mut self, name: &str, factory: F)
     where
         F: Fn(
@@ -53,6 +75,9 @@ impl RunnableRegistry {
             .insert(name.to_string(), Box::new(factory));
     }
 
+    /// Register a prompt factory, called with the saved template and input
+    /// variables. Saved prompts carry no name, so reconstruction uses the
+    /// factory registered as `"default"`.
     pub fn register_prompt<F>(&mut self, name: &str, factory: F)
     where
         F: Fn(String, Vec<String>) -> Result<Arc<dyn Runnable<Value, Value>>, WesichainError>
@@ -69,8 +94,9 @@ impl RunnableRegistry {
             factory(config)
         } else {
             Err(WesichainError::Custom(format!(
-                "Tool '{}' not found in registry",
-                name
+                "Tool '{}' not found in registry (registered: {})",
+                name,
+                registered_names(&self.tool_factories)
             )))
         }
     }
@@ -98,9 +124,19 @@ impl RunnableRegistry {
             factory(config)
         } else {
             Err(WesichainError::Custom(format!(
-                "LLM '{}' not found in registry",
-                name
+                "LLM '{}' not found in registry (registered: {})",
+                name,
+                registered_names(&self.llm_factories)
             )))
         }
     }
 }
+
+fn registered_names<F>(factories: &HashMap<String, F>) -> String {
+    let mut names: Vec<&str> = factories.keys().map(String::as_str).collect();
+    if names.is_empty() {
+        return "none".to_string();
+    }
+    names.sort_unstable();
+    names.join(", ")
+}
@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+
+use crate::{Document, WesichainError};
+
+/// Reorders retrieved documents by relevance to a query.
+///
+/// Implementations return every document they keep paired with its score,
+/// best match first; callers truncate to the number they need.
+#[async_trait]
+pub trait Reranker: Send + Sync {
+    async fn rerank(
+        &self,
+        query: &str,
+        docs: Vec<Document>,
+    ) -> Result<Vec<(Document, f32)>, WesichainError>;
+}
+
+type ScoreFn = dyn Fn(&str, &Document) -> f32 + Send + Sync;
+
+/// A [`Reranker`] that scores each document with an injected closure.
+///
+/// Useful for heuristic scoring (keyword overlap, metadata boosts) and for
+/// deterministic tests. Ties keep their input order.
+pub struct LocalScoreReranker {
+    score: Box<ScoreFn>,
+}
+
+impl LocalScoreReranker {
+    pub fn new<F>(score: F) -> Self
+    where
+        F: Fn(&str, &Document) -> f32 + Send + Sync + 'static,
+    {
+        Self {
+            score: Box::new(score),
+        }
+    }
+}
+
+#[async_trait]
+impl Reranker for LocalScoreReranker {
+    async fn rerank(
+        &self,
+        query: &str,
+        docs: Vec<Document>,
+    ) -> Result<Vec<(Document, f32)>, WesichainError> {
+        let mut scored: Vec<(Document, f32)> = docs
+            .into_iter()
+            .map(|doc| {
+                let score = (self.score)(query, &doc);
+                (doc, score)
+            })
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(scored)
+    }
+}
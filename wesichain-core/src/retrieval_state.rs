@@ -2,12 +2,23 @@ use crate::{Document, MetadataFilter};
 
 pub trait HasQuery {
     fn query(&self) -> &str;
+
+    /// Per-query result count. `None` defers to the retriever's configured default.
+    fn top_k(&self) -> Option<usize> {
+        None
+    }
 }
 
 pub trait HasRetrievedDocs {
     fn set_retrieved_docs(&mut self, docs: Vec<Document>);
 }
 
+/// Read access to the documents a [`HasRetrievedDocs`] state holds, for nodes
+/// that post-process retrieval results (e.g. reranking).
+pub trait ReadRetrievedDocs: HasRetrievedDocs {
+    fn retrieved_docs(&self) -> &[Document];
+}
+
 pub trait HasMetadataFilter {
     fn metadata_filter(&self) -> Option<MetadataFilter>;
 }
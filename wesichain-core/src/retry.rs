@@ -1,11 +1,18 @@
+use std::sync::Arc;
+
 use futures::stream::BoxStream;
 use rand::Rng;
 
+use crate::callbacks::{ensure_object, CallbackManager, RunContext, ToTraceOutput};
 use crate::{Runnable, StreamEvent, WesichainError};
 
+type RetryPredicate = Arc<dyn Fn(&WesichainError) -> bool + Send + Sync>;
+
 pub struct Retrying<R> {
     runnable: R,
     max_attempts: usize,
+    callbacks: Option<(CallbackManager, RunContext)>,
+    retry_if: Option<RetryPredicate>,
 }
 
 impl<R> Retrying<R> {
@@ -13,18 +20,44 @@ impl<R> Retrying<R> {
         Self {
             runnable,
             max_attempts,
+            callbacks: None,
+            retry_if: None,
+        }
+    }
+
+    /// Decide which errors are retried, replacing
+    /// [`WesichainError::is_retryable`].
+    pub fn with_retry_if<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&WesichainError) -> bool + Send + Sync + 'static,
+    {
+        self.retry_if = Some(Arc::new(predicate));
+        self
+    }
+
+    fn should_retry(&self, error: &WesichainError) -> bool {
+        match &self.retry_if {
+            Some(predicate) => predicate(error),
+            None => error.is_retryable(),
         }
     }
+
+    /// Report each retried attempt to `manager` via `on_retry`, using `ctx` as the run context.
+    pub fn with_callbacks(mut self, manager: CallbackManager, ctx: RunContext) -> Self {
+        self.callbacks = Some((manager, ctx));
+        self
+    }
 }
 
-pub fn is_retryable(error: &WesichainError) -> bool {
-    matches!(
-        error,
-        WesichainError::LlmProvider(_)
-            | WesichainError::ToolCallFailed { .. }
-            | WesichainError::Timeout(_)
-            | WesichainError::RateLimitExceeded { .. }
-    )
+async fn notify_retry(
+    callbacks: &Option<(CallbackManager, RunContext)>,
+    attempt: usize,
+    error: &WesichainError,
+) {
+    if let Some((manager, ctx)) = callbacks {
+        let error = ensure_object(error.to_string().to_trace_output());
+        manager.on_retry(ctx, attempt, &error).await;
+    }
 }
 
 #[async_trait::async_trait]
@@ -45,7 +78,7 @@ where
             match self.runnable.invoke(input.clone()).await {
                 Ok(output) => return Ok(output),
                 Err(error) => {
-                    if !is_retryable(&error) || attempt >= self.max_attempts {
+                    if !self.should_retry(&error) || attempt >= self.max_attempts {
                         if attempt >= self.max_attempts {
                             return Err(WesichainError::MaxRetriesExceeded {
                                 max: self.max_attempts,
@@ -54,6 +87,8 @@ where
                         return Err(error);
                     }
 
+                    notify_retry(&self.callbacks, attempt, &error).await;
+
                     // Exponential backoff: base 100ms * 2^(attempt-1)
                     // Cap at ~10s (attempt 7+) to avoid excessive delays in interactive apps
                     let base_delay_ms = 100u64 * (1u64 << (attempt - 1).min(7));
@@ -73,6 +108,7 @@ where
         use futures::StreamExt as _;
         let runnable = &self.runnable;
         let max_attempts = self.max_attempts;
+        let callbacks = &self.callbacks;
 
         async_stream::stream! {
             if max_attempts == 0 {
@@ -88,7 +124,10 @@ where
                 match inner.next().await {
                     None => break,
                     Some(first) => {
-                        if matches!(&first, Err(e) if is_retryable(e) && attempt < max_attempts) {
+                        if matches!(&first, Err(e) if self.should_retry(e) && attempt < max_attempts) {
+                            if let Err(error) = &first {
+                                notify_retry(callbacks, attempt, error).await;
+                            }
                             let base_delay_ms = 100u64 * (1u64 << (attempt - 1).min(7));
                             let jitter_ms = rand::thread_rng().gen_range(0..100u64);
                             let delay = std::time::Duration::from_millis(base_delay_ms + jitter_ms);
@@ -98,7 +137,7 @@ where
 
                         // Exhausted retries on a retryable error → emit MaxRetriesExceeded
                         let item = match first {
-                            Err(ref e) if is_retryable(e) => {
+                            Err(ref e) if self.should_retry(e) => {
                                 Err(WesichainError::MaxRetriesExceeded { max: max_attempts })
                             }
                             item => item,
@@ -1,8 +1,9 @@
 use async_trait::async_trait;
-use futures::stream::BoxStream;
+use futures::stream::{BoxStream, StreamExt};
+use std::any::Any;
 use std::sync::Arc;
 
-use crate::{serde::SerializableRunnable, WesichainError};
+use crate::{serde::SerializableRunnable, LlmResponse, Value, WesichainError};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum StreamEvent {
@@ -35,13 +36,50 @@ pub enum StreamEvent {
     },
 }
 
+/// Options for [`Runnable::batch_with_config`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchConfig {
+    /// Maximum number of inputs invoked at once; `None` invokes all of them
+    /// concurrently.
+    pub max_concurrency: Option<usize>,
+}
+
+impl BatchConfig {
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+}
+
 #[async_trait]
 pub trait Runnable<Input: Send + 'static, Output: Send + 'static>: Send + Sync {
     async fn invoke(&self, input: Input) -> Result<Output, WesichainError>;
 
+    /// Invoke every input concurrently. Results are returned in input order.
     async fn batch(&self, inputs: Vec<Input>) -> Vec<Result<Output, WesichainError>> {
+        self.batch_with_config(inputs, BatchConfig::default()).await
+    }
+
+    /// Invoke every input, at most `config.max_concurrency` at a time, and
+    /// return the results in input order.
+    ///
+    /// Runnables backed by a native batch endpoint override this method;
+    /// [`batch`](Self::batch) delegates to it.
+    async fn batch_with_config(
+        &self,
+        inputs: Vec<Input>,
+        config: BatchConfig,
+    ) -> Vec<Result<Output, WesichainError>> {
         let futures = inputs.into_iter().map(|i| self.invoke(i));
-        futures::future::join_all(futures).await
+        match config.max_concurrency {
+            Some(limit) => {
+                futures::stream::iter(futures)
+                    .buffered(limit.max(1))
+                    .collect()
+                    .await
+            }
+            None => futures::future::join_all(futures).await,
+        }
     }
 
     async fn abatch(&self, inputs: Vec<Input>) -> Vec<Result<Output, WesichainError>> {
@@ -52,7 +90,47 @@ pub trait Runnable<Input: Send + 'static, Output: Send + 'static>: Send + Sync {
         None
     }
 
-    fn stream<'a>(&'a self, input: Input) -> BoxStream<'a, Result<StreamEvent, WesichainError>>;
+    /// Stream the events produced while running `input`.
+    ///
+    /// The default runs [`invoke`](Self::invoke) and yields a single
+    /// [`StreamEvent::FinalAnswer`] (or the error). The trait places no
+    /// `Serialize` bound on `Output`, so only `String`, [`LlmResponse`] (its
+    /// `content`) and [`Value`] (as JSON text) outputs can be rendered; for
+    /// any other output the stream yields an error instead. Runnables that
+    /// produce incremental output, or whose output is of another type,
+    /// override this method.
+    fn stream<'a>(&'a self, input: Input) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
+        futures::stream::once(async move {
+            let output = self.invoke(input).await?;
+            final_answer_text(output).map(StreamEvent::FinalAnswer)
+        })
+        .boxed()
+    }
+}
+
+/// Text carried by the [`StreamEvent::FinalAnswer`] of the default
+/// [`Runnable::stream`], or an error for outputs it cannot render.
+fn final_answer_text<Output: 'static>(output: Output) -> Result<String, WesichainError> {
+    let output: Box<dyn Any> = Box::new(output);
+    let output = match output.downcast::<String>() {
+        Ok(text) => return Ok(*text),
+        Err(output) => output,
+    };
+    let output = match output.downcast::<LlmResponse>() {
+        Ok(response) => return Ok(response.content),
+        Err(output) => output,
+    };
+    match output.downcast::<Value>() {
+        Ok(value) => Ok(match *value {
+            Value::String(text) => text,
+            other => other.to_string(),
+        }),
+        Err(_) => Err(WesichainError::Custom(format!(
+            "the default Runnable::stream cannot render output of type `{}`; \
+             override stream for this runnable",
+            std::any::type_name::<Output>()
+        ))),
+    }
 }
 
 #[async_trait]
@@ -66,6 +144,18 @@ where
         (**self).invoke(input).await
     }
 
+    async fn batch(&self, inputs: Vec<Input>) -> Vec<Result<Output, WesichainError>> {
+        (**self).batch(inputs).await
+    }
+
+    async fn batch_with_config(
+        &self,
+        inputs: Vec<Input>,
+        config: BatchConfig,
+    ) -> Vec<Result<Output, WesichainError>> {
+        (**self).batch_with_config(inputs, config).await
+    }
+
     fn stream<'a>(&'a self, input: Input) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
         (**self).stream(input)
     }
@@ -86,6 +176,18 @@ where
         (**self).invoke(input).await
     }
 
+    async fn batch(&self, inputs: Vec<Input>) -> Vec<Result<Output, WesichainError>> {
+        (**self).batch(inputs).await
+    }
+
+    async fn batch_with_config(
+        &self,
+        inputs: Vec<Input>,
+        config: BatchConfig,
+    ) -> Vec<Result<Output, WesichainError>> {
+        (**self).batch_with_config(inputs, config).await
+    }
+
     fn stream<'a>(&'a self, input: Input) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
         (**self).stream(input)
     }
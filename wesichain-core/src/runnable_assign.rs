@@ -0,0 +1,62 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use futures::future::join_all;
+
+use crate::serde::SerializableRunnable;
+use crate::{Runnable, Value, WesichainError};
+
+/// Runnable that passes its object input through with computed keys added,
+/// like LangChain's `RunnablePassthrough.assign`.
+///
+/// Each assigned runnable runs concurrently on the full input, and its output
+/// is stored under its key. Other keys are kept as they were; an assigned key
+/// that already exists in the input is overwritten. Non-object inputs are an
+/// error.
+#[derive(Clone)]
+pub struct RunnableAssign {
+    steps: BTreeMap<String, Arc<dyn Runnable<Value, Value>>>,
+}
+
+impl RunnableAssign {
+    pub fn new(steps: BTreeMap<String, Arc<dyn Runnable<Value, Value>>>) -> Self {
+        Self { steps }
+    }
+
+    /// Add or replace the runnable computing `key`.
+    pub fn assign(
+        mut self,
+        key: impl Into<String>,
+        runnable: Arc<dyn Runnable<Value, Value>>,
+    ) -> Self {
+        self.steps.insert(key.into(), runnable);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Runnable<Value, Value> for RunnableAssign {
+    async fn invoke(&self, input: Value) -> Result<Value, WesichainError> {
+        let Value::Object(fields) = &input else {
+            return Err(WesichainError::Custom(format!(
+                "RunnableAssign expects a JSON object input, got {input}"
+            )));
+        };
+
+        let results = join_all(self.steps.values().map(|step| step.invoke(input.clone()))).await;
+
+        let mut output = fields.clone();
+        for (key, result) in self.steps.keys().zip(results) {
+            output.insert(key.clone(), result?);
+        }
+        Ok(Value::Object(output))
+    }
+
+    fn to_serializable(&self) -> Option<SerializableRunnable> {
+        let mut steps = HashMap::new();
+        for (key, step) in &self.steps {
+            steps.insert(key.clone(), step.to_serializable()?);
+        }
+        Some(SerializableRunnable::Assign { steps })
+    }
+}
@@ -5,14 +5,29 @@ use crate::{Runnable, StreamEvent, WesichainError};
 use async_trait::async_trait;
 use futures::future::join_all;
 use futures::stream::{self, BoxStream, StreamExt};
+use tokio::sync::Semaphore;
 
 pub struct RunnableParallel<Input, Output> {
     steps: BTreeMap<String, Arc<dyn Runnable<Input, Output> + Send + Sync>>,
+    max_concurrency: Option<usize>,
 }
 
 impl<Input, Output> RunnableParallel<Input, Output> {
     pub fn new(steps: BTreeMap<String, Arc<dyn Runnable<Input, Output> + Send + Sync>>) -> Self {
-        Self { steps }
+        Self {
+            steps,
+            max_concurrency: None,
+        }
+    }
+
+    /// Run at most `limit` branches at once. `0` means unbounded.
+    pub fn with_max_concurrency(mut self, limit: usize) -> Self {
+        self.max_concurrency = (limit > 0).then_some(limit);
+        self
+    }
+
+    fn semaphore(&self) -> Option<Arc<Semaphore>> {
+        self.max_concurrency.map(|limit| Arc::new(Semaphore::new(limit)))
     }
 }
 
@@ -23,12 +38,21 @@ where
     Output: Send + Sync + 'static,
 {
     async fn invoke(&self, input: Input) -> Result<BTreeMap<String, Output>, WesichainError> {
+        let semaphore = self.semaphore();
         let mut keys = Vec::new();
         let mut futures = Vec::new();
 
         for (key, step) in &self.steps {
             keys.push(key.clone());
-            futures.push(step.invoke(input.clone()));
+            let semaphore = semaphore.clone();
+            let input = input.clone();
+            futures.push(async move {
+                let _permit = match &semaphore {
+                    Some(semaphore) => semaphore.acquire().await.ok(),
+                    None => None,
+                };
+                step.invoke(input).await
+            });
         }
 
         let results = join_all(futures).await;
@@ -44,6 +68,8 @@ where
     fn stream<'a>(&'a self, input: Input) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
         // Fan-out: each branch emits a Metadata event tagging its name, then its own stream.
         // All branch streams are merged with select_all for interleaved output.
+        // With a concurrency limit, a branch holds its permit until its stream ends.
+        let semaphore = self.semaphore();
         let streams: Vec<BoxStream<'a, Result<StreamEvent, WesichainError>>> = self
             .steps
             .iter()
@@ -53,9 +79,17 @@ where
                     key: "parallel_step".to_string(),
                     value: serde_json::json!(branch_name),
                 });
-                stream::once(std::future::ready(metadata_event))
-                    .chain(step.stream(input.clone()))
-                    .boxed()
+                let branch = stream::once(std::future::ready(metadata_event))
+                    .chain(step.stream(input.clone()));
+                match semaphore.clone() {
+                    Some(semaphore) => Box::pin(async_stream::stream! {
+                        let _permit = semaphore.acquire_owned().await;
+                        for await event in branch {
+                            yield event;
+                        }
+                    }),
+                    None => branch.boxed(),
+                }
             })
             .collect();
 
@@ -147,6 +181,69 @@ mod tests {
         assert_eq!(content_count, 2);
     }
 
+    struct TrackedRunnable {
+        active: Arc<std::sync::atomic::AtomicUsize>,
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Runnable<String, String> for TrackedRunnable {
+        async fn invoke(&self, input: String) -> Result<String, WesichainError> {
+            use std::sync::atomic::Ordering;
+            let now = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.active.fetch_sub(1, Ordering::SeqCst);
+            Ok(input)
+        }
+
+        fn stream<'a>(
+            &'a self,
+            _input: String,
+        ) -> BoxStream<'a, Result<StreamEvent, WesichainError>> {
+            stream::empty().boxed()
+        }
+    }
+
+    type Steps = BTreeMap<String, Arc<dyn Runnable<String, String> + Send + Sync>>;
+
+    fn tracked_steps(count: usize) -> (Steps, Arc<std::sync::atomic::AtomicUsize>) {
+        let active = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let peak = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut steps = Steps::new();
+        for i in 0..count {
+            steps.insert(
+                format!("branch_{i}"),
+                Arc::new(TrackedRunnable {
+                    active: active.clone(),
+                    peak: peak.clone(),
+                }),
+            );
+        }
+        (steps, peak)
+    }
+
+    #[tokio::test]
+    async fn test_parallel_max_concurrency_bounds_branches() {
+        let (steps, peak) = tracked_steps(8);
+        let parallel = RunnableParallel::new(steps).with_max_concurrency(3);
+        let result = parallel.invoke("input".to_string()).await.unwrap();
+
+        assert_eq!(result.len(), 8);
+        assert!(result.values().all(|value| value == "input"));
+        let peak = peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(peak <= 3, "peak concurrency {peak} exceeded limit");
+        assert!(peak > 1, "branches should still overlap");
+    }
+
+    #[tokio::test]
+    async fn test_parallel_zero_max_concurrency_is_unbounded() {
+        let (steps, peak) = tracked_steps(4);
+        let parallel = RunnableParallel::new(steps).with_max_concurrency(0);
+        parallel.invoke("input".to_string()).await.unwrap();
+        assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 4);
+    }
+
     #[tokio::test]
     async fn test_parallel_stream_empty() {
         let steps: BTreeMap<String, Arc<dyn Runnable<String, String> + Send + Sync>> =
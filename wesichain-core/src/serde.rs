@@ -36,6 +36,34 @@ pub enum SerializableRunnable {
         schema: Option<Value>,
     },
     Passthrough,
+    /// A runnable with arguments bound via [`RunnableExt::bind`](crate::RunnableExt::bind).
+    Binding {
+        inner: Box<SerializableRunnable>,
+        bound: Value,
+    },
+    /// A [`RunnableBranch`](crate::RunnableBranch). Predicates are code, so
+    /// only their labels are kept and the branch can't be reconstructed.
+    Branch {
+        branches: Vec<SerializableBranch>,
+        default: Box<SerializableRunnable>,
+    },
+    /// A [`JsonPathRunnable`](crate::JsonPathRunnable).
+    JsonPath {
+        path: String,
+        #[serde(default)]
+        lenient: bool,
+    },
+    /// A [`RunnableAssign`](crate::RunnableAssign).
+    Assign {
+        steps: HashMap<String, SerializableRunnable>,
+    },
+}
+
+/// One labelled case of a [`SerializableRunnable::Branch`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SerializableBranch {
+    pub label: String,
+    pub runnable: SerializableRunnable,
 }
 
 impl SerializableRunnable {
@@ -0,0 +1,73 @@
+//! Vector similarity primitives shared by in-memory search, MMR and score
+//! normalization.
+//!
+//! Every function rejects vectors of different lengths with
+//! [`StoreError::DimensionMismatch`] and never returns NaN for zero vectors.
+
+use crate::StoreError;
+
+/// Similarity or distance measure between two embeddings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Metric {
+    #[default]
+    Cosine,
+    Dot,
+    Euclidean,
+}
+
+impl Metric {
+    /// Score `a` against `b` so that higher always means closer.
+    ///
+    /// Cosine and dot return the raw value; euclidean distance `d` is mapped
+    /// to `1 / (1 + d)`, which is `1.0` for identical vectors.
+    pub fn score(self, a: &[f32], b: &[f32]) -> Result<f32, StoreError> {
+        match self {
+            Self::Cosine => cosine(a, b),
+            Self::Dot => dot(a, b),
+            Self::Euclidean => euclidean(a, b).map(|distance| 1.0 / (1.0 + distance)),
+        }
+    }
+}
+
+/// Cosine similarity in `[-1, 1]`. Zero if either vector is all zeros.
+pub fn cosine(a: &[f32], b: &[f32]) -> Result<f32, StoreError> {
+    check_lengths(a, b)?;
+    let mut dot = 0.0;
+    let mut norm_a = 0.0;
+    let mut norm_b = 0.0;
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return Ok(0.0);
+    }
+    Ok(dot / (norm_a.sqrt() * norm_b.sqrt()))
+}
+
+/// Dot product.
+pub fn dot(a: &[f32], b: &[f32]) -> Result<f32, StoreError> {
+    check_lengths(a, b)?;
+    Ok(a.iter().zip(b).map(|(x, y)| x * y).sum())
+}
+
+/// Euclidean (L2) distance; lower means closer.
+pub fn euclidean(a: &[f32], b: &[f32]) -> Result<f32, StoreError> {
+    check_lengths(a, b)?;
+    Ok(a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f32>()
+        .sqrt())
+}
+
+fn check_lengths(a: &[f32], b: &[f32]) -> Result<(), StoreError> {
+    if a.len() != b.len() {
+        return Err(StoreError::DimensionMismatch {
+            expected: a.len(),
+            got: b.len(),
+        });
+    }
+    Ok(())
+}
@@ -88,12 +88,33 @@ impl<S: StateSchema> GraphState<S> {
 #[serde(bound = "S: StateSchema")]
 pub struct StateUpdate<S: StateSchema> {
     pub data: S::Update,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    goto: Vec<String>,
 }
 #[cfg(test)]
 #[path = "state_tests.rs"]
 mod state_tests;
 impl<S: StateSchema> StateUpdate<S> {
     pub fn new(data: S::Update) -> Self {
-        Self { data }
+        Self {
+            data,
+            goto: Vec::new(),
+        }
+    }
+
+    /// Route to `targets` after this update is applied, in addition to any
+    /// edges leaving the node. Lets a node pick follow-up nodes at runtime.
+    pub fn with_goto<I, T>(mut self, targets: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.goto.extend(targets.into_iter().map(Into::into));
+        self
+    }
+
+    /// Nodes to enqueue after this update, on top of the node's static edges.
+    pub fn goto(&self) -> &[String] {
+        &self.goto
     }
 }
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+use tokio::time::Instant;
+
+use crate::{StreamEvent, WesichainError};
+
+/// Coalesce consecutive `ContentChunk`s into fewer, larger chunks.
+///
+/// Buffered text is flushed as one `ContentChunk` once it reaches
+/// `max_buffer` characters or `min_interval` after its first chunk arrived,
+/// whichever comes first. Any other event, including errors, flushes the
+/// buffer and is then passed through immediately, so event order is kept.
+/// Remaining text is flushed when the input ends.
+pub fn buffer_stream<'a, S>(
+    stream: S,
+    min_interval: Duration,
+    max_buffer: usize,
+) -> BoxStream<'a, Result<StreamEvent, WesichainError>>
+where
+    S: Stream<Item = Result<StreamEvent, WesichainError>> + Send + 'a,
+{
+    async_stream::stream! {
+        let mut stream = Box::pin(stream);
+        let mut buffer = String::new();
+        let mut buffered_chars = 0;
+        let mut deadline: Option<Instant> = None;
+
+        loop {
+            let next = match deadline {
+                Some(at) => match tokio::time::timeout_at(at, stream.next()).await {
+                    Ok(next) => next,
+                    Err(_) => {
+                        deadline = None;
+                        buffered_chars = 0;
+                        yield Ok(StreamEvent::ContentChunk(std::mem::take(&mut buffer)));
+                        continue;
+                    }
+                },
+                None => stream.next().await,
+            };
+
+            match next {
+                Some(Ok(StreamEvent::ContentChunk(chunk))) => {
+                    if buffer.is_empty() {
+                        deadline = Some(Instant::now() + min_interval);
+                    }
+                    buffered_chars += chunk.chars().count();
+                    buffer.push_str(&chunk);
+                    if buffered_chars >= max_buffer {
+                        deadline = None;
+                        buffered_chars = 0;
+                        yield Ok(StreamEvent::ContentChunk(std::mem::take(&mut buffer)));
+                    }
+                }
+                Some(other) => {
+                    if !buffer.is_empty() {
+                        deadline = None;
+                        buffered_chars = 0;
+                        yield Ok(StreamEvent::ContentChunk(std::mem::take(&mut buffer)));
+                    }
+                    yield other;
+                }
+                None => {
+                    if !buffer.is_empty() {
+                        yield Ok(StreamEvent::ContentChunk(buffer));
+                    }
+                    break;
+                }
+            }
+        }
+    }
+    .boxed()
+}
@@ -0,0 +1,59 @@
+use futures::{Stream, StreamExt};
+
+use crate::{LlmResponse, StreamEvent, TokenUsage, ToolCallAssembler, WesichainError};
+
+/// Drain an LLM event stream and fold it into a complete [`LlmResponse`].
+///
+/// `ContentChunk`s are concatenated into `content`. A `FinalAnswer` only
+/// supplies the content when no chunks were streamed, since several providers
+/// repeat the full text there. Tool calls are assembled with a
+/// [`ToolCallAssembler`] and finished once the stream ends. The last
+/// `UsageUpdate` becomes `usage`. Other events are ignored.
+///
+/// Returns the first error yielded by the stream, or
+/// [`WesichainError::ParseFailed`] if a call's joined fragments are not valid
+/// JSON.
+pub async fn collect_stream<S>(stream: S) -> Result<LlmResponse, WesichainError>
+where
+    S: Stream<Item = Result<StreamEvent, WesichainError>>,
+{
+    let mut stream = std::pin::pin!(stream);
+    let mut content = String::new();
+    let mut final_answer = None;
+    let mut calls = ToolCallAssembler::new();
+    let mut usage = None;
+
+    while let Some(event) = stream.next().await {
+        match event? {
+            StreamEvent::ContentChunk(chunk) => content.push_str(&chunk),
+            StreamEvent::FinalAnswer(answer) => final_answer = Some(answer),
+            StreamEvent::ToolCallStart { id, name } => calls.start(&id, &name),
+            StreamEvent::ToolCallDelta { id, delta } => calls.delta(&id, delta),
+            StreamEvent::UsageUpdate {
+                input_tokens,
+                output_tokens,
+                ..
+            } => {
+                usage = Some(TokenUsage {
+                    prompt_tokens: input_tokens,
+                    completion_tokens: output_tokens,
+                    total_tokens: input_tokens + output_tokens,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    if content.is_empty() {
+        content = final_answer.unwrap_or_default();
+    }
+
+    let tool_calls = calls.finish()?;
+
+    Ok(LlmResponse {
+        content,
+        tool_calls,
+        usage,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,210 @@
+//! Helpers for testing code that consumes LLM output or event streams.
+//!
+//! Enabled by the `test-util` feature; add it to a crate's dev-dependencies:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! wesichain-core = { path = "../wesichain-core", features = ["test-util"] }
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use futures::stream::{self, BoxStream, StreamExt};
+
+use crate::{
+    Embedding, EmbeddingError, LlmRequest, LlmResponse, Runnable, StreamEvent, ToolCallingLlm,
+    Value, WesichainError,
+};
+
+/// A [`StreamEvent::ContentChunk`].
+pub fn chunk(text: impl Into<String>) -> StreamEvent {
+    StreamEvent::ContentChunk(text.into())
+}
+
+/// A [`StreamEvent::ToolCallStart`].
+pub fn tool_start(id: impl Into<String>, name: impl Into<String>) -> StreamEvent {
+    StreamEvent::ToolCallStart {
+        id: id.into(),
+        name: name.into(),
+    }
+}
+
+/// A [`StreamEvent::ToolCallDelta`]. Pass a `Value::String` for a raw JSON
+/// fragment, or any other value for whole arguments.
+pub fn tool_delta(id: impl Into<String>, delta: Value) -> StreamEvent {
+    StreamEvent::ToolCallDelta {
+        id: id.into(),
+        delta,
+    }
+}
+
+/// A [`StreamEvent::FinalAnswer`].
+pub fn final_answer(text: impl Into<String>) -> StreamEvent {
+    StreamEvent::FinalAnswer(text.into())
+}
+
+/// Stream `events` as successful items, in order.
+pub fn stream_from(
+    events: impl IntoIterator<Item = StreamEvent>,
+) -> BoxStream<'static, Result<StreamEvent, WesichainError>> {
+    let events: Vec<_> = events.into_iter().map(Ok).collect();
+    stream::iter(events).boxed()
+}
+
+/// Events a streaming provider would emit for `response`: its content as a
+/// single chunk, one start and delta per tool call, then the final answer.
+pub fn response_events(response: &LlmResponse) -> Vec<StreamEvent> {
+    let mut events = Vec::new();
+    if !response.content.is_empty() {
+        events.push(chunk(response.content.clone()));
+    }
+    for call in &response.tool_calls {
+        events.push(tool_start(call.id.clone(), call.name.clone()));
+        events.push(tool_delta(call.id.clone(), call.args.clone()));
+    }
+    events.push(final_answer(response.content.clone()));
+    events
+}
+
+/// Scripted LLM that replays canned responses and records its requests.
+///
+/// Each call consumes the next scripted response; the last one is repeated
+/// once the script runs out. `stream` yields the events set with
+/// [`with_stream`](Self::with_stream), or otherwise the
+/// [`response_events`] of the next response.
+#[derive(Clone, Default)]
+pub struct MockLlm {
+    responses: Arc<Mutex<VecDeque<LlmResponse>>>,
+    stream: Option<Vec<StreamEvent>>,
+    requests: Arc<Mutex<Vec<LlmRequest>>>,
+}
+
+impl MockLlm {
+    pub fn new(responses: impl IntoIterator<Item = LlmResponse>) -> Self {
+        Self {
+            responses: Arc::new(Mutex::new(responses.into_iter().collect())),
+            ..Self::default()
+        }
+    }
+
+    /// A mock that always answers with `content`.
+    pub fn text(content: impl Into<String>) -> Self {
+        Self::new([LlmResponse {
+            content: content.into(),
+            ..LlmResponse::default()
+        }])
+    }
+
+    /// Stream exactly `events` instead of deriving them from the response.
+    pub fn with_stream(mut self, events: impl IntoIterator<Item = StreamEvent>) -> Self {
+        self.stream = Some(events.into_iter().collect());
+        self
+    }
+
+    /// Requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<LlmRequest> {
+        self.requests
+            .lock()
+            .expect("mock llm lock poisoned")
+            .clone()
+    }
+
+    fn next_response(&self, request: LlmRequest) -> LlmResponse {
+        self.requests
+            .lock()
+            .expect("mock llm lock poisoned")
+            .push(request);
+        let mut responses = self.responses.lock().expect("mock llm lock poisoned");
+        if responses.len() > 1 {
+            responses.pop_front().unwrap_or_default()
+        } else {
+            responses.front().cloned().unwrap_or_default()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, LlmResponse> for MockLlm {
+    async fn invoke(&self, input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        Ok(self.next_response(input))
+    }
+
+    fn stream(&self, input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        let response = self.next_response(input);
+        match &self.stream {
+            Some(events) => stream_from(events.clone()),
+            None => stream_from(response_events(&response)),
+        }
+    }
+}
+
+impl ToolCallingLlm for MockLlm {}
+
+/// Embedding that returns registered vectors for known texts.
+///
+/// Register texts with [`with_mapping`](Self::with_mapping) to control exact
+/// similarities, e.g. for asserting nearest-neighbour order in store tests.
+/// Any other text gets a deterministic hash-derived vector.
+#[derive(Clone, Debug)]
+pub struct FakeEmbedding {
+    dimension: usize,
+    mappings: HashMap<String, Vec<f32>>,
+}
+
+impl FakeEmbedding {
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension,
+            mappings: HashMap::new(),
+        }
+    }
+
+    /// Embed `text` as exactly `vector`.
+    ///
+    /// # Panics
+    ///
+    /// If `vector` doesn't have the embedder's dimension.
+    pub fn with_mapping(mut self, text: impl Into<String>, vector: Vec<f32>) -> Self {
+        assert_eq!(
+            vector.len(),
+            self.dimension,
+            "fake embedding vector must have {} dimensions",
+            self.dimension
+        );
+        self.mappings.insert(text.into(), vector);
+        self
+    }
+
+    fn vector(&self, text: &str) -> Vec<f32> {
+        if let Some(vector) = self.mappings.get(text) {
+            return vector.clone();
+        }
+        (0..self.dimension)
+            .map(|index| {
+                // FNV-1a, seeded per component.
+                let mut hash = 0xcbf2_9ce4_8422_2325_u64 ^ index as u64;
+                for byte in text.as_bytes() {
+                    hash ^= u64::from(*byte);
+                    hash = hash.wrapping_mul(0x0100_0000_01b3);
+                }
+                (hash % 10_000) as f32 / 10_000.0
+            })
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedding for FakeEmbedding {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        Ok(self.vector(text))
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        Ok(texts.iter().map(|text| self.vector(text)).collect())
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
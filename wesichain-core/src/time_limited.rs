@@ -5,6 +5,14 @@ use futures::StreamExt as _;
 
 use crate::{Runnable, StreamEvent, WesichainError};
 
+/// Bounds how long a runnable may run. Built with
+/// [`RunnableExt::with_timeout`](crate::RunnableExt::with_timeout).
+///
+/// `invoke` fails with [`WesichainError::Timeout`] if the whole call takes
+/// longer than the timeout. `stream` applies it as an idle timeout instead: a
+/// long stream is fine as long as each event arrives within the timeout of
+/// the previous one (or of the start). On expiry the stream yields a single
+/// `Timeout` error and ends.
 pub struct TimeLimited<R> {
     inner: R,
     timeout: Duration,
@@ -1,4 +1,5 @@
 use crate::Value;
+use futures::stream::{self, BoxStream, StreamExt};
 use thiserror::Error;
 
 pub use tokio_util::sync::CancellationToken;
@@ -9,18 +10,43 @@ pub enum ToolError {
     InvalidInput(String),
     #[error("execution failed: {0}")]
     ExecutionFailed(String),
+    /// A transient failure, such as a timeout, that may succeed if retried.
+    #[error("retryable failure: {0}")]
+    Retryable(String),
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
 }
 
+impl ToolError {
+    /// Whether retrying the same call may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Retryable(_))
+    }
+}
+
 #[async_trait::async_trait]
 pub trait Tool: Send + Sync {
     fn name(&self) -> &str;
     fn description(&self) -> &str;
     fn schema(&self) -> Value;
     async fn invoke(&self, args: Value) -> Result<Value, ToolError>;
+
+    /// Whether callers should prefer [`invoke_stream`](Self::invoke_stream)
+    /// over `invoke`. Tools that override `invoke_stream` return `true`.
+    fn streams_output(&self) -> bool {
+        false
+    }
+
+    /// Run the tool, yielding its output in chunks as it is produced, e.g.
+    /// the lines of a long command's output. String chunks are joined into
+    /// the final result by hosts such as `ReActToolNode`.
+    ///
+    /// Defaults to a single chunk holding the result of `invoke`.
+    fn invoke_stream(&self, args: Value) -> BoxStream<'_, Result<Value, ToolError>> {
+        stream::once(self.invoke(args)).boxed()
+    }
 }
 
 #[derive(Clone, Debug)]
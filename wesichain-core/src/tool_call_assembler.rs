@@ -0,0 +1,114 @@
+use crate::{StreamEvent, ToolCall, Value, WesichainError};
+
+/// Arguments received so far for one tool call.
+enum PendingArgs {
+    /// No delta seen yet.
+    Empty,
+    /// Raw JSON text streamed as string fragments (OpenAI-style).
+    Fragments(String),
+    /// Structured arguments delivered as a whole value (Anthropic, Gemini).
+    Value(Value),
+}
+
+struct PendingCall {
+    id: String,
+    name: String,
+    args: PendingArgs,
+}
+
+/// Folds streamed `ToolCallStart`/`ToolCallDelta` events into finished
+/// [`ToolCall`]s.
+///
+/// Calls are keyed by id and kept in the order they were first seen, however
+/// their events interleave. String deltas are raw JSON fragments: they are
+/// buffered per id and only parsed in [`finish`](Self::finish), so a fragment
+/// may end anywhere, including mid-token or inside an escape sequence. A
+/// non-string delta is taken as the arguments directly.
+#[derive(Default)]
+pub struct ToolCallAssembler {
+    calls: Vec<PendingCall>,
+}
+
+impl ToolCallAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed `event`, returning whether it was a tool call event. Other
+    /// events are ignored.
+    pub fn push(&mut self, event: &StreamEvent) -> bool {
+        match event {
+            StreamEvent::ToolCallStart { id, name } => self.start(id, name),
+            StreamEvent::ToolCallDelta { id, delta } => self.delta(id, delta.clone()),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Record the start of call `id`. A repeated start only renames it.
+    pub fn start(&mut self, id: &str, name: &str) {
+        let index = self.index_of(id);
+        self.calls[index].name = name.to_string();
+    }
+
+    /// Append a delta to call `id`, registering it unnamed if it has not
+    /// started yet.
+    pub fn delta(&mut self, id: &str, delta: Value) {
+        let index = self.index_of(id);
+        let args = &mut self.calls[index].args;
+        match (args, delta) {
+            (PendingArgs::Fragments(buf), Value::String(fragment)) => buf.push_str(&fragment),
+            (args, Value::String(fragment)) => *args = PendingArgs::Fragments(fragment),
+            (args, value) => *args = PendingArgs::Value(value),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Parse every call's arguments. Calls with no arguments get an empty
+    /// object.
+    ///
+    /// Returns [`WesichainError::ParseFailed`] naming the call id if its
+    /// joined fragments are not valid JSON.
+    pub fn finish(self) -> Result<Vec<ToolCall>, WesichainError> {
+        self.calls
+            .into_iter()
+            .map(|call| {
+                let args = match call.args {
+                    PendingArgs::Empty => Value::Object(Default::default()),
+                    PendingArgs::Value(value) => value,
+                    PendingArgs::Fragments(raw) if raw.trim().is_empty() => {
+                        Value::Object(Default::default())
+                    }
+                    PendingArgs::Fragments(raw) => {
+                        serde_json::from_str(&raw).map_err(|err| WesichainError::ParseFailed {
+                            reason: format!("invalid arguments for tool call '{}': {err}", call.id),
+                            output: raw,
+                        })?
+                    }
+                };
+                Ok(ToolCall {
+                    id: call.id,
+                    name: call.name,
+                    args,
+                })
+            })
+            .collect()
+    }
+
+    fn index_of(&mut self, id: &str) -> usize {
+        match self.calls.iter().position(|call| call.id == id) {
+            Some(index) => index,
+            None => {
+                self.calls.push(PendingCall {
+                    id: id.to_string(),
+                    name: String::new(),
+                    args: PendingArgs::Empty,
+                });
+                self.calls.len() - 1
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::TokenUsage;
+
+/// Thread-safe running total of [`TokenUsage`] across many LLM calls.
+///
+/// Share it behind an `Arc` and call [`add`](Self::add) from each caller;
+/// [`total`](Self::total) can be read at any time. Sums are kept in `u64` and
+/// saturate to `u32::MAX` when converted back to [`TokenUsage`].
+#[derive(Debug, Default)]
+pub struct UsageAccumulator {
+    prompt_tokens: AtomicU64,
+    completion_tokens: AtomicU64,
+    total_tokens: AtomicU64,
+    calls: AtomicU64,
+}
+
+impl UsageAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one call's usage to the running total.
+    pub fn add(&self, usage: &TokenUsage) {
+        self.prompt_tokens
+            .fetch_add(u64::from(usage.prompt_tokens), Ordering::Relaxed);
+        self.completion_tokens
+            .fetch_add(u64::from(usage.completion_tokens), Ordering::Relaxed);
+        self.total_tokens
+            .fetch_add(u64::from(usage.total_tokens), Ordering::Relaxed);
+        self.calls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Usage summed over every [`add`](Self::add) so far.
+    pub fn total(&self) -> TokenUsage {
+        let load = |counter: &AtomicU64| {
+            u32::try_from(counter.load(Ordering::Relaxed)).unwrap_or(u32::MAX)
+        };
+        TokenUsage {
+            prompt_tokens: load(&self.prompt_tokens),
+            completion_tokens: load(&self.completion_tokens),
+            total_tokens: load(&self.total_tokens),
+        }
+    }
+
+    /// Number of calls recorded.
+    pub fn calls(&self) -> u64 {
+        self.calls.load(Ordering::Relaxed)
+    }
+}
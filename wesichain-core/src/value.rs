@@ -5,10 +5,20 @@ use crate::WesichainError;
 pub type Value = serde_json::Value;
 
 /// Converts a value into `Value`, falling back to `Value::Null` on serialization errors (v0 behavior).
+///
+/// Implemented for every `Serialize` type, so containers such as `Vec<T>`,
+/// `HashMap<String, T>`, `Option<T>` and tuples are covered whenever their
+/// elements are.
 pub trait IntoValue {
     fn into_value(self) -> Value;
 }
 
+/// Converts a `Value` back into a typed value.
+///
+/// Implemented for every `DeserializeOwned` type, including the same
+/// containers as [`IntoValue`]. Shape mismatches surface as
+/// [`WesichainError::Serde`], whose message names the expected JSON shape
+/// (e.g. "expected a sequence" or "expected a tuple of size 3").
 pub trait TryFromValue: Sized {
     fn try_from_value(value: Value) -> Result<Self, WesichainError>;
 }
@@ -30,3 +40,105 @@ where
         Ok(serde_json::from_value(value)?)
     }
 }
+
+/// Looks up a nested value by dotted path (e.g. `"a.b.c"` or `"items.0.name"`).
+///
+/// Numeric segments index into arrays; any other segment indexes into objects.
+/// Returns `None` if a segment is missing or the path runs into a scalar.
+/// An empty path returns the value itself.
+pub fn value_get_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if path.is_empty() {
+        return Some(value);
+    }
+    path.split('.')
+        .try_fold(value, |current, segment| match current {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+/// Sets a nested value by dotted path, creating intermediate objects as needed.
+///
+/// `Null` intermediates are replaced with empty objects. Array segments must be
+/// an existing index, or exactly the array length to append. Returns an error
+/// when the path runs into a scalar or an out-of-range index.
+pub fn value_set_path(
+    value: &mut Value,
+    path: &str,
+    new_value: Value,
+) -> Result<(), WesichainError> {
+    if path.is_empty() {
+        *value = new_value;
+        return Ok(());
+    }
+
+    let segments: Vec<&str> = path.split('.').collect();
+    let (last, parents) = segments
+        .split_last()
+        .expect("split yields at least one segment");
+
+    let mut current = value;
+    for (depth, segment) in parents.iter().enumerate() {
+        if current.is_null() {
+            *current = Value::Object(serde_json::Map::new());
+        }
+        current = match current {
+            Value::Object(map) => map
+                .entry(segment.to_string())
+                .or_insert_with(|| Value::Object(serde_json::Map::new())),
+            Value::Array(items) => {
+                let index = array_index(segment, items.len(), path)?;
+                if index == items.len() {
+                    items.push(Value::Object(serde_json::Map::new()));
+                }
+                &mut items[index]
+            }
+            other => return Err(path_type_mismatch(path, &segments[..depth], other)),
+        };
+    }
+
+    if current.is_null() {
+        *current = Value::Object(serde_json::Map::new());
+    }
+    match current {
+        Value::Object(map) => {
+            map.insert(last.to_string(), new_value);
+        }
+        Value::Array(items) => {
+            let index = array_index(last, items.len(), path)?;
+            if index == items.len() {
+                items.push(new_value);
+            } else {
+                items[index] = new_value;
+            }
+        }
+        other => return Err(path_type_mismatch(path, parents, other)),
+    }
+    Ok(())
+}
+
+fn array_index(segment: &str, len: usize, path: &str) -> Result<usize, WesichainError> {
+    match segment.parse::<usize>() {
+        Ok(index) if index <= len => Ok(index),
+        Ok(index) => Err(WesichainError::Custom(format!(
+            "path '{path}': index {index} out of bounds for array of length {len}"
+        ))),
+        Err(_) => Err(WesichainError::Custom(format!(
+            "path '{path}': expected array index, got '{segment}'"
+        ))),
+    }
+}
+
+fn path_type_mismatch(path: &str, prefix: &[&str], found: &Value) -> WesichainError {
+    let kind = match found {
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        _ => "non-container",
+    };
+    WesichainError::Custom(format!(
+        "path '{path}': cannot descend into {kind} at '{}'",
+        prefix.join(".")
+    ))
+}
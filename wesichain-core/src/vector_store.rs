@@ -1,7 +1,19 @@
+use std::collections::HashSet;
+
 use async_trait::async_trait;
 
 use crate::{Document, MetadataFilter, StoreError};
 
+/// Write semantics for [`VectorStore::add_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Insert new documents and overwrite existing ones with the same ID.
+    #[default]
+    Upsert,
+    /// Insert new documents; fail with [`StoreError::DuplicateId`] if any ID already exists.
+    InsertOnly,
+}
+
 #[derive(Clone, Debug)]
 pub struct SearchResult {
     pub document: Document,
@@ -19,6 +31,29 @@ pub trait VectorStore: Send + Sync {
     ) -> Result<Vec<SearchResult>, StoreError>;
     async fn delete(&self, ids: &[String]) -> Result<(), StoreError>;
 
+    /// Embedding dimension this store expects, if it can be determined
+    /// without a round trip to the backend.
+    ///
+    /// Returns `None` by default; stores that track their dimension locally
+    /// override this so callers can detect embedder mismatches up front.
+    fn dimension(&self) -> Option<usize> {
+        None
+    }
+
+    /// Add documents with explicit write semantics.
+    ///
+    /// `Upsert` delegates to [`add`](Self::add). Stores that cannot detect
+    /// existing IDs keep this default, which rejects `InsertOnly` rather than
+    /// silently overwriting.
+    async fn add_mode(&self, docs: Vec<Document>, mode: WriteMode) -> Result<(), StoreError> {
+        match mode {
+            WriteMode::Upsert => self.add(docs).await,
+            WriteMode::InsertOnly => Err(StoreError::Internal(Box::new(std::io::Error::other(
+                "insert-only writes are not supported by this vector store",
+            )))),
+        }
+    }
+
     async fn delete_strs(&self, ids: &[&str]) -> Result<(), StoreError>
     where
         Self: Sized,
@@ -55,6 +90,25 @@ impl<T: VectorStore + ?Sized> VectorStore for std::sync::Arc<T> {
     async fn delete(&self, ids: &[String]) -> Result<(), StoreError> {
         self.as_ref().delete(ids).await
     }
+
+    fn dimension(&self) -> Option<usize> {
+        self.as_ref().dimension()
+    }
+
+    async fn add_mode(&self, docs: Vec<Document>, mode: WriteMode) -> Result<(), StoreError> {
+        self.as_ref().add_mode(docs, mode).await
+    }
+}
+
+/// Returns the first ID that appears more than once within `docs`.
+///
+/// Insert-only implementations use this to reject batches that would conflict
+/// with themselves before asking the backend about existing IDs.
+pub fn find_duplicate_id(docs: &[Document]) -> Option<&str> {
+    let mut seen = HashSet::with_capacity(docs.len());
+    docs.iter()
+        .map(|doc| doc.id.as_str())
+        .find(|id| !seen.insert(*id))
 }
 
 pub async fn delete_strs_dyn(store: &dyn VectorStore, ids: &[&str]) -> Result<(), StoreError> {
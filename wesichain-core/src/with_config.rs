@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use futures::stream::BoxStream;
+
+use crate::callbacks::{
+    current_run_context, ensure_object, with_run_context, with_run_context_stream,
+};
+use crate::{
+    CallbackManager, RunContext, RunType, Runnable, StreamEvent, ToTraceInput, ToTraceOutput,
+    Value, WesichainError,
+};
+
+/// Runnable that labels its runs with extra tags and metadata, mirroring
+/// LangChain's `.with_config()`.
+///
+/// Built with [`RunnableExt::with_tags`](crate::RunnableExt::with_tags) or
+/// [`RunnableExt::with_metadata`](crate::RunnableExt::with_metadata). When
+/// invoked under an ambient run (see [`with_run_context`]), e.g. as a graph
+/// node or inside a [`TracedRunnable`](crate::TracedRunnable), it reports a
+/// child run carrying the merged tags and metadata, and runs nested inside it
+/// inherit them. With no ambient run, a manager set with
+/// [`with_callbacks`](Self::with_callbacks) gets a root run carrying the tags
+/// and metadata instead; without one the inner runnable is called directly.
+pub struct RunnableWithConfig<R> {
+    inner: R,
+    name: Option<String>,
+    tags: Vec<String>,
+    metadata: BTreeMap<String, Value>,
+    callbacks: Option<CallbackManager>,
+}
+
+impl<R> RunnableWithConfig<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            name: None,
+            tags: Vec::new(),
+            metadata: BTreeMap::new(),
+            callbacks: None,
+        }
+    }
+
+    /// Name of the reported run. Defaults to the inner runnable's type name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Add tags, keeping those already set.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        for tag in tags {
+            if !self.tags.contains(&tag) {
+                self.tags.push(tag);
+            }
+        }
+        self
+    }
+
+    /// Add metadata entries, replacing those with the same key.
+    pub fn with_metadata(mut self, metadata: BTreeMap<String, Value>) -> Self {
+        self.metadata.extend(metadata);
+        self
+    }
+
+    /// Report a root run to `callbacks` when invoked outside an ambient run.
+    pub fn with_callbacks(mut self, callbacks: CallbackManager) -> Self {
+        self.callbacks = Some(callbacks);
+        self
+    }
+
+    /// A child of the ambient run, or else a root run for the configured
+    /// callbacks, labeled with this wrapper's tags and metadata.
+    fn run(&self) -> Option<(CallbackManager, RunContext)> {
+        let name = self.name.clone().unwrap_or_else(short_type_name::<R>);
+        let Some((manager, parent)) = current_run_context().filter(|(m, _)| !m.is_noop()) else {
+            let manager = self.callbacks.clone().filter(|m| !m.is_noop())?;
+            let ctx = RunContext::root(
+                RunType::Runnable,
+                name,
+                self.tags.clone(),
+                self.metadata.clone(),
+            );
+            return Some((manager, ctx));
+        };
+        let mut ctx = parent.child(RunType::Runnable, name);
+        for tag in &self.tags {
+            if !ctx.tags.contains(tag) {
+                ctx.tags.push(tag.clone());
+            }
+        }
+        ctx.metadata
+            .extend(self.metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+        Some((manager, ctx))
+    }
+}
+
+#[async_trait::async_trait]
+impl<Input, Output, R> Runnable<Input, Output> for RunnableWithConfig<R>
+where
+    Input: Send + ToTraceInput + 'static,
+    Output: Send + ToTraceOutput + 'static,
+    R: Runnable<Input, Output> + Send + Sync,
+{
+    async fn invoke(&self, input: Input) -> Result<Output, WesichainError> {
+        let Some((manager, ctx)) = self.run() else {
+            return self.inner.invoke(input).await;
+        };
+
+        manager
+            .on_start(&ctx, &ensure_object(input.to_trace_input()))
+            .await;
+        let result = with_run_context(manager.clone(), ctx.clone(), self.inner.invoke(input)).await;
+        let duration_ms = ctx.start_instant.elapsed().as_millis();
+        match &result {
+            Ok(output) => {
+                let outputs = ensure_object(output.to_trace_output());
+                manager.on_end(&ctx, &outputs, duration_ms).await;
+            }
+            Err(err) => {
+                let error = ensure_object(err.to_string().to_trace_output());
+                manager.on_error(&ctx, &error, duration_ms).await;
+            }
+        }
+        result
+    }
+
+    fn stream(&self, input: Input) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        let Some((manager, ctx)) = self.run() else {
+            return self.inner.stream(input);
+        };
+
+        let inputs = ensure_object(input.to_trace_input());
+        let inner_stream =
+            with_run_context_stream(manager.clone(), ctx.clone(), || self.inner.stream(input));
+        Box::pin(async_stream::stream! {
+            manager.on_start(&ctx, &inputs).await;
+
+            let mut failure = None;
+            for await event in inner_stream {
+                if let Err(err) = &event {
+                    failure.get_or_insert_with(|| err.to_string());
+                }
+                yield event;
+            }
+
+            let duration_ms = ctx.start_instant.elapsed().as_millis();
+            match failure {
+                Some(message) => {
+                    let error = ensure_object(message.to_trace_output());
+                    manager.on_error(&ctx, &error, duration_ms).await;
+                }
+                None => {
+                    let outputs = ensure_object(Value::Object(Default::default()));
+                    manager.on_end(&ctx, &outputs, duration_ms).await;
+                }
+            }
+        })
+    }
+}
+
+/// `std::any::type_name` without module paths or generic arguments.
+fn short_type_name<T>() -> String {
+    let full = std::any::type_name::<T>();
+    let base = full.split('<').next().unwrap_or(full);
+    base.rsplit("::").next().unwrap_or(base).to_string()
+}
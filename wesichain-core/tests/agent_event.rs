@@ -8,6 +8,7 @@ fn agent_event_serializes_with_tagged_shape() {
         message: "Loading relevant chunks".to_string(),
         step: 1,
         thread_id: "thread-123".to_string(),
+        trace_id: None,
     };
 
     let value = serde_json::to_value(&event).expect("status event should serialize");
@@ -47,6 +48,7 @@ fn agent_event_step_accessor_returns_expected_values() {
         message: "Planning next action".to_string(),
         step: 3,
         thread_id: "thread-a".to_string(),
+        trace_id: None,
     };
     let metadata = AgentEvent::Metadata {
         key: "model".to_string(),
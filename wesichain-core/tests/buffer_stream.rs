@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use wesichain_core::{buffer_stream, StreamEvent, WesichainError};
+
+fn chunk(text: &str) -> Result<StreamEvent, WesichainError> {
+    Ok(StreamEvent::ContentChunk(text.to_string()))
+}
+
+fn contents(events: &[Result<StreamEvent, WesichainError>]) -> Vec<String> {
+    events
+        .iter()
+        .map(|event| match event {
+            Ok(StreamEvent::ContentChunk(text)) => format!("chunk:{text}"),
+            Ok(StreamEvent::FinalAnswer(text)) => format!("final:{text}"),
+            other => format!("{other:?}"),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn buffer_stream_coalesces_rapid_chunks_up_to_max_buffer() {
+    let input = stream::iter(vec![
+        chunk("He"),
+        chunk("llo"),
+        chunk(", "),
+        chunk("wor"),
+        chunk("ld"),
+    ]);
+
+    let events: Vec<_> = buffer_stream(input, Duration::from_secs(60), 5)
+        .collect()
+        .await;
+
+    assert_eq!(
+        contents(&events),
+        vec!["chunk:Hello", "chunk:, wor", "chunk:ld"]
+    );
+}
+
+#[tokio::test]
+async fn buffer_stream_flushes_before_final_answer() {
+    let input = stream::iter(vec![
+        chunk("4"),
+        chunk("2"),
+        Ok(StreamEvent::FinalAnswer("42".to_string())),
+        chunk("!"),
+    ]);
+
+    let events: Vec<_> = buffer_stream(input, Duration::from_secs(60), 100)
+        .collect()
+        .await;
+
+    assert_eq!(contents(&events), vec!["chunk:42", "final:42", "chunk:!"]);
+}
+
+#[tokio::test]
+async fn buffer_stream_flushes_after_min_interval() {
+    let input = stream::iter(vec![chunk("a"), chunk("b")]).chain(stream::once(async {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        chunk("c")
+    }));
+
+    let events: Vec<_> = buffer_stream(input, Duration::from_millis(20), 100)
+        .collect()
+        .await;
+
+    assert_eq!(contents(&events), vec!["chunk:ab", "chunk:c"]);
+}
+
+#[tokio::test]
+async fn buffer_stream_passes_errors_through_in_order() {
+    let input = stream::iter(vec![
+        chunk("partial"),
+        Err(WesichainError::LlmProvider("reset".to_string())),
+    ]);
+
+    let events: Vec<_> = buffer_stream(input, Duration::from_secs(60), 100)
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[0], Ok(StreamEvent::ContentChunk(text)) if text == "partial"));
+    assert!(matches!(&events[1], Err(WesichainError::LlmProvider(message)) if message == "reset"));
+}
@@ -0,0 +1,119 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use wesichain_core::{Runnable, RunnableExt, StreamEvent, WesichainError};
+
+/// Uppercases its input and counts how often it actually ran.
+#[derive(Clone, Default)]
+struct CountingUpper {
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl Runnable<String, String> for CountingUpper {
+    async fn invoke(&self, input: String) -> Result<String, WesichainError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if input.is_empty() {
+            return Err(WesichainError::Custom("empty input".to_string()));
+        }
+        Ok(input.to_uppercase())
+    }
+
+    fn stream(&self, input: String) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(vec![Ok(StreamEvent::ContentChunk(input))]).boxed()
+    }
+}
+
+#[tokio::test]
+async fn second_identical_invoke_hits_the_cache() {
+    let inner = CountingUpper::default();
+    let cached = inner.clone().with_cache(8);
+
+    assert_eq!(cached.invoke("hi".to_string()).await.unwrap(), "HI");
+    assert_eq!(cached.invoke("hi".to_string()).await.unwrap(), "HI");
+    assert_eq!(cached.invoke("other".to_string()).await.unwrap(), "OTHER");
+
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    assert_eq!(cached.len(), 2);
+}
+
+#[tokio::test]
+async fn expired_entries_are_recomputed() {
+    let inner = CountingUpper::default();
+    let cached = inner
+        .clone()
+        .with_cache(8)
+        .with_ttl(Duration::from_millis(50));
+
+    cached.invoke("hi".to_string()).await.unwrap();
+    cached.invoke("hi".to_string()).await.unwrap();
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+    tokio::time::sleep(Duration::from_millis(80)).await;
+    cached.invoke("hi".to_string()).await.unwrap();
+
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn least_recently_used_entry_is_evicted() {
+    let inner = CountingUpper::default();
+    let cached = inner.clone().with_cache(2);
+
+    for input in ["a", "b", "a", "c"] {
+        cached.invoke(input.to_string()).await.unwrap();
+    }
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 3);
+
+    // "b" was evicted to make room for "c"; "a" was used more recently.
+    cached.invoke("a".to_string()).await.unwrap();
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 3);
+    cached.invoke("b".to_string()).await.unwrap();
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn should_cache_predicate_and_errors_bypass_the_cache() {
+    let inner = CountingUpper::default();
+    let cached = inner
+        .clone()
+        .with_cache(8)
+        .with_should_cache(|input: &String| !input.starts_with("live:"));
+
+    cached.invoke("live:now".to_string()).await.unwrap();
+    cached.invoke("live:now".to_string()).await.unwrap();
+    assert!(cached.invoke(String::new()).await.is_err());
+    assert!(cached.invoke(String::new()).await.is_err());
+
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 4);
+    assert!(cached.is_empty());
+}
+
+#[tokio::test]
+async fn stream_passes_through() {
+    let cached = CountingUpper::default().with_cache(8);
+
+    let events: Vec<_> = cached.stream("hi".to_string()).collect().await;
+
+    assert!(matches!(&events[..], [Ok(StreamEvent::ContentChunk(text))] if text == "hi"));
+    assert!(cached.is_empty());
+}
+
+#[tokio::test]
+async fn distinct_inputs_never_share_an_entry() {
+    let inner = CountingUpper::default();
+    let cached = inner.clone().with_cache(64);
+
+    for i in 0..32 {
+        let input = format!("input {i}");
+        assert_eq!(
+            cached.invoke(input.clone()).await.unwrap(),
+            input.to_uppercase()
+        );
+    }
+
+    assert_eq!(inner.calls.load(Ordering::SeqCst), 32);
+    assert_eq!(cached.len(), 32);
+}
@@ -1,6 +1,13 @@
 use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 
-use wesichain_core::{ensure_object, CallbackManager, RunContext, RunType, Value};
+use futures::stream::{self, BoxStream, StreamExt};
+use wesichain_core::{
+    ensure_object, CallbackHandler, CallbackManager, Retrying, RunContext, RunType, Runnable,
+    StreamEvent, Value, WesichainError,
+};
 
 #[test]
 fn child_context_inherits_trace_and_parent() {
@@ -22,3 +29,88 @@ fn callback_manager_noop_has_no_handlers() {
     let manager = CallbackManager::noop();
     assert!(manager.is_noop());
 }
+
+#[derive(Default)]
+struct CountingHandler {
+    tool_starts: AtomicUsize,
+    tool_ends: AtomicUsize,
+    retries: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for CountingHandler {
+    async fn on_start(&self, _ctx: &RunContext, _inputs: &Value) {}
+    async fn on_end(&self, _ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {}
+    async fn on_error(&self, _ctx: &RunContext, _error: &Value, _duration_ms: u128) {}
+
+    async fn on_tool_start(&self, _ctx: &RunContext, _tool_name: &str, _args: &Value) {
+        self.tool_starts.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn on_tool_end(
+        &self,
+        _ctx: &RunContext,
+        _tool_name: &str,
+        _result: &Value,
+        _duration_ms: u128,
+    ) {
+        self.tool_ends.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn on_retry(&self, _ctx: &RunContext, _attempt: usize, _error: &Value) {
+        self.retries.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct FlakyRunnable {
+    failures: usize,
+    calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Runnable<(), ()> for FlakyRunnable {
+    async fn invoke(&self, _: ()) -> Result<(), WesichainError> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+            Err(WesichainError::Timeout(Duration::from_millis(1)))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn stream(&self, _: ()) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::empty().boxed()
+    }
+}
+
+#[tokio::test]
+async fn callback_manager_fans_out_tool_hooks() {
+    let handler = Arc::new(CountingHandler::default());
+    let manager = CallbackManager::new(vec![handler.clone(), handler.clone()]);
+    let ctx = RunContext::root(RunType::Tool, "calc".to_string(), vec![], BTreeMap::new());
+
+    manager.on_tool_start(&ctx, "calc", &Value::Null).await;
+    manager.on_tool_end(&ctx, "calc", &Value::from(42), 3).await;
+
+    assert_eq!(handler.tool_starts.load(Ordering::SeqCst), 2);
+    assert_eq!(handler.tool_ends.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn retrying_reports_each_retry() {
+    let handler = Arc::new(CountingHandler::default());
+    let manager = CallbackManager::new(vec![handler.clone()]);
+    let ctx = RunContext::root(RunType::Chain, "flaky".to_string(), vec![], BTreeMap::new());
+    let runnable = FlakyRunnable {
+        failures: 2,
+        calls: AtomicUsize::new(0),
+    };
+
+    Retrying::new(runnable, 3)
+        .with_callbacks(manager, ctx)
+        .invoke(())
+        .await
+        .unwrap();
+
+    assert_eq!(handler.retries.load(Ordering::SeqCst), 2);
+    assert_eq!(handler.tool_starts.load(Ordering::SeqCst), 0);
+}
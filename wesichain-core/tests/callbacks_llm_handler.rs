@@ -1,13 +1,17 @@
 use std::collections::BTreeMap;
 use std::sync::{Arc, Mutex};
 
+use futures::stream::{self, BoxStream, StreamExt};
 use wesichain_core::{
-    CallbackHandler, CallbackManager, LlmInput, LlmResult, RunContext, RunType, Value,
+    CallbackHandler, CallbackManager, LlmInput, LlmRequest, LlmResponse, LlmResult, RunContext,
+    RunType, Runnable, StreamEvent, TokenUsage, TracedRunnable, Value, WesichainError,
+    MESSAGE_COUNT_METADATA_KEY,
 };
 
 #[derive(Clone)]
 struct RecordingHandler {
     llm_starts: Arc<Mutex<Vec<(String, LlmInput)>>>,
+    llm_start_metadata: Arc<Mutex<Vec<BTreeMap<String, Value>>>>,
     llm_ends: Arc<Mutex<Vec<(String, LlmResult)>>>,
     generic_starts: Arc<Mutex<Vec<String>>>,
     generic_ends: Arc<Mutex<Vec<String>>>,
@@ -17,6 +21,7 @@ impl RecordingHandler {
     fn new() -> Self {
         Self {
             llm_starts: Arc::new(Mutex::new(Vec::new())),
+            llm_start_metadata: Arc::new(Mutex::new(Vec::new())),
             llm_ends: Arc::new(Mutex::new(Vec::new())),
             generic_starts: Arc::new(Mutex::new(Vec::new())),
             generic_ends: Arc::new(Mutex::new(Vec::new())),
@@ -41,6 +46,10 @@ impl CallbackHandler for RecordingHandler {
             .lock()
             .unwrap()
             .push((ctx.name.clone(), input.clone()));
+        self.llm_start_metadata
+            .lock()
+            .unwrap()
+            .push(ctx.metadata.clone());
     }
 
     async fn on_llm_end(&self, ctx: &RunContext, result: &LlmResult, _duration_ms: u128) {
@@ -119,3 +128,176 @@ async fn default_impl_fallback_to_on_start() {
     assert_eq!(starts.len(), 1);
     assert_eq!(starts[0], "fallback-test");
 }
+
+struct MockLlm;
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, LlmResponse> for MockLlm {
+    async fn invoke(&self, input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        Ok(LlmResponse {
+            content: "hi there".to_string(),
+            usage: Some(TokenUsage {
+                prompt_tokens: 12,
+                completion_tokens: 3,
+                total_tokens: 15,
+            }),
+            model: input.model,
+            finish_reason: Some("stop".to_string()),
+            ..Default::default()
+        })
+    }
+
+    fn stream(&self, _input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(vec![
+            Ok(StreamEvent::ContentChunk("hi ".to_string())),
+            Ok(StreamEvent::ContentChunk("there".to_string())),
+            Ok(StreamEvent::UsageUpdate {
+                input_tokens: 12,
+                output_tokens: 3,
+                cache_read_tokens: None,
+                cache_write_tokens: None,
+            }),
+            Ok(StreamEvent::FinalAnswer("hi there".to_string())),
+        ])
+        .boxed()
+    }
+}
+
+fn root(run_type: RunType) -> RunContext {
+    RunContext::root(run_type, "root".to_string(), vec![], BTreeMap::new())
+}
+
+#[tokio::test]
+async fn traced_llm_run_reports_model_and_token_usage() {
+    let handler = Arc::new(RecordingHandler::new());
+    let manager = CallbackManager::new(vec![handler.clone()]);
+    let traced = TracedRunnable::new(
+        MockLlm,
+        manager,
+        root(RunType::Chain),
+        RunType::Llm,
+        "chat".to_string(),
+    );
+    let request = LlmRequest::builder()
+        .model("gpt-4o")
+        .system("be brief")
+        .user("hello")
+        .build();
+
+    traced.invoke(request).await.unwrap();
+
+    let starts = handler.llm_starts.lock().unwrap();
+    assert_eq!(starts.len(), 1);
+    assert_eq!(starts[0].0, "chat");
+    assert_eq!(starts[0].1.model, "gpt-4o");
+    let metadata = handler.llm_start_metadata.lock().unwrap();
+    assert_eq!(metadata[0][MESSAGE_COUNT_METADATA_KEY], Value::from(2));
+    assert_eq!(starts[0].1.prompt, "system: be brief\nuser: hello");
+
+    let ends = handler.llm_ends.lock().unwrap();
+    assert_eq!(ends.len(), 1);
+    assert_eq!(ends[0].1.model, "gpt-4o");
+    assert_eq!(ends[0].1.generations, vec!["hi there".to_string()]);
+    let usage = ends[0].1.token_usage.as_ref().unwrap();
+    assert_eq!((usage.prompt_tokens, usage.completion_tokens), (12, 3));
+
+    assert!(handler.generic_starts.lock().unwrap().is_empty());
+    assert!(handler.generic_ends.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn traced_llm_stream_reports_final_answer_and_token_usage() {
+    let handler = Arc::new(RecordingHandler::new());
+    let manager = CallbackManager::new(vec![handler.clone()]);
+    let traced = TracedRunnable::new(
+        MockLlm,
+        manager,
+        root(RunType::Chain),
+        RunType::Llm,
+        "chat".to_string(),
+    );
+    let request = LlmRequest::builder().model("gpt-4o").user("hello").build();
+
+    let events: Vec<_> = traced.stream(request).collect().await;
+    assert_eq!(events.len(), 4);
+
+    let starts = handler.llm_starts.lock().unwrap();
+    assert_eq!(starts.len(), 1);
+    assert_eq!(starts[0].1.model, "gpt-4o");
+    let metadata = handler.llm_start_metadata.lock().unwrap();
+    assert_eq!(metadata[0][MESSAGE_COUNT_METADATA_KEY], Value::from(1));
+
+    let ends = handler.llm_ends.lock().unwrap();
+    assert_eq!(ends.len(), 1);
+    assert_eq!(ends[0].1.model, "gpt-4o");
+    assert_eq!(ends[0].1.generations, vec!["hi there".to_string()]);
+    assert_eq!(
+        ends[0].1.token_usage,
+        Some(TokenUsage {
+            prompt_tokens: 12,
+            completion_tokens: 3,
+            total_tokens: 15,
+        })
+    );
+
+    assert!(handler.generic_starts.lock().unwrap().is_empty());
+    assert!(handler.generic_ends.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn traced_non_llm_run_keeps_generic_hooks() {
+    let handler = Arc::new(RecordingHandler::new());
+    let manager = CallbackManager::new(vec![handler.clone()]);
+    let traced = TracedRunnable::new(
+        MockLlm,
+        manager,
+        root(RunType::Chain),
+        RunType::Chain,
+        "chain".to_string(),
+    );
+    let request = LlmRequest::builder().model("gpt-4o").user("hello").build();
+
+    traced.invoke(request).await.unwrap();
+
+    assert!(handler.llm_starts.lock().unwrap().is_empty());
+    assert!(handler.llm_ends.lock().unwrap().is_empty());
+    assert_eq!(*handler.generic_starts.lock().unwrap(), vec!["chain"]);
+    assert_eq!(*handler.generic_ends.lock().unwrap(), vec!["chain"]);
+}
+
+struct TextLlm;
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, String> for TextLlm {
+    async fn invoke(&self, _input: LlmRequest) -> Result<String, WesichainError> {
+        Ok("plain text".to_string())
+    }
+
+    fn stream(&self, _input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::empty().boxed()
+    }
+}
+
+#[tokio::test]
+async fn traced_llm_run_with_non_response_output_ends_with_llm_end() {
+    let handler = Arc::new(RecordingHandler::new());
+    let manager = CallbackManager::new(vec![handler.clone()]);
+    let traced = TracedRunnable::new(
+        TextLlm,
+        manager,
+        root(RunType::Chain),
+        RunType::Llm,
+        "chat".to_string(),
+    );
+    let request = LlmRequest::builder().model("gpt-4o").user("hello").build();
+
+    traced.invoke(request).await.unwrap();
+
+    assert_eq!(handler.llm_starts.lock().unwrap().len(), 1);
+    let ends = handler.llm_ends.lock().unwrap();
+    assert_eq!(ends.len(), 1);
+    assert_eq!(ends[0].1.model, "gpt-4o");
+    assert_eq!(ends[0].1.generations, vec!["plain text".to_string()]);
+    assert!(ends[0].1.token_usage.is_none());
+    assert!(handler.generic_ends.lock().unwrap().is_empty());
+}
@@ -0,0 +1,135 @@
+use futures::stream;
+use serde_json::json;
+use wesichain_core::{collect_stream, StreamEvent, TokenUsage, Value, WesichainError};
+
+fn events(
+    items: Vec<StreamEvent>,
+) -> impl futures::Stream<Item = Result<StreamEvent, WesichainError>> {
+    stream::iter(items.into_iter().map(Ok))
+}
+
+fn start(id: &str, name: &str) -> StreamEvent {
+    StreamEvent::ToolCallStart {
+        id: id.to_string(),
+        name: name.to_string(),
+    }
+}
+
+fn fragment(id: &str, text: &str) -> StreamEvent {
+    StreamEvent::ToolCallDelta {
+        id: id.to_string(),
+        delta: Value::String(text.to_string()),
+    }
+}
+
+#[tokio::test]
+async fn collect_stream_concatenates_content_chunks() {
+    let response = collect_stream(events(vec![
+        StreamEvent::ContentChunk("Hello, ".to_string()),
+        StreamEvent::ContentChunk("world".to_string()),
+        StreamEvent::FinalAnswer("Hello, world".to_string()),
+        StreamEvent::UsageUpdate {
+            input_tokens: 7,
+            output_tokens: 2,
+            cache_read_tokens: None,
+            cache_write_tokens: None,
+        },
+    ]))
+    .await
+    .unwrap();
+
+    assert_eq!(response.content, "Hello, world");
+    assert!(response.tool_calls.is_empty());
+    assert_eq!(
+        response.usage,
+        Some(TokenUsage {
+            prompt_tokens: 7,
+            completion_tokens: 2,
+            total_tokens: 9,
+        })
+    );
+}
+
+#[tokio::test]
+async fn collect_stream_uses_final_answer_without_chunks() {
+    let response = collect_stream(events(vec![StreamEvent::FinalAnswer("done".to_string())]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.content, "done");
+}
+
+#[tokio::test]
+async fn collect_stream_assembles_fragmented_tool_calls() {
+    let response = collect_stream(events(vec![
+        start("call_1", "get_weather"),
+        fragment("call_1", "{\"ci"),
+        start("call_2", "get_time"),
+        fragment("call_2", "{\"tz\":"),
+        fragment("call_1", "ty\": \"Paris\"}"),
+        fragment("call_2", "\"UTC\"}"),
+        StreamEvent::FinalAnswer(String::new()),
+    ]))
+    .await
+    .unwrap();
+
+    assert_eq!(response.content, "");
+    assert_eq!(response.tool_calls.len(), 2);
+    assert_eq!(response.tool_calls[0].id, "call_1");
+    assert_eq!(response.tool_calls[0].name, "get_weather");
+    assert_eq!(response.tool_calls[0].args, json!({"city": "Paris"}));
+    assert_eq!(response.tool_calls[1].name, "get_time");
+    assert_eq!(response.tool_calls[1].args, json!({"tz": "UTC"}));
+}
+
+#[tokio::test]
+async fn collect_stream_handles_mixed_content_and_structured_deltas() {
+    let response = collect_stream(events(vec![
+        StreamEvent::ThinkingChunk("let me check".to_string()),
+        StreamEvent::ContentChunk("Checking ".to_string()),
+        StreamEvent::ContentChunk("now.".to_string()),
+        start("toolu_1", "search"),
+        StreamEvent::ToolCallDelta {
+            id: "toolu_1".to_string(),
+            delta: json!({"query": "rust"}),
+        },
+        start("toolu_2", "noop"),
+        StreamEvent::FinalAnswer("Checking now.".to_string()),
+    ]))
+    .await
+    .unwrap();
+
+    assert_eq!(response.content, "Checking now.");
+    assert_eq!(response.tool_calls.len(), 2);
+    assert_eq!(response.tool_calls[0].args, json!({"query": "rust"}));
+    assert_eq!(response.tool_calls[1].args, json!({}));
+}
+
+#[tokio::test]
+async fn collect_stream_rejects_malformed_arguments() {
+    let err = collect_stream(events(vec![
+        start("call_1", "calculator"),
+        fragment("call_1", "{\"expression\": \"2+"),
+    ]))
+    .await
+    .unwrap_err();
+
+    match err {
+        WesichainError::ParseFailed { output, reason } => {
+            assert_eq!(output, "{\"expression\": \"2+");
+            assert!(reason.contains("call_1"), "{reason}");
+        }
+        other => panic!("expected ParseFailed, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn collect_stream_propagates_stream_errors() {
+    let items = vec![
+        Ok(StreamEvent::ContentChunk("partial".to_string())),
+        Err(WesichainError::LlmProvider("connection reset".to_string())),
+    ];
+    let err = collect_stream(stream::iter(items)).await.unwrap_err();
+
+    assert!(matches!(err, WesichainError::LlmProvider(message) if message == "connection reset"));
+}
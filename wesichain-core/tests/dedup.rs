@@ -0,0 +1,118 @@
+use wesichain_core::{dedup_documents, dedup_search_results, DedupKey, Document, SearchResult};
+
+fn doc(id: &str, content: &str) -> Document {
+    Document {
+        id: id.to_string(),
+        content: content.to_string(),
+        metadata: Default::default(),
+        embedding: None,
+    }
+}
+
+fn result(id: &str, content: &str, score: f32) -> SearchResult {
+    SearchResult {
+        document: doc(id, content),
+        score,
+    }
+}
+
+fn ids(docs: &[Document]) -> Vec<&str> {
+    docs.iter().map(|doc| doc.id.as_str()).collect()
+}
+
+fn summary(results: &[SearchResult]) -> Vec<(&str, f32)> {
+    results
+        .iter()
+        .map(|result| (result.document.id.as_str(), result.score))
+        .collect()
+}
+
+#[test]
+fn dedup_by_id_keeps_first_occurrence_in_order() {
+    let docs = vec![
+        doc("a", "alpha"),
+        doc("b", "beta"),
+        doc("a", "alpha, re-ingested"),
+        doc("c", "beta"),
+    ];
+
+    let kept = dedup_documents(docs, DedupKey::Id);
+
+    assert_eq!(ids(&kept), ["a", "b", "c"]);
+    assert_eq!(kept[0].content, "alpha");
+}
+
+#[test]
+fn dedup_by_content_ignores_ids() {
+    let docs = vec![
+        doc("a", "alpha"),
+        doc("b", "beta"),
+        doc("a", "gamma"),
+        doc("c", "alpha"),
+    ];
+
+    let kept = dedup_documents(docs, DedupKey::ContentHash);
+
+    assert_eq!(ids(&kept), ["a", "b", "a"]);
+    assert_eq!(kept[2].content, "gamma");
+}
+
+#[test]
+fn dedup_by_both_drops_id_or_content_matches() {
+    let docs = vec![
+        doc("a", "alpha"),
+        doc("a", "other"),
+        doc("b", "alpha"),
+        doc("c", "gamma"),
+    ];
+
+    let kept = dedup_documents(docs, DedupKey::Both);
+
+    assert_eq!(ids(&kept), ["a", "c"]);
+}
+
+#[test]
+fn search_results_keep_highest_score_on_id_collision() {
+    let results = vec![
+        result("a", "alpha", 0.4),
+        result("b", "beta", 0.7),
+        result("a", "alpha", 0.9),
+        result("b", "beta", 0.1),
+    ];
+
+    let kept = dedup_search_results(results, DedupKey::Id);
+
+    assert_eq!(summary(&kept), [("a", 0.9), ("b", 0.7)]);
+}
+
+#[test]
+fn search_results_keep_highest_score_on_content_collision() {
+    let results = vec![
+        result("retriever-1:7", "shared passage", 0.5),
+        result("x", "unique", 0.3),
+        result("retriever-2:42", "shared passage", 0.8),
+    ];
+
+    let kept = dedup_search_results(results, DedupKey::ContentHash);
+
+    assert_eq!(summary(&kept), [("retriever-2:42", 0.8), ("x", 0.3)]);
+}
+
+#[test]
+fn replaced_result_no_longer_matches_on_its_old_content() {
+    let results = vec![
+        result("a", "alpha", 0.5),
+        result("a", "beta", 0.9),
+        result("c", "alpha", 0.3),
+    ];
+
+    let kept = dedup_search_results(results, DedupKey::Both);
+
+    assert_eq!(summary(&kept), [("a", 0.9), ("c", 0.3)]);
+}
+
+#[test]
+fn empty_input_yields_empty_output() {
+    assert!(dedup_documents(Vec::new(), DedupKey::Both).is_empty());
+    assert!(dedup_search_results(Vec::new(), DedupKey::Both).is_empty());
+}
@@ -76,3 +76,13 @@ async fn embed_batch_helpers_work_for_dyn_embedding() {
         .unwrap();
     assert_eq!(result, vec![vec![0.0; 3], vec![0.0; 4]]);
 }
+
+#[tokio::test]
+async fn query_and_document_helpers_default_to_embed_and_embed_batch() {
+    let embedder: Arc<dyn Embedding> = Arc::new(TestEmbedding);
+    assert_eq!(embedder.embed_query("anything").await.unwrap(), vec![0.0]);
+
+    let texts = vec!["ab".to_string(), "c".to_string()];
+    let result = embedder.embed_documents(&texts).await.unwrap();
+    assert_eq!(result, vec![vec![0.0; 2], vec![0.0; 1]]);
+}
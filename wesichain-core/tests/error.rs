@@ -104,3 +104,40 @@ fn embedding_error_display_for_provider() {
     let err = EmbeddingError::Provider("overloaded".to_string());
     assert_eq!(format!("{err}"), "Embedding provider error: overloaded");
 }
+
+#[test]
+fn embedding_error_display_for_dimension_mismatch() {
+    let err = EmbeddingError::DimensionMismatch {
+        expected: 768,
+        got: 384,
+    };
+    assert_eq!(
+        format!("{err}"),
+        "Embedding dimension mismatch: expected 768, got 384"
+    );
+}
+
+#[test]
+fn embedding_error_is_retryable_only_for_transient_failures() {
+    let retryable = [
+        EmbeddingError::RateLimited { retry_after: None },
+        EmbeddingError::Timeout(Duration::from_secs(1)),
+        EmbeddingError::Transport("connection reset".to_string()),
+    ];
+    for err in retryable {
+        assert!(err.is_retryable(), "{err}");
+    }
+
+    let fatal = [
+        EmbeddingError::Auth("invalid api key".to_string()),
+        EmbeddingError::DimensionMismatch {
+            expected: 3,
+            got: 2,
+        },
+        EmbeddingError::InvalidResponse("bad shape".to_string()),
+        EmbeddingError::Provider("model not found".to_string()),
+    ];
+    for err in fatal {
+        assert!(!err.is_retryable(), "{err}");
+    }
+}
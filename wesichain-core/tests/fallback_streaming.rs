@@ -0,0 +1,105 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use wesichain_core::{Runnable, RunnableWithFallbacks, StreamEvent, WesichainError};
+
+/// Streams `chunks`, then fails with `error` if one is set.
+struct Scripted {
+    chunks: Vec<&'static str>,
+    error: Option<&'static str>,
+    streams: AtomicUsize,
+}
+
+impl Scripted {
+    fn new(chunks: &[&'static str], error: Option<&'static str>) -> Arc<Self> {
+        Arc::new(Self {
+            chunks: chunks.to_vec(),
+            error,
+            streams: AtomicUsize::new(0),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Runnable<String, String> for Scripted {
+    async fn invoke(&self, _input: String) -> Result<String, WesichainError> {
+        match self.error {
+            Some(error) => Err(WesichainError::Custom(error.to_string())),
+            None => Ok(self.chunks.concat()),
+        }
+    }
+
+    fn stream(&self, _input: String) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        self.streams.fetch_add(1, Ordering::SeqCst);
+        let chunks = self
+            .chunks
+            .iter()
+            .map(|chunk| Ok(StreamEvent::ContentChunk(chunk.to_string())));
+        let error = self
+            .error
+            .map(|error| Err(WesichainError::Custom(error.to_string())));
+        stream::iter(chunks.chain(error)).boxed()
+    }
+}
+
+fn with_fallback(
+    primary: Arc<Scripted>,
+    fallback: Arc<Scripted>,
+) -> RunnableWithFallbacks<String, String> {
+    RunnableWithFallbacks::new(primary, vec![fallback])
+}
+
+async fn collect(runnable: &RunnableWithFallbacks<String, String>) -> Vec<String> {
+    runnable
+        .stream("input".to_string())
+        .map(|event| match event {
+            Ok(StreamEvent::ContentChunk(chunk)) => chunk,
+            Ok(other) => format!("{other:?}"),
+            Err(err) => format!("error: {err}"),
+        })
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn stream_falls_back_when_primary_fails_before_first_chunk() {
+    let fallback = Scripted::new(&["from ", "fallback"], None);
+    let runnable = with_fallback(Scripted::new(&[], Some("down")), fallback.clone());
+
+    assert_eq!(collect(&runnable).await, ["from ", "fallback"]);
+    assert_eq!(fallback.streams.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn stream_surfaces_error_after_first_chunk() {
+    let fallback = Scripted::new(&["from fallback"], None);
+    let runnable = with_fallback(
+        Scripted::new(&["partial"], Some("cut off")),
+        fallback.clone(),
+    );
+
+    assert_eq!(collect(&runnable).await, ["partial", "error: cut off"]);
+    assert_eq!(fallback.streams.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn stream_replays_from_fallback_when_mid_stream_fallback_is_enabled() {
+    let runnable = with_fallback(
+        Scripted::new(&["partial"], Some("cut off")),
+        Scripted::new(&["from fallback"], None),
+    )
+    .with_fallback_on_mid_stream(true);
+
+    assert_eq!(collect(&runnable).await, ["partial", "from fallback"]);
+}
+
+#[tokio::test]
+async fn stream_surfaces_last_error_when_every_runnable_fails() {
+    let runnable = with_fallback(
+        Scripted::new(&[], Some("primary down")),
+        Scripted::new(&[], Some("fallback down")),
+    );
+
+    assert_eq!(collect(&runnable).await, ["error: fallback down"]);
+}
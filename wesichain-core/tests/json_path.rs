@@ -0,0 +1,94 @@
+use futures::StreamExt;
+use serde_json::json;
+use wesichain_core::{
+    reconstruct, JsonPathRunnable, Runnable, SerializableRunnable, StreamEvent, Value,
+    WesichainError,
+};
+
+fn response() -> Value {
+    json!({
+        "data": {
+            "user": {"name": "Ada", "tags": ["admin", "ops"]},
+            "items": [{"id": 1, "name": "first"}, {"id": 2, "name": "second"}]
+        }
+    })
+}
+
+#[tokio::test]
+async fn json_path_extracts_nested_object_field() {
+    let extractor = JsonPathRunnable::new("data.user.name");
+
+    assert_eq!(extractor.invoke(response()).await.unwrap(), json!("Ada"));
+}
+
+#[tokio::test]
+async fn json_path_extracts_array_elements_with_either_syntax() {
+    for path in [
+        "data.items.1.name",
+        "data.items[1].name",
+        "$.data.items[1].name",
+    ] {
+        let extractor = JsonPathRunnable::new(path);
+
+        assert_eq!(
+            extractor.invoke(response()).await.unwrap(),
+            json!("second"),
+            "{path}"
+        );
+    }
+    let tags = JsonPathRunnable::new("data.user.tags");
+    assert_eq!(
+        tags.invoke(response()).await.unwrap(),
+        json!(["admin", "ops"])
+    );
+}
+
+#[tokio::test]
+async fn json_path_streams_string_results_unquoted() {
+    let extractor = JsonPathRunnable::new("data.user.name");
+
+    let events: Vec<_> = extractor.stream(response()).collect().await;
+    assert!(matches!(
+        events.as_slice(),
+        [Ok(StreamEvent::FinalAnswer(answer))] if answer == "Ada"
+    ));
+}
+
+#[tokio::test]
+async fn json_path_errors_when_path_is_absent() {
+    let extractor = JsonPathRunnable::new("data.items[5].name");
+
+    let err = extractor.invoke(response()).await.unwrap_err();
+
+    let WesichainError::Custom(message) = err else {
+        panic!("expected Custom, got {err:?}");
+    };
+    assert_eq!(message, "JSON path 'data.items[5].name' not found");
+}
+
+#[tokio::test]
+async fn lenient_json_path_returns_null_when_path_is_absent() {
+    let extractor = JsonPathRunnable::new("data.user.email").with_lenient(true);
+
+    assert_eq!(extractor.invoke(response()).await.unwrap(), Value::Null);
+}
+
+#[tokio::test]
+async fn json_path_round_trips_through_serialization() {
+    let extractor = JsonPathRunnable::new("data.items[0].id").with_lenient(true);
+
+    let serialized = Runnable::<Value, Value>::to_serializable(&extractor).unwrap();
+    assert_eq!(
+        serialized,
+        SerializableRunnable::JsonPath {
+            path: "data.items[0].id".to_string(),
+            lenient: true,
+        }
+    );
+
+    let json = serialized.to_json().unwrap();
+    let restored =
+        reconstruct::<Value, Value>(SerializableRunnable::from_json(&json).unwrap(), None).unwrap();
+    assert_eq!(restored.invoke(response()).await.unwrap(), json!(1));
+    assert_eq!(restored.invoke(json!({})).await.unwrap(), Value::Null);
+}
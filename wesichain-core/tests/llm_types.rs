@@ -1,5 +1,5 @@
 use serde_json::json;
-use wesichain_core::{LlmRequest, LlmResponse, Message, Role, ToolCall, ToolSpec};
+use wesichain_core::{CacheControl, LlmRequest, LlmResponse, Message, Role, ToolCall, ToolSpec};
 
 #[test]
 fn llm_types_serialize_with_tool_calls() {
@@ -13,6 +13,7 @@ fn llm_types_serialize_with_tool_calls() {
         content: "".into(),
         tool_call_id: None,
         tool_calls: vec![call.clone()],
+        cache_control: None,
     };
     let req = LlmRequest {
         model: "test".to_string(),
@@ -25,6 +26,7 @@ fn llm_types_serialize_with_tool_calls() {
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
     let value = serde_json::to_value(req).expect("serialize request");
     assert!(value["messages"][0]["tool_calls"].is_array());
@@ -34,7 +36,133 @@ fn llm_types_serialize_with_tool_calls() {
         tool_calls: vec![call],
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
     let response_value = serde_json::to_value(response).expect("serialize response");
     assert!(response_value["tool_calls"].is_array());
 }
+
+#[test]
+fn finish_reason_normalizes_provider_strings() {
+    use wesichain_core::FinishReason;
+
+    let cases = [
+        ("stop", FinishReason::Stop),
+        ("end_turn", FinishReason::Stop),
+        ("STOP", FinishReason::Stop),
+        ("length", FinishReason::Length),
+        ("max_tokens", FinishReason::Length),
+        ("MAX_TOKENS", FinishReason::Length),
+        ("tool_calls", FinishReason::ToolCalls),
+        ("tool_use", FinishReason::ToolCalls),
+        ("content_filter", FinishReason::ContentFilter),
+        ("SAFETY", FinishReason::ContentFilter),
+        (
+            "MALFORMED_FUNCTION_CALL",
+            FinishReason::Other("MALFORMED_FUNCTION_CALL".to_string()),
+        ),
+    ];
+    for (raw, expected) in cases {
+        assert_eq!(FinishReason::from_raw(raw), expected, "raw reason {raw}");
+    }
+
+    let response = LlmResponse {
+        finish_reason: Some("max_tokens".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(response.finish(), Some(FinishReason::Length));
+    assert_eq!(response.finish_reason.as_deref(), Some("max_tokens"));
+    assert_eq!(LlmResponse::default().finish(), None);
+}
+
+#[test]
+fn llm_request_builder_matches_hand_built_request() {
+    let tool = ToolSpec {
+        name: "calculator".to_string(),
+        description: "math".to_string(),
+        parameters: json!({"type": "object"}),
+    };
+
+    let built = LlmRequest::builder()
+        .model("gpt-4o-mini")
+        .system("be terse")
+        .user("what is 2+2?")
+        .assistant("")
+        .tool_result("call-1", "4")
+        .tool(tool.clone())
+        .temperature(0.2)
+        .max_tokens(64)
+        .build();
+
+    let expected = LlmRequest {
+        model: "gpt-4o-mini".to_string(),
+        messages: vec![
+            Message {
+                role: Role::System,
+                content: "be terse".into(),
+                tool_call_id: None,
+                tool_calls: vec![],
+                cache_control: None,
+            },
+            Message {
+                role: Role::User,
+                content: "what is 2+2?".into(),
+                tool_call_id: None,
+                tool_calls: vec![],
+                cache_control: None,
+            },
+            Message {
+                role: Role::Assistant,
+                content: "".into(),
+                tool_call_id: None,
+                tool_calls: vec![],
+                cache_control: None,
+            },
+            Message {
+                role: Role::Tool,
+                content: "4".into(),
+                tool_call_id: Some("call-1".to_string()),
+                tool_calls: vec![],
+                cache_control: None,
+            },
+        ],
+        tools: vec![tool],
+        temperature: Some(0.2),
+        max_tokens: Some(64),
+        stop_sequences: vec![],
+        response_format: None,
+    };
+
+    assert_eq!(built, expected);
+}
+
+#[test]
+fn llm_request_builder_defaults_to_empty_request() {
+    let built = LlmRequest::builder().stop_sequence("END").build();
+
+    assert_eq!(
+        built,
+        LlmRequest {
+            model: String::new(),
+            messages: vec![],
+            tools: vec![],
+            temperature: None,
+            max_tokens: None,
+            stop_sequences: vec!["END".to_string()],
+            response_format: None,
+        }
+    );
+}
+
+#[test]
+fn message_cache_control_round_trips_and_is_omitted_when_unset() {
+    let cached = Message::system("shared prefix").with_cache_control(CacheControl::Ephemeral);
+    let value = serde_json::to_value(&cached).unwrap();
+    assert_eq!(value["cache_control"], json!({"type": "ephemeral"}));
+    assert_eq!(serde_json::from_value::<Message>(value).unwrap(), cached);
+
+    let plain = serde_json::to_value(Message::user("hi")).unwrap();
+    assert!(plain.get("cache_control").is_none());
+    let legacy: Message = serde_json::from_value(json!({"role": "user", "content": "hi"})).unwrap();
+    assert_eq!(legacy.cache_control, None);
+}
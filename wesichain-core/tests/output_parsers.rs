@@ -1,5 +1,8 @@
 use serde_json::{json, Value};
-use wesichain_core::{JsonOutputParser, LlmResponse, Runnable, StrOutputParser};
+use wesichain_core::{
+    save_runnable, JsonOutputParser, LlmResponse, Runnable, StrOutputParser, ToolCall,
+    ToolCallPolicy, WesichainError,
+};
 
 #[tokio::test]
 async fn test_str_output_parser() {
@@ -17,11 +20,66 @@ async fn test_str_output_parser() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
     let output = parser.invoke(response).await.unwrap();
     assert_eq!(output, "Hello from LLM");
 }
 
+#[tokio::test]
+async fn str_output_parser_trims_when_configured() {
+    let parser = StrOutputParser::builder().trim(true).build();
+
+    let output = parser.invoke("\n  Hello  \n".to_string()).await.unwrap();
+    assert_eq!(output, "Hello");
+
+    let response = LlmResponse {
+        content: "  Hi there\n".to_string(),
+        ..Default::default()
+    };
+    let output = parser.invoke(response).await.unwrap();
+    assert_eq!(output, "Hi there");
+
+    let untrimmed = StrOutputParser;
+    let output = untrimmed.invoke(" Hi ".to_string()).await.unwrap();
+    assert_eq!(output, " Hi ");
+}
+
+#[tokio::test]
+async fn str_output_parser_rejects_tool_calls_when_configured() {
+    let response = LlmResponse {
+        content: "Let me check.".to_string(),
+        tool_calls: vec![ToolCall {
+            id: "call_1".to_string(),
+            name: "search".to_string(),
+            args: json!({"q": "rust"}),
+        }],
+        ..Default::default()
+    };
+
+    let lenient = StrOutputParser;
+    let output = lenient.invoke(response.clone()).await.unwrap();
+    assert_eq!(output, "Let me check.");
+
+    let strict = StrOutputParser::builder()
+        .on_tool_calls(ToolCallPolicy::Error)
+        .build();
+    let err = strict.invoke(response).await.unwrap_err();
+    assert!(matches!(
+        err,
+        WesichainError::ParseFailed { output, reason }
+            if output == "Let me check." && reason.contains("search")
+    ));
+}
+
+#[test]
+fn configured_str_output_parser_is_not_persisted() {
+    let parser = StrOutputParser::builder().trim(true).build();
+    let file = tempfile::NamedTempFile::new().unwrap();
+
+    assert!(save_runnable::<LlmResponse, String>(file.path(), &parser).is_err());
+}
+
 #[tokio::test]
 async fn test_json_output_parser() {
     let parser = JsonOutputParser::<Value>::new();
@@ -47,11 +105,57 @@ async fn test_json_output_parser() {
 
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
     let output = parser.invoke(response).await.unwrap();
     assert_eq!(output, json!({"key": "value"}));
 }
 
+#[tokio::test]
+async fn json_output_parser_extracts_fenced_json_after_prose() {
+    let parser = JsonOutputParser::<Value>::new();
+    let input = "Here is the result:\n```json\n{\"items\": [1, 2]}\n```\nLet me know!";
+
+    let output = parser.invoke(input.to_string()).await.unwrap();
+    assert_eq!(output, json!({"items": [1, 2]}));
+
+    let bare = "```\n[\"a\", \"b\"]\n```";
+    let output = parser.invoke(bare.to_string()).await.unwrap();
+    assert_eq!(output, json!(["a", "b"]));
+}
+
+#[tokio::test]
+async fn json_output_parser_ignores_trailing_commentary() {
+    let parser = JsonOutputParser::<Value>::new();
+    let input = r#"Sure! {"answer": "use {braces} and [brackets]"} Hope that helps. {"second": 2}"#;
+
+    let output = parser.invoke(input.to_string()).await.unwrap();
+    assert_eq!(output, json!({"answer": "use {braces} and [brackets]"}));
+}
+
+#[tokio::test]
+async fn json_output_parser_skips_non_json_brackets() {
+    let parser = JsonOutputParser::<Value>::new();
+    let input = r#"[draft] The payload is {"ok": true}."#;
+
+    let output = parser.invoke(input.to_string()).await.unwrap();
+    assert_eq!(output, json!({"ok": true}));
+}
+
+#[tokio::test]
+async fn strict_json_output_parser_rejects_fenced_input() {
+    let parser = JsonOutputParser::<Value>::new().strict(true);
+
+    let fenced = "```json\n{\"key\": \"value\"}\n```";
+    assert!(parser.invoke(fenced.to_string()).await.is_err());
+
+    let output = parser
+        .invoke(r#" {"key": "value"} "#.to_string())
+        .await
+        .unwrap();
+    assert_eq!(output, json!({"key": "value"}));
+}
+
 #[tokio::test]
 async fn test_json_output_parser_typed() {
     #[derive(serde::Deserialize, serde::Serialize, PartialEq, Debug)]
@@ -74,3 +178,45 @@ async fn test_json_output_parser_typed() {
         }
     );
 }
+
+#[derive(serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[allow(dead_code)]
+struct Address {
+    city: String,
+    postcode: Option<String>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[allow(dead_code)]
+struct Person {
+    /// Full name as written in the source text.
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+    address: Address,
+    nickname: Option<String>,
+}
+
+#[test]
+fn test_structured_output_parser_format_instructions() {
+    let instructions =
+        wesichain_core::StructuredOutputParser::<Person>::new().format_instructions();
+
+    for field in ["name", "age", "tags", "address", "address.city"] {
+        assert!(
+            instructions.contains(&format!("`{field}` (")),
+            "missing {field}: {instructions}"
+        );
+    }
+    assert!(instructions.contains("- `name` (string, required): Full name as written"));
+    assert!(instructions.contains("- `tags` (array of string, required)"));
+    assert!(instructions.contains("- `nickname` (string or null, optional)"));
+
+    let example = instructions
+        .split("```json\n")
+        .nth(1)
+        .and_then(|rest| rest.split("\n```").next())
+        .expect("example block");
+    let example: Person = serde_json::from_str(example).expect("example parses as Person");
+    assert_eq!(example.address.city, "string");
+}
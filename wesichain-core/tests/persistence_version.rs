@@ -0,0 +1,65 @@
+use std::fs;
+
+use serde_json::{json, Value};
+use tempfile::NamedTempFile;
+use wesichain_core::{
+    load_runnable, save_runnable, LlmResponse, Runnable, StrOutputParser, PERSISTENCE_VERSION,
+};
+
+fn write_json(value: &Value) -> NamedTempFile {
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), serde_json::to_string(value).unwrap()).unwrap();
+    file
+}
+
+#[test]
+fn save_writes_version_envelope() {
+    let file = NamedTempFile::new().unwrap();
+    save_runnable::<LlmResponse, String>(file.path(), &StrOutputParser).unwrap();
+
+    let saved: Value = serde_json::from_str(&fs::read_to_string(file.path()).unwrap()).unwrap();
+    assert_eq!(saved["wesichain_version"], PERSISTENCE_VERSION);
+    assert_eq!(saved["runnable"]["type"], "parser");
+}
+
+#[tokio::test]
+async fn loads_version_less_file_as_current() {
+    let file = write_json(&json!({ "type": "parser", "kind": "str" }));
+
+    let loaded: Box<dyn Runnable<Value, Value>> = load_runnable(file.path(), None).unwrap();
+    assert_eq!(loaded.invoke(json!("hello")).await.unwrap(), json!("hello"));
+}
+
+#[tokio::test]
+async fn loads_same_version_file() {
+    let file = NamedTempFile::new().unwrap();
+    save_runnable::<LlmResponse, String>(file.path(), &StrOutputParser).unwrap();
+
+    let loaded: Box<dyn Runnable<Value, Value>> = load_runnable(file.path(), None).unwrap();
+    assert_eq!(loaded.invoke(json!("hello")).await.unwrap(), json!("hello"));
+}
+
+#[test]
+fn loads_older_minor_version() {
+    let file = write_json(&json!({
+        "wesichain_version": "0.1",
+        "runnable": { "type": "parser", "kind": "str" }
+    }));
+
+    assert!(load_runnable::<Value, Value>(file.path(), None).is_ok());
+}
+
+#[test]
+fn rejects_too_new_versions() {
+    for version in ["0.99", "1.0"] {
+        let file = write_json(&json!({
+            "wesichain_version": version,
+            "runnable": { "type": "parser", "kind": "str" }
+        }));
+
+        let err = load_runnable::<Value, Value>(file.path(), None)
+            .err()
+            .expect("newer version should be rejected");
+        assert!(err.to_string().contains(version), "{err}");
+    }
+}
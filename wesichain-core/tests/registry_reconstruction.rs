@@ -28,6 +28,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockLlm {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
     }
 
@@ -154,11 +155,13 @@ async fn test_registry_chain_reconstruction() {
             content: "hi".into(),
             tool_call_id: None,
             tool_calls: vec![],
+            cache_control: None,
         }],
         tools: vec![],
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
     let input = serde_json::to_value(&input_req).unwrap();
     let output = loaded_llm.invoke(input).await.unwrap();
@@ -282,3 +285,204 @@ async fn test_registry_chain_reconstruction() {
         "Tool mock_tool_1 processed: test args"
     );
 }
+
+// --- Mock LLM that reports the tools it was called with ---
+#[derive(Clone)]
+struct ToolEchoLlm;
+
+#[async_trait]
+impl Runnable<LlmRequest, LlmResponse> for ToolEchoLlm {
+    async fn invoke(&self, input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        let names: Vec<_> = input.tools.iter().map(|tool| tool.name.as_str()).collect();
+        Ok(LlmResponse {
+            content: format!("tools: {}", names.join(",")),
+            tool_calls: vec![],
+            usage: None,
+            model: String::new(),
+            finish_reason: None,
+        })
+    }
+
+    fn stream<'a>(
+        &'a self,
+        _input: LlmRequest,
+    ) -> futures::stream::BoxStream<'a, Result<wesichain_core::StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+
+    fn to_serializable(&self) -> Option<SerializableRunnable> {
+        Some(SerializableRunnable::Llm {
+            model: "tool-echo".to_string(),
+            params: HashMap::new(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_bound_llm_round_trip() {
+    use wesichain_core::RunnableExt;
+
+    let mut registry = RunnableRegistry::new();
+    registry.register_llm("tool-echo", |_params| Ok(Arc::new(ToolEchoLlm)));
+
+    let bound_args = serde_json::json!({
+        "tools": [{
+            "name": "calculator",
+            "description": "Adds numbers",
+            "parameters": {"type": "object"}
+        }]
+    });
+    let bound = ToolEchoLlm.bind(bound_args.clone());
+
+    let file = NamedTempFile::new().unwrap();
+    save_runnable(file.path(), &bound).unwrap();
+
+    let content = std::fs::read_to_string(file.path()).unwrap();
+    let ser = wesichain_core::parse_persisted(&content).unwrap();
+    match ser {
+        SerializableRunnable::Binding { inner, bound } => {
+            assert!(
+                matches!(*inner, SerializableRunnable::Llm { model, .. } if model == "tool-echo")
+            );
+            assert_eq!(bound, bound_args);
+        }
+        other => panic!("expected Binding, got {other:?}"),
+    }
+
+    let loaded: Box<dyn Runnable<Value, Value>> =
+        load_runnable(file.path(), Some(&registry)).unwrap();
+
+    let request = LlmRequest {
+        model: "tool-echo".to_string(),
+        messages: vec![Message {
+            role: Role::User,
+            content: "hi".into(),
+            tool_call_id: None,
+            tool_calls: vec![],
+            cache_control: None,
+        }],
+        tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
+    };
+    let output = loaded
+        .invoke(serde_json::to_value(request).unwrap())
+        .await
+        .unwrap();
+    let response: LlmResponse = serde_json::from_value(output).unwrap();
+    assert_eq!(response.content, "tools: calculator");
+}
+
+#[tokio::test]
+async fn test_reconstructed_binding_rejects_non_object_input() {
+    use wesichain_core::RunnableExt;
+
+    let mut registry = RunnableRegistry::new();
+    registry.register_llm("tool-echo", |_params| Ok(Arc::new(ToolEchoLlm)));
+
+    let bound = ToolEchoLlm.bind(serde_json::json!({"tools": []}));
+    let file = NamedTempFile::new().unwrap();
+    save_runnable(file.path(), &bound).unwrap();
+
+    let loaded: Box<dyn Runnable<Value, Value>> =
+        load_runnable(file.path(), Some(&registry)).unwrap();
+
+    let err = loaded
+        .invoke(Value::String("hi".to_string()))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("non-object input"), "{err}");
+}
+
+#[test]
+fn test_value_binding_merges_keys_and_extends_arrays() {
+    use wesichain_core::Bindable;
+
+    let mut input = serde_json::json!({"tools": ["a"], "model": "m", "kept": true});
+    input
+        .bind(serde_json::json!({"tools": ["b"], "model": "n"}))
+        .unwrap();
+    assert_eq!(
+        input,
+        serde_json::json!({"tools": ["a", "b"], "model": "n", "kept": true})
+    );
+
+    let mut scalar = Value::from(1);
+    assert!(scalar.bind(serde_json::json!({"model": "n"})).is_err());
+
+    let mut object = serde_json::json!({});
+    assert!(object.bind(Value::from("not an object")).is_err());
+}
+
+// --- Custom tool built entirely from its saved description ---
+struct ShoutTool {
+    description: String,
+}
+
+#[async_trait]
+impl Tool for ShoutTool {
+    fn name(&self) -> &str {
+        "shout"
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::json!({"type": "string"})
+    }
+
+    async fn invoke(&self, input: Value) -> Result<Value, ToolError> {
+        let text = input.as_str().unwrap_or_default().to_uppercase();
+        Ok(Value::String(format!("{} ({})", text, self.description)))
+    }
+}
+
+#[tokio::test]
+async fn custom_tool_factory_receives_saved_config() {
+    let mut registry = RunnableRegistry::new();
+    registry.register_tool("shout", |config| {
+        assert_eq!(config["schema"], serde_json::json!({"type": "string"}));
+        let description = config["description"]
+            .as_str()
+            .ok_or_else(|| WesichainError::Custom("missing description".to_string()))?;
+        Ok(Arc::new(ShoutTool {
+            description: description.to_string(),
+        }))
+    });
+
+    let saved = SerializableRunnable::Tool {
+        name: "shout".to_string(),
+        description: Some("loud echo".to_string()),
+        schema: Some(serde_json::json!({"type": "string"})),
+    };
+    let loaded = wesichain_core::reconstruct::<Value, Value>(saved, Some(&registry)).unwrap();
+
+    let output = loaded.invoke(serde_json::json!("hi")).await.unwrap();
+    assert_eq!(output, serde_json::json!("HI (loud echo)"));
+}
+
+#[test]
+fn unregistered_tool_error_lists_registered_names() {
+    let mut registry = RunnableRegistry::new();
+    registry.register_tool("search", |_config| {
+        Ok(Arc::new(MockTool {
+            name: "search".to_string(),
+        }))
+    });
+
+    let saved = SerializableRunnable::Tool {
+        name: "shout".to_string(),
+        description: None,
+        schema: None,
+    };
+    let err = match wesichain_core::reconstruct::<Value, Value>(saved, Some(&registry)) {
+        Ok(_) => panic!("unregistered tool should not reconstruct"),
+        Err(err) => err.to_string(),
+    };
+    assert!(err.contains("'shout' not found"), "{err}");
+    assert!(err.contains("registered: search"), "{err}");
+}
@@ -245,3 +245,46 @@ async fn retries_timeout_errors_until_success() {
     assert_eq!(output, "ok:ping".to_string());
     assert_eq!(attempts.load(Ordering::SeqCst), 3);
 }
+
+#[test]
+fn is_retryable_separates_transient_from_fatal_errors() {
+    assert!(WesichainError::LlmProvider("HTTP 503".to_string()).is_retryable());
+    assert!(WesichainError::RateLimitExceeded { retry_after: None }.is_retryable());
+    assert!(WesichainError::Timeout(Duration::from_secs(1)).is_retryable());
+
+    assert!(!WesichainError::ParseFailed {
+        output: "bad".to_string(),
+        reason: "invalid".to_string(),
+    }
+    .is_retryable());
+    assert!(!WesichainError::Custom("boom".to_string()).is_retryable());
+    let serde_error = serde_json::from_str::<u32>("x").unwrap_err();
+    assert!(!WesichainError::Serde(serde_error).is_retryable());
+}
+
+#[tokio::test]
+async fn retry_predicate_overrides_default_classification() {
+    let parse_failer = ParseFailer::new();
+    let attempts = parse_failer.attempts_counter();
+    let err = parse_failer
+        .with_retries(2)
+        .with_retry_if(|error| matches!(error, WesichainError::ParseFailed { .. }))
+        .invoke("ping".to_string())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, WesichainError::MaxRetriesExceeded { max: 2 }));
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+    let flaky = Flaky::new(1);
+    let attempts = flaky.attempts_counter();
+    let err = flaky
+        .with_retries(3)
+        .with_retry_if(|_| false)
+        .invoke("ping".to_string())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, WesichainError::LlmProvider(_)));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
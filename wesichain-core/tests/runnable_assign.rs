@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use serde_json::json;
+use wesichain_core::{
+    reconstruct, JsonPathRunnable, Runnable, RunnableAssign, SerializableRunnable, StreamEvent,
+    Value, WesichainError,
+};
+
+/// Stands in for a retriever: looks up context for the input's question.
+struct FakeRetriever;
+
+#[async_trait::async_trait]
+impl Runnable<Value, Value> for FakeRetriever {
+    async fn invoke(&self, input: Value) -> Result<Value, WesichainError> {
+        let question = input["question"].as_str().unwrap_or_default();
+        Ok(json!([format!("doc about {question}")]))
+    }
+
+    fn stream(&self, _input: Value) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::empty().boxed()
+    }
+}
+
+#[tokio::test]
+async fn assign_keeps_input_keys_and_adds_computed_ones() {
+    let assign = RunnableAssign::new(BTreeMap::new())
+        .assign("context", Arc::new(FakeRetriever))
+        .assign("topic", Arc::new(JsonPathRunnable::new("meta.topic")));
+
+    let output = assign
+        .invoke(json!({"question": "rust", "meta": {"topic": "lang"}}))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        output,
+        json!({
+            "question": "rust",
+            "meta": {"topic": "lang"},
+            "context": ["doc about rust"],
+            "topic": "lang"
+        })
+    );
+}
+
+#[tokio::test]
+async fn assign_overwrites_existing_key() {
+    let assign = RunnableAssign::new(BTreeMap::new()).assign("context", Arc::new(FakeRetriever));
+
+    let output = assign
+        .invoke(json!({"question": "rust", "context": "stale"}))
+        .await
+        .unwrap();
+
+    assert_eq!(output["context"], json!(["doc about rust"]));
+}
+
+#[tokio::test]
+async fn assign_rejects_non_object_input() {
+    let assign = RunnableAssign::new(BTreeMap::new()).assign("context", Arc::new(FakeRetriever));
+
+    let err = assign.invoke(json!("rust")).await.unwrap_err();
+
+    assert!(err.to_string().contains("expects a JSON object"), "{err}");
+}
+
+#[tokio::test]
+async fn assign_round_trips_through_serialization() {
+    let assign = RunnableAssign::new(BTreeMap::new())
+        .assign("first", Arc::new(JsonPathRunnable::new("items[0]")));
+
+    let serialized = assign.to_serializable().unwrap();
+    let json = serialized.to_json().unwrap();
+    assert_eq!(SerializableRunnable::from_json(&json).unwrap(), serialized);
+
+    let rebuilt: Arc<dyn Runnable<Value, Value> + Send + Sync> =
+        reconstruct(serialized, None).unwrap();
+    let output = rebuilt.invoke(json!({"items": ["a", "b"]})).await.unwrap();
+    assert_eq!(output, json!({"items": ["a", "b"], "first": "a"}));
+}
+
+#[test]
+fn assign_with_unserializable_step_is_not_serializable() {
+    let assign = RunnableAssign::new(BTreeMap::new()).assign("context", Arc::new(FakeRetriever));
+
+    assert!(assign.to_serializable().is_none());
+}
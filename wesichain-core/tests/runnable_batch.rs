@@ -1,6 +1,10 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
 use futures::stream::BoxStream;
 use futures::StreamExt;
-use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_core::{BatchConfig, Runnable, StreamEvent, WesichainError};
 
 struct Number;
 
@@ -42,3 +46,87 @@ async fn abatch_is_alias_for_batch() {
     assert_eq!(results[0].as_ref().unwrap(), &20);
     assert_eq!(results[1].as_ref().unwrap(), &40);
 }
+
+/// Sleeps longer for smaller inputs so completion order is the reverse of
+/// input order, and records the peak number of concurrent invocations.
+#[derive(Default)]
+struct SlowEcho {
+    running: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Runnable<u64, u64> for SlowEcho {
+    async fn invoke(&self, input: u64) -> Result<u64, WesichainError> {
+        let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(running, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(50 - input * 10)).await;
+        self.running.fetch_sub(1, Ordering::SeqCst);
+        Ok(input)
+    }
+
+    fn stream(&self, _input: u64) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+#[tokio::test]
+async fn batch_preserves_input_order() {
+    let runner = SlowEcho::default();
+
+    let results = runner.batch(vec![0, 1, 2, 3, 4]).await;
+
+    let outputs: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+    assert_eq!(outputs, vec![0, 1, 2, 3, 4]);
+    assert_eq!(runner.peak.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn batch_with_config_bounds_concurrency() {
+    let runner = SlowEcho::default();
+    let config = BatchConfig::default().with_max_concurrency(2);
+
+    let results = runner.batch_with_config(vec![0, 1, 2, 3, 4], config).await;
+
+    let outputs: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+    assert_eq!(outputs, vec![0, 1, 2, 3, 4]);
+    assert_eq!(runner.peak.load(Ordering::SeqCst), 2);
+}
+
+struct NativeBatch {
+    batch_calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Runnable<i32, i32> for NativeBatch {
+    async fn invoke(&self, input: i32) -> Result<i32, WesichainError> {
+        Ok(input)
+    }
+
+    async fn batch_with_config(
+        &self,
+        inputs: Vec<i32>,
+        _config: BatchConfig,
+    ) -> Vec<Result<i32, WesichainError>> {
+        self.batch_calls.fetch_add(1, Ordering::SeqCst);
+        inputs.into_iter().map(|input| Ok(input + 100)).collect()
+    }
+
+    fn stream(&self, _input: i32) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+#[tokio::test]
+async fn batch_overrides_are_used_through_trait_objects() {
+    let native = Arc::new(NativeBatch {
+        batch_calls: AtomicUsize::new(0),
+    });
+    let runner: Arc<dyn Runnable<i32, i32>> = native.clone();
+
+    let results = runner.batch(vec![1, 2]).await;
+
+    assert_eq!(results[0].as_ref().unwrap(), &101);
+    assert_eq!(results[1].as_ref().unwrap(), &102);
+    assert_eq!(native.batch_calls.load(Ordering::SeqCst), 1);
+}
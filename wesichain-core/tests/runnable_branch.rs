@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use wesichain_core::{
+    serde::SerializableRunnable, LlmResponse, Runnable, RunnableBranch, StrOutputParser,
+    StreamEvent, WesichainError,
+};
+
+struct Reply(&'static str);
+
+#[async_trait::async_trait]
+impl Runnable<String, String> for Reply {
+    async fn invoke(&self, input: String) -> Result<String, WesichainError> {
+        Ok(format!("{}: {input}", self.0))
+    }
+
+    fn stream(&self, _input: String) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(vec![Ok(StreamEvent::FinalAnswer(self.0.to_string()))]).boxed()
+    }
+}
+
+fn router() -> RunnableBranch<String, String> {
+    RunnableBranch::new(Arc::new(Reply("default")))
+        .branch(
+            "question",
+            |input: &String| input.ends_with('?'),
+            Arc::new(Reply("answer")),
+        )
+        .branch(
+            "greeting",
+            |input: &String| input.starts_with("hi"),
+            Arc::new(Reply("greet")),
+        )
+}
+
+#[tokio::test]
+async fn branch_routes_to_first_matching_predicate() {
+    let router = router();
+
+    assert_eq!(
+        router.invoke("what time is it?".to_string()).await.unwrap(),
+        "answer: what time is it?"
+    );
+    assert_eq!(
+        router.invoke("hi there".to_string()).await.unwrap(),
+        "greet: hi there"
+    );
+    // Both predicates match; the earlier branch wins.
+    assert_eq!(
+        router.invoke("hi?".to_string()).await.unwrap(),
+        "answer: hi?"
+    );
+}
+
+#[tokio::test]
+async fn branch_falls_back_to_default() {
+    let output = router()
+        .invoke("tell me a story".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(output, "default: tell me a story");
+}
+
+#[tokio::test]
+async fn branch_streams_only_the_selected_runnable() {
+    let router = router();
+    let events: Vec<_> = router.stream("hi again".to_string()).collect().await;
+
+    assert_eq!(events.len(), 1);
+    assert!(matches!(&events[0], Ok(StreamEvent::FinalAnswer(text)) if text == "greet"));
+}
+
+#[test]
+fn branch_serializes_labels_and_runnables() {
+    let router: RunnableBranch<LlmResponse, String> =
+        RunnableBranch::new(Arc::new(StrOutputParser)).branch(
+            "text_only",
+            |response: &LlmResponse| response.tool_calls.is_empty(),
+            Arc::new(StrOutputParser),
+        );
+
+    let Some(SerializableRunnable::Branch { branches, default }) = router.to_serializable() else {
+        panic!("expected a Branch");
+    };
+    assert_eq!(branches.len(), 1);
+    assert_eq!(branches[0].label, "text_only");
+    assert!(matches!(
+        &branches[0].runnable,
+        SerializableRunnable::Parser { kind, .. } if kind == "str"
+    ));
+    assert!(matches!(*default, SerializableRunnable::Parser { .. }));
+
+    let json = SerializableRunnable::Branch { branches, default }
+        .to_json()
+        .unwrap();
+    assert!(json.contains("\"type\":\"branch\""), "{json}");
+    assert!(json.contains("\"label\":\"text_only\""), "{json}");
+}
+
+#[test]
+fn branch_without_serializable_runnables_is_not_serializable() {
+    let router = router();
+
+    assert!(router.to_serializable().is_none());
+}
@@ -0,0 +1,73 @@
+use futures::StreamExt;
+use serde_json::json;
+use wesichain_core::{Runnable, StreamEvent, Value, WesichainError};
+
+struct Shout;
+
+#[async_trait::async_trait]
+impl Runnable<String, String> for Shout {
+    async fn invoke(&self, input: String) -> Result<String, WesichainError> {
+        if input.is_empty() {
+            return Err(WesichainError::InvalidConfig("empty input".to_string()));
+        }
+        Ok(input.to_uppercase())
+    }
+}
+
+struct Wrap;
+
+#[async_trait::async_trait]
+impl Runnable<String, Value> for Wrap {
+    async fn invoke(&self, input: String) -> Result<Value, WesichainError> {
+        Ok(json!({ "text": input }))
+    }
+}
+
+struct Length;
+
+#[async_trait::async_trait]
+impl Runnable<String, usize> for Length {
+    async fn invoke(&self, input: String) -> Result<usize, WesichainError> {
+        Ok(input.len())
+    }
+}
+
+#[tokio::test]
+async fn invoke_only_runnable_streams_its_output_as_final_answer() {
+    let events: Vec<_> = Shout.stream("hi".to_string()).collect().await;
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(
+        events[0].as_ref().unwrap(),
+        &StreamEvent::FinalAnswer("HI".to_string())
+    );
+}
+
+#[tokio::test]
+async fn default_stream_renders_value_output_as_json() {
+    let events: Vec<_> = Wrap.stream("hi".to_string()).collect().await;
+
+    assert_eq!(
+        events[0].as_ref().unwrap(),
+        &StreamEvent::FinalAnswer(r#"{"text":"hi"}"#.to_string())
+    );
+}
+
+#[tokio::test]
+async fn default_stream_errors_for_other_outputs() {
+    let events: Vec<_> = Length.stream("hi".to_string()).collect().await;
+
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        Err(WesichainError::Custom(message)) => assert!(message.contains("usize"), "{message}"),
+        other => panic!("expected an error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn default_stream_yields_invoke_errors() {
+    let events: Vec<_> = Shout.stream(String::new()).collect().await;
+
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], Err(WesichainError::InvalidConfig(_))));
+}
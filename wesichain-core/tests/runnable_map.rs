@@ -0,0 +1,93 @@
+use futures::stream::{self, BoxStream, StreamExt};
+
+use wesichain_core::{
+    LlmRequest, LlmResponse, Message, Runnable, RunnableExt, StreamEvent, WesichainError,
+};
+
+struct StubLlm;
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, LlmResponse> for StubLlm {
+    async fn invoke(&self, input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        Ok(LlmResponse {
+            content: format!("echo: {}", input.messages[0].content),
+            ..Default::default()
+        })
+    }
+
+    fn stream(&self, _input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(vec![
+            Ok(StreamEvent::ContentChunk("echo".to_string())),
+            Ok(StreamEvent::FinalAnswer("echo".to_string())),
+        ])
+        .boxed()
+    }
+}
+
+struct WordCount;
+
+#[async_trait::async_trait]
+impl Runnable<String, usize> for WordCount {
+    async fn invoke(&self, input: String) -> Result<usize, WesichainError> {
+        Ok(input.split_whitespace().count())
+    }
+
+    fn stream(&self, _input: String) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::empty().boxed()
+    }
+}
+
+fn request(text: &str) -> LlmRequest {
+    LlmRequest {
+        model: String::new(),
+        messages: vec![Message::user(text)],
+        tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
+    }
+}
+
+#[tokio::test]
+async fn map_extracts_content_from_llm_response() {
+    let content = StubLlm.map(|response: LlmResponse| Ok(response.content));
+
+    assert_eq!(content.invoke(request("hi")).await.unwrap(), "echo: hi");
+}
+
+#[tokio::test]
+async fn map_ok_composes_with_then() {
+    let chain = StubLlm
+        .map_ok(|response: LlmResponse| response.content)
+        .then(WordCount)
+        .map_ok(|count| count * 10);
+
+    assert_eq!(chain.invoke(request("one two")).await.unwrap(), 30);
+}
+
+#[tokio::test]
+async fn map_surfaces_transform_errors() {
+    let strict = StubLlm.map(|response: LlmResponse| {
+        if response.tool_calls.is_empty() {
+            Err(WesichainError::ParseFailed {
+                output: response.content,
+                reason: "expected a tool call".to_string(),
+            })
+        } else {
+            Ok(response.tool_calls)
+        }
+    });
+
+    let err = strict.invoke(request("hi")).await.unwrap_err();
+    assert!(matches!(err, WesichainError::ParseFailed { output, .. } if output == "echo: hi"));
+}
+
+#[tokio::test]
+async fn map_passes_stream_events_through() {
+    let content = StubLlm.map(|response: LlmResponse| Ok(response.content));
+    let events: Vec<_> = content.stream(request("hi")).collect().await;
+
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[0], Ok(StreamEvent::ContentChunk(chunk)) if chunk == "echo"));
+}
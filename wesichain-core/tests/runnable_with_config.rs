@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use futures::stream::{self, BoxStream, StreamExt};
+use serde_json::json;
+use wesichain_core::{
+    with_run_context, with_run_context_stream, CallbackHandler, CallbackManager, RunContext,
+    RunType, Runnable, RunnableExt, RunnableWithConfig, StreamEvent, TracedRunnable, Value,
+    WesichainError,
+};
+
+#[derive(Clone, Default)]
+struct RecordingHandler {
+    starts: Arc<Mutex<Vec<RunContext>>>,
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for RecordingHandler {
+    async fn on_start(&self, ctx: &RunContext, _inputs: &Value) {
+        self.starts.lock().unwrap().push(ctx.clone());
+    }
+
+    async fn on_end(&self, _ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {}
+
+    async fn on_error(&self, _ctx: &RunContext, _error: &Value, _duration_ms: u128) {}
+}
+
+struct Echo;
+
+#[async_trait::async_trait]
+impl Runnable<String, String> for Echo {
+    async fn invoke(&self, input: String) -> Result<String, WesichainError> {
+        Ok(input)
+    }
+
+    fn stream(&self, input: String) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(vec![Ok(StreamEvent::ContentChunk(input))]).boxed()
+    }
+}
+
+fn root() -> RunContext {
+    RunContext::root(
+        RunType::Chain,
+        "root".to_string(),
+        vec!["prod".to_string()],
+        BTreeMap::new(),
+    )
+}
+
+#[tokio::test]
+async fn wrapped_runnable_run_carries_tags_and_metadata() {
+    let handler = RecordingHandler::default();
+    let manager = CallbackManager::new(vec![Arc::new(handler.clone())]);
+    let root = root();
+
+    let runnable = Echo
+        .with_tags(vec!["rag".to_string()])
+        .with_metadata(BTreeMap::from([("user".to_string(), json!("u1"))]));
+    let output = with_run_context(manager, root.clone(), runnable.invoke("hi".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(output, "hi");
+
+    let starts = handler.starts.lock().unwrap();
+    assert_eq!(starts.len(), 1);
+    assert_eq!(starts[0].name, "Echo");
+    assert_eq!(starts[0].parent_run_id, Some(root.run_id));
+    assert_eq!(starts[0].tags, vec!["prod".to_string(), "rag".to_string()]);
+    assert_eq!(starts[0].metadata["user"], json!("u1"));
+}
+
+#[tokio::test]
+async fn wrapped_runnable_nests_under_traced_runnable() {
+    let handler = RecordingHandler::default();
+    let manager = CallbackManager::new(vec![Arc::new(handler.clone())]);
+
+    let traced = TracedRunnable::new(
+        Echo.with_tags(vec!["rag".to_string()]),
+        manager,
+        root(),
+        RunType::Chain,
+        "pipeline".to_string(),
+    );
+    traced.invoke("hi".to_string()).await.unwrap();
+
+    let starts = handler.starts.lock().unwrap();
+    let names: Vec<&str> = starts.iter().map(|ctx| ctx.name.as_str()).collect();
+    assert_eq!(names, vec!["pipeline", "Echo"]);
+    assert_eq!(starts[1].parent_run_id, Some(starts[0].run_id));
+    assert!(!starts[0].tags.contains(&"rag".to_string()));
+    assert!(starts[1].tags.contains(&"rag".to_string()));
+}
+
+#[tokio::test]
+async fn wrapped_runnable_nests_under_traced_runnable_when_streamed() {
+    let handler = RecordingHandler::default();
+    let manager = CallbackManager::new(vec![Arc::new(handler.clone())]);
+
+    let traced = TracedRunnable::new(
+        Echo.with_tags(vec!["rag".to_string()]),
+        manager,
+        root(),
+        RunType::Chain,
+        "pipeline".to_string(),
+    );
+    let events: Vec<_> = traced.stream("hi".to_string()).collect().await;
+    assert_eq!(events.len(), 1);
+
+    let starts = handler.starts.lock().unwrap();
+    let names: Vec<&str> = starts.iter().map(|ctx| ctx.name.as_str()).collect();
+    assert_eq!(names, vec!["pipeline", "Echo"]);
+    assert_eq!(starts[1].parent_run_id, Some(starts[0].run_id));
+}
+
+#[tokio::test]
+async fn nested_wrappers_keep_their_parent_when_streamed() {
+    let handler = RecordingHandler::default();
+    let manager = CallbackManager::new(vec![Arc::new(handler.clone())]);
+    let root = root();
+
+    let inner = Echo.with_tags(vec!["rag".to_string()]).with_name("inner");
+    let runnable = RunnableWithConfig::new(inner).with_name("outer");
+    let events: Vec<_> =
+        with_run_context_stream(manager, root.clone(), || runnable.stream("hi".to_string()))
+            .collect()
+            .await;
+    assert_eq!(events.len(), 1);
+
+    let starts = handler.starts.lock().unwrap();
+    let names: Vec<&str> = starts.iter().map(|ctx| ctx.name.as_str()).collect();
+    assert_eq!(names, vec!["outer", "inner"]);
+    assert_eq!(starts[0].parent_run_id, Some(root.run_id));
+    assert_eq!(starts[1].parent_run_id, Some(starts[0].run_id));
+}
+
+#[tokio::test]
+async fn without_ambient_run_the_wrapper_is_transparent() {
+    let runnable = Echo.with_tags(vec!["rag".to_string()]).with_name("echo");
+
+    assert_eq!(runnable.invoke("hi".to_string()).await.unwrap(), "hi");
+    let events: Vec<_> = runnable.stream("hi".to_string()).collect().await;
+    assert!(matches!(
+        events.as_slice(),
+        [Ok(StreamEvent::ContentChunk(chunk))] if chunk == "hi"
+    ));
+}
+
+#[tokio::test]
+async fn without_ambient_run_the_wrapper_reports_a_root_run_with_its_tags() {
+    let handler = RecordingHandler::default();
+    let manager = CallbackManager::new(vec![Arc::new(handler.clone())]);
+
+    let runnable = Echo
+        .with_tags(vec!["rag".to_string()])
+        .with_metadata(BTreeMap::from([("user".to_string(), json!("u1"))]))
+        .with_callbacks(manager);
+    assert_eq!(runnable.invoke("hi".to_string()).await.unwrap(), "hi");
+
+    let starts = handler.starts.lock().unwrap();
+    assert_eq!(starts.len(), 1);
+    assert_eq!(starts[0].name, "Echo");
+    assert_eq!(starts[0].parent_run_id, None);
+    assert_eq!(starts[0].tags, vec!["rag".to_string()]);
+    assert_eq!(starts[0].metadata["user"], json!("u1"));
+}
@@ -0,0 +1,217 @@
+use std::sync::{Arc, Mutex};
+
+use futures::stream::{self, BoxStream, StreamExt};
+use serde_json::json;
+use wesichain_core::{
+    with_run_context, CallbackHandler, CallbackManager, RunConfig, RunContext, RunType, Runnable,
+    RuntimeChain, StreamEvent, Value, WesichainError,
+};
+
+#[derive(Default)]
+struct Recorder {
+    events: Mutex<Vec<(String, RunContext)>>,
+}
+
+impl Recorder {
+    fn events(&self) -> Vec<(String, RunContext)> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for Recorder {
+    async fn on_start(&self, ctx: &RunContext, _inputs: &Value) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(("start".to_string(), ctx.clone()));
+    }
+
+    async fn on_end(&self, ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(("end".to_string(), ctx.clone()));
+    }
+
+    async fn on_error(&self, ctx: &RunContext, _error: &Value, _duration_ms: u128) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(("error".to_string(), ctx.clone()));
+    }
+}
+
+struct Increment;
+
+#[async_trait::async_trait]
+impl Runnable<Value, Value> for Increment {
+    async fn invoke(&self, input: Value) -> Result<Value, WesichainError> {
+        Ok(json!(input.as_i64().unwrap_or_default() + 1))
+    }
+
+    fn stream(&self, input: Value) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(vec![Ok(StreamEvent::FinalAnswer(input.to_string()))]).boxed()
+    }
+}
+
+struct Fail;
+
+#[async_trait::async_trait]
+impl Runnable<Value, Value> for Fail {
+    async fn invoke(&self, _input: Value) -> Result<Value, WesichainError> {
+        Err(WesichainError::Custom("boom".to_string()))
+    }
+
+    fn stream(&self, _input: Value) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(vec![Err(WesichainError::Custom("boom".to_string()))]).boxed()
+    }
+}
+
+fn config(recorder: &Arc<Recorder>) -> RunConfig {
+    RunConfig {
+        callbacks: Some(CallbackManager::new(vec![recorder.clone()])),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn invoke_with_config_traces_each_step_under_root() {
+    let recorder = Arc::new(Recorder::default());
+    let chain = RuntimeChain::new(vec![Arc::new(Increment), Arc::new(Increment)]);
+
+    let output = chain
+        .invoke_with_config(json!(1), config(&recorder))
+        .await
+        .unwrap();
+    assert_eq!(output, json!(3));
+
+    let events = recorder.events();
+    let kinds: Vec<_> = events
+        .iter()
+        .map(|(kind, ctx)| format!("{kind}:{}", ctx.name))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "start:RuntimeChain",
+            "start:step_0",
+            "end:step_0",
+            "start:step_1",
+            "end:step_1",
+            "end:RuntimeChain",
+        ]
+    );
+
+    let root = &events[0].1;
+    assert_eq!(root.parent_run_id, None);
+    for (_, ctx) in &events[1..5] {
+        assert_eq!(ctx.parent_run_id, Some(root.run_id));
+        assert_eq!(ctx.trace_id, root.trace_id);
+    }
+}
+
+#[tokio::test]
+async fn invoke_with_config_reports_failing_step() {
+    let recorder = Arc::new(Recorder::default());
+    let chain = RuntimeChain::new(vec![Arc::new(Increment), Arc::new(Fail)]);
+
+    let result = chain.invoke_with_config(json!(1), config(&recorder)).await;
+    assert!(result.is_err());
+
+    let kinds: Vec<_> = recorder
+        .events()
+        .iter()
+        .map(|(kind, ctx)| format!("{kind}:{}", ctx.name))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "start:RuntimeChain",
+            "start:step_0",
+            "end:step_0",
+            "start:step_1",
+            "error:step_1",
+            "error:RuntimeChain",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn stream_with_config_traces_each_step() {
+    let recorder = Arc::new(Recorder::default());
+    let chain = RuntimeChain::new(vec![Arc::new(Increment), Arc::new(Increment)]);
+
+    let events: Vec<_> = chain
+        .stream_with_config(json!(1), config(&recorder))
+        .collect()
+        .await;
+    assert!(matches!(
+        events.as_slice(),
+        [Ok(StreamEvent::FinalAnswer(answer))] if answer == "2"
+    ));
+
+    let kinds: Vec<_> = recorder
+        .events()
+        .iter()
+        .map(|(kind, ctx)| format!("{kind}:{}", ctx.name))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "start:RuntimeChain",
+            "start:step_0",
+            "end:step_0",
+            "start:step_1",
+            "end:step_1",
+            "end:RuntimeChain",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn invoke_without_callbacks_is_untraced() {
+    let chain = RuntimeChain::new(vec![Arc::new(Increment)]);
+    let output = chain.invoke(json!(41)).await.unwrap();
+    assert_eq!(output, json!(42));
+}
+
+#[tokio::test]
+async fn invoke_under_ambient_run_traces_a_child_run() {
+    let recorder = Arc::new(Recorder::default());
+    let manager = CallbackManager::new(vec![recorder.clone()]);
+    let parent = RunContext::root(
+        RunType::Chain,
+        "parent".to_string(),
+        Vec::new(),
+        Default::default(),
+    );
+    let chain = RuntimeChain::new(vec![Arc::new(Increment), Arc::new(Increment)]);
+
+    let output = with_run_context(manager, parent.clone(), chain.invoke(json!(1)))
+        .await
+        .unwrap();
+    assert_eq!(output, json!(3));
+
+    let events = recorder.events();
+    let kinds: Vec<_> = events
+        .iter()
+        .map(|(kind, ctx)| format!("{kind}:{}", ctx.name))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            "start:RuntimeChain",
+            "start:step_0",
+            "end:step_0",
+            "start:step_1",
+            "end:step_1",
+            "end:RuntimeChain",
+        ]
+    );
+
+    let chain_run = &events[0].1;
+    assert_eq!(chain_run.parent_run_id, Some(parent.run_id));
+    assert_eq!(chain_run.trace_id, parent.trace_id);
+    assert_eq!(events[1].1.parent_run_id, Some(chain_run.run_id));
+}
@@ -28,6 +28,7 @@ async fn test_serialization_str_parser() {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
         .await
         .unwrap();
@@ -35,7 +36,7 @@ async fn test_serialization_str_parser() {
 
     // Test enum structure
     let content = fs::read_to_string(path).unwrap();
-    let ser: SerializableRunnable = serde_json::from_str(&content).unwrap();
+    let ser = wesichain_core::parse_persisted(&content).unwrap();
     match ser {
         SerializableRunnable::Parser { kind, .. } => assert_eq!(kind, "str"),
         _ => panic!("Wrong type"),
@@ -118,7 +119,7 @@ async fn test_serialization_chain() {
     save_runnable(path, &chain).expect("Save failed");
 
     let content = fs::read_to_string(path).unwrap();
-    let ser: SerializableRunnable = serde_json::from_str(&content).unwrap();
+    let ser = wesichain_core::parse_persisted(&content).unwrap();
 
     // Check structure: Chain with 2 steps (Tool mock_1, Tool mock_2)
     match ser {
@@ -163,7 +164,7 @@ async fn test_serialization_parallel() {
     save_runnable(path, &parallel).unwrap();
 
     let content = fs::read_to_string(path).unwrap();
-    let ser: SerializableRunnable = serde_json::from_str(&content).unwrap();
+    let ser = wesichain_core::parse_persisted(&content).unwrap();
 
     match ser {
         SerializableRunnable::Parallel { steps } => {
@@ -0,0 +1,71 @@
+use wesichain_core::similarity::{cosine, dot, euclidean, Metric};
+use wesichain_core::StoreError;
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+}
+
+#[test]
+fn identical_vectors_are_maximally_similar() {
+    let v = [1.0, 2.0, 3.0];
+
+    assert_close(cosine(&v, &v).unwrap(), 1.0);
+    assert_close(dot(&v, &v).unwrap(), 14.0);
+    assert_close(euclidean(&v, &v).unwrap(), 0.0);
+    assert_close(Metric::Euclidean.score(&v, &v).unwrap(), 1.0);
+}
+
+#[test]
+fn orthogonal_vectors_have_zero_similarity() {
+    let a = [1.0, 0.0];
+    let b = [0.0, 2.0];
+
+    assert_close(cosine(&a, &b).unwrap(), 0.0);
+    assert_close(dot(&a, &b).unwrap(), 0.0);
+    assert_close(euclidean(&a, &b).unwrap(), 5.0_f32.sqrt());
+    assert_close(cosine(&a, &[-3.0, 0.0]).unwrap(), -1.0);
+}
+
+#[test]
+fn zero_vectors_do_not_produce_nan() {
+    let zero = [0.0, 0.0, 0.0];
+    let v = [1.0, 2.0, 3.0];
+
+    for metric in [Metric::Cosine, Metric::Dot, Metric::Euclidean] {
+        assert!(!metric.score(&zero, &v).unwrap().is_nan(), "{metric:?}");
+        assert!(!metric.score(&zero, &zero).unwrap().is_nan(), "{metric:?}");
+    }
+    assert_eq!(cosine(&zero, &v).unwrap(), 0.0);
+    assert_eq!(cosine(&[], &[]).unwrap(), 0.0);
+}
+
+#[test]
+fn mismatched_lengths_are_rejected() {
+    for metric in [Metric::Cosine, Metric::Dot, Metric::Euclidean] {
+        let err = metric.score(&[1.0, 2.0], &[1.0]).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                StoreError::DimensionMismatch {
+                    expected: 2,
+                    got: 1
+                }
+            ),
+            "{err:?}"
+        );
+    }
+}
+
+#[test]
+fn metric_score_ranks_closer_vectors_higher() {
+    let query = [1.0, 0.0];
+    let near = [0.9, 0.1];
+    let far = [-1.0, 0.5];
+
+    for metric in [Metric::Cosine, Metric::Dot, Metric::Euclidean] {
+        let near_score = metric.score(&query, &near).unwrap();
+        let far_score = metric.score(&query, &far).unwrap();
+        assert!(near_score > far_score, "{metric:?}");
+    }
+    assert_eq!(Metric::default(), Metric::Cosine);
+}
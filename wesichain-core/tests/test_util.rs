@@ -0,0 +1,116 @@
+use futures::StreamExt;
+use serde_json::json;
+use wesichain_core::test_util::{
+    chunk, final_answer, stream_from, tool_delta, tool_start, FakeEmbedding, MockLlm,
+};
+use wesichain_core::{
+    collect_stream, Embedding, LlmRequest, LlmResponse, Message, Runnable, StreamEvent, ToolCall,
+};
+
+fn request(text: &str) -> LlmRequest {
+    LlmRequest {
+        model: String::new(),
+        messages: vec![Message::user(text)],
+        tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
+    }
+}
+
+#[tokio::test]
+async fn stream_from_yields_events_in_order() {
+    let events: Vec<_> = stream_from(vec![
+        chunk("a"),
+        tool_start("call_1", "search"),
+        tool_delta("call_1", json!({"q": "rust"})),
+        final_answer("a"),
+    ])
+    .map(Result::unwrap)
+    .collect()
+    .await;
+
+    assert_eq!(
+        events,
+        vec![
+            StreamEvent::ContentChunk("a".to_string()),
+            StreamEvent::ToolCallStart {
+                id: "call_1".to_string(),
+                name: "search".to_string(),
+            },
+            StreamEvent::ToolCallDelta {
+                id: "call_1".to_string(),
+                delta: json!({"q": "rust"}),
+            },
+            StreamEvent::FinalAnswer("a".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn mock_llm_replays_script_and_records_requests() {
+    let tool_call = ToolCall {
+        id: "call_1".to_string(),
+        name: "search".to_string(),
+        args: json!({"q": "rust"}),
+    };
+    let llm = MockLlm::new([
+        LlmResponse {
+            tool_calls: vec![tool_call.clone()],
+            ..LlmResponse::default()
+        },
+        LlmResponse {
+            content: "done".to_string(),
+            ..LlmResponse::default()
+        },
+    ]);
+
+    let first = llm.invoke(request("one")).await.unwrap();
+    let second = collect_stream(llm.stream(request("two"))).await.unwrap();
+    let third = llm.invoke(request("three")).await.unwrap();
+
+    assert_eq!(first.tool_calls, vec![tool_call]);
+    assert_eq!(second.content, "done");
+    assert_eq!(third.content, "done");
+    let seen: Vec<_> = llm
+        .requests()
+        .iter()
+        .map(|request| request.messages[0].content.to_string())
+        .collect();
+    assert_eq!(seen, ["one", "two", "three"]);
+}
+
+#[tokio::test]
+async fn mock_llm_streams_scripted_events() {
+    let llm =
+        MockLlm::text("Hello").with_stream([chunk("Hel"), chunk("lo"), final_answer("Hello")]);
+
+    let events: Vec<_> = llm.stream(request("hi")).collect().await;
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(
+        collect_stream(llm.stream(request("hi")))
+            .await
+            .unwrap()
+            .content,
+        "Hello"
+    );
+}
+
+#[tokio::test]
+async fn fake_embedding_uses_mappings_then_hash_fallback() {
+    let embedder = FakeEmbedding::new(2).with_mapping("cat", vec![1.0, 0.0]);
+
+    assert_eq!(embedder.embed("cat").await.unwrap(), vec![1.0, 0.0]);
+    let unknown = embedder.embed("dog").await.unwrap();
+    assert_eq!(unknown.len(), 2);
+    assert_eq!(embedder.embed("dog").await.unwrap(), unknown);
+    assert_eq!(
+        embedder
+            .embed_batch(&["cat".to_string(), "dog".to_string()])
+            .await
+            .unwrap(),
+        vec![vec![1.0, 0.0], unknown]
+    );
+}
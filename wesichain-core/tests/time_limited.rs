@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use wesichain_core::{Runnable, RunnableExt, StreamEvent, WesichainError};
+
+/// Sleeps `delay` before answering, and before each of `chunks` when
+/// streaming. A chunk of `None` stalls forever.
+struct Slow {
+    delay: Duration,
+    chunks: Vec<Option<&'static str>>,
+}
+
+#[async_trait::async_trait]
+impl Runnable<String, String> for Slow {
+    async fn invoke(&self, input: String) -> Result<String, WesichainError> {
+        tokio::time::sleep(self.delay).await;
+        Ok(input)
+    }
+
+    fn stream(&self, _input: String) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        let delay = self.delay;
+        stream::iter(self.chunks.clone())
+            .then(move |chunk| async move {
+                match chunk {
+                    Some(chunk) => {
+                        tokio::time::sleep(delay).await;
+                        Ok(StreamEvent::ContentChunk(chunk.to_string()))
+                    }
+                    None => std::future::pending().await,
+                }
+            })
+            .boxed()
+    }
+}
+
+#[tokio::test]
+async fn slow_invoke_times_out() {
+    let runnable = Slow {
+        delay: Duration::from_secs(5),
+        chunks: Vec::new(),
+    }
+    .with_timeout(Duration::from_millis(50));
+
+    let err = runnable.invoke("hi".to_string()).await.unwrap_err();
+
+    assert!(
+        matches!(err, WesichainError::Timeout(d) if d == Duration::from_millis(50)),
+        "{err:?}"
+    );
+}
+
+#[tokio::test]
+async fn fast_invoke_passes_through() {
+    let runnable = Slow {
+        delay: Duration::from_millis(10),
+        chunks: Vec::new(),
+    }
+    .with_timeout(Duration::from_secs(1));
+
+    assert_eq!(runnable.invoke("hi".to_string()).await.unwrap(), "hi");
+}
+
+#[tokio::test]
+async fn stalled_stream_times_out_after_last_event() {
+    let runnable = Slow {
+        delay: Duration::from_millis(10),
+        chunks: vec![Some("a"), Some("b"), None],
+    }
+    .with_timeout(Duration::from_millis(100));
+
+    let events: Vec<_> = runnable.stream("hi".to_string()).collect().await;
+
+    assert_eq!(events.len(), 3);
+    assert!(matches!(&events[0], Ok(StreamEvent::ContentChunk(c)) if c == "a"));
+    assert!(matches!(&events[1], Ok(StreamEvent::ContentChunk(c)) if c == "b"));
+    assert!(matches!(events[2], Err(WesichainError::Timeout(_))));
+}
+
+#[tokio::test]
+async fn stream_timeout_is_per_event_not_total() {
+    // Five events 40ms apart take 200ms overall, past the 100ms timeout.
+    let runnable = Slow {
+        delay: Duration::from_millis(40),
+        chunks: vec![Some("a"); 5],
+    }
+    .with_timeout(Duration::from_millis(100));
+
+    let events: Vec<_> = runnable.stream("hi".to_string()).collect().await;
+
+    assert_eq!(events.len(), 5);
+    assert!(events.iter().all(Result::is_ok));
+}
@@ -0,0 +1,122 @@
+use serde_json::json;
+use wesichain_core::{StreamEvent, ToolCallAssembler, Value, WesichainError};
+
+fn start(id: &str, name: &str) -> StreamEvent {
+    StreamEvent::ToolCallStart {
+        id: id.to_string(),
+        name: name.to_string(),
+    }
+}
+
+fn fragment(id: &str, text: &str) -> StreamEvent {
+    StreamEvent::ToolCallDelta {
+        id: id.to_string(),
+        delta: Value::String(text.to_string()),
+    }
+}
+
+fn assemble(events: &[StreamEvent]) -> ToolCallAssembler {
+    let mut assembler = ToolCallAssembler::new();
+    for event in events {
+        assembler.push(event);
+    }
+    assembler
+}
+
+#[test]
+fn assembles_multiple_tool_calls_in_start_order() {
+    let calls = assemble(&[
+        start("call_1", "search"),
+        fragment("call_1", r#"{"query":"rust"}"#),
+        start("call_2", "weather"),
+        StreamEvent::ToolCallDelta {
+            id: "call_2".to_string(),
+            delta: json!({"city": "Paris"}),
+        },
+        start("call_3", "now"),
+    ])
+    .finish()
+    .unwrap();
+
+    let summary: Vec<_> = calls
+        .iter()
+        .map(|call| (call.id.as_str(), call.name.as_str(), call.args.clone()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("call_1", "search", json!({"query": "rust"})),
+            ("call_2", "weather", json!({"city": "Paris"})),
+            ("call_3", "now", json!({})),
+        ]
+    );
+}
+
+#[test]
+fn interleaved_fragments_are_buffered_per_id() {
+    let calls = assemble(&[
+        start("a", "add"),
+        start("b", "mul"),
+        fragment("b", r#"{"x":"#),
+        fragment("a", r#"{"x":"#),
+        fragment("a", "1}"),
+        fragment("b", "2}"),
+    ])
+    .finish()
+    .unwrap();
+
+    assert_eq!(calls[0].name, "add");
+    assert_eq!(calls[0].args, json!({"x": 1}));
+    assert_eq!(calls[1].name, "mul");
+    assert_eq!(calls[1].args, json!({"x": 2}));
+}
+
+#[test]
+fn fragments_may_split_tokens_and_escapes() {
+    let raw = r#"{"path":"C:\\tmp\\a.txt","note":"say \"hi\" \u00e9","n":12345}"#;
+    let mut events = vec![start("call_1", "write")];
+    for piece in raw.as_bytes().chunks(3) {
+        events.push(fragment("call_1", std::str::from_utf8(piece).unwrap()));
+    }
+
+    let calls = assemble(&events).finish().unwrap();
+
+    assert_eq!(
+        calls[0].args,
+        json!({"path": "C:\\tmp\\a.txt", "note": "say \"hi\" é", "n": 12345})
+    );
+}
+
+#[test]
+fn invalid_arguments_name_the_offending_call() {
+    let err = assemble(&[
+        start("ok", "search"),
+        fragment("ok", r#"{"query":"x"}"#),
+        start("broken", "search"),
+        fragment("broken", r#"{"query":"#),
+    ])
+    .finish()
+    .unwrap_err();
+
+    match err {
+        WesichainError::ParseFailed { reason, output } => {
+            assert!(reason.contains("'broken'"), "{reason}");
+            assert_eq!(output, r#"{"query":"#);
+        }
+        other => panic!("expected ParseFailed, got {other:?}"),
+    }
+}
+
+#[test]
+fn push_ignores_other_events() {
+    let mut assembler = ToolCallAssembler::new();
+
+    assert!(!assembler.push(&StreamEvent::ContentChunk("hi".to_string())));
+    assert!(assembler.is_empty());
+    assert!(assembler.push(&fragment("late", "{}")));
+    assert!(!assembler.is_empty());
+
+    let calls = assembler.finish().unwrap();
+    assert_eq!(calls[0].id, "late");
+    assert_eq!(calls[0].name, "");
+}
@@ -5,3 +5,10 @@ fn tool_error_is_displayable() {
     let err = ToolError::InvalidInput("missing field".to_string());
     assert!(err.to_string().contains("missing field"));
 }
+
+#[test]
+fn only_retryable_errors_are_retryable() {
+    assert!(ToolError::Retryable("timed out".to_string()).is_retryable());
+    assert!(!ToolError::InvalidInput("bad args".to_string()).is_retryable());
+    assert!(!ToolError::ExecutionFailed("boom".to_string()).is_retryable());
+}
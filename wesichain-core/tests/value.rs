@@ -1,5 +1,10 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
-use wesichain_core::{IntoValue, TryFromValue, Value, WesichainError};
+use serde_json::json;
+use wesichain_core::{
+    value_get_path, value_set_path, IntoValue, TryFromValue, Value, WesichainError,
+};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 struct Demo {
@@ -49,3 +54,96 @@ fn try_from_value_rejects_string_for_struct() {
         other => panic!("expected serde error, got {other:?}"),
     }
 }
+
+#[test]
+fn value_roundtrip_for_nested_collections() {
+    let input: Vec<HashMap<String, i64>> = vec![
+        HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]),
+        HashMap::new(),
+    ];
+    let value = input.clone().into_value();
+    assert_eq!(value, json!([{"a": 1, "b": 2}, {}]));
+    assert_eq!(
+        Vec::<HashMap<String, i64>>::try_from_value(value).unwrap(),
+        input
+    );
+
+    let tuple = ("id".to_string(), Some(3_u32), vec![true]);
+    let value = tuple.clone().into_value();
+    assert_eq!(value, json!(["id", 3, [true]]));
+    assert_eq!(
+        <(String, Option<u32>, Vec<bool>)>::try_from_value(value).unwrap(),
+        tuple
+    );
+
+    assert_eq!(Option::<i64>::try_from_value(Value::Null).unwrap(), None);
+    assert_eq!(None::<i64>.into_value(), Value::Null);
+}
+
+fn serde_message(error: WesichainError) -> String {
+    match error {
+        WesichainError::Serde(inner) => inner.to_string(),
+        other => panic!("expected serde error, got {other:?}"),
+    }
+}
+
+#[test]
+fn try_from_value_names_expected_collection_shape() {
+    let error = Vec::<i64>::try_from_value(json!({"a": 1})).unwrap_err();
+    assert!(serde_message(error).contains("expected a sequence"));
+
+    let error = HashMap::<String, i64>::try_from_value(json!([1, 2])).unwrap_err();
+    assert!(serde_message(error).contains("expected a map"));
+
+    let error = <(i64, i64, i64)>::try_from_value(json!([1, 2])).unwrap_err();
+    assert!(serde_message(error).contains("expected a tuple of size 3"));
+
+    let error = Vec::<HashMap<String, i64>>::try_from_value(json!([{"a": "one"}])).unwrap_err();
+    assert!(serde_message(error).contains("expected i64"));
+}
+
+#[test]
+fn value_get_path_traverses_objects_and_arrays() {
+    let value = json!({"a": {"items": [{"name": "first"}, {"name": "second"}]}});
+    assert_eq!(
+        value_get_path(&value, "a.items.1.name"),
+        Some(&json!("second"))
+    );
+    assert_eq!(value_get_path(&value, ""), Some(&value));
+    assert_eq!(value_get_path(&value, "a.items.5.name"), None);
+    assert_eq!(value_get_path(&value, "a.items.x"), None);
+    assert_eq!(value_get_path(&value, "a.items.0.name.deeper"), None);
+}
+
+#[test]
+fn value_set_path_creates_missing_objects() {
+    let mut value = json!({});
+    value_set_path(&mut value, "meta.source.page", json!(3)).unwrap();
+    assert_eq!(value, json!({"meta": {"source": {"page": 3}}}));
+
+    let mut null = Value::Null;
+    value_set_path(&mut null, "a", json!(true)).unwrap();
+    assert_eq!(null, json!({"a": true}));
+}
+
+#[test]
+fn value_set_path_updates_and_appends_array_elements() {
+    let mut value = json!({"items": [{"name": "first"}]});
+    value_set_path(&mut value, "items.0.name", json!("renamed")).unwrap();
+    value_set_path(&mut value, "items.1.name", json!("appended")).unwrap();
+    assert_eq!(
+        value,
+        json!({"items": [{"name": "renamed"}, {"name": "appended"}]})
+    );
+}
+
+#[test]
+fn value_set_path_rejects_type_mismatch() {
+    let mut value = json!({"a": "scalar", "items": [1]});
+    let original = value.clone();
+
+    assert!(value_set_path(&mut value, "a.b", json!(1)).is_err());
+    assert!(value_set_path(&mut value, "items.name", json!(1)).is_err());
+    assert!(value_set_path(&mut value, "items.5", json!(1)).is_err());
+    assert_eq!(value, original);
+}
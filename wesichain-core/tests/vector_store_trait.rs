@@ -2,8 +2,8 @@ use std::{error::Error, sync::Arc, sync::Mutex};
 
 use async_trait::async_trait;
 use wesichain_core::{
-    delete_ref_dyn, delete_strs_dyn, Document, MetadataFilter, SearchResult, StoreError,
-    VectorStore,
+    delete_ref_dyn, delete_strs_dyn, find_duplicate_id, Document, MetadataFilter, SearchResult,
+    StoreError, VectorStore, WriteMode,
 };
 
 #[derive(Clone, Default)]
@@ -101,3 +101,32 @@ fn vector_store_trait_store_error_internal_preserves_source() {
     assert_eq!(format!("{err}"), "Store error: disk");
     assert!(err.source().is_some());
 }
+
+#[tokio::test]
+async fn vector_store_trait_default_add_mode_rejects_insert_only() {
+    let store: Arc<dyn VectorStore> = Arc::new(RecordingStore::new());
+
+    store.add_mode(Vec::new(), WriteMode::Upsert).await.unwrap();
+
+    let err = store
+        .add_mode(Vec::new(), WriteMode::InsertOnly)
+        .await
+        .expect_err("stores without id detection must not silently upsert");
+    assert!(matches!(err, StoreError::Internal(_)));
+}
+
+#[test]
+fn vector_store_trait_find_duplicate_id_reports_first_repeat() {
+    let doc = |id: &str| Document {
+        id: id.to_string(),
+        content: String::new(),
+        metadata: Default::default(),
+        embedding: None,
+    };
+
+    assert_eq!(find_duplicate_id(&[doc("a"), doc("b")]), None);
+    assert_eq!(
+        find_duplicate_id(&[doc("a"), doc("b"), doc("b"), doc("a")]),
+        Some("b")
+    );
+}
@@ -33,6 +33,7 @@ async fn bind_works_on_llm_request() {
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let tool_spec = json!({
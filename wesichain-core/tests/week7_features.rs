@@ -150,6 +150,7 @@ async fn test_with_structured_output() {
         }],
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let llm = MockLlm { response };
@@ -162,6 +163,7 @@ async fn test_with_structured_output() {
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let result = chain.invoke(request).await.unwrap();
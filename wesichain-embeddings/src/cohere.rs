@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use wesichain_core::{Document, Reranker, WesichainError};
+
+const DEFAULT_BASE_URL: &str = "https://api.cohere.com";
+const DEFAULT_MODEL: &str = "rerank-english-v3.0";
+
+/// [`Reranker`] backed by Cohere's `/v1/rerank` endpoint.
+///
+/// Returned scores are Cohere's `relevance_score` values in `[0, 1]`.
+#[derive(Clone)]
+pub struct CohereReranker {
+    base_url: String,
+    api_key: String,
+    model: String,
+    top_n: Option<usize>,
+    http: Client,
+}
+
+impl CohereReranker {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_key: api_key.into(),
+            model: DEFAULT_MODEL.to_string(),
+            top_n: None,
+            http: Client::new(),
+        }
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Ask the API to return only the best `top_n` documents.
+    pub fn with_top_n(mut self, top_n: usize) -> Self {
+        self.top_n = Some(top_n);
+        self
+    }
+
+    fn rerank_url(&self) -> String {
+        let base = self.base_url.trim_end_matches('/');
+        let base = base.strip_suffix("/v1").unwrap_or(base);
+        format!("{base}/v1/rerank")
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RerankRequest<'a> {
+    model: &'a str,
+    query: &'a str,
+    documents: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_n: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RerankResponse {
+    results: Vec<RerankResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RerankResult {
+    index: usize,
+    relevance_score: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+#[async_trait]
+impl Reranker for CohereReranker {
+    async fn rerank(
+        &self,
+        query: &str,
+        docs: Vec<Document>,
+    ) -> Result<Vec<(Document, f32)>, WesichainError> {
+        if docs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let request = RerankRequest {
+            model: &self.model,
+            query,
+            documents: docs.iter().map(|doc| doc.content.as_str()).collect(),
+            top_n: self.top_n,
+        };
+        let response = self
+            .http
+            .post(self.rerank_url())
+            .bearer_auth(&self.api_key)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|err| {
+                WesichainError::Custom(format!("cohere rerank request failed: {err}"))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let message = serde_json::from_str::<ErrorResponse>(&body)
+                .map(|e| e.message)
+                .unwrap_or_else(|_| format!("HTTP {}: {}", status, body));
+            return Err(match status {
+                StatusCode::TOO_MANY_REQUESTS => {
+                    WesichainError::RateLimitExceeded { retry_after: None }
+                }
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                    WesichainError::AuthenticationFailed {
+                        provider: "cohere".to_string(),
+                        message,
+                    }
+                }
+                _ => WesichainError::Custom(format!("cohere rerank failed: {message}")),
+            });
+        }
+
+        let response = response.json::<RerankResponse>().await.map_err(|err| {
+            WesichainError::Custom(format!("invalid cohere rerank response: {err}"))
+        })?;
+
+        let mut slots: Vec<Option<Document>> = docs.into_iter().map(Some).collect();
+        let mut ranked = Vec::with_capacity(response.results.len());
+        for result in response.results {
+            let doc = slots
+                .get_mut(result.index)
+                .and_then(Option::take)
+                .ok_or_else(|| {
+                    WesichainError::Custom(format!(
+                        "invalid cohere rerank response: unexpected document index {}",
+                        result.index
+                    ))
+                })?;
+            ranked.push((doc, result.relevance_score));
+        }
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(ranked)
+    }
+}
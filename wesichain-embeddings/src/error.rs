@@ -7,6 +7,8 @@ pub enum EmbeddingProviderError {
     Request(String),
     #[error("invalid response: {0}")]
     InvalidResponse(String),
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
 }
 
 impl From<EmbeddingProviderError> for EmbeddingError {
@@ -16,6 +18,9 @@ impl From<EmbeddingProviderError> for EmbeddingError {
                 EmbeddingError::InvalidResponse(message)
             }
             EmbeddingProviderError::Request(message) => EmbeddingError::Provider(message),
+            other @ EmbeddingProviderError::InvalidConfig(_) => {
+                EmbeddingError::Other(Box::new(other))
+            }
         }
     }
 }
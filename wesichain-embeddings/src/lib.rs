@@ -1,4 +1,5 @@
 mod error;
+mod pool;
 
 #[cfg(feature = "openai")]
 mod openai;
@@ -6,13 +7,20 @@ mod openai;
 #[cfg(feature = "ollama")]
 mod ollama;
 
+#[cfg(feature = "openai-compatible")]
+mod openai_compatible;
+
 #[cfg(feature = "google")]
 mod google;
 
 #[cfg(feature = "candle")]
 mod candle;
 
+#[cfg(feature = "cohere")]
+mod cohere;
+
 pub use error::EmbeddingProviderError;
+pub use pool::EmbeddingPool;
 
 #[cfg(feature = "openai")]
 pub use openai::OpenAiEmbedding;
@@ -20,8 +28,14 @@ pub use openai::OpenAiEmbedding;
 #[cfg(feature = "ollama")]
 pub use ollama::OllamaEmbedding;
 
+#[cfg(feature = "openai-compatible")]
+pub use openai_compatible::OpenAiCompatibleEmbedding;
+
 #[cfg(feature = "google")]
 pub use google::GoogleEmbedding;
 
 #[cfg(feature = "candle")]
 pub use candle::CandleEmbedding;
+
+#[cfg(feature = "cohere")]
+pub use cohere::CohereReranker;
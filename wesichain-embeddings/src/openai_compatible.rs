@@ -0,0 +1,218 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::EmbeddingProviderError;
+use async_trait::async_trait;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use wesichain_core::{Embedding, EmbeddingError};
+
+const DEFAULT_BATCH_SIZE: usize = 128;
+
+/// Embedding client for any server exposing the OpenAI `/v1/embeddings` API
+/// (vLLM, LM Studio, Together, LiteLLM, ...).
+///
+/// The embedding dimension is learned from the first successful response and
+/// cached; [`Embedding::dimension`] returns `0` until then unless an expected
+/// dimension was supplied with [`with_dimension`](Self::with_dimension).
+#[derive(Clone)]
+pub struct OpenAiCompatibleEmbedding {
+    base_url: String,
+    api_key: Option<String>,
+    model: String,
+    batch_size: usize,
+    dimension: OnceLock<usize>,
+    http: Client,
+}
+
+impl OpenAiCompatibleEmbedding {
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_key: None,
+            model: model.into(),
+            batch_size: DEFAULT_BATCH_SIZE,
+            dimension: OnceLock::new(),
+            http: Client::new(),
+        }
+    }
+
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Maximum number of inputs sent per request. Values below 1 are treated as 1.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Pin the expected dimension; responses of any other size are rejected.
+    pub fn with_dimension(self, dimension: usize) -> Self {
+        let _ = self.dimension.set(dimension);
+        self
+    }
+
+    fn embeddings_url(&self) -> String {
+        let base = self.base_url.trim_end_matches('/');
+        let base = base.strip_suffix("/v1").unwrap_or(base);
+        format!("{base}/v1/embeddings")
+    }
+
+    async fn request_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let request = EmbeddingsRequest {
+            model: &self.model,
+            input: texts,
+        };
+
+        let mut builder = self.http.post(self.embeddings_url()).json(&request);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(|err| EmbeddingError::Transport(err.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let body = response.text().await.unwrap_or_default();
+            return Err(status_error(status, retry_after, &body));
+        }
+
+        let response = response
+            .json::<EmbeddingsResponse>()
+            .await
+            .map_err(|err| EmbeddingProviderError::Request(err.to_string()))?;
+
+        if response.data.len() != texts.len() {
+            return Err(EmbeddingProviderError::InvalidResponse(format!(
+                "expected {} embeddings, got {}",
+                texts.len(),
+                response.data.len()
+            ))
+            .into());
+        }
+
+        // Servers may return items out of order; `index` refers to the input position.
+        let mut slots: Vec<Option<Vec<f32>>> = vec![None; texts.len()];
+        for (position, item) in response.data.into_iter().enumerate() {
+            let index = item.index.unwrap_or(position);
+            let slot = slots.get_mut(index).ok_or_else(|| {
+                EmbeddingProviderError::InvalidResponse(format!(
+                    "embedding index {index} out of range"
+                ))
+            })?;
+            if slot.replace(item.embedding).is_some() {
+                return Err(EmbeddingProviderError::InvalidResponse(format!(
+                    "duplicate embedding index {index}"
+                ))
+                .into());
+            }
+        }
+
+        let embeddings: Vec<Vec<f32>> = slots.into_iter().flatten().collect();
+        if embeddings.iter().any(Vec::is_empty) {
+            return Err(EmbeddingProviderError::InvalidResponse(
+                "empty embedding vector".to_string(),
+            )
+            .into());
+        }
+
+        // Check the whole batch before caching its size, so a malformed
+        // response never becomes the expected dimension.
+        let Some(expected) = self
+            .dimension
+            .get()
+            .copied()
+            .or_else(|| embeddings.first().map(Vec::len))
+        else {
+            return Ok(embeddings);
+        };
+        if let Some(embedding) = embeddings.iter().find(|e| e.len() != expected) {
+            return Err(EmbeddingError::DimensionMismatch {
+                expected,
+                got: embedding.len(),
+            });
+        }
+        let _ = self.dimension.set(expected);
+
+        Ok(embeddings)
+    }
+}
+
+/// Classify a non-success response so callers can tell transient failures
+/// (rate limits, 5xx) from fatal ones (bad credentials, bad requests).
+fn status_error(status: StatusCode, retry_after: Option<Duration>, body: &str) -> EmbeddingError {
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return EmbeddingError::RateLimited { retry_after };
+    }
+    let message = serde_json::from_str::<ErrorResponse>(body)
+        .map(|e| e.error.message)
+        .unwrap_or_else(|_| format!("HTTP {}: {}", status, body));
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => EmbeddingError::Auth(message),
+        StatusCode::REQUEST_TIMEOUT => EmbeddingError::Transport(message),
+        status if status.is_server_error() => EmbeddingError::Transport(message),
+        _ => EmbeddingError::Provider(message),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct EmbeddingsRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingsResponse {
+    data: Vec<EmbeddingItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingItem {
+    embedding: Vec<f32>,
+    #[serde(default)]
+    index: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetail {
+    message: String,
+}
+
+#[async_trait]
+impl Embedding for OpenAiCompatibleEmbedding {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        let mut out = self.request_batch(&[text.to_string()]).await?;
+        out.pop().ok_or_else(|| {
+            EmbeddingProviderError::InvalidResponse("missing embedding".to_string()).into()
+        })
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let mut out = Vec::with_capacity(texts.len());
+        for chunk in texts.chunks(self.batch_size) {
+            out.extend(self.request_batch(chunk).await?);
+        }
+        Ok(out)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension.get().copied().unwrap_or(0)
+    }
+}
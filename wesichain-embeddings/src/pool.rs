@@ -0,0 +1,190 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use wesichain_core::{Embedding, EmbeddingError};
+
+use crate::EmbeddingProviderError;
+
+/// Spreads embedding requests over several interchangeable backends, such as
+/// the same model behind different API keys or replicas.
+///
+/// Single embeds go to the backends in turn. `embed_batch` splits its inputs
+/// into contiguous slices, one per backend, embeds them concurrently and
+/// returns the vectors in input order.
+///
+/// A request that fails with a retryable error (see
+/// [`EmbeddingError::is_retryable`]) moves on to the next backend, so a
+/// rate-limited or unreachable backend is skipped. Other errors are
+/// returned as-is.
+pub struct EmbeddingPool {
+    backends: Vec<Arc<dyn Embedding>>,
+    next: AtomicUsize,
+}
+
+impl EmbeddingPool {
+    /// Fails if `backends` is empty or the backends disagree on `dimension()`.
+    ///
+    /// A backend reporting dimension 0 has not learned it yet and is left out
+    /// of the check.
+    pub fn new(backends: Vec<Arc<dyn Embedding>>) -> Result<Self, EmbeddingProviderError> {
+        if backends.is_empty() {
+            return Err(EmbeddingProviderError::InvalidConfig(
+                "embedding pool has no backends".to_string(),
+            ));
+        }
+
+        let mut known = backends
+            .iter()
+            .map(|backend| backend.dimension())
+            .enumerate()
+            .filter(|(_, dimension)| *dimension != 0);
+        if let Some((_, expected)) = known.next() {
+            if let Some((index, dimension)) = known.find(|(_, dimension)| *dimension != expected) {
+                return Err(EmbeddingProviderError::InvalidConfig(format!(
+                    "backend {index} has dimension {dimension}, expected {expected}"
+                )));
+            }
+        }
+
+        Ok(Self {
+            backends,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.backends.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.backends.is_empty()
+    }
+
+    /// Index of the backend that serves the next request.
+    fn take_turn(&self) -> usize {
+        self.next.fetch_add(1, Ordering::Relaxed) % self.backends.len()
+    }
+
+    /// Run `call` against backend `first`, falling over to the following
+    /// backends while it fails with a retryable error.
+    async fn with_failover(
+        &self,
+        first: usize,
+        call: Call,
+        texts: &[String],
+    ) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let mut attempt = 0;
+        loop {
+            let backend = &self.backends[(first + attempt) % self.backends.len()];
+            match call.run(backend.as_ref(), texts).await {
+                Err(err) if err.is_retryable() && attempt + 1 < self.backends.len() => {
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn embed_one(&self, call: Call, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        let mut vectors = self
+            .with_failover(self.take_turn(), call, &[text.to_string()])
+            .await?;
+        vectors
+            .pop()
+            .ok_or_else(|| EmbeddingError::InvalidResponse("missing embedding".to_string()))
+    }
+
+    /// Split `texts` into one slice per backend and embed the slices
+    /// concurrently, through `embed_documents` when `documents` is set.
+    async fn embed_spread(
+        &self,
+        texts: &[String],
+        documents: bool,
+    ) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let call = if documents {
+            Call::Documents
+        } else {
+            Call::Batch
+        };
+
+        let first = self.take_turn();
+        let slices = self.backends.len().min(texts.len());
+        let slice_len = texts.len().div_ceil(slices);
+        let requests = texts.chunks(slice_len).enumerate().map(|(offset, slice)| {
+            let backend = (first + offset) % self.backends.len();
+            async move {
+                let vectors = self.with_failover(backend, call, slice).await?;
+                if vectors.len() != slice.len() {
+                    return Err(EmbeddingError::InvalidResponse(format!(
+                        "backend returned {} embeddings for {} inputs",
+                        vectors.len(),
+                        slice.len()
+                    )));
+                }
+                Ok(vectors)
+            }
+        });
+
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for vectors in futures::future::try_join_all(requests).await? {
+            embeddings.extend(vectors);
+        }
+        Ok(embeddings)
+    }
+}
+
+/// The `Embedding` method a pooled request goes through.
+#[derive(Clone, Copy)]
+enum Call {
+    Embed,
+    Query,
+    Batch,
+    Documents,
+}
+
+impl Call {
+    async fn run(
+        self,
+        backend: &dyn Embedding,
+        texts: &[String],
+    ) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        match self {
+            Call::Embed => Ok(vec![backend.embed(&texts[0]).await?]),
+            Call::Query => Ok(vec![backend.embed_query(&texts[0]).await?]),
+            Call::Batch => backend.embed_batch(texts).await,
+            Call::Documents => backend.embed_documents(texts).await,
+        }
+    }
+}
+
+#[async_trait]
+impl Embedding for EmbeddingPool {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.embed_one(Call::Embed, text).await
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        self.embed_spread(texts, false).await
+    }
+
+    async fn embed_query(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.embed_one(Call::Query, text).await
+    }
+
+    async fn embed_documents(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        self.embed_spread(texts, true).await
+    }
+
+    /// The first dimension a backend reports, or 0 while none knows it yet.
+    fn dimension(&self) -> usize {
+        self.backends
+            .iter()
+            .map(|backend| backend.dimension())
+            .find(|dimension| *dimension != 0)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "cohere")]
+mod cohere_tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use wesichain_core::{Document, Reranker, WesichainError};
+    use wesichain_embeddings::CohereReranker;
+
+    fn doc(id: &str, content: &str) -> Document {
+        Document {
+            id: id.to_string(),
+            content: content.to_string(),
+            metadata: HashMap::new(),
+            embedding: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn cohere_reranker_orders_documents_by_relevance() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/rerank"))
+            .and(header("authorization", "Bearer test-key"))
+            .and(body_partial_json(json!({
+                "model": "rerank-english-v3.0",
+                "query": "capital of france",
+                "documents": ["berlin", "paris", "madrid"],
+                "top_n": 2
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "abc",
+                "results": [
+                    {"index": 1, "relevance_score": 0.98},
+                    {"index": 2, "relevance_score": 0.12}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let reranker = CohereReranker::new("test-key")
+            .with_base_url(server.uri())
+            .with_top_n(2);
+        let ranked = reranker
+            .rerank(
+                "capital of france",
+                vec![doc("de", "berlin"), doc("fr", "paris"), doc("es", "madrid")],
+            )
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = ranked.iter().map(|(doc, _)| doc.id.as_str()).collect();
+        assert_eq!(ids, vec!["fr", "es"]);
+        assert!((ranked[0].1 - 0.98).abs() < f32::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn cohere_reranker_maps_rate_limits() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/rerank"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(json!({
+                "message": "slow down"
+            })))
+            .mount(&server)
+            .await;
+
+        let reranker = CohereReranker::new("test-key").with_base_url(server.uri());
+        let err = reranker
+            .rerank("query", vec![doc("a", "alpha")])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WesichainError::RateLimitExceeded { .. }));
+    }
+
+    #[tokio::test]
+    async fn cohere_reranker_rejects_out_of_range_index() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/rerank"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{"index": 5, "relevance_score": 0.5}]
+            })))
+            .mount(&server)
+            .await;
+
+        let reranker = CohereReranker::new("test-key").with_base_url(server.uri());
+        let err = reranker
+            .rerank("query", vec![doc("a", "alpha")])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("unexpected document index 5"));
+    }
+}
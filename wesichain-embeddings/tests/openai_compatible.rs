@@ -0,0 +1,223 @@
+#[cfg(feature = "openai-compatible")]
+mod openai_compatible_tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use wesichain_core::{Embedding, EmbeddingError};
+    use wesichain_embeddings::OpenAiCompatibleEmbedding;
+
+    const RECORDED_RESPONSE: &str = include_str!("fixtures/openai_compatible_embeddings.json");
+
+    #[tokio::test]
+    async fn openai_compatible_parses_recorded_response_in_request_order() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .and(header("authorization", "Bearer test-key"))
+            .and(body_partial_json(json!({
+                "model": "BAAI/bge-small-en-v1.5",
+                "input": ["first", "second"]
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(RECORDED_RESPONSE, "application/json"),
+            )
+            .mount(&server)
+            .await;
+
+        let embedder = OpenAiCompatibleEmbedding::new(server.uri(), "BAAI/bge-small-en-v1.5")
+            .with_api_key("test-key");
+        assert_eq!(embedder.dimension(), 0);
+
+        let inputs = vec!["first".to_string(), "second".to_string()];
+        let out = embedder.embed_batch(&inputs).await.unwrap();
+
+        assert_eq!(out[0], vec![-0.0021, 0.0334, -0.0517, 0.0902]);
+        assert_eq!(out[1], vec![0.0123, -0.0456, 0.0789, 0.1011]);
+        assert_eq!(embedder.dimension(), 4);
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_splits_inputs_by_batch_size() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{"embedding": [0.1, 0.2], "index": 0, "object": "embedding"}]
+            })))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let embedder = OpenAiCompatibleEmbedding::new(format!("{}/v1", server.uri()), "local")
+            .with_batch_size(1);
+        let inputs = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+
+        let out = embedder.embed_batch(&inputs).await.unwrap();
+        assert_eq!(out.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_rejects_mixed_dimensions() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [
+                    {"embedding": [0.1, 0.2, 0.3], "index": 0, "object": "embedding"},
+                    {"embedding": [0.4, 0.5], "index": 1, "object": "embedding"}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let embedder = OpenAiCompatibleEmbedding::new(server.uri(), "local");
+        let inputs = vec!["hello".to_string(), "world".to_string()];
+
+        let err = embedder.embed_batch(&inputs).await.unwrap_err();
+        assert!(matches!(
+            err,
+            EmbeddingError::DimensionMismatch {
+                expected: 3,
+                got: 2
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_does_not_cache_a_rejected_dimension() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [
+                    {"embedding": [0.1, 0.2, 0.3], "index": 0, "object": "embedding"},
+                    {"embedding": [0.4, 0.5], "index": 1, "object": "embedding"}
+                ]
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{"embedding": [0.1, 0.2], "index": 0, "object": "embedding"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let embedder = OpenAiCompatibleEmbedding::new(server.uri(), "local");
+        let inputs = vec!["hello".to_string(), "world".to_string()];
+        embedder.embed_batch(&inputs).await.unwrap_err();
+        assert_eq!(embedder.dimension(), 0);
+
+        let out = embedder.embed("hello").await.unwrap();
+        assert_eq!(out.len(), 2);
+        assert_eq!(embedder.dimension(), 2);
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_rejects_responses_not_matching_the_pinned_dimension() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": [{"embedding": [0.1, 0.2], "index": 0, "object": "embedding"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let embedder = OpenAiCompatibleEmbedding::new(server.uri(), "local").with_dimension(3);
+
+        let err = embedder.embed("hello").await.unwrap_err();
+        assert!(matches!(
+            err,
+            EmbeddingError::DimensionMismatch {
+                expected: 3,
+                got: 2
+            }
+        ));
+        assert_eq!(embedder.dimension(), 3);
+    }
+
+    async fn embed_against(response: ResponseTemplate) -> EmbeddingError {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/embeddings"))
+            .respond_with(response)
+            .mount(&server)
+            .await;
+
+        OpenAiCompatibleEmbedding::new(server.uri(), "local")
+            .embed("hello")
+            .await
+            .unwrap_err()
+    }
+
+    fn api_error(status: u16, message: &str) -> ResponseTemplate {
+        ResponseTemplate::new(status).set_body_json(json!({
+            "error": {"message": message, "type": "error"}
+        }))
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_maps_rate_limit_with_retry_after() {
+        let err =
+            embed_against(api_error(429, "slow down").insert_header("retry-after", "7")).await;
+
+        assert!(matches!(
+            err,
+            EmbeddingError::RateLimited {
+                retry_after: Some(d)
+            } if d == std::time::Duration::from_secs(7)
+        ));
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_maps_auth_failures() {
+        for status in [401, 403] {
+            let err = embed_against(api_error(status, "invalid api key")).await;
+
+            assert!(
+                matches!(&err, EmbeddingError::Auth(message) if message == "invalid api key"),
+                "{err:?}"
+            );
+            assert!(!err.is_retryable());
+        }
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_maps_server_errors_to_transport() {
+        let err = embed_against(ResponseTemplate::new(503).set_body_string("upstream down")).await;
+
+        let expected = "HTTP 503 Service Unavailable: upstream down";
+        assert!(
+            matches!(&err, EmbeddingError::Transport(message) if message == expected),
+            "{err:?}"
+        );
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_maps_bad_requests_to_provider() {
+        let err = embed_against(api_error(400, "model not found")).await;
+
+        assert!(
+            matches!(&err, EmbeddingError::Provider(message) if message == "model not found"),
+            "{err:?}"
+        );
+        assert!(!err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn openai_compatible_maps_connection_failures_to_transport() {
+        let err = OpenAiCompatibleEmbedding::new("http://127.0.0.1:1", "local")
+            .embed("hello")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, EmbeddingError::Transport(_)), "{err:?}");
+        assert!(err.is_retryable());
+    }
+}
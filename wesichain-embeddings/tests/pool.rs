@@ -0,0 +1,218 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use wesichain_core::{Embedding, EmbeddingError};
+use wesichain_embeddings::EmbeddingPool;
+
+/// Embeds each text as `[id, text length]` and records what it was sent.
+struct StubEmbedding {
+    id: f32,
+    dimension: usize,
+    seen: Mutex<Vec<String>>,
+}
+
+impl StubEmbedding {
+    fn new(id: f32) -> Arc<Self> {
+        Arc::new(Self {
+            id,
+            dimension: 2,
+            seen: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn seen(&self) -> Vec<String> {
+        self.seen.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedding for StubEmbedding {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.seen.lock().unwrap().push(text.to_string());
+        Ok(vec![self.id, text.len() as f32])
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for text in texts {
+            vectors.push(self.embed(text).await?);
+        }
+        Ok(vectors)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+}
+
+/// Reports dimension 0 until its first embed, like a provider without a
+/// pinned dimension.
+#[derive(Default)]
+struct UnpinnedEmbedding {
+    dimension: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Embedding for UnpinnedEmbedding {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.dimension.store(2, Ordering::Relaxed);
+        Ok(vec![0.0, text.len() as f32])
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for text in texts {
+            vectors.push(self.embed(text).await?);
+        }
+        Ok(vectors)
+    }
+
+    fn dimension(&self) -> usize {
+        self.dimension.load(Ordering::Relaxed)
+    }
+}
+
+/// Always fails with the given error.
+struct FailingEmbedding {
+    error: fn() -> EmbeddingError,
+}
+
+#[async_trait::async_trait]
+impl Embedding for FailingEmbedding {
+    async fn embed(&self, _text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        Err((self.error)())
+    }
+
+    async fn embed_batch(&self, _texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        Err((self.error)())
+    }
+
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+fn texts(items: &[&str]) -> Vec<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
+
+#[tokio::test]
+async fn pool_splits_batches_across_backends_and_keeps_order() {
+    let first = StubEmbedding::new(1.0);
+    let second = StubEmbedding::new(2.0);
+    let pool = EmbeddingPool::new(vec![first.clone(), second.clone()]).unwrap();
+
+    let vectors = pool
+        .embed_batch(&texts(&["a", "bb", "ccc", "dddd", "eeeee"]))
+        .await
+        .unwrap();
+
+    assert_eq!(first.seen(), ["a", "bb", "ccc"]);
+    assert_eq!(second.seen(), ["dddd", "eeeee"]);
+    let lengths: Vec<f32> = vectors.iter().map(|vector| vector[1]).collect();
+    assert_eq!(lengths, [1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(pool.dimension(), 2);
+}
+
+#[tokio::test]
+async fn pool_round_robins_single_embeds() {
+    let first = StubEmbedding::new(1.0);
+    let second = StubEmbedding::new(2.0);
+    let pool = EmbeddingPool::new(vec![first.clone(), second.clone()]).unwrap();
+
+    let ids = [
+        pool.embed("x").await.unwrap()[0],
+        pool.embed("y").await.unwrap()[0],
+        pool.embed("z").await.unwrap()[0],
+    ];
+
+    assert_eq!(ids, [1.0, 2.0, 1.0]);
+}
+
+#[test]
+fn pool_rejects_backends_with_different_dimensions() {
+    let wide = Arc::new(StubEmbedding {
+        id: 2.0,
+        dimension: 3,
+        seen: Mutex::new(Vec::new()),
+    });
+
+    let err = EmbeddingPool::new(vec![StubEmbedding::new(1.0), wide])
+        .err()
+        .expect("mismatched dimensions should be rejected");
+
+    assert!(err.to_string().contains("dimension 3, expected 2"), "{err}");
+    assert!(EmbeddingPool::new(Vec::new()).is_err());
+}
+
+#[test]
+fn pool_accepts_backends_that_have_not_learned_their_dimension() {
+    let pool = EmbeddingPool::new(vec![
+        Arc::new(UnpinnedEmbedding::default()),
+        StubEmbedding::new(1.0),
+    ])
+    .expect("an unknown dimension should not count as a mismatch");
+
+    assert_eq!(pool.dimension(), 2);
+}
+
+#[tokio::test]
+async fn unpinned_pool_reports_dimension_once_a_backend_learns_it() {
+    let pool = EmbeddingPool::new(vec![
+        Arc::new(UnpinnedEmbedding::default()),
+        Arc::new(UnpinnedEmbedding::default()),
+    ])
+    .unwrap();
+    assert_eq!(pool.dimension(), 0);
+
+    pool.embed("x").await.unwrap();
+
+    assert_eq!(pool.dimension(), 2);
+}
+
+#[tokio::test]
+async fn pool_skips_backends_with_retryable_errors() {
+    let dead = Arc::new(FailingEmbedding {
+        error: || EmbeddingError::Transport("connection refused".to_string()),
+    });
+    let live = StubEmbedding::new(2.0);
+    let pool = EmbeddingPool::new(vec![dead, live.clone()]).unwrap();
+
+    assert_eq!(pool.embed("x").await.unwrap(), vec![2.0, 1.0]);
+    let vectors = pool.embed_batch(&texts(&["a", "bb", "ccc"])).await.unwrap();
+
+    let lengths: Vec<f32> = vectors.iter().map(|vector| vector[1]).collect();
+    assert_eq!(lengths, [1.0, 2.0, 3.0]);
+    assert_eq!(live.seen(), ["x", "a", "bb", "ccc"]);
+}
+
+#[tokio::test]
+async fn pool_returns_fatal_errors_without_failover() {
+    let rejected = Arc::new(FailingEmbedding {
+        error: || EmbeddingError::Auth("invalid api key".to_string()),
+    });
+    let live = StubEmbedding::new(2.0);
+    let pool = EmbeddingPool::new(vec![rejected, live.clone()]).unwrap();
+
+    let err = pool.embed("x").await.unwrap_err();
+
+    assert!(matches!(err, EmbeddingError::Auth(_)), "{err:?}");
+    assert!(live.seen().is_empty());
+}
+
+#[tokio::test]
+async fn pool_reports_last_error_when_every_backend_fails() {
+    let pool = EmbeddingPool::new(vec![
+        Arc::new(FailingEmbedding {
+            error: || EmbeddingError::RateLimited { retry_after: None },
+        }),
+        Arc::new(FailingEmbedding {
+            error: || EmbeddingError::Transport("unreachable".to_string()),
+        }),
+    ])
+    .unwrap();
+
+    let err = pool.embed("x").await.unwrap_err();
+
+    assert!(matches!(err, EmbeddingError::Transport(_)), "{err:?}");
+}
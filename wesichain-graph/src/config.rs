@@ -1,3 +1,10 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use wesichain_core::{AgentEvent, CancellationToken, RunConfig, UsageAccumulator, Value};
+
+use crate::Observer;
+
 #[derive(Clone, Debug)]
 pub struct ExecutionConfig {
     pub max_steps: Option<usize>,
@@ -7,8 +14,22 @@ pub struct ExecutionConfig {
     pub max_loop_iterations: Option<u32>,
     pub cycle_detection: bool,
     pub cycle_window: usize,
+    /// Nodes allowed to repeat within `cycle_window`, such as the agent and
+    /// tool nodes of a ReAct loop. They are left out of cycle detection so
+    /// the rest of the graph stays protected; their repeats are still capped
+    /// by `max_loop_iterations`, `max_visits` and `max_steps`.
+    pub cycle_exempt: HashSet<String>,
     pub interrupt_before: Vec<String>,
     pub interrupt_after: Vec<String>,
+    /// Seed for weighted conditional routing; `None` seeds from OS entropy.
+    pub routing_seed: Option<u64>,
+    /// Maximum number of nodes running at once; `None` runs every ready node.
+    /// Ready nodes beyond the cap wait until a running node finishes.
+    pub max_concurrent_nodes: Option<usize>,
+    /// Save a checkpoint after a node only every this many steps. Runs still
+    /// checkpoint on interrupt and completion so they stay resumable.
+    /// `Some(1)` (the default) or `None` saves after every node.
+    pub checkpoint_every: Option<usize>,
 }
 
 impl Default for ExecutionConfig {
@@ -21,8 +42,12 @@ impl Default for ExecutionConfig {
             max_loop_iterations: Some(15),
             cycle_detection: true,
             cycle_window: 20,
+            cycle_exempt: HashSet::new(),
             interrupt_before: Vec::new(),
             interrupt_after: Vec::new(),
+            routing_seed: None,
+            max_concurrent_nodes: None,
+            checkpoint_every: Some(1),
         }
     }
 }
@@ -37,6 +62,11 @@ impl ExecutionConfig {
             max_loop_iterations: overrides.max_loop_iterations.or(self.max_loop_iterations),
             cycle_detection: overrides.cycle_detection.unwrap_or(self.cycle_detection),
             cycle_window: overrides.cycle_window.unwrap_or(self.cycle_window),
+            cycle_exempt: if !overrides.cycle_exempt.is_empty() {
+                overrides.cycle_exempt.clone()
+            } else {
+                self.cycle_exempt.clone()
+            },
             interrupt_before: if !overrides.interrupt_before.is_empty() {
                 overrides.interrupt_before.clone()
             } else {
@@ -47,16 +77,13 @@ impl ExecutionConfig {
             } else {
                 self.interrupt_after.clone()
             },
+            routing_seed: overrides.routing_seed.or(self.routing_seed),
+            max_concurrent_nodes: overrides.max_concurrent_nodes.or(self.max_concurrent_nodes),
+            checkpoint_every: overrides.checkpoint_every.or(self.checkpoint_every),
         }
     }
 }
 
-use std::sync::Arc;
-use tokio::sync::mpsc;
-use wesichain_core::{AgentEvent, RunConfig};
-
-use crate::Observer;
-
 #[derive(Clone, Default)]
 pub struct ExecutionOptions {
     pub max_steps: Option<usize>,
@@ -66,6 +93,8 @@ pub struct ExecutionOptions {
     pub max_loop_iterations: Option<u32>,
     pub cycle_detection: Option<bool>,
     pub cycle_window: Option<usize>,
+    /// Replaces [`ExecutionConfig::cycle_exempt`] when non-empty.
+    pub cycle_exempt: HashSet<String>,
     pub interrupt_before: Vec<String>,
     pub interrupt_after: Vec<String>,
     pub initial_queue: Option<Vec<(String, u64)>>,
@@ -76,6 +105,17 @@ pub struct ExecutionOptions {
     pub observer: Option<Arc<dyn Observer>>,
     pub agent_event_sender: Option<mpsc::Sender<AgentEvent>>,
     pub agent_event_thread_id: Option<String>,
+    pub routing_seed: Option<u64>,
+    pub max_concurrent_nodes: Option<usize>,
+    pub checkpoint_every: Option<usize>,
+    /// Read-only values exposed to every node via [`GraphContext::get`](crate::GraphContext::get).
+    pub context: HashMap<String, Value>,
+    /// Collects token usage reported by nodes via
+    /// [`GraphContext::report_usage`](crate::GraphContext::report_usage).
+    pub usage: Option<Arc<UsageAccumulator>>,
+    /// Stops the run with [`GraphError::Cancelled`](crate::GraphError::Cancelled)
+    /// once cancelled; progress is checkpointed so the run can be resumed.
+    pub cancellation: Option<CancellationToken>,
 }
 
 impl std::fmt::Debug for ExecutionOptions {
@@ -88,6 +128,7 @@ impl std::fmt::Debug for ExecutionOptions {
             .field("max_loop_iterations", &self.max_loop_iterations)
             .field("cycle_detection", &self.cycle_detection)
             .field("cycle_window", &self.cycle_window)
+            .field("cycle_exempt", &self.cycle_exempt)
             .field("interrupt_before", &self.interrupt_before)
             .field("interrupt_after", &self.interrupt_after)
             // Skip queue in debug output to avoid clutter, or summarize
@@ -101,6 +142,12 @@ impl std::fmt::Debug for ExecutionOptions {
             .field("observer", &self.observer.is_some())
             .field("agent_event_sender", &self.agent_event_sender.is_some())
             .field("agent_event_thread_id", &self.agent_event_thread_id)
+            .field("routing_seed", &self.routing_seed)
+            .field("max_concurrent_nodes", &self.max_concurrent_nodes)
+            .field("checkpoint_every", &self.checkpoint_every)
+            .field("context_keys", &self.context.keys().collect::<Vec<_>>())
+            .field("usage", &self.usage.is_some())
+            .field("cancellation", &self.cancellation.is_some())
             .finish()
     }
 }
@@ -0,0 +1,153 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+use crate::{END, START};
+
+#[derive(Clone, Copy)]
+enum Shape {
+    Terminal,
+    Node,
+    Condition,
+}
+
+#[derive(Clone, Copy)]
+enum Style {
+    Default,
+    Conditional,
+}
+
+struct DiagramNode {
+    id: String,
+    label: String,
+    shape: Shape,
+}
+
+/// Static view of a graph's topology, rendered by
+/// [`ExecutableGraph::to_mermaid`](crate::ExecutableGraph::to_mermaid) and
+/// [`ExecutableGraph::to_dot`](crate::ExecutableGraph::to_dot).
+///
+/// Node ids are positional (`n0`, `n1`, ...) so arbitrary node names never
+/// need escaping outside of labels. Routing closures can't be enumerated, so
+/// each node with one gets a dashed edge to its own `?` marker instead.
+pub(crate) struct Diagram {
+    nodes: Vec<DiagramNode>,
+    edges: Vec<(String, String, Style)>,
+}
+
+impl Diagram {
+    pub(crate) fn new<'a>(
+        entry: &str,
+        nodes: impl IntoIterator<Item = &'a String>,
+        edges: &HashMap<String, Vec<String>>,
+        routed: impl IntoIterator<Item = &'a String>,
+    ) -> Self {
+        let names: BTreeSet<&String> = nodes.into_iter().collect();
+        let mut ids: HashMap<&str, String> = HashMap::new();
+        ids.insert(START, START.to_string());
+        ids.insert(END, END.to_string());
+
+        let mut diagram_nodes = vec![DiagramNode {
+            id: START.to_string(),
+            label: "START".to_string(),
+            shape: Shape::Terminal,
+        }];
+        for (index, name) in names.iter().enumerate() {
+            let id = format!("n{index}");
+            ids.insert(name.as_str(), id.clone());
+            diagram_nodes.push(DiagramNode {
+                id,
+                label: (*name).clone(),
+                shape: Shape::Node,
+            });
+        }
+
+        let mut seen = BTreeSet::new();
+        let mut diagram_edges = Vec::new();
+        let mut push_edge = |from: &str, to: &str| {
+            if let (Some(from), Some(to)) = (ids.get(from), ids.get(to)) {
+                if seen.insert((from.clone(), to.clone())) {
+                    diagram_edges.push((from.clone(), to.clone(), Style::Default));
+                }
+            }
+        };
+        push_edge(START, entry);
+        for to in edges.get(START).into_iter().flatten() {
+            push_edge(START, to);
+        }
+        for name in &names {
+            for to in edges.get(*name).into_iter().flatten() {
+                push_edge(name, to);
+            }
+        }
+
+        let routed: BTreeSet<&String> = routed.into_iter().collect();
+        for (index, name) in routed.into_iter().enumerate() {
+            let Some(from) = ids.get(name.as_str()) else {
+                continue;
+            };
+            let id = format!("c{index}");
+            diagram_edges.push((from.clone(), id.clone(), Style::Conditional));
+            diagram_nodes.push(DiagramNode {
+                id,
+                label: "?".to_string(),
+                shape: Shape::Condition,
+            });
+        }
+
+        diagram_nodes.push(DiagramNode {
+            id: END.to_string(),
+            label: "END".to_string(),
+            shape: Shape::Terminal,
+        });
+
+        Self {
+            nodes: diagram_nodes,
+            edges: diagram_edges,
+        }
+    }
+
+    pub(crate) fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart TD\n");
+        for node in &self.nodes {
+            let label = node.label.replace('"', "#quot;");
+            let _ = match node.shape {
+                Shape::Terminal => writeln!(out, "    {}([\"{label}\"])", node.id),
+                Shape::Node => writeln!(out, "    {}[\"{label}\"]", node.id),
+                Shape::Condition => writeln!(out, "    {}{{\"{label}\"}}", node.id),
+            };
+        }
+        for (from, to, style) in &self.edges {
+            let arrow = match style {
+                Style::Default => "-->",
+                Style::Conditional => "-.->",
+            };
+            let _ = writeln!(out, "    {from} {arrow} {to}");
+        }
+        out
+    }
+
+    pub(crate) fn to_dot(&self) -> String {
+        let mut out = String::from("digraph {\n");
+        for node in &self.nodes {
+            let label = node.label.replace('\\', "\\\\").replace('"', "\\\"");
+            let shape = match node.shape {
+                Shape::Terminal => "oval",
+                Shape::Node => "box",
+                Shape::Condition => "diamond",
+            };
+            let _ = writeln!(out, "    {} [label=\"{label}\", shape={shape}];", node.id);
+        }
+        for (from, to, style) in &self.edges {
+            match style {
+                Style::Default => {
+                    let _ = writeln!(out, "    {from} -> {to};");
+                }
+                Style::Conditional => {
+                    let _ = writeln!(out, "    {from} -> {to} [style=dashed];");
+                }
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
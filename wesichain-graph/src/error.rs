@@ -1,4 +1,5 @@
 use thiserror::Error;
+use wesichain_core::WesichainError;
 
 #[derive(Debug, Error)]
 pub enum GraphError {
@@ -20,6 +21,8 @@ pub enum GraphError {
     CycleDetected { node: String, recent: Vec<String> },
     #[error("interrupted")]
     Interrupted,
+    #[error("cancelled")]
+    Cancelled,
     #[error("tool call failed for '{0}': {1}")]
     ToolCallFailed(String, String),
     #[error("invalid tool call response: {0}")]
@@ -39,8 +42,49 @@ pub enum GraphError {
         max: u32,
         path_id: u64,
     },
+    #[error("incompatible checkpoint: queued node '{missing_node}' does not exist")]
+    IncompatibleCheckpoint { missing_node: String },
     #[error("system error: {0}")]
     System(String),
     #[error(transparent)]
-    Wesichain(#[from] wesichain_core::WesichainError),
+    Wesichain(#[from] WesichainError),
+}
+
+/// A structural defect reported by [`GraphBuilder::validate`](crate::GraphBuilder::validate).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GraphValidationError {
+    #[error("entry node not set")]
+    MissingEntry,
+    #[error("entry '{node}' is not a node")]
+    UnknownEntry { node: String },
+    #[error("edge from unknown node '{from}'")]
+    UnknownEdgeSource { from: String },
+    #[error("edge from '{from}' to unknown node '{to}'")]
+    UnknownEdgeTarget { from: String, to: String },
+    #[error("conditional edge from unknown node '{from}'")]
+    UnknownConditionalSource { from: String },
+    #[error("node '{node}' has both a conditional and a weighted conditional edge")]
+    ConflictingConditionalEdges { node: String },
+    #[error("node '{node}' is unreachable from the entry")]
+    UnreachableNode { node: String },
+}
+
+impl GraphError {
+    /// Recovers the graph error carried by a [`WesichainError::Graph`], e.g. one
+    /// returned from [`ExecutableGraph::invoke`](crate::ExecutableGraph::invoke).
+    pub fn from_wesichain(error: &WesichainError) -> Option<&GraphError> {
+        match error {
+            WesichainError::Graph(inner) => inner.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl From<GraphError> for WesichainError {
+    fn from(error: GraphError) -> Self {
+        match error {
+            GraphError::Wesichain(inner) => inner,
+            other => WesichainError::Graph(Box::new(other)),
+        }
+    }
 }
@@ -1,37 +1,160 @@
-use ahash::RandomState;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::hash::Hash;
 use std::sync::Arc;
 
 use chrono::Utc;
 use futures::stream::{self, BoxStream, StreamExt};
 use petgraph::graph::Graph;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use tokio::sync::mpsc;
 use tokio::task::JoinSet;
+use uuid::Uuid;
 
+use crate::diagram::Diagram;
 use crate::observer::ObserverCallbackAdapter;
+use crate::streaming_node::Streaming;
 use crate::{
     Checkpoint, Checkpointer, EdgeKind, ExecutionConfig, ExecutionOptions, GraphError, GraphEvent,
-    GraphProgram, GraphState, NodeData, Observer, StateSchema, StateUpdate, END, START,
+    GraphProgram, GraphState, GraphValidationError, NodeData, Observer, StateSchema, StateUpdate,
+    StreamingNode, END, START,
 };
 use serde_json::json;
 use wesichain_core::{
-    ensure_object, AgentEvent, CallbackManager, RunContext, RunType, Runnable, ToTraceInput,
-    ToTraceOutput, WesichainError,
+    current_run_context, ensure_object, with_run_context, AgentEvent, CallbackManager,
+    CancellationToken, RunContext, RunType, Runnable, StreamEvent, ToTraceInput, ToTraceOutput,
+    TokenUsage, UsageAccumulator, Value, WesichainError,
 };
 
 pub type Condition<S> = Box<dyn Fn(&GraphState<S>) -> Vec<String> + Send + Sync>;
+pub type WeightedCondition<S> = Box<dyn Fn(&GraphState<S>) -> Vec<(String, f64)> + Send + Sync>;
+
+/// Read-only values supplied through [`ExecutionOptions::context`], shared by
+/// every node in a single run, plus the run's [`ExecutionOptions::usage`]
+/// accumulator if one was given.
+#[derive(Clone, Debug, Default)]
+pub struct GraphRunContext {
+    values: HashMap<String, Value>,
+    usage: Option<Arc<UsageAccumulator>>,
+    node_sender: Option<mpsc::UnboundedSender<(String, NodeMessage)>>,
+}
+
+/// What a running node sends back to the engine alongside its result.
+#[derive(Debug)]
+pub(crate) enum NodeMessage {
+    Stream(StreamEvent),
+    Status(String),
+}
+
+impl GraphRunContext {
+    pub fn new(values: HashMap<String, Value>) -> Self {
+        Self {
+            values,
+            usage: None,
+            node_sender: None,
+        }
+    }
+
+    pub fn with_usage(mut self, usage: Arc<UsageAccumulator>) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    pub fn usage(&self) -> Option<&Arc<UsageAccumulator>> {
+        self.usage.as_ref()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    pub fn values(&self) -> &HashMap<String, Value> {
+        &self.values
+    }
+
+    pub(crate) fn with_node_sender(
+        mut self,
+        sender: mpsc::UnboundedSender<(String, NodeMessage)>,
+    ) -> Self {
+        self.node_sender = Some(sender);
+        self
+    }
+
+    /// Forward a streaming node's event to the run's graph stream, if any.
+    pub(crate) fn emit_stream_event(&self, node: &str, event: StreamEvent) {
+        if let Some(sender) = &self.node_sender {
+            let _ = sender.send((node.to_string(), NodeMessage::Stream(event)));
+        }
+    }
+}
+
+/// Handle for reporting a node's progress while it runs, e.g.
+/// `"fetched 3/10 pages"`.
+///
+/// The engine forwards each message as [`GraphEvent::NodeStatus`](crate::GraphEvent::NodeStatus)
+/// and, when an agent event sender is configured, as an [`AgentEvent::Status`]
+/// whose `stage` is the node's id. Sending is a no-op outside a graph run.
+/// Get one with [`GraphContext::status_sink`], or call [`GraphContext::status`].
+#[derive(Clone, Debug, Default)]
+pub struct StatusSink {
+    node: String,
+    sender: Option<mpsc::UnboundedSender<(String, NodeMessage)>>,
+}
+
+impl StatusSink {
+    pub fn send(&self, message: impl Into<String>) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send((self.node.clone(), NodeMessage::Status(message.into())));
+        }
+    }
+}
 
 pub struct GraphContext {
     pub remaining_steps: Option<usize>,
     pub observer: Option<Arc<dyn Observer>>,
     pub node_id: String,
+    /// Run-scoped configuration; the same instance is handed to every node.
+    pub run_context: Arc<GraphRunContext>,
+}
+
+impl GraphContext {
+    /// Callback manager and this node's run context, when the graph runs with
+    /// callbacks. Only set while the graph is invoking the node.
+    pub fn callbacks(&self) -> Option<(CallbackManager, RunContext)> {
+        current_run_context()
+    }
+
+    /// Shorthand for `self.run_context.get(key)`.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.run_context.get(key)
+    }
+
+    /// Add an LLM call's token usage to the run's accumulator. A no-op when
+    /// the call reported no usage or the run has no accumulator.
+    pub fn report_usage(&self, usage: Option<&TokenUsage>) {
+        if let (Some(accumulator), Some(usage)) = (self.run_context.usage(), usage) {
+            accumulator.add(usage);
+        }
+    }
+
+    /// Report an intermediate status message for this node. See [`StatusSink`].
+    pub fn status(&self, message: impl Into<String>) {
+        self.status_sink().send(message);
+    }
+
+    /// A cloneable [`StatusSink`] for this node, e.g. to hand to spawned work.
+    pub fn status_sink(&self) -> StatusSink {
+        StatusSink {
+            node: self.node_id.clone(),
+            sender: self.run_context.node_sender.clone(),
+        }
+    }
 }
 
 async fn emit_status_event(
     sender: &Option<mpsc::Sender<AgentEvent>>,
     step: &mut usize,
     thread_id: &str,
+    trace_id: Option<Uuid>,
     stage: impl Into<String>,
     message: impl Into<String>,
 ) {
@@ -43,6 +166,7 @@ async fn emit_status_event(
                 message: message.into(),
                 step: *step,
                 thread_id: thread_id.to_string(),
+                trace_id,
             })
             .await;
     }
@@ -51,6 +175,7 @@ async fn emit_status_event(
 async fn emit_error_event(
     sender: &Option<mpsc::Sender<AgentEvent>>,
     step: &mut usize,
+    trace_id: Option<Uuid>,
     message: impl Into<String>,
     source: Option<String>,
 ) {
@@ -62,6 +187,7 @@ async fn emit_error_event(
                 step: *step,
                 recoverable: false,
                 source,
+                trace_id,
             })
             .await;
     }
@@ -95,6 +221,7 @@ pub struct GraphBuilder<S: StateSchema> {
     nodes: HashMap<String, Arc<dyn GraphNode<S>>>,
     edges: HashMap<String, Vec<String>>,
     conditional: HashMap<String, Condition<S>>,
+    weighted_conditional: HashMap<String, WeightedCondition<S>>,
     checkpointer: Option<(Box<dyn Checkpointer<S>>, String)>,
     observer: Option<Arc<dyn Observer>>,
     default_config: ExecutionConfig,
@@ -115,6 +242,7 @@ impl<S: StateSchema> GraphBuilder<S> {
             nodes: HashMap::new(),
             edges: HashMap::new(),
             conditional: HashMap::new(),
+            weighted_conditional: HashMap::new(),
             checkpointer: None,
             observer: None,
             default_config: ExecutionConfig::default(),
@@ -132,6 +260,17 @@ impl<S: StateSchema> GraphBuilder<S> {
         self
     }
 
+    /// Add a node that runs through [`Runnable::stream`], forwarding each
+    /// event as [`GraphEvent::NodeStream`]. See [`StreamingNode`].
+    pub fn add_streaming_node<R>(mut self, name: &str, node: R) -> Self
+    where
+        R: StreamingNode<S> + Send + Sync + 'static,
+    {
+        self.nodes
+            .insert(name.to_string(), Arc::new(Streaming::new(node)));
+        self
+    }
+
     pub fn set_entry(mut self, name: &str) -> Self {
         self.entry = Some(name.to_string());
         self
@@ -161,6 +300,22 @@ impl<S: StateSchema> GraphBuilder<S> {
             .insert(from.to_string(), Box::new(condition));
         self
     }
+
+    /// Route to a single target chosen at random in proportion to its weight.
+    ///
+    /// Weights are normalized by their sum. If any weight is negative or
+    /// non-finite, or all weights are zero, execution routes to `END`.
+    /// Set `ExecutionConfig::routing_seed` for reproducible selection.
+    /// A node can't also have an [`add_conditional_edge`](Self::add_conditional_edge);
+    /// [`validate`](Self::validate) reports the conflict.
+    pub fn add_weighted_conditional_edge<F>(mut self, from: &str, condition: F) -> Self
+    where
+        F: Fn(&GraphState<S>) -> Vec<(String, f64)> + Send + Sync + 'static,
+    {
+        self.weighted_conditional
+            .insert(from.to_string(), Box::new(condition));
+        self
+    }
     #[deprecated(since = "0.3.0", note = "Use `with_default_config` instead")]
     pub fn with_config(mut self, config: ExecutionConfig) -> Self {
         self.default_config = config;
@@ -203,11 +358,107 @@ impl<S: StateSchema> GraphBuilder<S> {
         self
     }
 
+    /// Check the graph's structure without running it, returning every defect
+    /// found.
+    ///
+    /// Conditional edges choose their targets at run time, so unreachable
+    /// nodes are only reported when no conditional edge is reachable from the
+    /// entry. Targets of [`StateUpdate::with_goto`] are not visible here; nodes
+    /// only reached that way are reported as unreachable.
+    pub fn validate(&self) -> Result<(), Vec<GraphValidationError>> {
+        let mut errors = Vec::new();
+        let is_node = |name: &str| self.nodes.contains_key(name);
+
+        match self.entry.as_deref() {
+            None => errors.push(GraphValidationError::MissingEntry),
+            Some(entry) if !is_node(entry) => errors.push(GraphValidationError::UnknownEntry {
+                node: entry.to_string(),
+            }),
+            Some(_) => {}
+        }
+
+        let mut sources = self.edges.keys().collect::<Vec<_>>();
+        sources.sort();
+        for from in sources {
+            if from != START && !is_node(from) {
+                errors.push(GraphValidationError::UnknownEdgeSource { from: from.clone() });
+            }
+            for to in &self.edges[from] {
+                if to != END && !is_node(to) {
+                    errors.push(GraphValidationError::UnknownEdgeTarget {
+                        from: from.clone(),
+                        to: to.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut conditional_sources = self
+            .conditional
+            .keys()
+            .chain(self.weighted_conditional.keys())
+            .collect::<Vec<_>>();
+        conditional_sources.sort();
+        conditional_sources.dedup();
+        for from in conditional_sources {
+            if !is_node(from) {
+                errors.push(GraphValidationError::UnknownConditionalSource { from: from.clone() });
+            }
+            if self.conditional.contains_key(from) && self.weighted_conditional.contains_key(from) {
+                errors
+                    .push(GraphValidationError::ConflictingConditionalEdges { node: from.clone() });
+            }
+        }
+
+        let mut queue = self
+            .entry
+            .iter()
+            .chain(self.edges.get(START).into_iter().flatten())
+            .filter(|name| is_node(name))
+            .collect::<VecDeque<_>>();
+        let mut reached = HashSet::new();
+        let mut dynamic = false;
+        while let Some(node) = queue.pop_front() {
+            if !reached.insert(node) {
+                continue;
+            }
+            dynamic |=
+                self.conditional.contains_key(node) || self.weighted_conditional.contains_key(node);
+            queue.extend(
+                self.edges
+                    .get(node)
+                    .into_iter()
+                    .flatten()
+                    .filter(|name| is_node(name)),
+            );
+        }
+        if !reached.is_empty() && !dynamic {
+            let mut unreachable = self
+                .nodes
+                .keys()
+                .filter(|name| !reached.contains(name))
+                .collect::<Vec<_>>();
+            unreachable.sort();
+            errors.extend(
+                unreachable
+                    .into_iter()
+                    .map(|node| GraphValidationError::UnreachableNode { node: node.clone() }),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     pub fn build(self) -> ExecutableGraph<S> {
         ExecutableGraph {
             nodes: self.nodes,
             edges: self.edges,
             conditional: self.conditional,
+            weighted_conditional: self.weighted_conditional,
             checkpointer: self.checkpointer,
             observer: self.observer,
             default_config: self.default_config,
@@ -250,14 +501,54 @@ impl<S: StateSchema> GraphBuilder<S> {
     }
 }
 
-fn stable_hash<T: Hash + ?Sized>(t: &T) -> u64 {
-    RandomState::with_seeds(0x517cc1b727220a95, 0x6ed9eba1999cd92d, 0, 0).hash_one(t)
+/// Path id for the branch from `parent` to `target` when a node fans out.
+///
+/// 64-bit FNV-1a over the parent id's little-endian bytes followed by the
+/// target name, so a given topology yields the same ids on every run,
+/// platform and toolchain.
+fn derive_path_id(parent: u64, target: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    parent
+        .to_le_bytes()
+        .iter()
+        .chain(target.as_bytes())
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+        })
+}
+
+fn select_weighted_target(targets: Vec<(String, f64)>, rng: &mut StdRng) -> String {
+    let valid = targets
+        .iter()
+        .all(|(_, weight)| weight.is_finite() && *weight >= 0.0);
+    let total: f64 = targets.iter().map(|(_, weight)| weight).sum();
+    if !valid || total <= 0.0 {
+        return END.to_string();
+    }
+
+    let mut pick = rng.gen::<f64>() * total;
+    for (target, weight) in &targets {
+        if pick < *weight {
+            return target.clone();
+        }
+        pick -= weight;
+    }
+    // Floating-point rounding can leave `pick` just past the last bucket.
+    targets
+        .into_iter()
+        .rev()
+        .find(|(_, weight)| *weight > 0.0)
+        .map(|(target, _)| target)
+        .unwrap_or_else(|| END.to_string())
 }
 
 pub struct ExecutableGraph<S: StateSchema> {
     nodes: HashMap<String, Arc<dyn GraphNode<S>>>,
     edges: HashMap<String, Vec<String>>,
     conditional: HashMap<String, Condition<S>>,
+    weighted_conditional: HashMap<String, WeightedCondition<S>>,
     checkpointer: Option<(Box<dyn Checkpointer<S>>, String)>,
     observer: Option<Arc<dyn Observer>>,
     default_config: ExecutionConfig,
@@ -294,7 +585,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
         let observer = options.observer.clone().or_else(|| self.observer.clone());
         let mut run_config = options.run_config.clone().unwrap_or_default();
 
-        if let Some(obs) = observer {
+        if let Some(obs) = observer.clone() {
             let adapter = Arc::new(ObserverCallbackAdapter(obs));
             let handlers = if let Some(mut manager) = run_config.callbacks.take() {
                 // Merge the adapter into the existing CallbackManager
@@ -328,6 +619,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
             // Unified fields
             active_tasks: HashSet<(String, u64)>,
             callbacks: Option<(CallbackManager, RunContext)>,
+            trace_id: Option<Uuid>,
             callback_nodes: HashMap<(String, u64), RunContext>,
             agent_event_sender: Option<mpsc::Sender<AgentEvent>>,
             agent_event_thread_id: String,
@@ -336,6 +628,59 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
             initialized: bool,
             run_config: Option<wesichain_core::RunConfig>, // Store for delayed init
             observer: Option<Arc<dyn Observer>>,
+            rng: StdRng,
+            run_context: Arc<GraphRunContext>,
+            node_messages: mpsc::UnboundedReceiver<(String, NodeMessage)>,
+            cancellation: Option<CancellationToken>,
+        }
+
+        /// Checkpoint a run that stops before `current` executes, putting
+        /// `current` back in the saved queue so a resume runs it.
+        async fn save_checkpoint<S: StateSchema>(
+            checkpointer: Option<&(Box<dyn Checkpointer<S>>, String)>,
+            ctx: &mut StreamState<S>,
+            current: &str,
+            path_id: u64,
+        ) {
+            let (Some((checkpointer, _)), Some(thread_id)) =
+                (checkpointer, ctx.checkpoint_thread_id.as_deref())
+            else {
+                return;
+            };
+            let mut full_queue = ctx.queue.iter().cloned().collect::<Vec<_>>();
+            full_queue.push((current.to_string(), path_id));
+            full_queue.extend(ctx.active_tasks.iter().cloned());
+
+            let checkpoint = Checkpoint::new(
+                thread_id.to_string(),
+                ctx.state.clone(),
+                ctx.step_count as u64,
+                current.to_string(),
+                full_queue,
+            );
+            match checkpointer.save(&checkpoint).await {
+                Ok(()) => {
+                    ctx.pending_events.push_back(GraphEvent::CheckpointSaved {
+                        node: current.to_string(),
+                        timestamp: Utc::now().timestamp_millis() as u64,
+                        trace_id: ctx.trace_id,
+                    });
+                    if let Some((manager, root)) = &ctx.callbacks {
+                        manager
+                            .on_event(root, "checkpoint_saved", &json!({"node_id": current}))
+                            .await;
+                    }
+                }
+                Err(e) => {
+                    let graph_err = GraphError::from(e);
+                    if let Some((manager, root)) = &ctx.callbacks {
+                        let error_value = ensure_object(graph_err.to_string().to_trace_output());
+                        let duration_ms = root.start_instant.elapsed().as_millis();
+                        manager.on_error(root, &error_value, duration_ms).await;
+                    }
+                    ctx.pending_events.push_back(GraphEvent::Error(graph_err));
+                }
+            }
         }
 
         if !self.nodes.contains_key(&self.entry) {
@@ -346,6 +691,10 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
         }
 
         let effective = self.default_config.merge(&options);
+        let rng = effective
+            .routing_seed
+            .map(StdRng::seed_from_u64)
+            .unwrap_or_else(StdRng::from_entropy);
 
         let agent_event_thread_id = options
             .agent_event_thread_id
@@ -360,6 +709,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
             .unwrap_or_else(|| VecDeque::from([(self.entry.clone(), 0)]));
 
         let initial_step = options.initial_step.unwrap_or(0);
+        let (node_sender, node_messages) = mpsc::unbounded_channel();
 
         let stream_state = StreamState {
             state,
@@ -374,6 +724,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
             path_visits: HashMap::new(),
             active_tasks: HashSet::new(),
             callbacks: None, // Will init in loop
+            trace_id: None,
             callback_nodes: HashMap::new(),
             agent_event_sender: options.agent_event_sender,
             agent_event_thread_id,
@@ -381,7 +732,17 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
             checkpoint_thread_id,
             initialized: false,
             run_config: run_config_option,
-            observer: options.observer,
+            observer,
+            rng,
+            run_context: Arc::new(
+                match options.usage {
+                    Some(usage) => GraphRunContext::new(options.context).with_usage(usage),
+                    None => GraphRunContext::new(options.context),
+                }
+                .with_node_sender(node_sender),
+            ),
+            node_messages,
+            cancellation: options.cancellation,
         };
 
         stream::unfold(stream_state, move |mut ctx| async move {
@@ -405,6 +766,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                 );
                                 let inputs = ensure_object(ctx.state.to_trace_input());
                                 manager.on_start(&root, &inputs).await;
+                                ctx.trace_id = Some(root.trace_id);
                                 ctx.callbacks = Some((manager, root));
                             }
                         }
@@ -416,8 +778,46 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                     return Some((Ok(event), ctx));
                 }
 
-                // 3. Process Queue
-                if let Some((current, path_id)) = ctx.queue.pop_front() {
+                // 3. Process Queue, leaving ready nodes queued while the
+                // concurrency cap is reached so step 4 can free a slot
+                let at_capacity = ctx
+                    .effective
+                    .max_concurrent_nodes
+                    .is_some_and(|max| ctx.join_set.len() >= max.max(1));
+                let next = if at_capacity {
+                    None
+                } else {
+                    ctx.queue.pop_front()
+                };
+                if let Some((current, path_id)) = next {
+                    // Cancellation
+                    if ctx
+                        .cancellation
+                        .as_ref()
+                        .is_some_and(|token| token.is_cancelled())
+                    {
+                        let error = GraphError::Cancelled;
+                        if let Some((manager, root)) = &ctx.callbacks {
+                            let error_value = ensure_object(error.to_string().to_trace_output());
+                            // Close the runs of nodes still in flight before the
+                            // join set drops them
+                            for (_, node_ctx) in ctx.callback_nodes.drain() {
+                                let duration_ms = node_ctx.start_instant.elapsed().as_millis();
+                                manager.on_error(&node_ctx, &error_value, duration_ms).await;
+                            }
+                            let duration_ms = root.start_instant.elapsed().as_millis();
+                            manager.on_error(root, &error_value, duration_ms).await;
+                        }
+
+                        // Save progress so the run can be resumed
+                        save_checkpoint(self.checkpointer.as_ref(), &mut ctx, &current, path_id)
+                            .await;
+
+                        ctx.join_set.shutdown().await;
+                        ctx.pending_events.push_back(GraphEvent::Error(error));
+                        continue;
+                    }
+
                     // Safety Checks
                     // Global Timer
                     if let Some(duration) = ctx.effective.max_duration {
@@ -434,6 +834,15 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                 manager.on_error(root, &error_value, duration_ms).await;
                             }
 
+                            // Save progress so the run can be resumed
+                            save_checkpoint(
+                                self.checkpointer.as_ref(),
+                                &mut ctx,
+                                &current,
+                                path_id,
+                            )
+                            .await;
+
                             ctx.join_set.shutdown().await;
                             ctx.pending_events.push_back(GraphEvent::Error(error));
                             continue;
@@ -453,6 +862,16 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                 let duration_ms = root.start_instant.elapsed().as_millis();
                                 manager.on_error(root, &error_value, duration_ms).await;
                             }
+
+                            // Save progress so the run can be resumed
+                            save_checkpoint(
+                                self.checkpointer.as_ref(),
+                                &mut ctx,
+                                &current,
+                                path_id,
+                            )
+                            .await;
+
                             ctx.join_set.shutdown().await;
                             ctx.pending_events.push_back(GraphEvent::Error(error));
                             continue;
@@ -506,7 +925,9 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                     ctx.step_count += 1;
 
                     // Cycle detection
-                    if ctx.effective.cycle_detection {
+                    if ctx.effective.cycle_detection
+                        && !ctx.effective.cycle_exempt.contains(&current)
+                    {
                         if ctx.recent.len() == ctx.effective.cycle_window {
                             ctx.recent.pop_front();
                         }
@@ -541,47 +962,8 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                         }
 
                         // Save checkpoint on interrupt
-                        if let (Some((checkpointer, _)), Some(thread_id)) = (
-                            self.checkpointer.as_ref(),
-                            ctx.checkpoint_thread_id.as_deref(),
-                        ) {
-                            let mut full_queue = ctx.queue.iter().cloned().collect::<Vec<_>>();
-                            full_queue.push((current.clone(), path_id));
-                            full_queue.extend(ctx.active_tasks.iter().cloned());
-
-                            let checkpoint = Checkpoint::new(
-                                thread_id.to_string(),
-                                ctx.state.clone(),
-                                ctx.step_count as u64,
-                                current.clone(),
-                                full_queue,
-                            );
-                            if let Err(e) = checkpointer.save(&checkpoint).await {
-                                let graph_err = GraphError::from(e);
-                                if let Some((manager, root)) = &ctx.callbacks {
-                                    let error_value =
-                                        ensure_object(graph_err.to_string().to_trace_output());
-                                    let duration_ms = root.start_instant.elapsed().as_millis();
-                                    manager.on_error(root, &error_value, duration_ms).await;
-                                }
-                                ctx.pending_events.push_back(GraphEvent::Error(graph_err));
-                            } else {
-                                ctx.pending_events.push_back(GraphEvent::CheckpointSaved {
-                                    node: current.clone(),
-                                    timestamp: Utc::now().timestamp_millis() as u64,
-                                });
-                                if let Some((manager, root)) = &ctx.callbacks {
-                                    // Checkpoint saved event
-                                    manager
-                                        .on_event(
-                                            root,
-                                            "checkpoint_saved",
-                                            &json!({"node_id": current}),
-                                        )
-                                        .await;
-                                }
-                            }
-                        }
+                        save_checkpoint(self.checkpointer.as_ref(), &mut ctx, &current, path_id)
+                            .await;
 
                         ctx.join_set.shutdown().await;
                         ctx.pending_events.push_back(GraphEvent::Error(error));
@@ -606,15 +988,18 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                         &ctx.agent_event_sender,
                         &mut ctx.agent_event_step,
                         &ctx.agent_event_thread_id,
+                        ctx.trace_id,
                         "node_start",
                         format!("Starting node {current}"),
                     )
                     .await;
 
+                    let mut node_callbacks = None;
                     if let Some((manager, root)) = &ctx.callbacks {
                         let node_ctx = root.child(RunType::Chain, current.clone());
                         let node_inputs = ensure_object(ctx.state.to_trace_input());
                         manager.on_start(&node_ctx, &node_inputs).await;
+                        node_callbacks = Some((manager.clone(), node_ctx.clone()));
                         ctx.callback_nodes
                             .insert((current.clone(), path_id), node_ctx);
                     }
@@ -622,6 +1007,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                     ctx.pending_events.push_back(GraphEvent::NodeEnter {
                         node: current.clone(),
                         timestamp: Utc::now().timestamp_millis() as u64,
+                        trace_id: ctx.trace_id,
                     });
 
                     // Prepare Node Execution
@@ -630,6 +1016,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                     let node_ctx_obs = ctx.observer.clone();
                     let node_id = current.clone();
                     let effective_config_spawn = ctx.effective.clone();
+                    let cancellation = ctx.cancellation.clone();
                     let remaining = effective_config_spawn
                         .max_steps
                         .map(|m| m.saturating_sub(ctx.step_count)); // approximate
@@ -638,13 +1025,31 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                         remaining_steps: remaining,
                         observer: node_ctx_obs,
                         node_id: node_id.clone(),
+                        run_context: ctx.run_context.clone(),
                     };
 
                     ctx.active_tasks.insert((current.clone(), path_id));
 
                     // Spawn
                     ctx.join_set.spawn(async move {
-                        let future = node.invoke_with_context(input_state, &context);
+                        let invocation = async {
+                            let invocation = node.invoke_with_context(input_state, &context);
+                            match node_callbacks {
+                                Some((manager, run)) => {
+                                    with_run_context(manager, run, invocation).await
+                                }
+                                None => invocation.await,
+                            }
+                        };
+                        let future = async move {
+                            match cancellation {
+                                Some(token) => tokio::select! {
+                                    result = invocation => result,
+                                    _ = token.cancelled() => Err(WesichainError::Cancelled),
+                                },
+                                None => invocation.await,
+                            }
+                        };
                         let result = if let Some(timeout) = effective_config_spawn.node_timeout {
                             match tokio::time::timeout(timeout, future).await {
                                 Ok(res) => res,
@@ -664,7 +1069,40 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
 
                 // 4. Process Completed Tasks
                 if !ctx.join_set.is_empty() {
-                    if let Some(join_res) = ctx.join_set.join_next().await {
+                    // Forward streaming node events and status messages before the
+                    // completion that follows them, so they precede the node's
+                    // NodeFinished.
+                    let join_next = tokio::select! {
+                        biased;
+                        Some((node, message)) = ctx.node_messages.recv() => {
+                            let event = match message {
+                                NodeMessage::Stream(event) => GraphEvent::NodeStream {
+                                    node,
+                                    event,
+                                    trace_id: ctx.trace_id,
+                                },
+                                NodeMessage::Status(message) => {
+                                    emit_status_event(
+                                        &ctx.agent_event_sender,
+                                        &mut ctx.agent_event_step,
+                                        &ctx.agent_event_thread_id,
+                                        ctx.trace_id,
+                                        node.clone(),
+                                        message.clone(),
+                                    )
+                                    .await;
+                                    GraphEvent::NodeStatus {
+                                        node,
+                                        message,
+                                        trace_id: ctx.trace_id,
+                                    }
+                                }
+                            };
+                            return Some((Ok(event), ctx));
+                        }
+                        join_res = ctx.join_set.join_next() => join_res,
+                    };
+                    if let Some(join_res) = join_next {
                         let (current, invoke_res, path_id) = match join_res {
                             Ok(r) => r,
                             Err(err) => {
@@ -677,17 +1115,42 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
 
                         ctx.active_tasks.remove(&(current.clone(), path_id));
 
+                        // A node interrupted by cancellation has not run; requeue it
+                        // so the cancellation check checkpoints it as pending.
+                        if matches!(invoke_res, Err(WesichainError::Cancelled))
+                            && ctx
+                                .cancellation
+                                .as_ref()
+                                .is_some_and(|token| token.is_cancelled())
+                        {
+                            if let Some(node_ctx) =
+                                ctx.callback_nodes.remove(&(current.clone(), path_id))
+                            {
+                                if let Some((manager, _root)) = &ctx.callbacks {
+                                    let error_value = ensure_object(
+                                        GraphError::Cancelled.to_string().to_trace_output(),
+                                    );
+                                    let duration_ms = node_ctx.start_instant.elapsed().as_millis();
+                                    manager.on_error(&node_ctx, &error_value, duration_ms).await;
+                                }
+                            }
+                            ctx.queue.push_front((current, path_id));
+                            continue;
+                        }
+
                         match invoke_res {
                             Ok(update) => {
                                 // Node Success
                                 let output_debug =
                                     serde_json::to_string(&update).unwrap_or_default();
+                                let goto = update.goto().to_vec();
                                 ctx.state = ctx.state.apply_update(update.clone());
 
                                 ctx.pending_events.push_back(GraphEvent::NodeFinished {
                                     node: current.clone(),
                                     output: output_debug,
                                     timestamp: Utc::now().timestamp_millis() as u64,
+                                    trace_id: ctx.trace_id,
                                 });
 
                                 // CRITICAL: Emit StateUpdate for invoke_graph consumers
@@ -711,6 +1174,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                     &ctx.agent_event_sender,
                                     &mut ctx.agent_event_step,
                                     &ctx.agent_event_thread_id,
+                                    ctx.trace_id,
                                     "node_end",
                                     format!("Completed node {current}"),
                                 )
@@ -719,11 +1183,15 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                 ctx.pending_events.push_back(GraphEvent::NodeExit {
                                     node: current.clone(),
                                     timestamp: Utc::now().timestamp_millis() as u64,
+                                    trace_id: ctx.trace_id,
                                 });
 
                                 // 4c. Route Next (moved before Checkpoint)
                                 if let Some(condition) = self.conditional.get(&current) {
                                     let targets = condition(&ctx.state);
+                                    if let Some(observer) = &ctx.observer {
+                                        observer.on_route(&current, &targets).await;
+                                    }
                                     let next_paths: Vec<(String, u64)> = if targets.len() > 1 {
                                         targets
                                             .into_iter()
@@ -731,7 +1199,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                                 if t == END {
                                                     (t, path_id)
                                                 } else {
-                                                    let h = stable_hash(&(path_id, &t));
+                                                    let h = derive_path_id(path_id, &t);
                                                     (t, h)
                                                 }
                                             })
@@ -754,6 +1222,25 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                         }
                                         ctx.queue.push_back((next, next_path_id));
                                     }
+                                } else if let Some(condition) =
+                                    self.weighted_conditional.get(&current)
+                                {
+                                    let next =
+                                        select_weighted_target(condition(&ctx.state), &mut ctx.rng);
+                                    if let Some(observer) = &ctx.observer {
+                                        observer
+                                            .on_route(&current, std::slice::from_ref(&next))
+                                            .await;
+                                    }
+                                    if next != END {
+                                        if self.nodes.contains_key(&next) {
+                                            ctx.queue.push_back((next, path_id));
+                                        } else {
+                                            let error = GraphError::InvalidEdge { node: next };
+                                            ctx.pending_events.push_back(GraphEvent::Error(error));
+                                            ctx.join_set.shutdown().await;
+                                        }
+                                    }
                                 } else if let Some(targets) = self.edges.get(&current) {
                                     let next_paths: Vec<(String, u64)> = if targets.len() > 1 {
                                         targets
@@ -762,7 +1249,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                                 if *t == END {
                                                     (t.clone(), path_id)
                                                 } else {
-                                                    (t.clone(), stable_hash(&(path_id, t)))
+                                                    (t.clone(), derive_path_id(path_id, t))
                                                 }
                                             })
                                             .collect()
@@ -785,9 +1272,39 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                     }
                                 }
 
-                                // 4a. Checkpoint
+                                // Dynamic routing requested by the node itself; like
+                                // static edges, a single target stays on this path
+                                let fan_out = goto.len() > 1;
+                                for next in goto {
+                                    if next == END {
+                                        continue;
+                                    }
+                                    if !self.nodes.contains_key(&next) {
+                                        let error = GraphError::InvalidEdge { node: next };
+                                        ctx.pending_events.push_back(GraphEvent::Error(error));
+                                        ctx.join_set.shutdown().await;
+                                        continue;
+                                    }
+                                    let next_path_id = if fan_out {
+                                        derive_path_id(path_id, &next)
+                                    } else {
+                                        path_id
+                                    };
+                                    ctx.queue.push_back((next, next_path_id));
+                                }
+
+                                // 4a. Checkpoint, every `checkpoint_every` steps and
+                                // whenever the run is about to stop
+                                let interrupt_after =
+                                    ctx.effective.interrupt_after.contains(&current)
+                                        || self.interrupt_after.contains(&current);
+                                let completing =
+                                    ctx.queue.is_empty() && ctx.active_tasks.is_empty();
+                                let every = ctx.effective.checkpoint_every.unwrap_or(1).max(1);
+                                let due =
+                                    ctx.step_count % every == 0 || interrupt_after || completing;
                                 if let (Some((checkpointer, _)), Some(thread_id)) = (
-                                    self.checkpointer.as_ref(),
+                                    self.checkpointer.as_ref().filter(|_| due),
                                     ctx.checkpoint_thread_id.as_deref(),
                                 ) {
                                     let mut full_queue =
@@ -819,6 +1336,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                         ctx.pending_events.push_back(GraphEvent::CheckpointSaved {
                                             node: current.clone(),
                                             timestamp: Utc::now().timestamp_millis() as u64,
+                                            trace_id: ctx.trace_id,
                                         });
 
                                         if let Some((manager, root)) = &ctx.callbacks {
@@ -835,9 +1353,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                                 }
 
                                 // 4b. Interrupt After (AFTER checkpoint)
-                                if ctx.effective.interrupt_after.contains(&current)
-                                    || self.interrupt_after.contains(&current)
-                                {
+                                if interrupt_after {
                                     let error = GraphError::Interrupted;
                                     if let Some((manager, root)) = &ctx.callbacks {
                                         let error_value =
@@ -886,6 +1402,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                         &ctx.agent_event_sender,
                         &mut ctx.agent_event_step,
                         &ctx.agent_event_thread_id,
+                        ctx.trace_id,
                         "completed",
                         "Graph execution completed",
                     )
@@ -926,6 +1443,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
                         state = saved.state;
                         // Important: when resuming, we must respect the saved queue and step
                         if !saved.queue.is_empty() {
+                            self.ensure_queue_compatible(&saved.queue)?;
                             options.initial_queue = Some(saved.queue);
                             options.initial_step = Some(saved.step as usize + 1);
                         } else {
@@ -947,6 +1465,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
             emit_error_event(
                 &agent_event_sender,
                 &mut agent_event_step,
+                None,
                 error.to_string(),
                 Some("graph".to_string()),
             )
@@ -972,9 +1491,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
     }
 
     pub async fn invoke(&self, state: GraphState<S>) -> Result<GraphState<S>, WesichainError> {
-        self.invoke_graph(state)
-            .await
-            .map_err(|err| WesichainError::Custom(err.to_string()))
+        self.invoke_graph(state).await.map_err(WesichainError::from)
     }
 
     pub async fn invoke_with_options(
@@ -984,7 +1501,7 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
     ) -> Result<GraphState<S>, WesichainError> {
         self.invoke_graph_with_options(state, options)
             .await
-            .map_err(|err| WesichainError::Custom(err.to_string()))
+            .map_err(WesichainError::from)
     }
 
     pub async fn get_state(&self, thread_id: &str) -> Result<Option<GraphState<S>>, GraphError> {
@@ -996,11 +1513,16 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
         }
     }
 
+    /// Continue a run from `checkpoint`'s queue and step.
+    ///
+    /// Fails with [`GraphError::IncompatibleCheckpoint`] before running anything
+    /// if the queue names a node this graph no longer has.
     pub async fn resume(
         &self,
         checkpoint: Checkpoint<S>,
         mut options: ExecutionOptions,
     ) -> Result<GraphState<S>, GraphError> {
+        self.ensure_queue_compatible(&checkpoint.queue)?;
         options.initial_queue = Some(checkpoint.queue);
         // Start from next logical step
         options.initial_step = Some(checkpoint.step as usize + 1);
@@ -1008,6 +1530,45 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
             .await
     }
 
+    /// Like [`resume`](Self::resume), but first applies `input` to the
+    /// checkpointed state as an update from a synthetic `"human"` node.
+    ///
+    /// Use this to approve, reject or edit before the queued nodes run. The
+    /// edited state is saved as a new checkpoint (when a checkpointer is
+    /// configured) before execution continues.
+    pub async fn resume_with_input(
+        &self,
+        checkpoint: Checkpoint<S>,
+        input: S::Update,
+        options: ExecutionOptions,
+    ) -> Result<GraphState<S>, GraphError> {
+        self.ensure_queue_compatible(&checkpoint.queue)?;
+        let state = checkpoint.state.apply_update(StateUpdate::new(input));
+        let checkpoint = Checkpoint::new(
+            checkpoint.thread_id,
+            state,
+            checkpoint.step,
+            "human".to_string(),
+            checkpoint.queue,
+        );
+        if let Some((checkpointer, _)) = &self.checkpointer {
+            checkpointer.save(&checkpoint).await?;
+        }
+        self.resume(checkpoint, options).await
+    }
+
+    fn ensure_queue_compatible(&self, queue: &[(String, u64)]) -> Result<(), GraphError> {
+        match queue
+            .iter()
+            .find(|(node, _)| !self.nodes.contains_key(node))
+        {
+            Some((node, _)) => Err(GraphError::IncompatibleCheckpoint {
+                missing_node: node.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
     pub async fn update_state(
         &self,
         thread_id: &str,
@@ -1037,13 +1598,36 @@ impl<S: StateSchema<Update = S>> ExecutableGraph<S> {
     }
 }
 
+impl<S: StateSchema> ExecutableGraph<S> {
+    /// Mermaid flowchart of the graph's nodes and edges, including `START`
+    /// and `END`. Conditional routes are drawn as dashed edges to a `?`
+    /// node, since their targets are only known at run time.
+    pub fn to_mermaid(&self) -> String {
+        self.diagram().to_mermaid()
+    }
+
+    /// Graphviz DOT rendering of the same diagram as
+    /// [`to_mermaid`](Self::to_mermaid).
+    pub fn to_dot(&self) -> String {
+        self.diagram().to_dot()
+    }
+
+    fn diagram(&self) -> Diagram {
+        Diagram::new(
+            &self.entry,
+            self.nodes.keys(),
+            &self.edges,
+            self.conditional
+                .keys()
+                .chain(self.weighted_conditional.keys()),
+        )
+    }
+}
+
 #[async_trait::async_trait]
 impl<S: StateSchema<Update = S>> Runnable<GraphState<S>, StateUpdate<S>> for ExecutableGraph<S> {
     async fn invoke(&self, input: GraphState<S>) -> Result<StateUpdate<S>, WesichainError> {
-        let result = self
-            .invoke_graph(input)
-            .await
-            .map_err(|e| WesichainError::Custom(e.to_string()))?;
+        let result = self.invoke_graph(input).await?;
         Ok(StateUpdate::new(result.data))
     }
 
@@ -1056,12 +1640,10 @@ impl<S: StateSchema<Update = S>> Runnable<GraphState<S>, StateUpdate<S>> for Exe
         stream
             .filter_map(|event_res| async move {
                 match event_res {
-                    Ok(GraphEvent::Error(e)) | Err(e) => {
-                        Some(Err(WesichainError::Custom(e.to_string())))
-                    }
-                    // In a real implementation, we would map Node events to metadata
-                    // or if the graph output was compatible, stream chunks.
-                    // For now, subgraphs are mostly opaque unless we add a specific event mapper.
+                    Ok(GraphEvent::Error(e)) | Err(e) => Some(Err(WesichainError::from(e))),
+                    // Only streaming nodes surface their events; everything else
+                    // about the run stays opaque to the outer stream.
+                    Ok(GraphEvent::NodeStream { event, .. }) => Some(Ok(event)),
                     _ => None,
                 }
             })
@@ -1075,20 +1657,11 @@ mod tests {
 
     #[test]
     fn test_stable_path_hashing() {
-        let parent_id = 12345u64;
-        let node_name = "test_node";
-
-        // Hash with our specific fixed keys
-        let state = RandomState::with_seeds(0x517cc1b727220a95, 0x6ed9eba1999cd92d, 0, 0);
-        let hash1 = state.hash_one((parent_id, node_name));
-
-        // Re-compute to ensure determinism
-        let expected1 = state.hash_one((parent_id, node_name));
-        assert_eq!(hash1, expected1, "Hash MUST be deterministic");
-
-        let different_hash =
-            RandomState::with_seeds(123, 456, 0, 0).hash_one((parent_id, node_name));
+        // Pinned values: path ids must not drift across platforms or toolchains.
+        assert_eq!(derive_path_id(12345, "test_node"), 0x0f0e_383a_4dc2_239d);
+        assert_eq!(derive_path_id(0, "B"), 0xe604_443a_248f_c065);
 
-        assert_ne!(hash1, different_hash, "Should differ from arbitrary keys");
+        assert_ne!(derive_path_id(0, "B"), derive_path_id(0, "C"));
+        assert_ne!(derive_path_id(0, "B"), derive_path_id(1, "B"));
     }
 }
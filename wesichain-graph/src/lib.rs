@@ -1,19 +1,24 @@
 mod checkpoint;
 mod config;
+mod diagram;
 mod error;
 mod file_checkpointer;
 mod graph;
 pub mod hitl;
 mod interrupt;
+mod map_reduce_node;
 mod observer;
 mod parallel_agents;
 mod program;
 mod react_agent;
 pub mod react_subgraph;
 mod reducer;
+mod rerank_node;
 mod retriever_node;
 pub mod state;
 mod stream;
+mod streaming_node;
+mod subgraph_node;
 pub mod supervisor;
 mod tool_node;
 
@@ -21,25 +26,34 @@ pub use checkpoint::{
     Checkpoint, CheckpointMetadata, Checkpointer, HistoryCheckpointer, InMemoryCheckpointer,
 };
 pub use config::{ExecutionConfig, ExecutionOptions};
-pub use error::GraphError;
+pub use error::{GraphError, GraphValidationError};
 pub use file_checkpointer::{CheckpointRecord, FileCheckpointer};
-pub use graph::{ExecutableGraph, GraphBuilder, GraphContext, GraphNode};
+pub use graph::{
+    ExecutableGraph, GraphBuilder, GraphContext, GraphNode, GraphRunContext, StatusSink,
+};
+pub use hitl::{
+    ApprovalChannel, ApprovalDecision, ApprovalDefault, ApprovalGate, ApprovalRequest,
+    ApprovalState,
+};
 pub use interrupt::GraphInterrupt;
+pub use map_reduce_node::{MapFailurePolicy, MapReduceNode};
 pub use observer::Observer;
+pub use parallel_agents::parallel_agents;
 pub use program::{EdgeKind, GraphProgram, NodeData};
 #[allow(deprecated)]
 pub use react_agent::{ReActAgentNode, ToolFailurePolicy};
 pub use react_subgraph::{ContextCompressor, ReActGraphBuilder, TokenThresholdCompressor};
 pub use reducer::{AddCounter, AppendVec, MergeMap, Override};
+pub use rerank_node::RerankNode;
 pub use retriever_node::RetrieverNode;
 pub use state::{
     Append, GraphState, Overwrite, Reducer, StateReducer, StateSchema, StateUpdate, Union,
 };
 pub use stream::GraphEvent;
-pub use tool_node::{HasToolCalls, ToolNode};
-pub use hitl::{ApprovalChannel, ApprovalDecision, ApprovalDefault, ApprovalGate, ApprovalRequest, ApprovalState};
+pub use streaming_node::StreamingNode;
+pub use subgraph_node::SubgraphNode;
 pub use supervisor::{Supervisor, SupervisorBuilder, WorkerRunner, WorkerSpec};
-pub use parallel_agents::parallel_agents;
+pub use tool_node::{HasToolCalls, ToolNode};
 
 pub const START: &str = "__start";
 pub const END: &str = "__end";
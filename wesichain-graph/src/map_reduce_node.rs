@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use tokio::task::JoinSet;
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+
+use crate::{GraphState, StateSchema, StateUpdate};
+
+/// What [`MapReduceNode`] does when the mapper fails for an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MapFailurePolicy {
+    /// Abort the remaining items and fail the node with the first error.
+    #[default]
+    FailFast,
+    /// Leave the item out of the reduction and hand its error to the
+    /// [`on_error`](MapReduceNode::on_error) hook.
+    SkipAndCollectErrors,
+}
+
+type ItemsFn<S, Item> = dyn Fn(&S) -> Vec<Item> + Send + Sync;
+type ReduceFn<S, Partial> = dyn Fn(&mut S, Partial) + Send + Sync;
+type ErrorFn<S> = dyn Fn(&mut S, usize, WesichainError) + Send + Sync;
+
+/// Graph node that maps a runnable over a list taken from state, then folds
+/// the partial results back into the state.
+///
+/// Items are mapped concurrently, at most `max_concurrency` at a time (4 by
+/// default). Partials are reduced in item order regardless of which finished
+/// first, so the resulting state is deterministic.
+pub struct MapReduceNode<Item: Send + 'static, Partial: Send + 'static, S> {
+    items: Arc<ItemsFn<S, Item>>,
+    mapper: Arc<dyn Runnable<Item, Partial>>,
+    reducer: Arc<ReduceFn<S, Partial>>,
+    on_error: Option<Arc<ErrorFn<S>>>,
+    max_concurrency: usize,
+    policy: MapFailurePolicy,
+}
+
+impl<Item: Send + 'static, Partial: Send + 'static, S> MapReduceNode<Item, Partial, S> {
+    /// `items` picks the list to map over, `mapper` runs once per item and
+    /// `reducer` folds each partial into a copy of the state.
+    pub fn new<F, R>(items: F, mapper: Arc<dyn Runnable<Item, Partial>>, reducer: R) -> Self
+    where
+        F: Fn(&S) -> Vec<Item> + Send + Sync + 'static,
+        R: Fn(&mut S, Partial) + Send + Sync + 'static,
+    {
+        Self {
+            items: Arc::new(items),
+            mapper,
+            reducer: Arc::new(reducer),
+            on_error: None,
+            max_concurrency: 4,
+            policy: MapFailurePolicy::FailFast,
+        }
+    }
+
+    /// Maximum number of items mapped at once. `0` is treated as `1`.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    pub fn with_failure_policy(mut self, policy: MapFailurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Record skipped items in the state. Called in item order with the
+    /// item's index and error, after all partials have been reduced. Only
+    /// used with [`MapFailurePolicy::SkipAndCollectErrors`].
+    pub fn on_error<F>(mut self, on_error: F) -> Self
+    where
+        F: Fn(&mut S, usize, WesichainError) + Send + Sync + 'static,
+    {
+        self.on_error = Some(Arc::new(on_error));
+        self
+    }
+}
+
+#[async_trait]
+impl<Item, Partial, S> Runnable<GraphState<S>, StateUpdate<S>> for MapReduceNode<Item, Partial, S>
+where
+    Item: Send + 'static,
+    Partial: Send + 'static,
+    S: StateSchema<Update = S>,
+{
+    async fn invoke(&self, input: GraphState<S>) -> Result<StateUpdate<S>, WesichainError> {
+        let mut pending = (self.items)(&input.data).into_iter().enumerate();
+        let mut join_set: JoinSet<(usize, Result<Partial, WesichainError>)> = JoinSet::new();
+        let mut results = Vec::new();
+
+        loop {
+            while join_set.len() < self.max_concurrency {
+                let Some((index, item)) = pending.next() else {
+                    break;
+                };
+                let mapper = self.mapper.clone();
+                join_set.spawn(async move { (index, mapper.invoke(item).await) });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            let (index, result) =
+                joined.map_err(|e| WesichainError::Custom(format!("map task panicked: {e}")))?;
+            if self.policy == MapFailurePolicy::FailFast {
+                // Dropping the join set aborts the items still in flight.
+                results.push((index, Ok(result?)));
+            } else {
+                results.push((index, result));
+            }
+        }
+        results.sort_by_key(|(index, _)| *index);
+
+        let mut next = input.data;
+        let mut errors = Vec::new();
+        for (index, result) in results {
+            match result {
+                Ok(partial) => (self.reducer)(&mut next, partial),
+                Err(error) => errors.push((index, error)),
+            }
+        }
+        if let Some(on_error) = &self.on_error {
+            for (index, error) in errors {
+                on_error(&mut next, index, error);
+            }
+        }
+        Ok(StateUpdate::new(next))
+    }
+
+    fn stream(&self, _input: GraphState<S>) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::empty().boxed()
+    }
+}
@@ -6,6 +6,9 @@ pub trait Observer: Send + Sync + 'static {
     async fn on_tool_call(&self, _node_id: &str, _tool_name: &str, _args: &serde_json::Value) {}
     async fn on_tool_result(&self, _node_id: &str, _tool_name: &str, _result: &serde_json::Value) {}
     async fn on_checkpoint_saved(&self, _node_id: &str) {}
+    /// Called with the targets a conditional edge out of `from` selected,
+    /// before they are scheduled. May include `END`.
+    async fn on_route(&self, _from: &str, _targets: &[String]) {}
 }
 
 pub struct ObserverCallbackAdapter(pub std::sync::Arc<dyn Observer>);
@@ -64,12 +64,14 @@ impl ReActAgentNode {
             content: prompt.into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         });
         messages.push(Message {
             role: Role::User,
             content: state.user_input().to_string().into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         });
 
         let mut pending_tool_calls: VecDeque<ToolCall> = VecDeque::new();
@@ -84,6 +86,7 @@ impl ReActAgentNode {
                             content: thought.into(),
                             tool_call_id: None,
                             tool_calls: Vec::new(),
+                            cache_control: None,
                         });
                     }
                     pending_thought = Some(text.clone());
@@ -95,6 +98,7 @@ impl ReActAgentNode {
                         content: content.into(),
                         tool_call_id: None,
                         tool_calls: vec![call.clone()],
+                        cache_control: None,
                     });
                     pending_tool_calls.push_back(call.clone());
                 }
@@ -112,6 +116,7 @@ impl ReActAgentNode {
                         content: value.to_string().into(),
                         tool_call_id: Some(call.id),
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                 }
                 ReActStep::FinalAnswer(text) => {
@@ -121,6 +126,7 @@ impl ReActAgentNode {
                             content: thought.into(),
                             tool_call_id: None,
                             tool_calls: Vec::new(),
+                            cache_control: None,
                         });
                     }
                     messages.push(Message {
@@ -128,6 +134,7 @@ impl ReActAgentNode {
                         content: text.clone().into(),
                         tool_call_id: None,
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                 }
                 ReActStep::Error(text) => {
@@ -137,6 +144,7 @@ impl ReActAgentNode {
                             content: thought.into(),
                             tool_call_id: None,
                             tool_calls: Vec::new(),
+                            cache_control: None,
                         });
                     }
                     messages.push(Message {
@@ -144,6 +152,7 @@ impl ReActAgentNode {
                         content: text.clone().into(),
                         tool_call_id: None,
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                 }
             }
@@ -155,6 +164,7 @@ impl ReActAgentNode {
                 content: thought.into(),
                 tool_call_id: None,
                 tool_calls: Vec::new(),
+                cache_control: None,
             });
         }
 
@@ -264,13 +274,16 @@ where
                     temperature: None,
                     max_tokens: None,
                     stop_sequences: vec![],
+                    response_format: None,
                 })
                 .await?;
             let LlmResponse {
                 content,
                 tool_calls,
+                usage,
                 ..
             } = response;
+            context.report_usage(usage.as_ref());
             last_content = Some(content.clone());
             data.increment_iteration();
 
@@ -1,20 +1,24 @@
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
 
+use futures::StreamExt;
 use wesichain_core::{
-    HasFinalOutput, HasUserInput, LlmRequest, LlmResponse, Message, ReActStep, Role, Runnable,
-    ScratchpadState, Tool, ToolCall, ToolCallingLlm, ToolSpec, Value, WesichainError,
+    ensure_object, HasFinalOutput, HasUserInput, LlmRequest, LlmResponse, Message, ReActStep, Role,
+    RunType, Runnable, ScratchpadState, Tool, ToolCall, ToolCallingLlm, ToolError, ToolSpec, Value,
+    WesichainError,
 };
 use wesichain_prompt::PromptTemplate;
 
 use crate::config::ExecutionConfig;
 use crate::error::GraphError;
-use crate::graph::{ExecutableGraph, GraphBuilder, GraphContext, GraphNode};
+use crate::graph::{ExecutableGraph, GraphBuilder, GraphContext, GraphNode, StatusSink};
 
 use crate::state::{GraphState, StateSchema, StateUpdate};
 use crate::{END, START};
 
 const DEFAULT_SYSTEM_PROMPT: &str = "You are a helpful assistant. Use tools when helpful. If a tool is used, wait for the tool result before answering.";
+const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(200);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ToolFailurePolicy {
@@ -73,18 +77,21 @@ impl ContextCompressor for TokenThresholdCompressor {
                     content: "Summarise the following conversation in 2-3 sentences.".into(),
                     tool_call_id: None,
                     tool_calls: vec![],
+                    cache_control: None,
                 },
                 Message {
                     role: Role::User,
                     content: history_text.into(),
                     tool_call_id: None,
                     tool_calls: vec![],
+                    cache_control: None,
                 },
             ],
             tools: vec![],
             temperature: Some(0.0),
             max_tokens: Some(256),
             stop_sequences: vec![],
+            response_format: None,
         };
 
         let summary = self.llm.invoke(summary_req).await?.content;
@@ -101,6 +108,7 @@ impl ContextCompressor for TokenThresholdCompressor {
             content: format!("[Context summary] {summary}").into(),
             tool_call_id: None,
             tool_calls: vec![],
+            cache_control: None,
         });
         // Keep the last user message if different from summary
         if let Some(last) = messages.last() {
@@ -126,7 +134,12 @@ pub struct AgentNode {
 
 impl AgentNode {
     pub fn new(llm: Arc<dyn ToolCallingLlm>, tools: Vec<ToolSpec>, prompt: PromptTemplate) -> Self {
-        Self { llm, tools, prompt, context_compressor: None }
+        Self {
+            llm,
+            tools,
+            prompt,
+            context_compressor: None,
+        }
     }
 
     pub fn with_context_compressor(mut self, compressor: Arc<dyn ContextCompressor>) -> Self {
@@ -145,12 +158,14 @@ impl AgentNode {
             content: prompt.into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         });
         messages.push(Message {
             role: Role::User,
             content: state.user_input().to_string().into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         });
 
         let mut pending_tool_calls: VecDeque<ToolCall> = VecDeque::new();
@@ -165,6 +180,7 @@ impl AgentNode {
                             content: thought.into(),
                             tool_call_id: None,
                             tool_calls: Vec::new(),
+                            cache_control: None,
                         });
                     }
                     pending_thought = Some(text.clone());
@@ -176,6 +192,7 @@ impl AgentNode {
                         content: content.into(),
                         tool_call_id: None,
                         tool_calls: vec![call.clone()],
+                        cache_control: None,
                     });
                     pending_tool_calls.push_back(call.clone());
                 }
@@ -193,6 +210,7 @@ impl AgentNode {
                         content: value.to_string().into(),
                         tool_call_id: Some(call.id),
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                 }
                 ReActStep::FinalAnswer(text) => {
@@ -202,6 +220,7 @@ impl AgentNode {
                             content: thought.into(),
                             tool_call_id: None,
                             tool_calls: Vec::new(),
+                            cache_control: None,
                         });
                     }
                     messages.push(Message {
@@ -209,6 +228,7 @@ impl AgentNode {
                         content: text.clone().into(),
                         tool_call_id: None,
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                 }
                 ReActStep::Error(text) => {
@@ -218,6 +238,7 @@ impl AgentNode {
                             content: thought.into(),
                             tool_call_id: None,
                             tool_calls: Vec::new(),
+                            cache_control: None,
                         });
                     }
                     messages.push(Message {
@@ -225,6 +246,7 @@ impl AgentNode {
                         content: text.clone().into(),
                         tool_call_id: None,
                         tool_calls: Vec::new(),
+                        cache_control: None,
                     });
                 }
             }
@@ -236,6 +258,7 @@ impl AgentNode {
                 content: thought.into(),
                 tool_call_id: None,
                 tool_calls: Vec::new(),
+                cache_control: None,
             });
         }
 
@@ -257,7 +280,7 @@ where
     async fn invoke_with_context(
         &self,
         input: GraphState<S>,
-        _context: &GraphContext,
+        context: &GraphContext,
     ) -> Result<StateUpdate<S>, WesichainError> {
         let mut data = input.data;
         data.ensure_scratchpad();
@@ -281,14 +304,17 @@ where
                 temperature: None,
                 max_tokens: None,
                 stop_sequences: vec![],
+                response_format: None,
             })
             .await?;
 
         let LlmResponse {
             content,
             tool_calls,
+            usage,
             ..
         } = response;
+        context.report_usage(usage.as_ref());
 
         // Create delta for update
         let mut delta = S::default();
@@ -326,11 +352,17 @@ where
 /// It reads the latest tool call from the scratchpad, executes it, and pushes
 /// the result back as a new [`ReActStep`](wesichain_core::ReActStep).
 ///
+/// Tools that [stream their output](Tool::streams_output) are run through
+/// [`Tool::invoke_stream`]; each chunk is reported as a node status message as
+/// it arrives, and the observation is the chunks joined together.
+///
 /// Use this when building agents via [`ReActGraphBuilder`].
 /// For generic tool execution in non-ReAct workflows, use [`ToolNode`](crate::ToolNode).
 pub struct ReActToolNode {
     tools: HashMap<String, Arc<dyn Tool>>,
     failure_policy: ToolFailurePolicy,
+    max_retries: usize,
+    retry_backoff: Duration,
 }
 
 impl ReActToolNode {
@@ -338,8 +370,27 @@ impl ReActToolNode {
         Self {
             tools,
             failure_policy,
+            max_retries: 0,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
         }
     }
+
+    /// Retry a call up to `max_retries` more times while the tool fails with a
+    /// [retryable](wesichain_core::ToolError::is_retryable) error. The failure
+    /// policy only applies once retries are exhausted or the error is not
+    /// retryable. A streaming tool that fails after reporting a chunk is not
+    /// retried, so no chunk is reported twice. Defaults to 0.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Delay before the first retry, doubled for each retry after it.
+    /// Defaults to 200ms.
+    pub fn with_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -406,13 +457,46 @@ where
 
             let node_id = context.node_id.clone();
             let observer = context.observer.clone();
+            let callbacks = context.callbacks();
             let _failure_policy = self.failure_policy;
+            let max_retries = self.max_retries;
+            let mut backoff = self.retry_backoff;
+            let status = context.status_sink();
 
             join_set.spawn(async move {
-                let result = tool
-                    .invoke(call.args.clone())
-                    .await
-                    .map_err(|e| WesichainError::Custom(e.to_string()));
+                let tool_callbacks = callbacks.map(|(manager, parent)| {
+                    let ctx = parent.child(RunType::Tool, call.name.clone());
+                    (manager, ctx)
+                });
+                if let Some((manager, ctx)) = &tool_callbacks {
+                    manager.on_tool_start(ctx, &call.name, &call.args).await;
+                }
+                let mut attempt = 0;
+                let result = loop {
+                    match invoke_tool(tool.as_ref(), call.args.clone(), &status).await {
+                        (Err(err), false) if err.is_retryable() && attempt < max_retries => {
+                            attempt += 1;
+                            if let Some((manager, ctx)) = &tool_callbacks {
+                                let error = ensure_object(Value::String(err.to_string()));
+                                manager.on_retry(ctx, attempt, &error).await;
+                            }
+                            tokio::time::sleep(backoff).await;
+                            backoff = backoff.saturating_mul(2);
+                        }
+                        (result, _) => break result,
+                    }
+                }
+                .map_err(|e| WesichainError::Custom(e.to_string()));
+                if let Some((manager, ctx)) = &tool_callbacks {
+                    let duration_ms = ctx.start_instant.elapsed().as_millis();
+                    match &result {
+                        Ok(res) => manager.on_tool_end(ctx, &call.name, res, duration_ms).await,
+                        Err(err) => {
+                            let error = ensure_object(Value::String(err.to_string()));
+                            manager.on_error(ctx, &error, duration_ms).await;
+                        }
+                    }
+                }
                 // Side effects like observer can happen here or after join.
                 // Doing here is fine.
                 if let Some(observer) = &observer {
@@ -481,11 +565,55 @@ where
     }
 }
 
+/// Invoke `tool`, streaming its chunks to `status` if it streams its output.
+///
+/// Also returns whether any chunk was reported, in which case a failed call
+/// must not be retried: its chunks have already been sent.
+async fn invoke_tool(
+    tool: &dyn Tool,
+    args: Value,
+    status: &StatusSink,
+) -> (Result<Value, ToolError>, bool) {
+    if !tool.streams_output() {
+        return (tool.invoke(args).await, false);
+    }
+
+    let mut stream = tool.invoke_stream(args);
+    let mut chunks = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(err) => return (Err(err), !chunks.is_empty()),
+        };
+        match &chunk {
+            Value::String(text) => status.send(text.clone()),
+            other => status.send(other.to_string()),
+        }
+        chunks.push(chunk);
+    }
+    let streamed = !chunks.is_empty();
+    (Ok(join_chunks(chunks)), streamed)
+}
+
+/// Concatenate string chunks; a lone chunk is returned as is and any other
+/// mix becomes an array.
+fn join_chunks(mut chunks: Vec<Value>) -> Value {
+    if chunks.len() == 1 {
+        return chunks.remove(0);
+    }
+    if chunks.iter().all(Value::is_string) {
+        return Value::String(chunks.iter().filter_map(Value::as_str).collect());
+    }
+    Value::Array(chunks)
+}
+
 pub struct ReActGraphBuilder {
     llm: Option<Arc<dyn ToolCallingLlm>>,
     tools: Vec<Arc<dyn Tool>>,
     prompt: PromptTemplate,
     tool_failure_policy: ToolFailurePolicy,
+    tool_retries: usize,
+    tool_retry_backoff: Duration,
     context_compressor: Option<Arc<dyn ContextCompressor>>,
 }
 
@@ -502,6 +630,8 @@ impl ReActGraphBuilder {
             tools: Vec::new(),
             prompt: PromptTemplate::new(DEFAULT_SYSTEM_PROMPT.to_string()),
             tool_failure_policy: ToolFailurePolicy::FailFast,
+            tool_retries: 0,
+            tool_retry_backoff: DEFAULT_RETRY_BACKOFF,
             context_compressor: None,
         }
     }
@@ -526,6 +656,18 @@ impl ReActGraphBuilder {
         self
     }
 
+    /// See [`ReActToolNode::with_max_retries`].
+    pub fn tool_retries(mut self, retries: usize) -> Self {
+        self.tool_retries = retries;
+        self
+    }
+
+    /// See [`ReActToolNode::with_retry_backoff`].
+    pub fn tool_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.tool_retry_backoff = backoff;
+        self
+    }
+
     /// Attach a context compressor to the agent node.
     pub fn with_context_compressor(mut self, compressor: impl ContextCompressor + 'static) -> Self {
         self.context_compressor = Some(Arc::new(compressor));
@@ -566,11 +708,13 @@ impl ReActGraphBuilder {
             agent_node = agent_node.with_context_compressor(compressor);
         }
         let agent_node = agent_node;
-        let tool_node = ReActToolNode::new(tool_map, self.tool_failure_policy);
+        let tool_node = ReActToolNode::new(tool_map, self.tool_failure_policy)
+            .with_max_retries(self.tool_retries)
+            .with_retry_backoff(self.tool_retry_backoff);
 
         let builder = GraphBuilder::<S>::new()
             .with_default_config(ExecutionConfig {
-                cycle_detection: false,
+                cycle_exempt: ["agent", "tools"].map(String::from).into(),
                 ..Default::default()
             })
             .add_node("agent", agent_node)
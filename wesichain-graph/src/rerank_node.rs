@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::StreamExt;
+use wesichain_core::{
+    HasQuery, ReadRetrievedDocs, Reranker, Runnable, StreamEvent, WesichainError,
+};
+
+use crate::{GraphState, StateSchema, StateUpdate};
+
+/// Graph node that reorders the state's retrieved documents with a
+/// [`Reranker`] and keeps the best `top_k`.
+///
+/// The state must implement [`HasQuery`] for the query the documents are
+/// scored against and [`ReadRetrievedDocs`] to read and replace the
+/// candidates. Typically placed after a [`RetrieverNode`](crate::RetrieverNode)
+/// configured to over-fetch; the state's `top_k` override is not applied here.
+pub struct RerankNode {
+    reranker: Arc<dyn Reranker>,
+    top_k: usize,
+}
+
+impl RerankNode {
+    pub fn new(reranker: Arc<dyn Reranker>, top_k: usize) -> Self {
+        Self { reranker, top_k }
+    }
+}
+
+#[async_trait]
+impl<S> Runnable<GraphState<S>, StateUpdate<S>> for RerankNode
+where
+    S: StateSchema<Update = S> + HasQuery + ReadRetrievedDocs,
+{
+    async fn invoke(&self, input: GraphState<S>) -> Result<StateUpdate<S>, WesichainError> {
+        let docs = input.data.retrieved_docs().to_vec();
+        let mut ranked = self.reranker.rerank(input.data.query(), docs).await?;
+        ranked.truncate(self.top_k);
+
+        let mut state = input;
+        state
+            .data
+            .set_retrieved_docs(ranked.into_iter().map(|(doc, _)| doc).collect());
+        Ok(StateUpdate::new(state.data))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<S>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
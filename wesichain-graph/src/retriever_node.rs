@@ -5,6 +5,7 @@ use futures::stream::StreamExt;
 use wesichain_core::{
     Document, Embedding, EmbeddingError, HasMetadataFilter, HasQuery, HasRetrievedDocs,
     MetadataFilter, Runnable, SearchResult, StoreError, StreamEvent, VectorStore, WesichainError,
+    WriteMode,
 };
 use wesichain_retrieval::Retriever;
 
@@ -27,6 +28,14 @@ impl Embedding for DynEmbedding {
         self.0.embed_batch(texts).await
     }
 
+    async fn embed_query(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.0.embed_query(text).await
+    }
+
+    async fn embed_documents(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        self.0.embed_documents(texts).await
+    }
+
     fn dimension(&self) -> usize {
         self.0.dimension()
     }
@@ -57,8 +66,24 @@ impl VectorStore for DynVectorStore {
     async fn delete(&self, ids: &[String]) -> Result<(), StoreError> {
         self.0.delete(ids).await
     }
+
+    fn dimension(&self) -> Option<usize> {
+        self.0.dimension()
+    }
+
+    async fn add_mode(&self, docs: Vec<Document>, mode: WriteMode) -> Result<(), StoreError> {
+        self.0.add_mode(docs, mode).await
+    }
 }
 
+/// Graph node that embeds the state's query, searches a vector store and
+/// writes the matching documents back into the state.
+///
+/// The state must implement:
+/// - [`HasQuery`] for the query text and an optional per-query `top_k`, which
+///   overrides the `top_k` given to [`RetrieverNode::new`];
+/// - [`HasMetadataFilter`] for an optional filter passed to the store;
+/// - [`HasRetrievedDocs`] to receive the results, best match first.
 pub struct RetrieverNode {
     retriever: Retriever<DynEmbedding, DynVectorStore>,
     top_k: usize,
@@ -94,10 +119,11 @@ where
 {
     async fn invoke(&self, input: GraphState<S>) -> Result<StateUpdate<S>, WesichainError> {
         let query = input.data.query();
+        let top_k = input.data.top_k().unwrap_or(self.top_k);
         let filter = input.data.metadata_filter();
         let results = self
             .retriever
-            .retrieve(query, self.top_k, filter.as_ref())
+            .retrieve(query, top_k, filter.as_ref())
             .await
             .map_err(|err| WesichainError::Custom(err.to_string()))?;
         let results = self.apply_threshold(results);
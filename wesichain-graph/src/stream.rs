@@ -1,4 +1,5 @@
 use crate::{GraphError, StateSchema, StateUpdate};
+use uuid::Uuid;
 use wesichain_core::StreamEvent;
 
 #[derive(Debug)]
@@ -6,21 +7,55 @@ pub enum GraphEvent<S: StateSchema> {
     NodeEnter {
         node: String,
         timestamp: u64,
+        trace_id: Option<Uuid>,
     },
     NodeExit {
         node: String,
         timestamp: u64,
+        trace_id: Option<Uuid>,
     },
     NodeFinished {
         node: String,
         output: String,
         timestamp: u64,
+        trace_id: Option<Uuid>,
     }, // For inspection of content
     CheckpointSaved {
         node: String,
         timestamp: u64,
+        trace_id: Option<Uuid>,
     },
     StateUpdate(StateUpdate<S>),
     StreamEvent(StreamEvent),
+    /// An event from a [`StreamingNode`](crate::StreamingNode), forwarded
+    /// while the node runs.
+    NodeStream {
+        node: String,
+        event: StreamEvent,
+        trace_id: Option<Uuid>,
+    },
+    /// A progress message a node reported through its
+    /// [`StatusSink`](crate::StatusSink) while running.
+    NodeStatus {
+        node: String,
+        message: String,
+        trace_id: Option<Uuid>,
+    },
     Error(GraphError),
 }
+
+impl<S: StateSchema> GraphEvent<S> {
+    /// Trace of the run that emitted this event, set when the run has
+    /// callbacks configured.
+    pub fn trace_id(&self) -> Option<Uuid> {
+        match self {
+            Self::NodeEnter { trace_id, .. }
+            | Self::NodeExit { trace_id, .. }
+            | Self::NodeFinished { trace_id, .. }
+            | Self::CheckpointSaved { trace_id, .. }
+            | Self::NodeStream { trace_id, .. }
+            | Self::NodeStatus { trace_id, .. } => *trace_id,
+            _ => None,
+        }
+    }
+}
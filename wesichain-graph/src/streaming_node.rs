@@ -0,0 +1,58 @@
+use futures::StreamExt;
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+
+use crate::{GraphContext, GraphNode, GraphState, StateSchema, StateUpdate};
+
+/// Opt-in for nodes whose [`Runnable::stream`] events should reach the
+/// graph's stream, e.g. an LLM call whose tokens should show up live.
+///
+/// Add one with [`GraphBuilder::add_streaming_node`](crate::GraphBuilder::add_streaming_node).
+/// The graph then drives the node through `stream` instead of `invoke`,
+/// forwarding each event as [`GraphEvent::NodeStream`](crate::GraphEvent::NodeStream),
+/// and builds the node's state update from the collected events with
+/// [`finish`](Self::finish).
+pub trait StreamingNode<S: StateSchema>: Runnable<GraphState<S>, StateUpdate<S>> {
+    /// Build the state update from the node's input and every event its
+    /// stream yielded, in order.
+    fn finish(
+        &self,
+        input: GraphState<S>,
+        events: Vec<StreamEvent>,
+    ) -> Result<StateUpdate<S>, WesichainError>;
+}
+
+pub(crate) struct Streaming<R> {
+    inner: R,
+}
+
+impl<R> Streaming<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait::async_trait]
+impl<S, R> GraphNode<S> for Streaming<R>
+where
+    S: StateSchema,
+    R: StreamingNode<S> + Send + Sync,
+{
+    async fn invoke_with_context(
+        &self,
+        input: GraphState<S>,
+        context: &GraphContext,
+    ) -> Result<StateUpdate<S>, WesichainError> {
+        let mut events = Vec::new();
+        {
+            let mut stream = self.inner.stream(input.clone());
+            while let Some(event) = stream.next().await {
+                let event = event?;
+                context
+                    .run_context
+                    .emit_stream_event(&context.node_id, event.clone());
+                events.push(event);
+            }
+        }
+        self.inner.finish(input, events)
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+
+use crate::{ExecutableGraph, GraphState, StateSchema, StateUpdate};
+
+type ProjectFn<Outer, Inner> = Box<dyn Fn(&Outer) -> Inner + Send + Sync>;
+type MergeFn<Outer, Inner> = Box<dyn Fn(&mut Outer, Inner) + Send + Sync>;
+
+/// Graph node that runs a graph over a different state type.
+///
+/// On each invocation the parent state is projected into a fresh `Inner`
+/// state, the inner graph runs to completion, and its final state is merged
+/// back into a copy of the parent state, which becomes the node's update.
+/// Inner failures are passed through as-is, so the parent graph reports them
+/// once as [`GraphError::NodeFailed`](crate::GraphError::NodeFailed) naming
+/// this node. [`stream`](Runnable::stream) forwards the inner graph's stream.
+pub struct SubgraphNode<Inner: StateSchema, Outer> {
+    graph: ExecutableGraph<Inner>,
+    project: ProjectFn<Outer, Inner>,
+    merge: MergeFn<Outer, Inner>,
+}
+
+impl<Inner, Outer> SubgraphNode<Inner, Outer>
+where
+    Inner: StateSchema<Update = Inner>,
+    Outer: StateSchema<Update = Outer>,
+{
+    pub fn new<P, M>(graph: ExecutableGraph<Inner>, project: P, merge: M) -> Self
+    where
+        P: Fn(&Outer) -> Inner + Send + Sync + 'static,
+        M: Fn(&mut Outer, Inner) + Send + Sync + 'static,
+    {
+        Self {
+            graph,
+            project: Box::new(project),
+            merge: Box::new(merge),
+        }
+    }
+}
+
+#[async_trait]
+impl<Inner, Outer> Runnable<GraphState<Outer>, StateUpdate<Outer>> for SubgraphNode<Inner, Outer>
+where
+    Inner: StateSchema<Update = Inner>,
+    Outer: StateSchema<Update = Outer>,
+{
+    async fn invoke(&self, input: GraphState<Outer>) -> Result<StateUpdate<Outer>, WesichainError> {
+        let inner = GraphState::new((self.project)(&input.data));
+        let result = self.graph.invoke_graph(inner).await?;
+
+        let mut outer = input.data;
+        (self.merge)(&mut outer, result.data);
+        Ok(StateUpdate::new(outer))
+    }
+
+    fn stream(
+        &self,
+        input: GraphState<Outer>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        let inner = GraphState::new((self.project)(&input.data));
+        self.graph.stream(inner)
+    }
+}
@@ -129,8 +129,8 @@ where
         );
 
         let mut messages = vec![
-            Message { role: wesichain_core::Role::System, content: MessageContent::Text(system_prompt), tool_call_id: None, tool_calls: vec![] },
-            Message { role: wesichain_core::Role::User, content: MessageContent::Text(task.clone()), tool_call_id: None, tool_calls: vec![] },
+            Message { role: wesichain_core::Role::System, content: MessageContent::Text(system_prompt), tool_call_id: None, tool_calls: vec![], cache_control: None },
+            Message { role: wesichain_core::Role::User, content: MessageContent::Text(task.clone()), tool_call_id: None, tool_calls: vec![], cache_control: None },
         ];
 
         for _ in 0..self.max_rounds {
@@ -141,6 +141,7 @@ where
                 temperature: Some(0.0),
                 max_tokens: None,
                 stop_sequences: vec![],
+                response_format: None,
             };
 
             let resp = self.llm.invoke(req).await?;
@@ -168,6 +169,7 @@ where
                         content: MessageContent::Text(text),
                         tool_call_id: None,
                         tool_calls: vec![],
+                        cache_control: None,
                     });
                     messages.push(Message {
                         role: wesichain_core::Role::User,
@@ -176,6 +178,7 @@ where
                         )),
                         tool_call_id: None,
                         tool_calls: vec![],
+                        cache_control: None,
                     });
                 }
                 Ok(SupervisorDecision::Finish { answer }) => {
@@ -15,6 +15,8 @@ use crate::{GraphState, StateSchema, StateUpdate};
 /// [`ReActToolNode`](crate::react_subgraph::ReActToolNode) with `ScratchpadState` instead.
 pub trait HasToolCalls {
     fn tool_calls(&self) -> &Vec<ToolCall>;
+    /// Receives one [`Role::Tool`] message per call, in call order, with
+    /// `tool_call_id` set to the originating [`ToolCall::id`].
     fn push_tool_result(&mut self, message: Message);
 }
 
@@ -23,6 +25,9 @@ pub trait HasToolCalls {
 /// `ToolNode` executes all pending tool calls from state via the [`HasToolCalls`] trait.
 /// This is the general-purpose tool executor suitable for any workflow.
 ///
+/// Calls run concurrently. Each result is tagged with its call's id, so the
+/// next LLM turn can pair results with calls whatever order they finished in.
+///
 /// For ReAct-style agents, prefer [`ReActToolNode`](crate::react_subgraph::ReActToolNode)
 /// which integrates with the scratchpad pattern (`ScratchpadState`).
 pub struct ToolNode {
@@ -93,6 +98,7 @@ where
                 content: output?.into(),
                 tool_call_id: Some(call_id),
                 tool_calls: Vec::new(),
+                cache_control: None,
             });
         }
         Ok(StateUpdate::new(next))
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use wesichain_core::{
+    CallbackHandler, CallbackManager, CancellationToken, RunConfig, RunContext, Value,
+    WesichainError,
+};
+use wesichain_graph::{
+    Checkpointer, ExecutionOptions, GraphBuilder, GraphContext, GraphError, GraphNode, GraphState,
+    InMemoryCheckpointer, StateSchema, StateUpdate, END,
+};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct CountState {
+    count: u32,
+}
+
+impl StateSchema for CountState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+struct Increment {
+    delay: Duration,
+}
+
+#[async_trait::async_trait]
+impl GraphNode<CountState> for Increment {
+    async fn invoke_with_context(
+        &self,
+        input: GraphState<CountState>,
+        _: &GraphContext,
+    ) -> Result<StateUpdate<CountState>, WesichainError> {
+        tokio::time::sleep(self.delay).await;
+        Ok(StateUpdate::new(CountState {
+            count: input.data.count + 1,
+        }))
+    }
+}
+
+#[derive(Default)]
+struct RunRecorder {
+    started: Mutex<HashSet<Uuid>>,
+    ended: Mutex<HashSet<Uuid>>,
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for RunRecorder {
+    async fn on_start(&self, ctx: &RunContext, _inputs: &Value) {
+        self.started.lock().unwrap().insert(ctx.run_id);
+    }
+
+    async fn on_end(&self, ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {
+        self.ended.lock().unwrap().insert(ctx.run_id);
+    }
+
+    async fn on_error(&self, ctx: &RunContext, _error: &Value, _duration_ms: u128) {
+        self.ended.lock().unwrap().insert(ctx.run_id);
+    }
+}
+
+#[tokio::test]
+async fn cancelling_mid_run_saves_resumable_checkpoint() {
+    let checkpointer = InMemoryCheckpointer::default();
+    let graph = GraphBuilder::new()
+        .add_node(
+            "fast",
+            Increment {
+                delay: Duration::ZERO,
+            },
+        )
+        .add_node(
+            "slow",
+            Increment {
+                delay: Duration::from_millis(300),
+            },
+        )
+        .add_edge("fast", "slow")
+        .add_edge("slow", END)
+        .set_entry("fast")
+        .with_checkpointer(checkpointer.clone(), "thread-1")
+        .build();
+
+    let recorder = Arc::new(RunRecorder::default());
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        canceller.cancel();
+    });
+
+    let err = graph
+        .invoke_graph_with_options(
+            GraphState::new(CountState::default()),
+            ExecutionOptions {
+                cancellation: Some(token),
+                run_config: Some(RunConfig {
+                    callbacks: Some(CallbackManager::new(vec![recorder.clone()])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("run should be cancelled");
+    assert!(matches!(err, GraphError::Cancelled));
+
+    let started = recorder.started.lock().unwrap().clone();
+    let ended = recorder.ended.lock().unwrap().clone();
+    assert_eq!(started.len(), 3, "root, fast and slow runs should start");
+    assert_eq!(started, ended, "every started run should be ended");
+
+    let checkpoint = checkpointer
+        .load("thread-1")
+        .await
+        .unwrap()
+        .expect("cancellation should leave a checkpoint");
+    assert_eq!(checkpoint.node, "slow");
+    assert_eq!(checkpoint.state.data.count, 1);
+    assert!(checkpoint.queue.iter().any(|(node, _)| node == "slow"));
+
+    let resumed = graph
+        .resume(checkpoint, ExecutionOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(resumed.data.count, 2);
+}
+
+#[tokio::test]
+async fn cancelled_token_stops_run_before_first_node() {
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let graph = GraphBuilder::new()
+        .add_node(
+            "fast",
+            Increment {
+                delay: Duration::ZERO,
+            },
+        )
+        .add_edge("fast", END)
+        .set_entry("fast")
+        .build();
+
+    let err = graph
+        .invoke_graph_with_options(
+            GraphState::new(CountState::default()),
+            ExecutionOptions {
+                cancellation: Some(token),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("run should be cancelled");
+    assert!(matches!(err, GraphError::Cancelled));
+}
@@ -0,0 +1,138 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use wesichain_core::WesichainError;
+use wesichain_graph::{
+    Checkpoint, Checkpointer, ExecutionOptions, GraphBuilder, GraphContext, GraphNode, GraphState,
+    InMemoryCheckpointer, StateSchema, StateUpdate, END,
+};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct CountState {
+    count: u32,
+}
+
+impl StateSchema for CountState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+struct Increment;
+
+#[async_trait::async_trait]
+impl GraphNode<CountState> for Increment {
+    async fn invoke_with_context(
+        &self,
+        input: GraphState<CountState>,
+        _: &GraphContext,
+    ) -> Result<StateUpdate<CountState>, WesichainError> {
+        Ok(StateUpdate::new(CountState {
+            count: input.data.count + 1,
+        }))
+    }
+}
+
+#[derive(Clone, Default)]
+struct CountingCheckpointer {
+    inner: InMemoryCheckpointer<CountState>,
+    saves: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl Checkpointer<CountState> for CountingCheckpointer {
+    async fn save(&self, checkpoint: &Checkpoint<CountState>) -> Result<(), WesichainError> {
+        self.saves.fetch_add(1, Ordering::SeqCst);
+        self.inner.save(checkpoint).await
+    }
+
+    async fn load(
+        &self,
+        thread_id: &str,
+    ) -> Result<Option<Checkpoint<CountState>>, WesichainError> {
+        self.inner.load(thread_id).await
+    }
+}
+
+fn seven_node_path(
+    checkpointer: &CountingCheckpointer,
+) -> wesichain_graph::ExecutableGraph<CountState> {
+    let names: Vec<String> = (1..=7).map(|i| format!("n{i}")).collect();
+    let mut builder = GraphBuilder::new().set_entry("n1");
+    for (index, name) in names.iter().enumerate() {
+        let next = names.get(index + 1).map(String::as_str).unwrap_or(END);
+        builder = builder.add_node(name, Increment).add_edge(name, next);
+    }
+    builder
+        .with_checkpointer(checkpointer.clone(), "thread-1")
+        .build()
+}
+
+async fn run(checkpoint_every: Option<usize>) -> (CountingCheckpointer, GraphState<CountState>) {
+    let checkpointer = CountingCheckpointer::default();
+    let state = seven_node_path(&checkpointer)
+        .invoke_graph_with_options(
+            GraphState::new(CountState::default()),
+            ExecutionOptions {
+                checkpoint_every,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    (checkpointer, state)
+}
+
+#[tokio::test]
+async fn checkpoints_after_every_node_by_default() {
+    let (checkpointer, state) = run(None).await;
+
+    assert_eq!(state.data.count, 7);
+    assert_eq!(checkpointer.saves.load(Ordering::SeqCst), 7);
+}
+
+#[tokio::test]
+async fn checkpoint_every_saves_periodically_and_on_completion() {
+    let (checkpointer, state) = run(Some(3)).await;
+
+    assert_eq!(state.data.count, 7);
+    // Steps 3 and 6, plus the final node.
+    assert_eq!(checkpointer.saves.load(Ordering::SeqCst), 3);
+
+    let last = checkpointer.load("thread-1").await.unwrap().unwrap();
+    assert_eq!(last.node, "n7");
+    assert_eq!(last.state.data.count, 7);
+    assert!(last.queue.is_empty());
+}
+
+#[tokio::test]
+async fn periodic_checkpoint_resumes_from_its_position() {
+    let checkpointer = CountingCheckpointer::default();
+    let graph = seven_node_path(&checkpointer);
+    let err = graph
+        .invoke_graph_with_options(
+            GraphState::new(CountState::default()),
+            ExecutionOptions {
+                checkpoint_every: Some(3),
+                interrupt_after: vec!["n4".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, wesichain_graph::GraphError::Interrupted));
+
+    // Step 3 plus the interrupt after n4.
+    assert_eq!(checkpointer.saves.load(Ordering::SeqCst), 2);
+    let checkpoint = checkpointer.load("thread-1").await.unwrap().unwrap();
+    assert_eq!(checkpoint.state.data.count, 4);
+    assert_eq!(checkpoint.queue, vec![("n5".to_string(), 0)]);
+
+    let resumed = graph
+        .resume(checkpoint, ExecutionOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(resumed.data.count, 7);
+}
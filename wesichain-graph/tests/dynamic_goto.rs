@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use wesichain_core::WesichainError;
+use wesichain_graph::{
+    ExecutionOptions, GraphBuilder, GraphContext, GraphError, GraphNode, GraphState, StateSchema,
+    StateUpdate, END,
+};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct PlanState {
+    visited: Vec<String>,
+}
+
+impl StateSchema for PlanState {
+    type Update = Self;
+    fn apply(current: &Self, update: Self) -> Self {
+        let mut visited = current.visited.clone();
+        visited.extend(update.visited);
+        Self { visited }
+    }
+}
+
+/// Records its name and routes to `goto` at run time.
+struct Step {
+    name: &'static str,
+    goto: Vec<&'static str>,
+}
+
+impl Step {
+    fn new(name: &'static str) -> Self {
+        Self { name, goto: vec![] }
+    }
+
+    fn goto(mut self, targets: Vec<&'static str>) -> Self {
+        self.goto = targets;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl GraphNode<PlanState> for Step {
+    async fn invoke_with_context(
+        &self,
+        _input: GraphState<PlanState>,
+        _: &GraphContext,
+    ) -> Result<StateUpdate<PlanState>, WesichainError> {
+        let update = PlanState {
+            visited: vec![self.name.to_string()],
+        };
+        Ok(StateUpdate::new(update).with_goto(self.goto.clone()))
+    }
+}
+
+#[tokio::test]
+async fn node_routes_to_a_node_without_a_static_edge() {
+    let graph = GraphBuilder::new()
+        .add_node("planner", Step::new("planner").goto(vec!["researcher"]))
+        .add_node("researcher", Step::new("researcher"))
+        .add_edge("researcher", END)
+        .set_entry("planner")
+        .build();
+
+    let state = graph
+        .invoke_graph(GraphState::new(PlanState::default()))
+        .await
+        .unwrap();
+
+    assert_eq!(state.data.visited, vec!["planner", "researcher"]);
+}
+
+#[tokio::test]
+async fn goto_targets_run_alongside_static_edges() {
+    let graph = GraphBuilder::new()
+        .add_node("planner", Step::new("planner").goto(vec!["researcher"]))
+        .add_node("review", Step::new("review"))
+        .add_node("researcher", Step::new("researcher"))
+        .add_edge("planner", "review")
+        .add_edge("review", END)
+        .add_edge("researcher", END)
+        .set_entry("planner")
+        .build();
+
+    let state = graph
+        .invoke_graph(GraphState::new(PlanState::default()))
+        .await
+        .unwrap();
+
+    assert_eq!(state.data.visited.len(), 3);
+    assert_eq!(state.data.visited[0], "planner");
+    assert!(state.data.visited.contains(&"review".to_string()));
+    assert!(state.data.visited.contains(&"researcher".to_string()));
+}
+
+#[tokio::test]
+async fn goto_end_finishes_the_branch() {
+    let graph = GraphBuilder::new()
+        .add_node("planner", Step::new("planner").goto(vec![END]))
+        .set_entry("planner")
+        .build();
+
+    let state = graph
+        .invoke_graph(GraphState::new(PlanState::default()))
+        .await
+        .unwrap();
+
+    assert_eq!(state.data.visited, vec!["planner"]);
+}
+
+#[tokio::test]
+async fn goto_unknown_node_is_an_invalid_edge() {
+    let graph = GraphBuilder::new()
+        .add_node("planner", Step::new("planner").goto(vec!["ghost"]))
+        .set_entry("planner")
+        .build();
+
+    let err = graph
+        .invoke_graph(GraphState::new(PlanState::default()))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, GraphError::InvalidEdge { node } if node == "ghost"));
+}
+
+#[tokio::test]
+async fn single_goto_loop_hits_max_loop_iterations() {
+    let graph = GraphBuilder::new()
+        .add_node("a", Step::new("a").goto(vec!["b"]))
+        .add_node("b", Step::new("b").goto(vec!["a"]))
+        .set_entry("a")
+        .build();
+
+    let options = ExecutionOptions {
+        max_visits: Some(100),
+        max_loop_iterations: Some(3),
+        cycle_detection: Some(false),
+        max_steps: Some(100),
+        ..Default::default()
+    };
+    let err = graph
+        .invoke_graph_with_options(GraphState::new(PlanState::default()), options)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        GraphError::MaxLoopIterationsExceeded { node, max: 3, .. } if node == "a"
+    ));
+}
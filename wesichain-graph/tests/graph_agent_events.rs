@@ -1,8 +1,14 @@
+use std::sync::{Arc, Mutex};
+
 use futures::stream::StreamExt;
 use serde::{Deserialize, Serialize};
-use wesichain_core::{AgentEvent, Runnable, StreamEvent, WesichainError};
+use wesichain_core::{
+    AgentEvent, CallbackHandler, CallbackManager, RunConfig, RunContext, Runnable, StreamEvent,
+    Value, WesichainError,
+};
 use wesichain_graph::{
-    ExecutionOptions, GraphBuilder, GraphError, GraphState, StateSchema, StateUpdate,
+    ExecutionOptions, GraphBuilder, GraphContext, GraphError, GraphEvent, GraphNode, GraphState,
+    StateSchema, StateUpdate, StreamingNode,
 };
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
@@ -38,6 +44,52 @@ impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for AddOne {
     }
 }
 
+struct Speaker;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for Speaker {
+    async fn invoke(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        panic!("streaming nodes are driven through stream")
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::iter(vec![Ok(StreamEvent::ContentChunk("hi".to_string()))]).boxed()
+    }
+}
+
+impl StreamingNode<DemoState> for Speaker {
+    fn finish(
+        &self,
+        input: GraphState<DemoState>,
+        _events: Vec<StreamEvent>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        Ok(StateUpdate::new(input.data))
+    }
+}
+
+struct FetchPages;
+
+#[async_trait::async_trait]
+impl GraphNode<DemoState> for FetchPages {
+    async fn invoke_with_context(
+        &self,
+        input: GraphState<DemoState>,
+        context: &GraphContext,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        context.status("fetched 1/2 pages");
+        context.status_sink().send("fetched 2/2 pages");
+        Ok(StateUpdate::new(DemoState {
+            count: input.data.count + 2,
+        }))
+    }
+}
+
 #[tokio::test]
 async fn graph_emits_monotonic_status_events_with_thread_id() {
     let graph = GraphBuilder::new()
@@ -123,3 +175,176 @@ async fn graph_emits_terminal_error_event_for_missing_entry() {
         other => panic!("expected AgentEvent::Error, got {other:?}"),
     }
 }
+
+#[derive(Default)]
+struct RootRecorder {
+    root: Mutex<Option<RunContext>>,
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for RootRecorder {
+    async fn on_start(&self, ctx: &RunContext, _inputs: &Value) {
+        let mut root = self.root.lock().unwrap();
+        if ctx.parent_run_id.is_none() {
+            *root = Some(ctx.clone());
+        }
+    }
+
+    async fn on_end(&self, _ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {}
+
+    async fn on_error(&self, _ctx: &RunContext, _error: &Value, _duration_ms: u128) {}
+}
+
+#[tokio::test]
+async fn graph_events_carry_root_trace_id_when_callbacks_configured() {
+    let graph = GraphBuilder::new()
+        .add_streaming_node("speak", Speaker)
+        .add_node("one", AddOne)
+        .add_edge("speak", "one")
+        .set_entry("speak")
+        .build();
+    let recorder = Arc::new(RootRecorder::default());
+    let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+
+    let graph_events: Vec<_> = graph
+        .stream_invoke_with_options(
+            GraphState::new(DemoState { count: 0 }),
+            ExecutionOptions {
+                agent_event_sender: Some(tx),
+                run_config: Some(RunConfig {
+                    callbacks: Some(CallbackManager::new(vec![recorder.clone()])),
+                    ..RunConfig::default()
+                }),
+                ..ExecutionOptions::default()
+            },
+        )
+        .map(|event| event.expect("graph should succeed"))
+        .collect()
+        .await;
+    let mut agent_events = Vec::new();
+    while let Some(event) = rx.recv().await {
+        agent_events.push(event);
+    }
+
+    let root = recorder.root.lock().unwrap().clone().expect("root run");
+    assert_eq!(root.trace_id, root.run_id);
+
+    let traced: Vec<_> = graph_events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                GraphEvent::NodeEnter { .. }
+                    | GraphEvent::NodeExit { .. }
+                    | GraphEvent::NodeStream { .. }
+            )
+        })
+        .collect();
+    assert_eq!(traced.len(), 5);
+    assert!(traced
+        .iter()
+        .all(|event| event.trace_id() == Some(root.run_id)));
+
+    assert!(!agent_events.is_empty());
+    assert!(agent_events
+        .iter()
+        .all(|event| event.trace_id() == Some(root.run_id)));
+}
+
+#[tokio::test]
+async fn graph_events_have_no_trace_id_without_callbacks() {
+    let graph = GraphBuilder::new()
+        .add_node("one", AddOne)
+        .set_entry("one")
+        .build();
+
+    let events: Vec<_> = graph
+        .stream_invoke(GraphState::new(DemoState { count: 0 }))
+        .map(|event| event.expect("graph should succeed"))
+        .collect()
+        .await;
+
+    assert!(events.iter().all(|event| event.trace_id().is_none()));
+}
+
+#[tokio::test]
+async fn node_status_messages_reach_agent_events_in_order() {
+    let graph = GraphBuilder::new()
+        .add_node("fetch", FetchPages)
+        .set_entry("fetch")
+        .build();
+    let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+
+    let out = graph
+        .invoke_graph_with_options(
+            GraphState::new(DemoState { count: 0 }),
+            ExecutionOptions {
+                agent_event_sender: Some(tx),
+                agent_event_thread_id: Some("thread-status".to_string()),
+                ..ExecutionOptions::default()
+            },
+        )
+        .await
+        .expect("graph should succeed");
+    assert_eq!(out.data.count, 2);
+
+    let mut statuses = Vec::new();
+    while let Some(event) = rx.recv().await {
+        if let AgentEvent::Status {
+            stage,
+            message,
+            step,
+            thread_id,
+            ..
+        } = event
+        {
+            assert_eq!(thread_id, "thread-status");
+            statuses.push((stage, message, step));
+        }
+    }
+
+    let stages: Vec<(&str, &str)> = statuses
+        .iter()
+        .map(|(stage, message, _)| (stage.as_str(), message.as_str()))
+        .collect();
+    assert_eq!(
+        stages,
+        vec![
+            ("node_start", "Starting node fetch"),
+            ("fetch", "fetched 1/2 pages"),
+            ("fetch", "fetched 2/2 pages"),
+            ("node_end", "Completed node fetch"),
+            ("completed", "Graph execution completed"),
+        ]
+    );
+    assert!(statuses.windows(2).all(|pair| pair[1].2 > pair[0].2));
+}
+
+#[tokio::test]
+async fn node_status_messages_are_streamed_as_graph_events() {
+    let graph = GraphBuilder::new()
+        .add_node("fetch", FetchPages)
+        .set_entry("fetch")
+        .build();
+
+    let events: Vec<_> = graph
+        .stream_invoke(GraphState::new(DemoState { count: 0 }))
+        .collect()
+        .await;
+    let statuses: Vec<(&str, &str)> = events
+        .iter()
+        .filter_map(|event| match event {
+            Ok(GraphEvent::NodeStatus { node, message, .. }) => {
+                Some((node.as_str(), message.as_str()))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ("fetch", "fetched 1/2 pages"),
+            ("fetch", "fetched 2/2 pages")
+        ]
+    );
+}
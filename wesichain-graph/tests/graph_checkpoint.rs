@@ -2,8 +2,8 @@ use futures::stream::StreamExt;
 use serde::{Deserialize, Serialize};
 use wesichain_core::{Runnable, StreamEvent, WesichainError};
 use wesichain_graph::{
-    Checkpointer, ExecutionOptions, GraphBuilder, GraphState, InMemoryCheckpointer, StateSchema,
-    StateUpdate,
+    Checkpoint, Checkpointer, ExecutionOptions, GraphBuilder, GraphError, GraphState,
+    InMemoryCheckpointer, StateSchema, StateUpdate,
 };
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
@@ -148,3 +148,28 @@ async fn graph_auto_resume_without_checkpoint_starts_from_input_state() {
 
     assert_eq!(out.data.count, 1);
 }
+
+#[tokio::test]
+async fn resume_rejects_checkpoint_queueing_a_removed_node() {
+    let graph = GraphBuilder::new()
+        .add_node("one", AddOne)
+        .set_entry("one")
+        .build();
+    let checkpoint = Checkpoint::new(
+        "thread-1".to_string(),
+        GraphState::new(DemoState { count: 1 }),
+        3,
+        "one".to_string(),
+        vec![("renamed".to_string(), 0)],
+    );
+
+    let err = graph
+        .resume(checkpoint, ExecutionOptions::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        GraphError::IncompatibleCheckpoint { missing_node } if missing_node == "renamed"
+    ));
+}
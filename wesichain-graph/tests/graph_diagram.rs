@@ -0,0 +1,97 @@
+use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_graph::{ExecutableGraph, GraphBuilder, GraphState, StateSchema, StateUpdate, END};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct DemoState {
+    count: i32,
+}
+
+impl StateSchema for DemoState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+struct Inc;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for Inc {
+    async fn invoke(
+        &self,
+        input: GraphState<DemoState>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        Ok(StateUpdate::new(DemoState {
+            count: input.data.count + 1,
+        }))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+fn build_graph() -> ExecutableGraph<DemoState> {
+    GraphBuilder::new()
+        .add_node("plan", Inc)
+        .add_node("act", Inc)
+        .add_node("review", Inc)
+        .set_entry("plan")
+        .add_edge("plan", "act")
+        .add_conditional_edge("act", |state: &GraphState<DemoState>| {
+            if state.data.count > 2 {
+                vec!["review".to_string()]
+            } else {
+                vec!["act".to_string()]
+            }
+        })
+        .add_edge("review", END)
+        .build()
+}
+
+#[test]
+fn mermaid_lists_every_node_and_terminal() {
+    let mermaid = build_graph().to_mermaid();
+
+    assert!(mermaid.starts_with("flowchart TD\n"));
+    for name in ["START", "END", "plan", "act", "review"] {
+        assert!(mermaid.contains(&format!("\"{name}\"")), "{mermaid}");
+    }
+    // Nodes are numbered by name: act = n0, plan = n1, review = n2.
+    assert!(mermaid.contains("__start --> n1"), "{mermaid}");
+    assert!(mermaid.contains("n1 --> n0"), "{mermaid}");
+    assert!(mermaid.contains("n2 --> __end"), "{mermaid}");
+    assert!(mermaid.contains("n0 -.-> c0"), "{mermaid}");
+    assert!(mermaid.contains("c0{\"?\"}"), "{mermaid}");
+}
+
+#[test]
+fn dot_lists_every_node_and_marks_conditional_edges() {
+    let dot = build_graph().to_dot();
+
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.ends_with("}\n"));
+    for name in ["START", "END", "plan", "act", "review"] {
+        assert!(dot.contains(&format!("label=\"{name}\"")), "{dot}");
+    }
+    assert!(dot.contains("__start -> n1;"), "{dot}");
+    assert!(dot.contains("n0 -> c0 [style=dashed];"), "{dot}");
+    assert!(dot.contains("c0 [label=\"?\", shape=diamond];"), "{dot}");
+}
+
+#[test]
+fn diagram_labels_are_escaped() {
+    let graph: ExecutableGraph<DemoState> = GraphBuilder::new()
+        .add_node("say \"hi\"", Inc)
+        .set_entry("say \"hi\"")
+        .add_edge("say \"hi\"", END)
+        .build();
+
+    assert!(graph.to_mermaid().contains("n0[\"say #quot;hi#quot;\"]"));
+    assert!(graph.to_dot().contains("label=\"say \\\"hi\\\"\""));
+}
@@ -1,5 +1,7 @@
+use futures::stream::{BoxStream, StreamExt};
 use serde::{Deserialize, Serialize};
-use wesichain_graph::{GraphBuilder, GraphError, GraphState, StateSchema};
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_graph::{GraphBuilder, GraphError, GraphState, StateSchema, StateUpdate};
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
 struct DemoState {
@@ -20,3 +22,53 @@ async fn graph_returns_missing_node_error() {
     let err = graph.invoke_graph(state).await.unwrap_err();
     assert!(matches!(err, GraphError::MissingNode { .. }));
 }
+
+struct FailingNode;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for FailingNode {
+    async fn invoke(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        Err(WesichainError::ToolCallFailed {
+            tool_name: "search".to_string(),
+            reason: "timeout".to_string(),
+        })
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+#[tokio::test]
+async fn invoke_keeps_failed_node_inspectable() {
+    let graph = GraphBuilder::new()
+        .add_node("lookup", FailingNode)
+        .set_entry("lookup")
+        .build();
+
+    let err = graph
+        .invoke(GraphState::new(DemoState { count: 0 }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "node failed: lookup: Tool call failed for 'search': timeout"
+    );
+    match GraphError::from_wesichain(&err) {
+        Some(GraphError::NodeFailed { node, source }) => {
+            assert_eq!(node, "lookup");
+            assert!(matches!(
+                source.downcast_ref::<WesichainError>(),
+                Some(WesichainError::ToolCallFailed { tool_name, .. }) if tool_name == "search"
+            ));
+        }
+        other => panic!("expected NodeFailed, got {other:?}"),
+    }
+}
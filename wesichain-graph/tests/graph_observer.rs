@@ -2,7 +2,9 @@ use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
 use wesichain_core::{Runnable, StreamEvent, WesichainError};
-use wesichain_graph::{GraphBuilder, GraphError, GraphState, Observer, StateSchema, StateUpdate};
+use wesichain_graph::{
+    GraphBuilder, GraphError, GraphState, Observer, StateSchema, StateUpdate, END,
+};
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
 struct DemoState {
@@ -53,6 +55,13 @@ impl Observer for CollectingObserver {
     }
 
     async fn on_error(&self, _node_id: &str, _error: &GraphError) {}
+
+    async fn on_route(&self, from: &str, targets: &[String]) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("route:{from}->{}", targets.join(",")));
+    }
 }
 
 #[tokio::test]
@@ -69,3 +78,47 @@ async fn observer_receives_node_events() {
     graph.invoke_graph(state).await.unwrap();
     assert_eq!(events.lock().unwrap().as_slice(), ["start:inc", "end:inc"]);
 }
+
+fn routes(events: &[String]) -> Vec<&str> {
+    events
+        .iter()
+        .filter(|event| event.starts_with("route:"))
+        .map(String::as_str)
+        .collect()
+}
+
+#[tokio::test]
+async fn observer_receives_conditional_routing_decisions() {
+    let observer = CollectingObserver::default();
+    let events = observer.events.clone();
+    let graph = GraphBuilder::new()
+        .add_node("inc", Inc)
+        .add_node("small", Inc)
+        .add_node("large", Inc)
+        .set_entry("inc")
+        .add_conditional_edge("inc", |state: &GraphState<DemoState>| {
+            if state.data.count < 5 {
+                vec!["small".to_string()]
+            } else {
+                vec!["large".to_string(), END.to_string()]
+            }
+        })
+        .with_observer(Arc::new(observer))
+        .build();
+
+    graph
+        .invoke_graph(GraphState::new(DemoState { count: 0 }))
+        .await
+        .unwrap();
+    assert_eq!(routes(&events.lock().unwrap()), ["route:inc->small"]);
+
+    events.lock().unwrap().clear();
+    let _: Vec<_> = graph
+        .stream_invoke(GraphState::new(DemoState { count: 5 }))
+        .collect()
+        .await;
+    assert_eq!(
+        routes(&events.lock().unwrap()),
+        [format!("route:inc->large,{END}")]
+    );
+}
@@ -2,7 +2,8 @@ use futures::stream::StreamExt;
 use serde::{Deserialize, Serialize};
 use wesichain_core::{Runnable, StreamEvent, WesichainError};
 use wesichain_graph::{
-    ExecutionConfig, ExecutionOptions, GraphBuilder, GraphState, StateSchema, StateUpdate,
+    ExecutableGraph, ExecutionConfig, ExecutionOptions, GraphBuilder, GraphError, GraphState,
+    StateSchema, StateUpdate, END,
 };
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
@@ -99,3 +100,48 @@ async fn graph_options_override_defaults() {
     let out = graph.invoke_with_options(state, options).await.unwrap();
     assert_eq!(out.data.count, 2);
 }
+
+/// `agent` loops on itself until the count reaches 5, then hands over to `next`.
+fn looping_agent(next: &'static str) -> ExecutableGraph<DemoState> {
+    GraphBuilder::new()
+        .with_default_config(ExecutionConfig {
+            cycle_window: 4,
+            cycle_exempt: ["agent".to_string()].into(),
+            ..ExecutionConfig::default()
+        })
+        .add_node("agent", Inc)
+        .add_node("check", Inc)
+        .add_conditional_edge("agent", move |state: &GraphState<DemoState>| {
+            if state.data.count < 5 {
+                vec!["agent".to_string()]
+            } else {
+                vec![next.to_string()]
+            }
+        })
+        .add_edge("check", "check")
+        .set_entry("agent")
+        .build()
+}
+
+#[tokio::test]
+async fn cycle_exempt_node_loops_freely() {
+    let out = looping_agent(END)
+        .invoke_graph(GraphState::new(DemoState::default()))
+        .await
+        .unwrap();
+
+    assert_eq!(out.data.count, 5);
+}
+
+#[tokio::test]
+async fn non_exempt_repeat_still_trips_cycle_detection() {
+    let err = looping_agent("check")
+        .invoke_graph(GraphState::new(DemoState::default()))
+        .await
+        .unwrap_err();
+
+    match err {
+        GraphError::CycleDetected { node, .. } => assert_eq!(node, "check"),
+        other => panic!("expected CycleDetected, got {other:?}"),
+    }
+}
@@ -1,7 +1,9 @@
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use wesichain_core::{Runnable, StreamEvent, WesichainError};
-use wesichain_graph::{GraphBuilder, GraphEvent, GraphState, StateSchema, StateUpdate};
+use wesichain_graph::{
+    GraphBuilder, GraphEvent, GraphState, StateSchema, StateUpdate, StreamingNode,
+};
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
 struct DemoState {
@@ -48,3 +50,92 @@ async fn stream_emits_node_events() {
     let first = events.next().await.unwrap().unwrap();
     assert!(matches!(first, GraphEvent::NodeEnter { node, .. } if node == "inc"));
 }
+
+struct Speaker;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for Speaker {
+    async fn invoke(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        panic!("streaming nodes are driven through stream")
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::iter(vec![
+            Ok(StreamEvent::ContentChunk("hel".to_string())),
+            Ok(StreamEvent::ContentChunk("lo".to_string())),
+        ])
+        .boxed()
+    }
+}
+
+impl StreamingNode<DemoState> for Speaker {
+    fn finish(
+        &self,
+        input: GraphState<DemoState>,
+        events: Vec<StreamEvent>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        Ok(StateUpdate::new(DemoState {
+            count: input.data.count + events.len() as i32,
+        }))
+    }
+}
+
+#[tokio::test]
+async fn streaming_node_chunks_reach_graph_streams() {
+    let graph = GraphBuilder::new()
+        .add_streaming_node("speak", Speaker)
+        .add_node("inc", Inc)
+        .add_edge("speak", "inc")
+        .set_entry("speak")
+        .build();
+
+    let events: Vec<_> = graph
+        .stream_invoke(GraphState::new(DemoState { count: 0 }))
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+    let chunks: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            GraphEvent::NodeStream {
+                node,
+                event: StreamEvent::ContentChunk(chunk),
+                ..
+            } => Some((node.as_str(), chunk.as_str())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(chunks, vec![("speak", "hel"), ("speak", "lo")]);
+
+    let finished = events
+        .iter()
+        .position(|event| matches!(event, GraphEvent::NodeFinished { node, .. } if node == "speak"))
+        .unwrap();
+    let last_chunk = events
+        .iter()
+        .rposition(|event| matches!(event, GraphEvent::NodeStream { .. }))
+        .unwrap();
+    assert!(last_chunk < finished);
+
+    let outer: Vec<_> = graph
+        .stream(GraphState::new(DemoState { count: 0 }))
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+    assert!(matches!(
+        outer.as_slice(),
+        [StreamEvent::ContentChunk(a), StreamEvent::ContentChunk(b)] if a == "hel" && b == "lo"
+    ));
+
+    let state = graph
+        .invoke_graph(GraphState::new(DemoState { count: 0 }))
+        .await
+        .unwrap();
+    assert_eq!(state.data.count, 3);
+}
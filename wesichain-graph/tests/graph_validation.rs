@@ -0,0 +1,170 @@
+use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_graph::{
+    GraphBuilder, GraphState, GraphValidationError, StateSchema, StateUpdate, END, START,
+};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct DemoState {
+    count: i32,
+}
+
+impl StateSchema for DemoState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+struct AddOne;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for AddOne {
+    async fn invoke(
+        &self,
+        input: GraphState<DemoState>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        Ok(StateUpdate::new(DemoState {
+            count: input.data.count + 1,
+        }))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<DemoState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+#[test]
+fn valid_graph_passes() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("a", AddOne)
+        .add_node("b", AddOne)
+        .add_edge("a", "b")
+        .add_edge("b", END)
+        .set_entry("a");
+
+    assert_eq!(builder.validate(), Ok(()));
+}
+
+#[test]
+fn reports_missing_entry() {
+    let builder = GraphBuilder::<DemoState>::new().add_node("a", AddOne);
+
+    assert_eq!(
+        builder.validate(),
+        Err(vec![GraphValidationError::MissingEntry])
+    );
+}
+
+#[test]
+fn reports_entry_that_is_not_a_node() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("a", AddOne)
+        .set_entry("missing");
+
+    let errors = builder.validate().unwrap_err();
+    assert_eq!(
+        errors[0],
+        GraphValidationError::UnknownEntry {
+            node: "missing".to_string()
+        }
+    );
+}
+
+#[test]
+fn reports_unknown_edge_targets_and_sources() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("a", AddOne)
+        .add_edge(START, "a")
+        .add_edge("a", "ghost")
+        .add_edge("phantom", END)
+        .set_entry("a");
+
+    assert_eq!(
+        builder.validate(),
+        Err(vec![
+            GraphValidationError::UnknownEdgeTarget {
+                from: "a".to_string(),
+                to: "ghost".to_string(),
+            },
+            GraphValidationError::UnknownEdgeSource {
+                from: "phantom".to_string(),
+            },
+        ])
+    );
+}
+
+#[test]
+fn reports_conditional_edges_on_unknown_sources() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("a", AddOne)
+        .add_edge("a", END)
+        .add_conditional_edge("nowhere", |_| vec![END.to_string()])
+        .set_entry("a");
+
+    assert_eq!(
+        builder.validate(),
+        Err(vec![GraphValidationError::UnknownConditionalSource {
+            from: "nowhere".to_string(),
+        }])
+    );
+}
+
+#[test]
+fn reports_nodes_with_conditional_and_weighted_edges() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("a", AddOne)
+        .add_conditional_edge("a", |_| vec![END.to_string()])
+        .add_weighted_conditional_edge("a", |_| vec![(END.to_string(), 1.0)])
+        .set_entry("a");
+
+    assert_eq!(
+        builder.validate(),
+        Err(vec![GraphValidationError::ConflictingConditionalEdges {
+            node: "a".to_string(),
+        }])
+    );
+}
+
+#[test]
+fn reports_unreachable_nodes() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("a", AddOne)
+        .add_node("orphan", AddOne)
+        .add_edge("a", END)
+        .set_entry("a");
+
+    assert_eq!(
+        builder.validate(),
+        Err(vec![GraphValidationError::UnreachableNode {
+            node: "orphan".to_string(),
+        }])
+    );
+}
+
+#[test]
+fn nodes_behind_conditional_edges_are_not_reported_unreachable() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("router", AddOne)
+        .add_node("branch", AddOne)
+        .add_conditional_edge("router", |_| vec!["branch".to_string()])
+        .set_entry("router");
+
+    assert_eq!(builder.validate(), Ok(()));
+}
+
+#[test]
+fn reports_every_defect_at_once() {
+    let builder = GraphBuilder::<DemoState>::new()
+        .add_node("a", AddOne)
+        .add_edge("a", "ghost")
+        .add_conditional_edge("nowhere", |_| vec![END.to_string()]);
+
+    let errors = builder.validate().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(errors.contains(&GraphValidationError::MissingEntry));
+}
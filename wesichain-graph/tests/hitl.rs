@@ -82,6 +82,7 @@ async fn gate_as_graph_node() {
         remaining_steps: None,
         observer: None,
         node_id: "gate-node".to_string(),
+        run_context: Default::default(),
     };
     let input = GraphState::new(SimpleState { value: 42 });
     let update: StateUpdate<SimpleState> = gate.invoke_with_context(input, &ctx).await.unwrap();
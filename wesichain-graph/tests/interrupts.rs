@@ -4,8 +4,8 @@ use std::time::Duration;
 use tokio::time::sleep;
 use wesichain_core::{Runnable, StreamEvent, WesichainError};
 use wesichain_graph::{
-    Checkpointer, ExecutionOptions, GraphBuilder, GraphError, GraphState, InMemoryCheckpointer,
-    StateSchema, StateUpdate,
+    Checkpointer, ExecutionOptions, GraphBuilder, GraphError, GraphState, HistoryCheckpointer,
+    InMemoryCheckpointer, StateSchema, StateUpdate,
 };
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
@@ -49,6 +49,21 @@ impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for RecordNode {
     }
 }
 
+/// Records the state it was run with.
+struct ReviewNode;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<DemoState>, StateUpdate<DemoState>> for ReviewNode {
+    async fn invoke(
+        &self,
+        input: GraphState<DemoState>,
+    ) -> Result<StateUpdate<DemoState>, WesichainError> {
+        Ok(StateUpdate::new(DemoState {
+            executed: vec![format!("B saw {}", input.data.executed.join(","))],
+        }))
+    }
+}
+
 #[tokio::test]
 async fn test_interrupt_after_resume() {
     let checkpointer = InMemoryCheckpointer::default();
@@ -239,3 +254,56 @@ async fn test_parallel_interrupt_resume() {
     assert!(executed.contains(&"C".to_string()));
     assert_eq!(executed.len(), 3);
 }
+
+#[tokio::test]
+async fn test_resume_with_input_injects_human_edit() {
+    let checkpointer = InMemoryCheckpointer::default();
+    let graph = GraphBuilder::<DemoState>::new()
+        .add_node(
+            "A",
+            RecordNode {
+                name: "A".to_string(),
+                delay: None,
+            },
+        )
+        .add_node("B", ReviewNode)
+        .add_edge("A", "B")
+        .set_entry("A")
+        .with_checkpointer(checkpointer.clone(), "thread-4")
+        .build();
+
+    let options = ExecutionOptions {
+        interrupt_before: vec!["B".to_string()],
+        ..Default::default()
+    };
+    let result = graph
+        .invoke_graph_with_options(GraphState::new(DemoState::default()), options)
+        .await;
+    assert!(matches!(result, Err(GraphError::Interrupted)));
+
+    let checkpoint = checkpointer.load("thread-4").await.unwrap().unwrap();
+    let interrupted_step = checkpoint.step;
+    let edit = DemoState {
+        executed: vec!["human".to_string()],
+    };
+
+    let resumed = graph
+        .resume_with_input(checkpoint, edit, ExecutionOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(resumed.data.executed, vec!["A", "human", "B saw A,human"]);
+
+    // The edit takes no step of its own: B is numbered as after a plain resume.
+    let last = checkpointer.load("thread-4").await.unwrap().unwrap();
+    assert_eq!(last.node, "B");
+    assert_eq!(last.step, interrupted_step + 2);
+
+    // The edit was checkpointed before B ran, at the interrupted step.
+    let fork = HistoryCheckpointer::<DemoState>::fork(&checkpointer, "thread-4", interrupted_step)
+        .await
+        .unwrap();
+    let injected = checkpointer.load(&fork).await.unwrap().unwrap();
+    assert_eq!(injected.node, "human");
+    assert_eq!(injected.state.data.executed, vec!["A", "human"]);
+    assert!(injected.queue.iter().any(|(n, _)| n == "B"));
+}
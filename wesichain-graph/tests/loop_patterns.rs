@@ -180,3 +180,58 @@ async fn test_infinite_loop() {
         _ => panic!("Expected MaxLoopIterationsExceeded, got {:?}", result),
     }
 }
+
+// Scenario 3: Fan-out then loop
+// A -> B -> B ...
+// A -> C
+// B's branch gets a path id derived from the root path and "B"; the id is
+// pinned so errors stay reproducible across runs and platforms.
+#[tokio::test]
+async fn test_fan_out_path_ids_are_stable() {
+    let graph = GraphBuilder::<TestState>::new()
+        .add_node(
+            "A",
+            PassNode {
+                name: "A".to_string(),
+            },
+        )
+        .add_node(
+            "B",
+            PassNode {
+                name: "B".to_string(),
+            },
+        )
+        .add_node(
+            "C",
+            PassNode {
+                name: "C".to_string(),
+            },
+        )
+        .set_entry("A")
+        .add_edge("A", "B")
+        .add_edge("A", "C")
+        .add_edge("B", "B")
+        .add_edge("C", END)
+        .build();
+
+    for _ in 0..2 {
+        let options = ExecutionOptions {
+            max_visits: Some(100),
+            max_loop_iterations: Some(2),
+            cycle_detection: Some(false),
+            max_steps: Some(100),
+            ..Default::default()
+        };
+        let result = graph
+            .invoke_graph_with_options(GraphState::new(TestState::default()), options)
+            .await;
+
+        match result {
+            Err(GraphError::MaxLoopIterationsExceeded { node, path_id, .. }) => {
+                assert_eq!(node, "B");
+                assert_eq!(path_id, 0xe604_443a_248f_c065);
+            }
+            _ => panic!("Expected MaxLoopIterationsExceeded, got {:?}", result),
+        }
+    }
+}
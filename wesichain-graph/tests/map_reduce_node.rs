@@ -0,0 +1,111 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_graph::{GraphState, MapFailurePolicy, MapReduceNode, StateSchema};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct SumState {
+    numbers: Vec<i64>,
+    total: i64,
+    errors: Vec<String>,
+}
+
+impl StateSchema for SumState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+/// Passes numbers through, rejecting negatives. Later items finish first so
+/// completion order differs from item order.
+#[derive(Default)]
+struct CheckedIdentity {
+    in_flight: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Runnable<i64, i64> for CheckedIdentity {
+    async fn invoke(&self, input: i64) -> Result<i64, WesichainError> {
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20 - input.unsigned_abs().min(10) * 2)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        if input < 0 {
+            return Err(WesichainError::Custom(format!("negative: {input}")));
+        }
+        Ok(input)
+    }
+
+    fn stream(&self, _input: i64) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::empty().boxed()
+    }
+}
+
+fn sum_node(mapper: Arc<CheckedIdentity>) -> MapReduceNode<i64, i64, SumState> {
+    MapReduceNode::new(
+        |state: &SumState| state.numbers.clone(),
+        mapper,
+        |state: &mut SumState, partial| state.total += partial,
+    )
+    .on_error(|state: &mut SumState, index, error| {
+        state.errors.push(format!("{index}: {error}"));
+    })
+}
+
+fn numbers(numbers: Vec<i64>) -> GraphState<SumState> {
+    GraphState::new(SumState {
+        numbers,
+        ..Default::default()
+    })
+}
+
+#[tokio::test]
+async fn map_reduce_sums_numbers_with_bounded_concurrency() {
+    let mapper = Arc::new(CheckedIdentity::default());
+    let node = sum_node(mapper.clone()).with_max_concurrency(2);
+
+    let update = node.invoke(numbers((1..=6).collect())).await.unwrap();
+
+    assert_eq!(update.data.total, 21);
+    assert!(update.data.errors.is_empty());
+    assert_eq!(mapper.peak.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn map_reduce_fails_fast_by_default() {
+    let node = sum_node(Arc::new(CheckedIdentity::default()));
+
+    let err = node.invoke(numbers(vec![1, -2, 3])).await.unwrap_err();
+
+    assert_eq!(err.to_string(), "negative: -2");
+}
+
+#[tokio::test]
+async fn map_reduce_skips_and_collects_errors_in_item_order() {
+    let node = sum_node(Arc::new(CheckedIdentity::default()))
+        .with_failure_policy(MapFailurePolicy::SkipAndCollectErrors);
+
+    let update = node.invoke(numbers(vec![5, -1, 10, -3, 2])).await.unwrap();
+
+    assert_eq!(update.data.total, 17);
+    assert_eq!(
+        update.data.errors,
+        vec!["1: negative: -1".to_string(), "3: negative: -3".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn map_reduce_over_empty_list_keeps_state() {
+    let node = sum_node(Arc::new(CheckedIdentity::default()));
+
+    let update = node.invoke(numbers(Vec::new())).await.unwrap();
+
+    assert_eq!(update.data, SumState::default());
+}
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 use wesichain_core::WesichainError;
@@ -115,3 +117,76 @@ async fn test_parallel_execution() {
     );
     assert!(duration > Duration::from_millis(200), "Execution too fast!");
 }
+
+struct TrackedNode {
+    id: String,
+    active: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl GraphNode<ParallelState> for TrackedNode {
+    async fn invoke_with_context(
+        &self,
+        _input: GraphState<ParallelState>,
+        _context: &GraphContext,
+    ) -> Result<StateUpdate<ParallelState>, WesichainError> {
+        let running = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(running, Ordering::SeqCst);
+        sleep(Duration::from_millis(20)).await;
+        self.active.fetch_sub(1, Ordering::SeqCst);
+        Ok(StateUpdate::new(ParallelState {
+            logs: vec![self.id.clone()],
+        }))
+    }
+}
+
+#[tokio::test]
+async fn test_max_concurrent_nodes_bounds_fan_out() {
+    let active = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let workers: Vec<String> = (0..12).map(|i| format!("worker_{i}")).collect();
+
+    let mut builder = GraphBuilder::<ParallelState>::new()
+        .with_default_config(ExecutionConfig {
+            cycle_detection: false,
+            max_concurrent_nodes: Some(3),
+            ..Default::default()
+        })
+        .add_node(
+            "fan_out",
+            SleepyNode {
+                id: "fan_out".to_string(),
+                delay_ms: 0,
+            },
+        )
+        .set_entry("fan_out");
+    let targets = workers.clone();
+    builder = builder.add_conditional_edge("fan_out", move |_| targets.clone());
+    for worker in &workers {
+        builder = builder
+            .add_node(
+                worker,
+                TrackedNode {
+                    id: worker.clone(),
+                    active: active.clone(),
+                    peak: peak.clone(),
+                },
+            )
+            .add_edge(worker, END);
+    }
+
+    let result = builder
+        .build()
+        .invoke(GraphState::new(ParallelState::default()))
+        .await
+        .expect("Graph failed");
+
+    assert_eq!(peak.load(Ordering::SeqCst), 3);
+    let mut logs = result.data.logs;
+    logs.sort();
+    let mut expected = workers;
+    expected.push("fan_out".to_string());
+    expected.sort();
+    assert_eq!(logs, expected);
+}
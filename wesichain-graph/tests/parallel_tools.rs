@@ -1,10 +1,13 @@
+use futures::stream::{self, BoxStream, StreamExt};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time::sleep;
 
 use wesichain_core::{
-    HasFinalOutput, HasUserInput, ReActStep, ScratchpadState, Tool, ToolCall, Value,
+    with_run_context, CallbackHandler, CallbackManager, HasFinalOutput, HasUserInput, ReActStep,
+    RunContext, RunType, ScratchpadState, Tool, ToolCall, ToolError, Value,
 };
 
 use serde::{Deserialize, Serialize};
@@ -112,6 +115,7 @@ async fn test_parallel_tool_node() {
         remaining_steps: None,
         observer: None,
         node_id: "tools".to_string(),
+        run_context: Default::default(),
     };
 
     let start = std::time::Instant::now();
@@ -155,3 +159,290 @@ async fn test_parallel_tool_node() {
         _ => panic!("Expected observation 3"),
     }
 }
+
+/// Fails with a retryable error `failures` times, then succeeds.
+struct FlakyTool {
+    failures: usize,
+    calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Tool for FlakyTool {
+    fn name(&self) -> &str {
+        "flaky_tool"
+    }
+    fn description(&self) -> &str {
+        "Times out a few times before answering"
+    }
+    fn schema(&self) -> Value {
+        Value::Null
+    }
+    async fn invoke(&self, _args: Value) -> Result<Value, ToolError> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        if call < self.failures {
+            Err(ToolError::Retryable("timed out".to_string()))
+        } else {
+            Ok(Value::String("ok".to_string()))
+        }
+    }
+}
+
+fn flaky_call_input() -> GraphState<TestState> {
+    let mut state = TestState::default();
+    state.scratchpad.push(ReActStep::Action(ToolCall {
+        name: "flaky_tool".to_string(),
+        args: Value::Null,
+        id: "id1".to_string(),
+    }));
+    GraphState::new(state)
+}
+
+fn tool_context() -> GraphContext {
+    GraphContext {
+        remaining_steps: None,
+        observer: None,
+        node_id: "tools".to_string(),
+        run_context: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn retryable_tool_errors_are_retried_until_success() {
+    let tool = Arc::new(FlakyTool {
+        failures: 2,
+        calls: AtomicUsize::new(0),
+    });
+    let tools_map: HashMap<String, Arc<dyn Tool>> =
+        HashMap::from([(tool.name().to_string(), tool.clone() as Arc<dyn Tool>)]);
+    let node = ReActToolNode::new(tools_map, ToolFailurePolicy::FailFast).with_max_retries(2);
+
+    let result = node
+        .invoke_with_context(flaky_call_input(), &tool_context())
+        .await
+        .expect("tool should succeed after retries");
+
+    assert_eq!(tool.calls.load(Ordering::SeqCst), 3);
+    assert!(matches!(
+        result.data.scratchpad.as_slice(),
+        [ReActStep::Observation(Value::String(output))] if output == "ok"
+    ));
+}
+
+#[tokio::test]
+async fn exhausted_retries_fall_back_to_failure_policy() {
+    let tool = Arc::new(FlakyTool {
+        failures: 2,
+        calls: AtomicUsize::new(0),
+    });
+    let tools_map: HashMap<String, Arc<dyn Tool>> =
+        HashMap::from([(tool.name().to_string(), tool.clone() as Arc<dyn Tool>)]);
+    let node = ReActToolNode::new(tools_map, ToolFailurePolicy::AppendErrorAndContinue)
+        .with_max_retries(1);
+
+    let result = node
+        .invoke_with_context(flaky_call_input(), &tool_context())
+        .await
+        .expect("error should be appended as an observation");
+
+    assert_eq!(tool.calls.load(Ordering::SeqCst), 2);
+    assert!(matches!(
+        result.data.scratchpad.as_slice(),
+        [ReActStep::Observation(Value::String(output))] if output.starts_with("[TOOL ERROR] flaky_tool")
+    ));
+}
+
+#[tokio::test]
+async fn retries_back_off_exponentially() {
+    let tool = Arc::new(FlakyTool {
+        failures: 2,
+        calls: AtomicUsize::new(0),
+    });
+    let tools_map: HashMap<String, Arc<dyn Tool>> =
+        HashMap::from([(tool.name().to_string(), tool.clone() as Arc<dyn Tool>)]);
+    let node = ReActToolNode::new(tools_map, ToolFailurePolicy::FailFast)
+        .with_max_retries(2)
+        .with_retry_backoff(Duration::from_millis(50));
+
+    let started = std::time::Instant::now();
+    node.invoke_with_context(flaky_call_input(), &tool_context())
+        .await
+        .expect("tool should succeed after retries");
+
+    assert_eq!(tool.calls.load(Ordering::SeqCst), 3);
+    assert!(started.elapsed() >= Duration::from_millis(150));
+}
+
+/// Streams one chunk, then fails with a retryable error.
+struct FlakyStreamingTool {
+    calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Tool for FlakyStreamingTool {
+    fn name(&self) -> &str {
+        "flaky_tool"
+    }
+    fn description(&self) -> &str {
+        "Drops the connection mid-stream"
+    }
+    fn schema(&self) -> Value {
+        Value::Null
+    }
+    async fn invoke(&self, _args: Value) -> Result<Value, ToolError> {
+        panic!("streaming tools are run through invoke_stream")
+    }
+    fn streams_output(&self) -> bool {
+        true
+    }
+    fn invoke_stream(&self, _args: Value) -> BoxStream<'_, Result<Value, ToolError>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        stream::iter(vec![
+            Ok(Value::String("partial".to_string())),
+            Err(ToolError::Retryable("connection reset".to_string())),
+        ])
+        .boxed()
+    }
+}
+
+#[tokio::test]
+async fn streaming_tools_are_not_retried_after_reporting_a_chunk() {
+    let tool = Arc::new(FlakyStreamingTool {
+        calls: AtomicUsize::new(0),
+    });
+    let tools_map: HashMap<String, Arc<dyn Tool>> =
+        HashMap::from([(tool.name().to_string(), tool.clone() as Arc<dyn Tool>)]);
+    let node = ReActToolNode::new(tools_map, ToolFailurePolicy::AppendErrorAndContinue)
+        .with_max_retries(2);
+
+    let result = node
+        .invoke_with_context(flaky_call_input(), &tool_context())
+        .await
+        .expect("error should be appended as an observation");
+
+    assert_eq!(tool.calls.load(Ordering::SeqCst), 1);
+    assert!(matches!(
+        result.data.scratchpad.as_slice(),
+        [ReActStep::Observation(Value::String(output))] if output.starts_with("[TOOL ERROR] flaky_tool")
+    ));
+}
+
+#[derive(Default)]
+struct ToolRecorder {
+    events: Mutex<Vec<(String, String, Option<uuid::Uuid>)>>,
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for ToolRecorder {
+    async fn on_start(&self, _ctx: &RunContext, _inputs: &Value) {}
+
+    async fn on_end(&self, _ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {}
+
+    async fn on_error(&self, ctx: &RunContext, _error: &Value, _duration_ms: u128) {
+        self.events.lock().unwrap().push((
+            "error".to_string(),
+            ctx.name.clone(),
+            ctx.parent_run_id,
+        ));
+    }
+
+    async fn on_retry(&self, ctx: &RunContext, attempt: usize, _error: &Value) {
+        self.events.lock().unwrap().push((
+            "retry".to_string(),
+            attempt.to_string(),
+            ctx.parent_run_id,
+        ));
+    }
+
+    async fn on_tool_start(&self, ctx: &RunContext, tool_name: &str, _args: &Value) {
+        self.events.lock().unwrap().push((
+            "start".to_string(),
+            tool_name.to_string(),
+            ctx.parent_run_id,
+        ));
+    }
+
+    async fn on_tool_end(
+        &self,
+        ctx: &RunContext,
+        tool_name: &str,
+        _result: &Value,
+        _duration_ms: u128,
+    ) {
+        self.events.lock().unwrap().push((
+            "end".to_string(),
+            tool_name.to_string(),
+            ctx.parent_run_id,
+        ));
+    }
+}
+
+#[tokio::test]
+async fn tool_runs_are_reported_to_callbacks_under_the_node_run() {
+    let flaky = Arc::new(FlakyTool {
+        failures: 2,
+        calls: AtomicUsize::new(0),
+    });
+    let tools_map: HashMap<String, Arc<dyn Tool>> =
+        HashMap::from([(flaky.name().to_string(), flaky as Arc<dyn Tool>)]);
+    let node = ReActToolNode::new(tools_map, ToolFailurePolicy::FailFast)
+        .with_max_retries(2)
+        .with_retry_backoff(Duration::ZERO);
+    let recorder = Arc::new(ToolRecorder::default());
+    let manager = CallbackManager::new(vec![recorder.clone()]);
+    let node_run = RunContext::root(
+        RunType::Chain,
+        "tools".to_string(),
+        vec![],
+        Default::default(),
+    );
+
+    with_run_context(
+        manager,
+        node_run.clone(),
+        node.invoke_with_context(flaky_call_input(), &tool_context()),
+    )
+    .await
+    .expect("tool should succeed");
+
+    let parent = Some(node_run.run_id);
+    assert_eq!(
+        *recorder.events.lock().unwrap(),
+        vec![
+            ("start".to_string(), "flaky_tool".to_string(), parent),
+            ("retry".to_string(), "1".to_string(), parent),
+            ("retry".to_string(), "2".to_string(), parent),
+            ("end".to_string(), "flaky_tool".to_string(), parent),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn failed_tool_runs_end_with_on_error() {
+    let tool = Arc::new(FlakyTool {
+        failures: 1,
+        calls: AtomicUsize::new(0),
+    });
+    let tools_map: HashMap<String, Arc<dyn Tool>> =
+        HashMap::from([(tool.name().to_string(), tool as Arc<dyn Tool>)]);
+    let node = ReActToolNode::new(tools_map, ToolFailurePolicy::AppendErrorAndContinue);
+    let recorder = Arc::new(ToolRecorder::default());
+    let manager = CallbackManager::new(vec![recorder.clone()]);
+    let node_run = RunContext::root(
+        RunType::Chain,
+        "tools".to_string(),
+        vec![],
+        Default::default(),
+    );
+
+    with_run_context(
+        manager,
+        node_run.clone(),
+        node.invoke_with_context(flaky_call_input(), &tool_context()),
+    )
+    .await
+    .expect("error should be appended as an observation");
+
+    let events = recorder.events.lock().unwrap();
+    let kinds: Vec<&str> = events.iter().map(|(kind, _, _)| kind.as_str()).collect();
+    assert_eq!(kinds, vec!["start", "error"]);
+}
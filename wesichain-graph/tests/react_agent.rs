@@ -1,4 +1,5 @@
 #![allow(deprecated)]
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use futures::StreamExt;
@@ -6,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use wesichain_core::{
     HasFinalOutput, HasUserInput, LlmRequest, LlmResponse, ReActStep, Runnable, ScratchpadState,
-    Tool, ToolCall, ToolCallingLlm, ToolError, Value, WesichainError,
+    TokenUsage, Tool, ToolCall, ToolCallingLlm, ToolError, UsageAccumulator, Value, WesichainError,
 };
 use wesichain_graph::{ExecutionOptions, GraphBuilder, GraphState, ReActAgentNode, StateSchema};
 
@@ -94,6 +95,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockLlm {
             }],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
     }
 
@@ -136,3 +138,85 @@ async fn react_agent_executes_tool_and_finishes() {
         .iter()
         .any(|step| matches!(step, ReActStep::Observation(_))));
 }
+
+/// Requests the calculator on the first call and answers on the second,
+/// reporting the same usage each time.
+struct TwoTurnLlm {
+    calls: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, LlmResponse> for TwoTurnLlm {
+    async fn invoke(&self, _request: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        let first = self.calls.fetch_add(1, Ordering::SeqCst) == 0;
+        let tool_calls = if first {
+            vec![ToolCall {
+                id: "c1".to_string(),
+                name: "calculator".to_string(),
+                args: json!({"expression": "2+2"}),
+            }]
+        } else {
+            vec![]
+        };
+        Ok(LlmResponse {
+            content: if first {
+                String::new()
+            } else {
+                "4".to_string()
+            },
+            tool_calls,
+            usage: Some(TokenUsage {
+                prompt_tokens: 10,
+                completion_tokens: 5,
+                total_tokens: 15,
+            }),
+            ..Default::default()
+        })
+    }
+
+    fn stream(
+        &self,
+        _input: LlmRequest,
+    ) -> futures::stream::BoxStream<'_, Result<wesichain_core::StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+impl ToolCallingLlm for TwoTurnLlm {}
+
+#[tokio::test]
+async fn react_agent_reports_usage_to_run_accumulator() {
+    let node = ReActAgentNode::builder()
+        .llm(Arc::new(TwoTurnLlm {
+            calls: AtomicUsize::new(0),
+        }))
+        .tools(vec![Arc::new(MockTool)])
+        .build()
+        .unwrap();
+    let graph = GraphBuilder::new()
+        .add_node("agent", node)
+        .set_entry("agent")
+        .build();
+
+    let usage = Arc::new(UsageAccumulator::new());
+    let options = ExecutionOptions {
+        usage: Some(usage.clone()),
+        ..Default::default()
+    };
+    let state = GraphState::new(DemoState {
+        input: "2+2".to_string(),
+        ..Default::default()
+    });
+    let out = graph.invoke_with_options(state, options).await.unwrap();
+
+    assert_eq!(out.data.final_output.as_deref(), Some("4"));
+    assert_eq!(usage.calls(), 2);
+    assert_eq!(
+        usage.total(),
+        TokenUsage {
+            prompt_tokens: 20,
+            completion_tokens: 10,
+            total_tokens: 30,
+        }
+    );
+}
@@ -5,7 +5,7 @@ use wesichain_core::{
     HasFinalOutput, HasUserInput, LlmRequest, LlmResponse, ReActStep, Runnable, ScratchpadState,
     StreamEvent, Tool, ToolCallingLlm, ToolError, Value, WesichainError,
 };
-use wesichain_graph::{GraphState, ReActGraphBuilder, StateSchema};
+use wesichain_graph::{GraphEvent, GraphState, ReActGraphBuilder, StateSchema};
 
 // --- Mock State ---
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -154,6 +154,7 @@ async fn test_react_subgraph_execution() {
             }],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         },
         // Response 2: Final answer
         LlmResponse {
@@ -161,6 +162,7 @@ async fn test_react_subgraph_execution() {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         },
     ];
 
@@ -205,3 +207,95 @@ async fn test_react_subgraph_execution() {
         _ => panic!("Expected FinalAnswer"),
     }
 }
+
+struct StreamingTool;
+
+#[async_trait::async_trait]
+impl Tool for StreamingTool {
+    fn name(&self) -> &str {
+        "tail"
+    }
+    fn description(&self) -> &str {
+        "streams lines"
+    }
+    fn schema(&self) -> Value {
+        Value::Null
+    }
+    async fn invoke(&self, _args: Value) -> Result<Value, ToolError> {
+        panic!("streaming tools are run through invoke_stream")
+    }
+    fn streams_output(&self) -> bool {
+        true
+    }
+    fn invoke_stream(&self, _args: Value) -> BoxStream<'_, Result<Value, ToolError>> {
+        stream::iter(["line 1\n", "line 2\n", "line 3\n"])
+            .map(|line| Ok(Value::String(line.to_string())))
+            .boxed()
+    }
+}
+
+fn streaming_tool_graph() -> wesichain_graph::ExecutableGraph<MockState> {
+    let call_tool = LlmResponse {
+        content: String::new(),
+        tool_calls: vec![wesichain_core::ToolCall {
+            id: "call_1".to_string(),
+            name: "tail".to_string(),
+            args: Value::Null,
+        }],
+        usage: None,
+        model: String::new(),
+        finish_reason: None,
+    };
+    let answer = LlmResponse {
+        content: "Done".to_string(),
+        tool_calls: vec![],
+        usage: None,
+        model: String::new(),
+        finish_reason: None,
+    };
+
+    ReActGraphBuilder::new()
+        .llm(Arc::new(MockLlm::new(vec![call_tool, answer])))
+        .tools(vec![Arc::new(StreamingTool)])
+        .build::<MockState>()
+        .expect("Failed to build graph")
+}
+
+#[tokio::test]
+async fn streaming_tool_chunks_are_reported_in_order() {
+    let input = MockState {
+        input: "Hello".to_string(),
+        ..Default::default()
+    };
+
+    let events: Vec<_> = streaming_tool_graph()
+        .stream_invoke(GraphState::new(input.clone()))
+        .collect()
+        .await;
+    let chunks: Vec<(String, String)> = events
+        .into_iter()
+        .filter_map(|event| match event {
+            Ok(GraphEvent::NodeStatus { node, message, .. }) => Some((node, message)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        chunks,
+        vec![
+            ("tools".to_string(), "line 1\n".to_string()),
+            ("tools".to_string(), "line 2\n".to_string()),
+            ("tools".to_string(), "line 3\n".to_string()),
+        ]
+    );
+
+    let result = streaming_tool_graph()
+        .invoke(GraphState::new(input))
+        .await
+        .expect("Execution failed");
+    assert!(result
+        .data
+        .scratchpad
+        .contains(&ReActStep::Observation(Value::String(
+            "line 1\nline 2\nline 3\n".to_string()
+        ))));
+}
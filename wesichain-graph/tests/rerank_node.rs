@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use wesichain_core::{
+    Document, HasQuery, HasRetrievedDocs, LocalScoreReranker, ReadRetrievedDocs, Runnable,
+};
+use wesichain_graph::{GraphState, RerankNode, StateSchema, StateUpdate};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct RagState {
+    query: String,
+    docs: Vec<Document>,
+}
+
+impl StateSchema for RagState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+impl HasQuery for RagState {
+    fn query(&self) -> &str {
+        &self.query
+    }
+}
+
+impl HasRetrievedDocs for RagState {
+    fn set_retrieved_docs(&mut self, docs: Vec<Document>) {
+        self.docs = docs;
+    }
+}
+
+impl ReadRetrievedDocs for RagState {
+    fn retrieved_docs(&self) -> &[Document] {
+        &self.docs
+    }
+}
+
+fn doc(id: &str, content: &str) -> Document {
+    Document {
+        id: id.to_string(),
+        content: content.to_string(),
+        metadata: HashMap::new(),
+        embedding: None,
+    }
+}
+
+/// Counts query terms present in the document.
+fn term_overlap() -> LocalScoreReranker {
+    LocalScoreReranker::new(|query, doc| {
+        query
+            .split_whitespace()
+            .filter(|term| doc.content.contains(term))
+            .count() as f32
+    })
+}
+
+#[tokio::test]
+async fn rerank_node_reorders_and_truncates_docs() {
+    let node = RerankNode::new(Arc::new(term_overlap()), 2);
+    let state = GraphState::new(RagState {
+        query: "rust async runtime".to_string(),
+        docs: vec![
+            doc("none", "python scripting"),
+            doc("one", "rust ownership"),
+            doc("three", "rust async runtime internals"),
+            doc("two", "async rust"),
+        ],
+    });
+
+    let update: StateUpdate<RagState> = node.invoke(state).await.unwrap();
+    let ids: Vec<_> = update.data.docs.iter().map(|doc| doc.id.as_str()).collect();
+    assert_eq!(ids, vec!["three", "two"]);
+}
+
+#[tokio::test]
+async fn rerank_node_keeps_input_order_for_ties() {
+    let node = RerankNode::new(Arc::new(LocalScoreReranker::new(|_, _| 1.0)), 10);
+    let state = GraphState::new(RagState {
+        query: "anything".to_string(),
+        docs: vec![doc("a", "x"), doc("b", "y"), doc("c", "z")],
+    });
+
+    let update: StateUpdate<RagState> = node.invoke(state).await.unwrap();
+    let ids: Vec<_> = update.data.docs.iter().map(|doc| doc.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "b", "c"]);
+}
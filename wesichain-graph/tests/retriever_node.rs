@@ -62,3 +62,111 @@ async fn retriever_node_updates_state() {
     let update: StateUpdate<DemoState> = node.invoke(state).await.unwrap();
     assert_eq!(update.data.docs.len(), 1);
 }
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct AdaptiveState {
+    query: String,
+    top_k: Option<usize>,
+    source: Option<String>,
+    docs: Vec<Document>,
+}
+
+impl StateSchema for AdaptiveState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+impl HasQuery for AdaptiveState {
+    fn query(&self) -> &str {
+        &self.query
+    }
+
+    fn top_k(&self) -> Option<usize> {
+        self.top_k
+    }
+}
+
+impl HasRetrievedDocs for AdaptiveState {
+    fn set_retrieved_docs(&mut self, docs: Vec<Document>) {
+        self.docs = docs;
+    }
+}
+
+impl HasMetadataFilter for AdaptiveState {
+    fn metadata_filter(&self) -> Option<MetadataFilter> {
+        self.source
+            .clone()
+            .map(|source| MetadataFilter::Eq("source".to_string(), source.into()))
+    }
+}
+
+async fn seeded_store(embedder: &HashEmbedder) -> Arc<InMemoryVectorStore> {
+    let store = Arc::new(InMemoryVectorStore::new());
+    let mut docs = Vec::new();
+    for (id, source) in [
+        ("a1", "alpha"),
+        ("a2", "alpha"),
+        ("b1", "beta"),
+        ("b2", "beta"),
+    ] {
+        let content = format!("rust retrieval {id}");
+        docs.push(Document {
+            id: id.to_string(),
+            embedding: Some(embedder.embed(&content).await.unwrap()),
+            content,
+            metadata: HashMap::from([("source".to_string(), source.into())]),
+        });
+    }
+    store.add(docs).await.unwrap();
+    store
+}
+
+#[tokio::test]
+async fn retriever_node_uses_filter_and_top_k_from_state() {
+    let embedder = Arc::new(HashEmbedder::new(8));
+    let store = seeded_store(&embedder).await;
+    let node = RetrieverNode::new(embedder, store, 10, None);
+
+    let unfiltered: StateUpdate<AdaptiveState> = node
+        .invoke(GraphState::new(AdaptiveState {
+            query: "rust retrieval".to_string(),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    assert_eq!(unfiltered.data.docs.len(), 4);
+
+    let filtered: StateUpdate<AdaptiveState> = node
+        .invoke(GraphState::new(AdaptiveState {
+            query: "rust retrieval".to_string(),
+            source: Some("beta".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    let mut ids: Vec<_> = filtered
+        .data
+        .docs
+        .iter()
+        .map(|doc| doc.id.as_str())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["b1", "b2"]);
+
+    let narrowed: StateUpdate<AdaptiveState> = node
+        .invoke(GraphState::new(AdaptiveState {
+            query: "rust retrieval".to_string(),
+            top_k: Some(1),
+            source: Some("alpha".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    assert_eq!(narrowed.data.docs.len(), 1);
+    assert_eq!(
+        narrowed.data.docs[0].metadata.get("source"),
+        Some(&"alpha".into())
+    );
+}
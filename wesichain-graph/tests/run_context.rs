@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use wesichain_core::WesichainError;
+use wesichain_graph::{
+    ExecutionOptions, GraphBuilder, GraphContext, GraphNode, GraphRunContext, GraphState,
+    StateSchema, StateUpdate, END,
+};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct LocaleState {
+    seen: Vec<String>,
+}
+
+impl StateSchema for LocaleState {
+    type Update = Self;
+
+    fn apply(current: &Self, update: Self) -> Self {
+        let mut seen = current.seen.clone();
+        seen.extend(update.seen);
+        LocaleState { seen }
+    }
+}
+
+struct ReadLocale {
+    name: &'static str,
+    contexts: Arc<Mutex<Vec<Arc<GraphRunContext>>>>,
+}
+
+#[async_trait::async_trait]
+impl GraphNode<LocaleState> for ReadLocale {
+    async fn invoke_with_context(
+        &self,
+        _: GraphState<LocaleState>,
+        context: &GraphContext,
+    ) -> Result<StateUpdate<LocaleState>, WesichainError> {
+        self.contexts
+            .lock()
+            .unwrap()
+            .push(context.run_context.clone());
+        let locale = context
+            .get("locale")
+            .and_then(|value| value.as_str())
+            .unwrap_or("missing");
+        Ok(StateUpdate::new(LocaleState {
+            seen: vec![format!("{}:{locale}", self.name)],
+        }))
+    }
+}
+
+fn locale_graph(
+    contexts: &Arc<Mutex<Vec<Arc<GraphRunContext>>>>,
+) -> wesichain_graph::ExecutableGraph<LocaleState> {
+    GraphBuilder::new()
+        .add_node(
+            "first",
+            ReadLocale {
+                name: "first",
+                contexts: contexts.clone(),
+            },
+        )
+        .add_node(
+            "second",
+            ReadLocale {
+                name: "second",
+                contexts: contexts.clone(),
+            },
+        )
+        .add_edge("first", "second")
+        .add_edge("second", END)
+        .set_entry("first")
+        .build()
+}
+
+#[tokio::test]
+async fn nodes_share_injected_run_context() {
+    let contexts = Arc::new(Mutex::new(Vec::new()));
+    let options = ExecutionOptions {
+        context: HashMap::from([("locale".to_string(), json!("fr-CA"))]),
+        ..Default::default()
+    };
+
+    let out = locale_graph(&contexts)
+        .invoke_graph_with_options(GraphState::new(LocaleState::default()), options)
+        .await
+        .unwrap();
+
+    assert_eq!(out.data.seen, vec!["first:fr-CA", "second:fr-CA"]);
+    let contexts = contexts.lock().unwrap();
+    assert_eq!(contexts.len(), 2);
+    assert!(Arc::ptr_eq(&contexts[0], &contexts[1]));
+}
+
+#[tokio::test]
+async fn run_context_is_empty_by_default() {
+    let contexts = Arc::new(Mutex::new(Vec::new()));
+
+    let out = locale_graph(&contexts)
+        .invoke_graph(GraphState::new(LocaleState::default()))
+        .await
+        .unwrap();
+
+    assert_eq!(out.data.seen, vec!["first:missing", "second:missing"]);
+    assert!(contexts.lock().unwrap()[0].values().is_empty());
+}
@@ -0,0 +1,257 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_graph::{
+    GraphBuilder, GraphError, GraphState, StateSchema, StateUpdate, StreamingNode, SubgraphNode,
+    END, START,
+};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct ParentState {
+    input: i64,
+    result: Option<i64>,
+    trail: Vec<String>,
+}
+
+impl StateSchema for ParentState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct MathState {
+    value: i64,
+    ops: Vec<String>,
+}
+
+impl StateSchema for MathState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+struct Double;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<MathState>, StateUpdate<MathState>> for Double {
+    async fn invoke(
+        &self,
+        input: GraphState<MathState>,
+    ) -> Result<StateUpdate<MathState>, WesichainError> {
+        let mut state = input.data;
+        state.value *= 2;
+        state.ops.push("double".to_string());
+        Ok(StateUpdate::new(state))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<MathState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+struct AddOne;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<MathState>, StateUpdate<MathState>> for AddOne {
+    async fn invoke(
+        &self,
+        input: GraphState<MathState>,
+    ) -> Result<StateUpdate<MathState>, WesichainError> {
+        let mut state = input.data;
+        state.value += 1;
+        state.ops.push("add_one".to_string());
+        Ok(StateUpdate::new(state))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<MathState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+struct Failing;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<MathState>, StateUpdate<MathState>> for Failing {
+    async fn invoke(
+        &self,
+        _input: GraphState<MathState>,
+    ) -> Result<StateUpdate<MathState>, WesichainError> {
+        Err(WesichainError::Custom("division by zero".to_string()))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<MathState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+struct Announce;
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<MathState>, StateUpdate<MathState>> for Announce {
+    async fn invoke(
+        &self,
+        _input: GraphState<MathState>,
+    ) -> Result<StateUpdate<MathState>, WesichainError> {
+        panic!("streaming nodes are driven through stream")
+    }
+
+    fn stream(
+        &self,
+        input: GraphState<MathState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::iter(vec![Ok(StreamEvent::ContentChunk(format!(
+            "value={}",
+            input.data.value
+        )))])
+        .boxed()
+    }
+}
+
+impl StreamingNode<MathState> for Announce {
+    fn finish(
+        &self,
+        input: GraphState<MathState>,
+        _events: Vec<StreamEvent>,
+    ) -> Result<StateUpdate<MathState>, WesichainError> {
+        Ok(StateUpdate::new(input.data))
+    }
+}
+
+struct Mark(&'static str);
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<ParentState>, StateUpdate<ParentState>> for Mark {
+    async fn invoke(
+        &self,
+        input: GraphState<ParentState>,
+    ) -> Result<StateUpdate<ParentState>, WesichainError> {
+        let mut state = input.data;
+        state.trail.push(self.0.to_string());
+        Ok(StateUpdate::new(state))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<ParentState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+fn math_node(
+    graph: wesichain_graph::ExecutableGraph<MathState>,
+) -> SubgraphNode<MathState, ParentState> {
+    SubgraphNode::new(
+        graph,
+        |parent: &ParentState| MathState {
+            value: parent.input,
+            ops: Vec::new(),
+        },
+        |parent: &mut ParentState, inner: MathState| {
+            parent.result = Some(inner.value);
+            parent.trail.extend(inner.ops);
+        },
+    )
+}
+
+fn parent_graph(
+    inner: wesichain_graph::ExecutableGraph<MathState>,
+) -> wesichain_graph::ExecutableGraph<ParentState> {
+    GraphBuilder::new()
+        .add_node("before", Mark("before"))
+        .add_node("math", math_node(inner))
+        .add_node("after", Mark("after"))
+        .add_edge(START, "before")
+        .add_edge("before", "math")
+        .add_edge("math", "after")
+        .add_edge("after", END)
+        .set_entry("before")
+        .build()
+}
+
+#[tokio::test]
+async fn subgraph_node_runs_differently_typed_graph() {
+    let inner = GraphBuilder::new()
+        .add_node("double", Double)
+        .add_node("add_one", AddOne)
+        .add_edge(START, "double")
+        .add_edge("double", "add_one")
+        .add_edge("add_one", END)
+        .set_entry("double")
+        .build();
+
+    let state = GraphState::new(ParentState {
+        input: 5,
+        ..Default::default()
+    });
+    let out = parent_graph(inner).invoke_graph(state).await.unwrap();
+
+    assert_eq!(out.data.input, 5);
+    assert_eq!(out.data.result, Some(11));
+    assert_eq!(out.data.trail, vec!["before", "double", "add_one", "after"]);
+}
+
+#[tokio::test]
+async fn subgraph_node_reports_inner_failure_as_node_failed() {
+    let inner = GraphBuilder::new()
+        .add_node("double", Double)
+        .add_node("divide", Failing)
+        .add_edge(START, "double")
+        .add_edge("double", "divide")
+        .add_edge("divide", END)
+        .set_entry("double")
+        .build();
+
+    let state = GraphState::new(ParentState::default());
+    let err = parent_graph(inner).invoke_graph(state).await.unwrap_err();
+
+    match err {
+        GraphError::NodeFailed { node, source } => {
+            assert_eq!(node, "math");
+            let message = source.to_string();
+            assert!(!message.contains("math"), "{message}");
+            assert!(message.contains("node failed: divide"), "{message}");
+            assert!(message.contains("division by zero"), "{message}");
+        }
+        other => panic!("expected NodeFailed, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn subgraph_node_stream_forwards_inner_graph_stream() {
+    let inner = GraphBuilder::new()
+        .add_node("double", Double)
+        .add_streaming_node("announce", Announce)
+        .add_edge(START, "double")
+        .add_edge("double", "announce")
+        .add_edge("announce", END)
+        .set_entry("double")
+        .build();
+
+    let state = GraphState::new(ParentState {
+        input: 4,
+        ..Default::default()
+    });
+    let events: Vec<_> = math_node(inner)
+        .stream(state)
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    assert!(matches!(
+        events.as_slice(),
+        [StreamEvent::ContentChunk(chunk)] if chunk == "value=8"
+    ));
+}
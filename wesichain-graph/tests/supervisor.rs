@@ -39,6 +39,7 @@ impl Runnable<LlmRequest, LlmResponse> for SequenceLlm {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
     }
 
@@ -0,0 +1,155 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use wesichain_core::{
+    CallbackHandler, CallbackManager, RunConfig, RunContext, Value, WesichainError,
+};
+use wesichain_graph::{
+    Checkpointer, ExecutionOptions, GraphBuilder, GraphContext, GraphError, GraphNode, GraphState,
+    InMemoryCheckpointer, StateSchema, StateUpdate, END,
+};
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct CountState {
+    count: u32,
+}
+
+impl StateSchema for CountState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+struct Increment {
+    delay: Duration,
+}
+
+#[async_trait::async_trait]
+impl GraphNode<CountState> for Increment {
+    async fn invoke_with_context(
+        &self,
+        input: GraphState<CountState>,
+        _: &GraphContext,
+    ) -> Result<StateUpdate<CountState>, WesichainError> {
+        tokio::time::sleep(self.delay).await;
+        Ok(StateUpdate::new(CountState {
+            count: input.data.count + 1,
+        }))
+    }
+}
+
+#[derive(Default)]
+struct EventRecorder {
+    events: Mutex<Vec<(String, Value)>>,
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for EventRecorder {
+    async fn on_start(&self, _ctx: &RunContext, _inputs: &Value) {}
+
+    async fn on_end(&self, _ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {}
+
+    async fn on_error(&self, _ctx: &RunContext, _error: &Value, _duration_ms: u128) {}
+
+    async fn on_event(&self, _ctx: &RunContext, event: &str, data: &Value) {
+        self.events
+            .lock()
+            .unwrap()
+            .push((event.to_string(), data.clone()));
+    }
+}
+
+fn two_step_graph(
+    checkpointer: &InMemoryCheckpointer<CountState>,
+) -> wesichain_graph::ExecutableGraph<CountState> {
+    GraphBuilder::new()
+        .add_node(
+            "slow",
+            Increment {
+                delay: Duration::from_millis(50),
+            },
+        )
+        .add_node(
+            "after",
+            Increment {
+                delay: Duration::ZERO,
+            },
+        )
+        .add_edge("slow", "after")
+        .add_edge("after", END)
+        .set_entry("slow")
+        .with_checkpointer(checkpointer.clone(), "thread-1")
+        .build()
+}
+
+#[tokio::test]
+async fn global_timeout_saves_resumable_checkpoint() {
+    let checkpointer = InMemoryCheckpointer::default();
+    let graph = two_step_graph(&checkpointer);
+
+    let err = graph
+        .invoke_graph_with_options(
+            GraphState::new(CountState::default()),
+            ExecutionOptions {
+                max_duration: Some(Duration::from_millis(10)),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("run should time out");
+    assert!(matches!(err, GraphError::Timeout { .. }));
+
+    let checkpoint = checkpointer
+        .load("thread-1")
+        .await
+        .unwrap()
+        .expect("timeout should leave a checkpoint");
+    assert_eq!(checkpoint.node, "after");
+    assert_eq!(checkpoint.state.data.count, 1);
+    assert!(checkpoint.queue.iter().any(|(node, _)| node == "after"));
+
+    let resumed = graph
+        .resume(checkpoint, ExecutionOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(resumed.data.count, 2);
+}
+
+#[tokio::test]
+async fn max_steps_exceeded_saves_resumable_checkpoint() {
+    let checkpointer = InMemoryCheckpointer::default();
+    let graph = two_step_graph(&checkpointer);
+    let recorder = Arc::new(EventRecorder::default());
+
+    let err = graph
+        .invoke_graph_with_options(
+            GraphState::new(CountState::default()),
+            ExecutionOptions {
+                max_steps: Some(1),
+                run_config: Some(RunConfig {
+                    callbacks: Some(CallbackManager::new(vec![recorder.clone()])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("run should exceed max steps");
+    assert!(matches!(err, GraphError::MaxStepsExceeded { .. }));
+
+    let checkpoint = checkpointer.load("thread-1").await.unwrap().unwrap();
+    assert_eq!(checkpoint.node, "after");
+    assert_eq!(checkpoint.state.data.count, 1);
+    assert!(recorder.events.lock().unwrap().contains(&(
+        "checkpoint_saved".to_string(),
+        serde_json::json!({"node_id": "after"})
+    )));
+
+    let resumed = graph
+        .resume(checkpoint, ExecutionOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(resumed.data.count, 2);
+}
@@ -76,3 +76,69 @@ async fn tool_node_executes_calls_and_appends_results() {
         Some("1".to_string())
     );
 }
+
+/// Sleeps for `args.delay_ms` before echoing, so later calls can finish first.
+struct SlowEcho;
+
+#[async_trait::async_trait]
+impl Tool for SlowEcho {
+    fn name(&self) -> &str {
+        "slow_echo"
+    }
+
+    fn description(&self) -> &str {
+        "echo after a delay"
+    }
+
+    fn schema(&self) -> Value {
+        serde_json::json!({"type": "object"})
+    }
+
+    async fn invoke(&self, input: Value) -> Result<Value, ToolError> {
+        let delay = input["delay_ms"].as_u64().unwrap_or(0);
+        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+        Ok(input["text"].clone())
+    }
+}
+
+#[tokio::test]
+async fn tool_node_tags_parallel_results_with_their_call_ids() {
+    let calls = vec![
+        ToolCall {
+            id: "call_slow".into(),
+            name: "slow_echo".into(),
+            args: serde_json::json!({"text": "slow", "delay_ms": 50}),
+        },
+        ToolCall {
+            id: "call_fast".into(),
+            name: "slow_echo".into(),
+            args: serde_json::json!({"text": "fast", "delay_ms": 0}),
+        },
+    ];
+    let state = GraphState::new(AgentState {
+        tool_calls: calls,
+        tool_results: Vec::new(),
+    });
+    let node = ToolNode::new(vec![Arc::new(SlowEcho)]);
+
+    let update = node.invoke(state).await.unwrap();
+
+    let results: Vec<_> = update
+        .data
+        .tool_results
+        .iter()
+        .map(|message| {
+            (
+                message.tool_call_id.as_deref(),
+                message.content.to_text_lossy(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            (Some("call_slow"), "\"slow\"".to_string()),
+            (Some("call_fast"), "\"fast\"".to_string()),
+        ]
+    );
+}
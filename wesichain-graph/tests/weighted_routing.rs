@@ -0,0 +1,154 @@
+use futures::stream::StreamExt;
+use serde::{Deserialize, Serialize};
+use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_graph::{ExecutionConfig, GraphBuilder, GraphState, StateSchema, StateUpdate, END};
+
+const ROUNDS: u32 = 400;
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+struct RouteState {
+    rounds: u32,
+    path: String,
+}
+
+impl StateSchema for RouteState {
+    type Update = Self;
+    fn apply(_: &Self, update: Self) -> Self {
+        update
+    }
+}
+
+struct Record(&'static str);
+
+#[async_trait::async_trait]
+impl Runnable<GraphState<RouteState>, StateUpdate<RouteState>> for Record {
+    async fn invoke(
+        &self,
+        input: GraphState<RouteState>,
+    ) -> Result<StateUpdate<RouteState>, WesichainError> {
+        let mut data = input.data;
+        data.path.push_str(self.0);
+        if self.0 == "r" {
+            data.rounds += 1;
+        }
+        Ok(StateUpdate::new(data))
+    }
+
+    fn stream(
+        &self,
+        _input: GraphState<RouteState>,
+    ) -> futures::stream::BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        futures::stream::empty().boxed()
+    }
+}
+
+fn unbounded_config(seed: u64) -> ExecutionConfig {
+    ExecutionConfig {
+        max_steps: None,
+        max_visits: None,
+        max_loop_iterations: None,
+        cycle_detection: false,
+        routing_seed: Some(seed),
+        ..Default::default()
+    }
+}
+
+fn ab_graph(seed: u64) -> wesichain_graph::ExecutableGraph<RouteState> {
+    GraphBuilder::new()
+        .with_default_config(unbounded_config(seed))
+        .add_node("router", Record("r"))
+        .add_node("a", Record("a"))
+        .add_node("b", Record("b"))
+        .add_weighted_conditional_edge("router", |state: &GraphState<RouteState>| {
+            if state.data.rounds > ROUNDS {
+                vec![(END.to_string(), 1.0)]
+            } else {
+                vec![("a".to_string(), 3.0), ("b".to_string(), 1.0)]
+            }
+        })
+        .add_edge("a", "router")
+        .add_edge("b", "router")
+        .set_entry("router")
+        .build()
+}
+
+#[tokio::test]
+async fn weighted_edge_follows_normalized_weights() {
+    let out = ab_graph(7)
+        .invoke(GraphState::new(RouteState::default()))
+        .await
+        .unwrap();
+
+    let a = out.data.path.matches('a').count();
+    let b = out.data.path.matches('b').count();
+    assert_eq!(a + b, ROUNDS as usize);
+    // Expected 300/100 for a 3:1 split; allow generous sampling slack.
+    assert!((260..=340).contains(&a), "a selected {a} times");
+    assert!((60..=140).contains(&b), "b selected {b} times");
+}
+
+#[tokio::test]
+async fn weighted_edge_is_deterministic_for_a_fixed_seed() {
+    let first = ab_graph(42)
+        .invoke(GraphState::new(RouteState::default()))
+        .await
+        .unwrap();
+    let second = ab_graph(42)
+        .invoke(GraphState::new(RouteState::default()))
+        .await
+        .unwrap();
+
+    assert_eq!(first.data.path, second.data.path);
+}
+
+#[tokio::test]
+async fn weighted_edge_routes_to_end_on_invalid_weights() {
+    for weights in [
+        vec![("a".to_string(), 0.0), ("b".to_string(), 0.0)],
+        vec![("a".to_string(), 2.0), ("b".to_string(), -1.0)],
+        vec![],
+    ] {
+        let graph = GraphBuilder::new()
+            .with_default_config(unbounded_config(1))
+            .add_node("router", Record("r"))
+            .add_node("a", Record("a"))
+            .add_node("b", Record("b"))
+            .add_weighted_conditional_edge("router", move |_: &GraphState<RouteState>| {
+                weights.clone()
+            })
+            .set_entry("router")
+            .build();
+
+        let out = graph
+            .invoke(GraphState::new(RouteState::default()))
+            .await
+            .unwrap();
+        assert_eq!(out.data.path, "r");
+    }
+}
+
+#[tokio::test]
+async fn weighted_edge_never_selects_zero_weight_targets() {
+    let graph = GraphBuilder::new()
+        .with_default_config(unbounded_config(3))
+        .add_node("router", Record("r"))
+        .add_node("a", Record("a"))
+        .add_node("b", Record("b"))
+        .add_weighted_conditional_edge("router", |state: &GraphState<RouteState>| {
+            if state.data.rounds >= 50 {
+                vec![(END.to_string(), 1.0)]
+            } else {
+                vec![("a".to_string(), 0.0), ("b".to_string(), 1.0)]
+            }
+        })
+        .add_edge("a", "router")
+        .add_edge("b", "router")
+        .set_entry("router")
+        .build();
+
+    let out = graph
+        .invoke(GraphState::new(RouteState::default()))
+        .await
+        .unwrap();
+    assert!(!out.data.path.contains('a'));
+}
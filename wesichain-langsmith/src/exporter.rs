@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::sync::{
     atomic::{AtomicUsize, Ordering},
     Arc,
@@ -6,7 +6,7 @@ use std::sync::{
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use serde_json::{json, Value};
 use thiserror::Error;
 use tokio::sync::{Mutex, Notify};
@@ -21,6 +21,14 @@ pub struct FlushStats {
     pub runs_flushed: usize,
     pub events_flushed: usize,
     pub batches_flushed: usize,
+    /// Total serialized size of the run payloads sent.
+    pub bytes_sent: usize,
+    /// Runs in the flushed batches with a field (inputs, outputs, metadata,
+    /// error) cut down by `truncate_value` before being queued; a run counts
+    /// once per batch. Non-zero means trace data was dropped.
+    pub runs_truncated: usize,
+    /// Serialized size of the largest single run payload sent.
+    pub largest_run_bytes: usize,
 }
 
 #[derive(Debug, Error)]
@@ -40,12 +48,20 @@ struct ExporterInner {
     config: LangSmithConfig,
     client: LangSmithClient,
     store: Arc<RunContextStore>,
-    queue: Mutex<VecDeque<RunEvent>>,
+    queue: Mutex<VecDeque<QueuedEvent>>,
     dropped: AtomicUsize,
+    truncated_runs: DashSet<Uuid>,
     notify: Notify,
     dotted_orders: DashMap<Uuid, String>,
 }
 
+/// A queued event, flagged when a field of its run was truncated since the
+/// run's previous event was enqueued.
+struct QueuedEvent {
+    event: RunEvent,
+    truncated: bool,
+}
+
 impl LangSmithExporter {
     pub fn new(config: LangSmithConfig, store: Arc<RunContextStore>) -> Self {
         let client = LangSmithClient::new(config.api_url.clone(), config.api_key.clone());
@@ -55,6 +71,7 @@ impl LangSmithExporter {
             store,
             queue: Mutex::new(VecDeque::new()),
             dropped: AtomicUsize::new(0),
+            truncated_runs: DashSet::new(),
             notify: Notify::new(),
             dotted_orders: DashMap::new(),
         });
@@ -63,6 +80,11 @@ impl LangSmithExporter {
     }
 
     pub async fn enqueue(&self, event: RunEvent) {
+        let truncated = self
+            .inner
+            .truncated_runs
+            .remove(&event_run_id(&event))
+            .is_some();
         let mut queue = self.inner.queue.lock().await;
         let capacity = self.inner.config.queue_capacity;
         if capacity == 0 {
@@ -73,7 +95,7 @@ impl LangSmithExporter {
             queue.pop_front();
             self.inner.dropped.fetch_add(1, Ordering::Relaxed);
         }
-        queue.push_back(event);
+        queue.push_back(QueuedEvent { event, truncated });
         if queue.len() >= self.inner.config.max_batch_size {
             self.inner.notify.notify_one();
         }
@@ -83,6 +105,14 @@ impl LangSmithExporter {
         self.inner.dropped.load(Ordering::Relaxed)
     }
 
+    /// Notes that a field of `run_id` was truncated before being enqueued.
+    /// The flag travels with the run's next enqueued event and is counted in
+    /// [`FlushStats::runs_truncated`] when that event is flushed; it is lost
+    /// with the event if the queue drops it.
+    pub fn record_truncated_run(&self, run_id: Uuid) {
+        self.inner.truncated_runs.insert(run_id);
+    }
+
     pub async fn flush(&self, timeout: Duration) -> Result<FlushStats, FlushError> {
         match tokio::time::timeout(timeout, self.inner.flush_all()).await {
             Ok(result) => Ok(result?),
@@ -119,6 +149,9 @@ impl ExporterInner {
             stats.runs_flushed += batch_stats.runs_flushed;
             stats.events_flushed += batch_stats.events_flushed;
             stats.batches_flushed += batch_stats.batches_flushed;
+            stats.bytes_sent += batch_stats.bytes_sent;
+            stats.runs_truncated += batch_stats.runs_truncated;
+            stats.largest_run_bytes = stats.largest_run_bytes.max(batch_stats.largest_run_bytes);
         }
         Ok(stats)
     }
@@ -144,15 +177,22 @@ impl ExporterInner {
             batches_flushed: 1,
             ..Default::default()
         };
-        for event in batch {
-            self.send_event(event).await?;
+        let mut truncated_runs = HashSet::new();
+        for QueuedEvent { event, truncated } in batch {
+            if truncated && truncated_runs.insert(event_run_id(&event)) {
+                stats.runs_truncated += 1;
+            }
+            let bytes = self.send_event(event).await?;
             stats.events_flushed += 1;
             stats.runs_flushed += 1;
+            stats.bytes_sent += bytes;
+            stats.largest_run_bytes = stats.largest_run_bytes.max(bytes);
         }
         Ok(stats)
     }
 
-    async fn send_event(&self, event: RunEvent) -> Result<(), LangSmithError> {
+    /// Sends one event and returns the serialized size of its payload.
+    async fn send_event(&self, event: RunEvent) -> Result<usize, LangSmithError> {
         match event {
             RunEvent::Start {
                 run_id,
@@ -181,7 +221,8 @@ impl ExporterInner {
                     metadata,
                     session_name,
                 );
-                self.client.create_run(run_id, &payload).await
+                self.client.create_run(run_id, &payload).await?;
+                Ok(payload_size(&payload))
             }
             RunEvent::Update {
                 run_id,
@@ -202,7 +243,8 @@ impl ExporterInner {
                 }
                 let payload =
                     build_update_payload(end_time, resolved_outputs, resolved_error, duration_ms);
-                self.client.update_run(run_id, &payload).await
+                self.client.update_run(run_id, &payload).await?;
+                Ok(payload_size(&payload))
             }
         }
     }
@@ -284,6 +326,16 @@ fn build_update_payload(
     Value::Object(payload)
 }
 
+fn event_run_id(event: &RunEvent) -> Uuid {
+    match event {
+        RunEvent::Start { run_id, .. } | RunEvent::Update { run_id, .. } => *run_id,
+    }
+}
+
+fn payload_size(payload: &Value) -> usize {
+    serde_json::to_vec(payload).map_or(0, |bytes| bytes.len())
+}
+
 fn duration_to_u64(duration_ms: u128) -> u64 {
     u64::try_from(duration_ms).unwrap_or(u64::MAX)
 }
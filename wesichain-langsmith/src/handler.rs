@@ -9,7 +9,7 @@ use uuid::Uuid;
 use wesichain_core::{CallbackHandler, LlmInput, LlmResult, RunContext, RunType as CoreRunType};
 
 use crate::{
-    ensure_object, sanitize_value, truncate_value, FlushError, FlushStats, LangSmithConfig,
+    ensure_object, sanitize_value, truncate_value_checked, FlushError, FlushStats, LangSmithConfig,
     LangSmithExporter, ProbabilitySampler, RunContextStore, RunEvent, RunType, Sampler,
 };
 
@@ -68,21 +68,26 @@ impl LangSmithCallbackHandler {
         }
     }
 
-    fn sanitize_object(&self, value: Value) -> Value {
-        let sanitized = sanitize_value(value, self.redact_regex.as_ref());
-        let truncated = truncate_value(sanitized, self.max_bytes);
-        ensure_object(truncated)
+    fn sanitize_object(&self, run_id: Uuid, value: Value) -> Value {
+        ensure_object(self.sanitize_and_truncate(run_id, value))
     }
 
-    fn sanitize_error(&self, value: Value) -> String {
-        let sanitized = sanitize_value(value, self.redact_regex.as_ref());
-        let truncated = truncate_value(sanitized, self.max_bytes);
-        match truncated {
+    fn sanitize_error(&self, run_id: Uuid, value: Value) -> String {
+        match self.sanitize_and_truncate(run_id, value) {
             Value::String(text) => text,
             other => other.to_string(),
         }
     }
 
+    fn sanitize_and_truncate(&self, run_id: Uuid, value: Value) -> Value {
+        let sanitized = sanitize_value(value, self.redact_regex.as_ref());
+        let (truncated, was_truncated) = truncate_value_checked(sanitized, self.max_bytes);
+        if was_truncated {
+            self.exporter.record_truncated_run(run_id);
+        }
+        truncated
+    }
+
     fn map_run_type(run_type: &CoreRunType) -> RunType {
         match run_type {
             CoreRunType::Chain => RunType::Chain,
@@ -94,7 +99,7 @@ impl LangSmithCallbackHandler {
         }
     }
 
-    fn prepare_llm_inputs(&self, input: &LlmInput) -> Value {
+    fn prepare_llm_inputs(&self, run_id: Uuid, input: &LlmInput) -> Value {
         let mut invocation_params = serde_json::Map::new();
         if let Some(temp) = input.temperature {
             invocation_params.insert("temperature".to_string(), json!(temp));
@@ -108,12 +113,12 @@ impl LangSmithCallbackHandler {
 
         json!({
             "model": input.model,
-            "prompt": self.sanitize_object(Value::String(input.prompt.clone())),
+            "prompt": self.sanitize_object(run_id, Value::String(input.prompt.clone())),
             "invocation_params": invocation_params,
         })
     }
 
-    fn prepare_llm_outputs(&self, result: &LlmResult) -> Value {
+    fn prepare_llm_outputs(&self, run_id: Uuid, result: &LlmResult) -> Value {
         let mut outputs = serde_json::Map::new();
         outputs.insert("generations".to_string(), json!(result.generations));
         outputs.insert("model".to_string(), json!(result.model));
@@ -129,7 +134,7 @@ impl LangSmithCallbackHandler {
             );
         }
 
-        self.sanitize_object(Value::Object(outputs))
+        self.sanitize_object(run_id, Value::Object(outputs))
     }
 }
 
@@ -140,9 +145,9 @@ impl CallbackHandler for LangSmithCallbackHandler {
             return;
         }
 
-        let inputs = self.sanitize_object(inputs.clone());
+        let inputs = self.sanitize_object(ctx.run_id, inputs.clone());
         let metadata = serde_json::to_value(&ctx.metadata).unwrap_or(Value::Null);
-        let metadata = self.sanitize_object(metadata);
+        let metadata = self.sanitize_object(ctx.run_id, metadata);
         let event = RunEvent::Start {
             run_id: ctx.run_id,
             parent_run_id: ctx.parent_run_id,
@@ -163,7 +168,7 @@ impl CallbackHandler for LangSmithCallbackHandler {
             self.maybe_clear_trace(ctx);
             return;
         }
-        let outputs = self.sanitize_object(outputs.clone());
+        let outputs = self.sanitize_object(ctx.run_id, outputs.clone());
         let event = RunEvent::Update {
             run_id: ctx.run_id,
             end_time: Some(Utc::now()),
@@ -180,7 +185,7 @@ impl CallbackHandler for LangSmithCallbackHandler {
             self.maybe_clear_trace(ctx);
             return;
         }
-        let error = self.sanitize_error(error.clone());
+        let error = self.sanitize_error(ctx.run_id, error.clone());
         let event = RunEvent::Update {
             run_id: ctx.run_id,
             end_time: Some(Utc::now()),
@@ -197,9 +202,9 @@ impl CallbackHandler for LangSmithCallbackHandler {
             return;
         }
 
-        let inputs = self.prepare_llm_inputs(input);
+        let inputs = self.prepare_llm_inputs(ctx.run_id, input);
         let metadata = serde_json::to_value(&ctx.metadata).unwrap_or(Value::Null);
-        let metadata = self.sanitize_object(metadata);
+        let metadata = self.sanitize_object(ctx.run_id, metadata);
 
         let event = RunEvent::Start {
             run_id: ctx.run_id,
@@ -222,7 +227,7 @@ impl CallbackHandler for LangSmithCallbackHandler {
             return;
         }
 
-        let outputs = self.prepare_llm_outputs(result);
+        let outputs = self.prepare_llm_outputs(ctx.run_id, result);
 
         let event = RunEvent::Update {
             run_id: ctx.run_id,
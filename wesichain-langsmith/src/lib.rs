@@ -118,7 +118,6 @@ mod handler;
 mod observer;
 mod run_store;
 mod sampler;
-mod sanitize;
 
 pub use client::{LangSmithClient, LangSmithError};
 pub use config::LangSmithConfig;
@@ -128,4 +127,6 @@ pub use handler::LangSmithCallbackHandler;
 pub use observer::LangSmithObserver;
 pub use run_store::{RunContextStore, RunMetadata, RunUpdateDecision};
 pub use sampler::{ProbabilitySampler, Sampler};
-pub use sanitize::{ensure_object, sanitize_value, truncate_value};
+pub use wesichain_sanitize::{
+    ensure_object, sanitize_value, truncate_value, truncate_value_checked,
+};
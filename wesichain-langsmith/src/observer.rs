@@ -10,7 +10,7 @@ use uuid::Uuid;
 use wesichain_graph::{GraphError, Observer};
 
 use crate::{
-    ensure_object, sanitize_value, truncate_value, LangSmithConfig, LangSmithExporter,
+    ensure_object, sanitize_value, truncate_value_checked, LangSmithConfig, LangSmithExporter,
     ProbabilitySampler, RunEvent, RunType, Sampler,
 };
 
@@ -91,9 +91,12 @@ impl LangSmithObserver {
         self.exporter.flush(timeout).await
     }
 
-    fn prepare_value(&self, value: &Value) -> Value {
+    fn prepare_value(&self, run_id: Uuid, value: &Value) -> Value {
         let redacted = sanitize_value(value.clone(), self.redact_regex.as_ref());
-        let truncated = truncate_value(redacted, MAX_FIELD_BYTES);
+        let (truncated, was_truncated) = truncate_value_checked(redacted, MAX_FIELD_BYTES);
+        if was_truncated {
+            self.exporter.record_truncated_run(run_id);
+        }
         ensure_object(truncated)
     }
 
@@ -129,7 +132,7 @@ impl Observer for LangSmithObserver {
         if !context.sampled {
             return;
         }
-        let inputs = self.prepare_value(input);
+        let inputs = self.prepare_value(context.run_id, input);
         self.exporter
             .enqueue(RunEvent::Start {
                 run_id: context.run_id,
@@ -155,7 +158,7 @@ impl Observer for LangSmithObserver {
             self.node_runs.remove(node_id);
             return;
         }
-        let outputs = self.prepare_value(output);
+        let outputs = self.prepare_value(context.run_id, output);
         self.exporter
             .enqueue(RunEvent::Update {
                 run_id: context.run_id,
@@ -204,7 +207,7 @@ impl Observer for LangSmithObserver {
         let key = format!("{}::{}", node_id, tool_name);
         self.push_tool_run(key, run_id);
 
-        let inputs = self.prepare_value(args);
+        let inputs = self.prepare_value(run_id, args);
         self.exporter
             .enqueue(RunEvent::Start {
                 run_id,
@@ -234,7 +237,7 @@ impl Observer for LangSmithObserver {
             Some(id) => id,
             None => return,
         };
-        let outputs = self.prepare_value(result);
+        let outputs = self.prepare_value(run_id, result);
         self.exporter
             .enqueue(RunEvent::Update {
                 run_id,
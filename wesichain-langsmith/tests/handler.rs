@@ -5,6 +5,8 @@ use secrecy::SecretString;
 use serde_json::json;
 use uuid::Uuid;
 use wesichain_core::{CallbackHandler, RunContext, RunType};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use wesichain_langsmith::{LangSmithCallbackHandler, LangSmithConfig, Sampler};
 
@@ -39,3 +41,103 @@ async fn sampling_short_circuits_before_enqueue() {
     let stats = handler.flush(Duration::from_millis(50)).await.unwrap();
     assert_eq!(stats.runs_flushed, 0);
 }
+
+#[tokio::test]
+async fn flush_reports_payload_sizes_and_truncated_runs() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/runs"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let config = LangSmithConfig {
+        api_key: SecretString::new("key".to_string()),
+        api_url: server.uri(),
+        project_name: "test".to_string(),
+        flush_interval: Duration::from_secs(3600),
+        max_batch_size: 10,
+        queue_capacity: 10,
+        sampling_rate: 1.0,
+        redact_regex: None,
+    };
+    let handler = LangSmithCallbackHandler::new(config);
+
+    let oversized = RunContext::root(
+        RunType::Chain,
+        "big".to_string(),
+        vec![],
+        Default::default(),
+    );
+    handler
+        .on_start(&oversized, &json!({"text": "x".repeat(250_000)}))
+        .await;
+    // A second truncated field of the same run in the batch counts once.
+    handler
+        .on_end(&oversized, &json!({"text": "y".repeat(250_000)}), 5)
+        .await;
+    let small = RunContext::root(
+        RunType::Chain,
+        "small".to_string(),
+        vec![],
+        Default::default(),
+    );
+    handler.on_start(&small, &json!({"text": "hello"})).await;
+
+    let stats = handler.flush(Duration::from_secs(5)).await.unwrap();
+
+    assert_eq!(stats.runs_flushed, 3);
+    assert_eq!(stats.runs_truncated, 1);
+    assert!(stats.largest_run_bytes > 100_000, "{stats:?}");
+    assert!(stats.largest_run_bytes < 101_000, "{stats:?}");
+    assert!(stats.bytes_sent > stats.largest_run_bytes, "{stats:?}");
+}
+
+#[tokio::test]
+async fn dropped_event_does_not_carry_its_truncation_to_later_events() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/runs"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("PATCH"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let config = LangSmithConfig {
+        api_key: SecretString::new("key".to_string()),
+        api_url: server.uri(),
+        project_name: "test".to_string(),
+        flush_interval: Duration::from_secs(3600),
+        max_batch_size: 10,
+        queue_capacity: 1,
+        sampling_rate: 1.0,
+        redact_regex: None,
+    };
+    let handler = LangSmithCallbackHandler::new(config);
+
+    let oversized = RunContext::root(
+        RunType::Chain,
+        "big".to_string(),
+        vec![],
+        Default::default(),
+    );
+    handler
+        .on_start(&oversized, &json!({"text": "x".repeat(250_000)}))
+        .await;
+    // Evicts the truncated start event from the full queue.
+    handler
+        .on_end(&oversized, &json!({"text": "done"}), 5)
+        .await;
+    assert_eq!(handler.dropped_events(), 1);
+
+    let stats = handler.flush(Duration::from_secs(5)).await.unwrap();
+    assert_eq!(stats.runs_flushed, 1);
+    assert_eq!(stats.runs_truncated, 0);
+}
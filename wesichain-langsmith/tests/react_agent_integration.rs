@@ -101,6 +101,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockLlm {
             }],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
     }
 
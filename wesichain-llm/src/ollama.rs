@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::error::Category;
 use serde_json::Deserializer;
 
-use wesichain_core::{Runnable, StreamEvent, WesichainError};
+use wesichain_core::{ResponseFormat, Runnable, StreamEvent, Value, WesichainError};
 
 use crate::{LlmRequest, LlmResponse, Message, ToolCall, ToolSpec};
 
@@ -112,6 +112,33 @@ impl OllamaClient {
             http,
         })
     }
+
+    /// Use a pre-configured HTTP client, e.g. for proxies or custom TLS roots.
+    /// Replaces the default client and its 120s timeout.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.http = client;
+        self
+    }
+}
+
+/// Ollama has no prompt caching; drop hints rather than send unknown fields.
+fn without_cache_hints(messages: Vec<Message>) -> Vec<Message> {
+    messages
+        .into_iter()
+        .map(|message| Message {
+            cache_control: None,
+            ..message
+        })
+        .collect()
+}
+
+/// Ollama's `format` takes `"json"` or a JSON schema; text needs no option.
+fn ollama_format(response_format: Option<ResponseFormat>) -> Option<Value> {
+    match response_format? {
+        ResponseFormat::Text => None,
+        ResponseFormat::JsonObject => Some(Value::String("json".to_string())),
+        ResponseFormat::JsonSchema(schema) => Some(schema),
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -120,6 +147,8 @@ struct OllamaChatRequest {
     messages: Vec<Message>,
     #[serde(default)]
     tools: Vec<ToolSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<Value>,
     stream: bool,
 }
 
@@ -129,6 +158,8 @@ struct OllamaChatResponse {
     #[serde(default)]
     tool_calls: Vec<ToolCall>,
     done: bool,
+    #[serde(default)]
+    done_reason: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -149,6 +180,7 @@ impl Runnable<LlmRequest, LlmResponse> for OllamaClient {
             model,
             messages,
             tools,
+            response_format,
             ..
         } = input;
         let model = if model.is_empty() {
@@ -158,8 +190,9 @@ impl Runnable<LlmRequest, LlmResponse> for OllamaClient {
         };
         let request = OllamaChatRequest {
             model,
-            messages,
+            messages: without_cache_hints(messages),
             tools,
+            format: ollama_format(response_format),
             stream: false,
         };
 
@@ -182,6 +215,7 @@ impl Runnable<LlmRequest, LlmResponse> for OllamaClient {
             tool_calls: response.tool_calls,
             usage: None,
             model: String::new(),
+            finish_reason: response.done_reason,
         })
     }
 
@@ -193,6 +227,7 @@ impl Runnable<LlmRequest, LlmResponse> for OllamaClient {
             model,
             messages,
             tools,
+            response_format,
             ..
         } = input;
         let model = if model.is_empty() {
@@ -202,8 +237,9 @@ impl Runnable<LlmRequest, LlmResponse> for OllamaClient {
         };
         let request = OllamaChatRequest {
             model,
-            messages,
+            messages: without_cache_hints(messages),
             tools,
+            format: ollama_format(response_format),
             stream: true,
         };
 
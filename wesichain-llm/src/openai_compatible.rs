@@ -10,6 +10,9 @@ use url::Url;
 #[derive(Serialize, Debug, Clone)]
 pub struct ChatCompletionRequest {
     pub model: String,
+    /// Messages carrying a [`CacheControl`](wesichain_core::CacheControl)
+    /// hint are sent with it on their last content part.
+    #[serde(serialize_with = "serialize_messages")]
     pub messages: Vec<wesichain_core::Message>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Vec<wesichain_core::ToolSpec>>,
@@ -17,9 +20,75 @@ pub struct ChatCompletionRequest {
     pub temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_tokens: Option<u32>,
+    /// Sent in OpenAI's `response_format` shape; a JSON schema is wrapped as
+    /// `{"type": "json_schema", "json_schema": {"name": "response", ...}}`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_response_format"
+    )]
+    pub response_format: Option<wesichain_core::ResponseFormat>,
     pub stream: bool,
 }
 
+fn serialize_response_format<S>(
+    response_format: &Option<wesichain_core::ResponseFormat>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use wesichain_core::ResponseFormat;
+
+    let value = match response_format {
+        None => return serializer.serialize_none(),
+        Some(ResponseFormat::Text) => serde_json::json!({"type": "text"}),
+        Some(ResponseFormat::JsonObject) => serde_json::json!({"type": "json_object"}),
+        Some(ResponseFormat::JsonSchema(schema)) => serde_json::json!({
+            "type": "json_schema",
+            "json_schema": {"name": "response", "schema": schema},
+        }),
+    };
+    value.serialize(serializer)
+}
+
+/// Serialize messages, moving each `cache_control` hint onto the message's
+/// last content part, the form gateways with prompt caching accept.
+fn serialize_messages<S>(
+    messages: &[wesichain_core::Message],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::{Error, SerializeSeq};
+
+    let mut seq = serializer.serialize_seq(Some(messages.len()))?;
+    for message in messages {
+        let Some(cache_control) = message.cache_control else {
+            seq.serialize_element(message)?;
+            continue;
+        };
+        let mut value = serde_json::to_value(message).map_err(S::Error::custom)?;
+        let cache_control = serde_json::to_value(cache_control).map_err(S::Error::custom)?;
+        if let Some(object) = value.as_object_mut() {
+            object.remove("cache_control");
+            let mut parts = match object.remove("content") {
+                Some(serde_json::Value::Array(parts)) => parts,
+                Some(serde_json::Value::String(text)) => {
+                    vec![serde_json::json!({ "type": "text", "text": text })]
+                }
+                _ => Vec::new(),
+            };
+            if let Some(serde_json::Value::Object(last)) = parts.last_mut() {
+                last.insert("cache_control".to_string(), cache_control);
+            }
+            object.insert("content".to_string(), serde_json::Value::Array(parts));
+        }
+        seq.serialize_element(&value)?;
+    }
+    seq.end()
+}
+
 /// Non-streaming response from chat completions
 #[derive(Deserialize, Debug, Clone)]
 pub struct ChatCompletionResponse {
@@ -76,6 +145,26 @@ pub struct Delta {
     pub role: Option<String>,
     #[serde(default)]
     pub content: Option<String>,
+    #[serde(default)]
+    pub tool_calls: Option<Vec<ToolCallChunk>>,
+}
+
+/// Incremental tool call fragment; `index` identifies the call across chunks.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ToolCallChunk {
+    pub index: u32,
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub function: Option<FunctionDelta>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct FunctionDelta {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub arguments: Option<String>,
 }
 
 /// OpenAI-style error response
@@ -100,6 +189,8 @@ pub struct OpenAiCompatibleBuilder {
     api_key: Option<Secret<String>>,
     default_model: Option<String>,
     timeout: Duration,
+    http_client: Option<reqwest::Client>,
+    prompt_caching: bool,
 }
 
 impl Default for OpenAiCompatibleBuilder {
@@ -109,6 +200,8 @@ impl Default for OpenAiCompatibleBuilder {
             api_key: None,
             default_model: None,
             timeout: Duration::from_secs(60),
+            http_client: None,
+            prompt_caching: false,
         }
     }
 }
@@ -144,6 +237,22 @@ impl OpenAiCompatibleBuilder {
         self
     }
 
+    /// Use a pre-configured HTTP client, e.g. one routed through a proxy or
+    /// trusting custom TLS roots. The client's own timeout applies in place of
+    /// [`timeout`](Self::timeout).
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Forward message [`CacheControl`](wesichain_core::CacheControl) hints
+    /// as `cache_control` blocks. Only enable this for gateways that accept
+    /// them; by default the hints are dropped.
+    pub fn prompt_caching(mut self, enabled: bool) -> Self {
+        self.prompt_caching = enabled;
+        self
+    }
+
     pub fn build(self) -> Result<OpenAiCompatibleClient, wesichain_core::WesichainError> {
         let base_url = self.base_url.ok_or_else(|| {
             wesichain_core::WesichainError::InvalidConfig("base_url is required".to_string())
@@ -153,15 +262,18 @@ impl OpenAiCompatibleBuilder {
             wesichain_core::WesichainError::InvalidConfig("api_key is required".to_string())
         })?;
 
-        let http = reqwest::Client::builder()
-            .timeout(self.timeout)
-            .build()
-            .map_err(|e| {
-                wesichain_core::WesichainError::LlmProvider(format!(
-                    "Failed to create HTTP client: {}",
-                    e
-                ))
-            })?;
+        let http = match self.http_client {
+            Some(client) => client,
+            None => reqwest::Client::builder()
+                .timeout(self.timeout)
+                .build()
+                .map_err(|e| {
+                    wesichain_core::WesichainError::LlmProvider(format!(
+                        "Failed to create HTTP client: {}",
+                        e
+                    ))
+                })?,
+        };
 
         Ok(OpenAiCompatibleClient {
             http,
@@ -169,6 +281,7 @@ impl OpenAiCompatibleBuilder {
             api_key,
             default_model: self.default_model.unwrap_or_default(),
             timeout: self.timeout,
+            prompt_caching: self.prompt_caching,
         })
     }
 }
@@ -178,53 +291,145 @@ use wesichain_core::WesichainError;
 
 use bytes::BytesMut;
 use futures::{stream, StreamExt};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use wesichain_core::ToolCallAssembler;
 
 /// Parse a server-sent event line
 fn parse_sse_line(line: &str) -> Option<&str> {
     line.trim().strip_prefix("data: ")
 }
 
+/// Metadata key under which the assembled `Vec<ToolCall>` is emitted at the end of a stream.
+pub const STREAM_TOOL_CALLS_KEY: &str = "tool_calls";
+
+/// Incremental decoder for chat completion SSE bytes.
+///
+/// Tool call fragments are tracked per `index`: the first fragment emits
+/// `ToolCallStart`, every `arguments` piece emits a `ToolCallDelta` carrying the
+/// raw string fragment, and `[DONE]` emits the calls assembled by a
+/// [`ToolCallAssembler`] as `Metadata { key: "tool_calls", .. }` ahead of
+/// `FinalAnswer`. A body that ends without `[DONE]` still gets its assembled
+/// calls from [`finish`](Self::finish). Arguments that are not valid JSON end
+/// the stream with [`WesichainError::ParseFailed`].
+#[derive(Default)]
+struct SseDecoder {
+    buffer: BytesMut,
+    /// Id and (possibly fragmented) name of each call, by `index`.
+    tool_calls: BTreeMap<u32, (String, String)>,
+    assembler: ToolCallAssembler,
+}
+
+impl SseDecoder {
+    fn push(&mut self, bytes: &[u8]) -> Vec<Result<StreamEvent, WesichainError>> {
+        self.buffer.extend_from_slice(bytes);
+        let mut events = Vec::new();
+
+        // Process complete lines in buffer
+        while let Some(pos) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line = self.buffer.split_to(pos + 1);
+            let line_str = String::from_utf8_lossy(&line);
+
+            if let Some(data) = parse_sse_line(&line_str) {
+                if data == "[DONE]" {
+                    match self.finish_tool_calls() {
+                        Ok(Some(event)) => events.push(Ok(event)),
+                        Ok(None) => {}
+                        Err(error) => {
+                            events.push(Err(error));
+                            return events;
+                        }
+                    }
+                    events.push(Ok(StreamEvent::FinalAnswer(String::new())));
+                } else if let Ok(chunk) = serde_json::from_str::<ChatCompletionChunk>(data) {
+                    for choice in chunk.choices {
+                        if let Some(content) = choice.delta.content {
+                            events.push(Ok(StreamEvent::ContentChunk(content)));
+                        }
+                        for call in choice.delta.tool_calls.unwrap_or_default() {
+                            self.push_tool_call(call, &mut events);
+                        }
+                    }
+                }
+            }
+        }
+
+        events
+    }
+
+    fn push_tool_call(
+        &mut self,
+        chunk: ToolCallChunk,
+        events: &mut Vec<Result<StreamEvent, WesichainError>>,
+    ) {
+        let function = chunk.function.unwrap_or_default();
+        let is_new = !self.tool_calls.contains_key(&chunk.index);
+        let (id, name) = self.tool_calls.entry(chunk.index).or_default();
+        // The id is fixed by the call's first fragment; a later one must not
+        // re-key the call in the assembler.
+        if is_new {
+            *id = chunk.id.unwrap_or_else(|| format!("call_{}", chunk.index));
+        }
+        let renamed = function.name.is_some();
+        if let Some(fragment) = function.name {
+            name.push_str(&fragment);
+        }
+        if is_new || renamed {
+            self.assembler.start(id, name);
+        }
+        if is_new {
+            events.push(Ok(StreamEvent::ToolCallStart {
+                id: id.clone(),
+                name: name.clone(),
+            }));
+        }
+        if let Some(arguments) = function.arguments.filter(|a| !a.is_empty()) {
+            let delta = Value::String(arguments);
+            self.assembler.delta(id, delta.clone());
+            events.push(Ok(StreamEvent::ToolCallDelta {
+                id: id.clone(),
+                delta,
+            }));
+        }
+    }
+
+    /// Called once the body ends; emits the tool calls a body without
+    /// `[DONE]` left assembled.
+    fn finish(&mut self) -> Vec<Result<StreamEvent, WesichainError>> {
+        self.finish_tool_calls().transpose().into_iter().collect()
+    }
+
+    fn finish_tool_calls(&mut self) -> Result<Option<StreamEvent>, WesichainError> {
+        if self.assembler.is_empty() {
+            return Ok(None);
+        }
+
+        self.tool_calls.clear();
+        let calls = std::mem::take(&mut self.assembler).finish()?;
+        Ok(Some(StreamEvent::Metadata {
+            key: STREAM_TOOL_CALLS_KEY.to_string(),
+            value: serde_json::to_value(calls).unwrap_or(Value::Null),
+        }))
+    }
+}
+
 /// Parse SSE stream into StreamEvents
 fn parse_sse_stream(
     response: reqwest::Response,
 ) -> BoxStream<'static, Result<StreamEvent, WesichainError>> {
     let stream = response.bytes_stream();
-    let mut buffer = BytesMut::new();
+    let mut decoder = SseDecoder::default();
 
     stream
-        .flat_map(move |chunk| {
-            match chunk {
-                Ok(bytes) => {
-                    buffer.extend_from_slice(&bytes);
-                    let mut events = Vec::new();
-
-                    // Process complete lines in buffer
-                    while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
-                        let line = buffer.split_to(pos + 1);
-                        let line_str = String::from_utf8_lossy(&line);
-
-                        if let Some(data) = parse_sse_line(&line_str) {
-                            if data == "[DONE]" {
-                                events.push(Ok(StreamEvent::FinalAnswer(String::new())));
-                            } else if let Ok(chunk) =
-                                serde_json::from_str::<ChatCompletionChunk>(data)
-                            {
-                                for choice in chunk.choices {
-                                    if let Some(content) = choice.delta.content {
-                                        events.push(Ok(StreamEvent::ContentChunk(content)));
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    stream::iter(events)
-                }
-                Err(e) => stream::iter(vec![Err(WesichainError::LlmProvider(format!(
-                    "Stream error: {}",
-                    e
-                )))]),
-            }
+        .map(Some)
+        .chain(stream::once(async { None }))
+        .flat_map(move |chunk| match chunk {
+            Some(Ok(bytes)) => stream::iter(decoder.push(&bytes)),
+            Some(Err(e)) => stream::iter(vec![Err(WesichainError::LlmProvider(format!(
+                "Stream error: {}",
+                e
+            )))]),
+            None => stream::iter(decoder.finish()),
         })
         .boxed()
 }
@@ -238,6 +443,7 @@ pub struct OpenAiCompatibleClient {
     default_model: String,
     #[allow(dead_code)]
     timeout: Duration,
+    prompt_caching: bool,
 }
 
 impl OpenAiCompatibleClient {
@@ -250,6 +456,37 @@ impl OpenAiCompatibleClient {
         self.default_model = model.into();
     }
 
+    /// Replace the HTTP client used for requests, e.g. with one routed through
+    /// a proxy or trusting custom TLS roots.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http = client;
+        self
+    }
+
+    /// Enable or disable forwarding of prompt-caching hints; see
+    /// [`OpenAiCompatibleBuilder::prompt_caching`].
+    pub fn with_prompt_caching(mut self, enabled: bool) -> Self {
+        self.prompt_caching = enabled;
+        self
+    }
+
+    /// Drop cache hints unless this endpoint was configured to accept them.
+    fn request_messages(
+        &self,
+        messages: Vec<wesichain_core::Message>,
+    ) -> Vec<wesichain_core::Message> {
+        if self.prompt_caching {
+            return messages;
+        }
+        messages
+            .into_iter()
+            .map(|message| wesichain_core::Message {
+                cache_control: None,
+                ..message
+            })
+            .collect()
+    }
+
     /// Make a non-streaming chat completion request
     async fn chat_completion(
         &self,
@@ -378,7 +615,7 @@ impl Runnable<LlmRequest, LlmResponse> for OpenAiCompatibleClient {
 
         let request = ChatCompletionRequest {
             model,
-            messages: input.messages,
+            messages: self.request_messages(input.messages),
             tools: if input.tools.is_empty() {
                 None
             } else {
@@ -386,6 +623,7 @@ impl Runnable<LlmRequest, LlmResponse> for OpenAiCompatibleClient {
             },
             temperature: input.temperature,
             max_tokens: input.max_tokens,
+            response_format: input.response_format,
             stream: false,
         };
 
@@ -408,6 +646,7 @@ impl Runnable<LlmRequest, LlmResponse> for OpenAiCompatibleClient {
             tool_calls: choice.message.tool_calls.unwrap_or_default(),
             usage,
             model: String::new(),
+            finish_reason: choice.finish_reason,
         })
     }
 
@@ -420,7 +659,7 @@ impl Runnable<LlmRequest, LlmResponse> for OpenAiCompatibleClient {
 
         let request = ChatCompletionRequest {
             model,
-            messages: input.messages,
+            messages: self.request_messages(input.messages),
             tools: if input.tools.is_empty() {
                 None
             } else {
@@ -428,6 +667,7 @@ impl Runnable<LlmRequest, LlmResponse> for OpenAiCompatibleClient {
             },
             temperature: input.temperature,
             max_tokens: input.max_tokens,
+            response_format: input.response_format,
             stream: true,
         };
 
@@ -442,3 +682,174 @@ impl Runnable<LlmRequest, LlmResponse> for OpenAiCompatibleClient {
 }
 
 impl wesichain_core::ToolCallingLlm for OpenAiCompatibleClient {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode an SSE body delivered as arbitrary byte chunks.
+    fn decode(chunks: &[&[u8]]) -> Result<Vec<StreamEvent>, WesichainError> {
+        let mut decoder = SseDecoder::default();
+        let mut events = chunks
+            .iter()
+            .flat_map(|chunk| decoder.push(chunk))
+            .collect::<Vec<_>>();
+        events.extend(decoder.finish());
+        events.into_iter().collect()
+    }
+
+    #[test]
+    fn test_stream_accumulates_tool_call_argument_fragments() {
+        use serde_json::json;
+        use wesichain_core::ToolCall;
+
+        let body = concat!(
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"role":"assistant","tool_calls":[{"index":0,"id":"call_abc","type":"function","function":{"name":"get_weather","arguments":""}}]},"finish_reason":null}]}"#,
+            "\n\n",
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"city\":"}}]},"finish_reason":null}]}"#,
+            "\n\n",
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"\"Paris\"}"}}]},"finish_reason":"tool_calls"}]}"#,
+            "\n\n",
+            "data: [DONE]\n\n",
+        )
+        .as_bytes();
+
+        // Split at awkward byte boundaries, including mid-line and mid-escape.
+        let splits = [17, 180, 181, 420, 650, body.len() - 3];
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        for end in splits {
+            chunks.push(&body[start..end]);
+            start = end;
+        }
+        chunks.push(&body[start..]);
+
+        let events = decode(&chunks).unwrap();
+
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::ToolCallStart {
+                    id: "call_abc".to_string(),
+                    name: "get_weather".to_string(),
+                },
+                StreamEvent::ToolCallDelta {
+                    id: "call_abc".to_string(),
+                    delta: json!("{\"city\":"),
+                },
+                StreamEvent::ToolCallDelta {
+                    id: "call_abc".to_string(),
+                    delta: json!("\"Paris\"}"),
+                },
+                StreamEvent::Metadata {
+                    key: "tool_calls".to_string(),
+                    value: serde_json::to_value(vec![ToolCall {
+                        id: "call_abc".to_string(),
+                        name: "get_weather".to_string(),
+                        args: json!({"city": "Paris"}),
+                    }])
+                    .unwrap(),
+                },
+                StreamEvent::FinalAnswer(String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stream_tracks_parallel_tool_calls_by_index() {
+        let body = concat!(
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"id":"a","function":{"name":"first","arguments":"{}"}},{"index":1,"id":"b","function":{"name":"second","arguments":"{\"x\""}}]},"finish_reason":null}]}"#,
+            "\n",
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":1,"function":{"arguments":":1}"}}]},"finish_reason":null}]}"#,
+            "\n",
+            "data: [DONE]\n",
+        );
+
+        let events = decode(&[body.as_bytes()]).unwrap();
+
+        let starts = events
+            .iter()
+            .filter(|event| matches!(event, StreamEvent::ToolCallStart { .. }))
+            .count();
+        assert_eq!(starts, 2);
+
+        let Some(StreamEvent::Metadata { value, .. }) = events
+            .iter()
+            .find(|event| matches!(event, StreamEvent::Metadata { .. }))
+        else {
+            panic!("missing tool_calls metadata");
+        };
+        assert_eq!(value[0]["name"], "first");
+        assert_eq!(value[1]["id"], "b");
+        assert_eq!(value[1]["args"]["x"], 1);
+    }
+
+    #[test]
+    fn test_stream_rejects_invalid_tool_call_arguments() {
+        let body = concat!(
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"id":"a","function":{"name":"first","arguments":"{\"x\":"}}]},"finish_reason":null}]}"#,
+            "\n",
+            "data: [DONE]\n",
+        );
+
+        let err = decode(&[body.as_bytes()]).unwrap_err();
+
+        assert!(matches!(
+            err,
+            WesichainError::ParseFailed { output, reason } if output == "{\"x\":" && reason.contains("'a'")
+        ));
+    }
+
+    #[test]
+    fn test_stream_flushes_tool_calls_without_done() {
+        let body = concat!(
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"id":"a","function":{"name":"first","arguments":"{\"x\":1}"}}]},"finish_reason":"tool_calls"}]}"#,
+            "\n",
+        );
+
+        let events = decode(&[body.as_bytes()]).unwrap();
+
+        let Some(StreamEvent::Metadata { key, value }) = events.last() else {
+            panic!("missing tool_calls metadata");
+        };
+        assert_eq!(key, "tool_calls");
+        assert_eq!(value[0]["id"], "a");
+        assert_eq!(value[0]["args"]["x"], 1);
+    }
+
+    #[test]
+    fn test_stream_keeps_first_id_when_a_later_fragment_carries_one() {
+        let body = concat!(
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"name":"lookup","arguments":"{\"q\":"}}]},"finish_reason":null}]}"#,
+            "\n",
+            r#"data: {"id":"c","object":"chat.completion.chunk","created":1,"model":"m","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"id":"call_late","function":{"arguments":"1}"}}]},"finish_reason":null}]}"#,
+            "\n",
+            "data: [DONE]\n",
+        );
+
+        let events = decode(&[body.as_bytes()]).unwrap();
+
+        let starts: Vec<_> = events
+            .iter()
+            .filter(|event| matches!(event, StreamEvent::ToolCallStart { .. }))
+            .collect();
+        assert_eq!(
+            starts,
+            [&StreamEvent::ToolCallStart {
+                id: "call_0".to_string(),
+                name: "lookup".to_string(),
+            }]
+        );
+
+        let Some(StreamEvent::Metadata { value, .. }) = events
+            .iter()
+            .find(|event| matches!(event, StreamEvent::Metadata { .. }))
+        else {
+            panic!("missing tool_calls metadata");
+        };
+        assert_eq!(value.as_array().map(Vec::len), Some(1));
+        assert_eq!(value[0]["id"], "call_0");
+        assert_eq!(value[0]["name"], "lookup");
+        assert_eq!(value[0]["args"]["q"], 1);
+    }
+}
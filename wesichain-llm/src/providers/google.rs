@@ -11,8 +11,8 @@ use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::time::Duration;
 use wesichain_core::{
-    LlmRequest, LlmResponse, Message, Role, Runnable, StreamEvent, ToolCall, ToolSpec,
-    WesichainError,
+    LlmRequest, LlmResponse, Message, ResponseFormat, Role, Runnable, StreamEvent, ToolCall,
+    ToolSpec, WesichainError,
 };
 
 const GEMINI_BASE_URL: &str = "https://generativelanguage.googleapis.com";
@@ -45,6 +45,13 @@ impl GoogleClient {
         self
     }
 
+    /// Use a pre-configured HTTP client, e.g. for proxies or custom TLS roots.
+    /// Replaces the default client and its 120s timeout.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.http = client;
+        self
+    }
+
     fn model_name(&self, request_model: &str) -> String {
         let model = if request_model.is_empty() {
             self.model.as_str()
@@ -84,6 +91,10 @@ struct GenerationConfig {
     max_output_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_schema: Option<Value>,
 }
 
 #[derive(Debug, Serialize)]
@@ -406,8 +417,16 @@ fn build_generation_config(input: &LlmRequest) -> Option<GenerationConfig> {
     let has_temp = input.temperature.is_some();
     let has_max = input.max_tokens.is_some();
     let has_stop = !input.stop_sequences.is_empty();
+    let (response_mime_type, response_schema) = match &input.response_format {
+        None => (None, None),
+        Some(ResponseFormat::Text) => (Some("text/plain".to_string()), None),
+        Some(ResponseFormat::JsonObject) => (Some("application/json".to_string()), None),
+        Some(ResponseFormat::JsonSchema(schema)) => {
+            (Some("application/json".to_string()), Some(schema.clone()))
+        }
+    };
 
-    if !has_temp && !has_max && !has_stop {
+    if !has_temp && !has_max && !has_stop && response_mime_type.is_none() {
         return None;
     }
 
@@ -419,6 +438,8 @@ fn build_generation_config(input: &LlmRequest) -> Option<GenerationConfig> {
         } else {
             None
         },
+        response_mime_type,
+        response_schema,
     })
 }
 
@@ -544,6 +565,7 @@ impl Runnable<LlmRequest, LlmResponse> for GoogleClient {
             tool_calls,
             usage,
             model: String::new(),
+            finish_reason,
         })
     }
 
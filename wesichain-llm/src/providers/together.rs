@@ -52,6 +52,12 @@ impl TogetherClient {
         self.0.set_default_model(model);
         self
     }
+
+    /// Use a pre-configured HTTP client, e.g. for proxies or custom TLS roots.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.0 = self.0.with_http_client(client);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -22,6 +22,7 @@ async fn test_google_simple_completion() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        response_format: None,
     };
 
     let response = client.invoke(request).await.expect("Request failed");
@@ -43,6 +44,7 @@ async fn test_google_streaming_completion() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        response_format: None,
     };
 
     let mut stream = client.stream(request);
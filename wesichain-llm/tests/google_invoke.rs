@@ -2,7 +2,7 @@
 
 use httpmock::prelude::*;
 use serde_json::json;
-use wesichain_core::{Runnable, WesichainError};
+use wesichain_core::{FinishReason, Runnable, WesichainError};
 use wesichain_llm::{GoogleClient, LlmRequest, Message, Role, ToolSpec};
 
 #[tokio::test]
@@ -44,11 +44,17 @@ async fn google_invoke_maps_text_response() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
     };
 
     let response = client.invoke(request).await.unwrap();
     assert_eq!(response.content, "hello");
     assert!(response.tool_calls.is_empty());
+    assert_eq!(response.finish_reason.as_deref(), Some("STOP"));
+    assert_eq!(response.finish(), Some(FinishReason::Stop));
     mock.assert();
 }
 
@@ -131,6 +137,10 @@ async fn google_invoke_folds_system_messages_into_system_instruction() {
             },
         ],
         tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
     };
 
     let response = client.invoke(request).await.unwrap();
@@ -215,6 +225,10 @@ async fn google_invoke_maps_tool_declarations_and_function_calls() {
                 "required": ["expression"]
             }),
         }],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
     };
 
     let response = client.invoke(request).await.unwrap();
@@ -255,6 +269,10 @@ async fn google_invoke_returns_error_for_blocked_finish_reason_without_content()
             tool_calls: vec![],
         }],
         tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
     };
 
     let err = client.invoke(request).await.unwrap_err();
@@ -290,11 +308,16 @@ async fn google_invoke_returns_partial_text_when_blocked_with_content() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
     };
 
     let response = client.invoke(request).await.unwrap();
     assert_eq!(response.content, "partial");
     assert!(response.tool_calls.is_empty());
+    assert_eq!(response.finish(), Some(FinishReason::ContentFilter));
 }
 
 #[tokio::test]
@@ -321,6 +344,10 @@ async fn google_invoke_surfaces_rate_limit_message() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
     };
 
     let err = client.invoke(request).await.unwrap_err();
@@ -328,3 +355,43 @@ async fn google_invoke_surfaces_rate_limit_message() {
         matches!(err, WesichainError::LlmProvider(message) if message.contains("quota exceeded"))
     );
 }
+
+#[tokio::test]
+async fn google_invoke_maps_max_tokens_finish_reason() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1beta/models/gemini-1.5-flash:generateContent")
+            .query_param("key", "test-key");
+        then.status(200).json_body(json!({
+            "candidates": [
+                {
+                    "content": {
+                        "parts": [{"text": "Once upon a"}]
+                    },
+                    "finishReason": "MAX_TOKENS"
+                }
+            ]
+        }));
+    });
+
+    let client = GoogleClient::new("test-key", "gemini-1.5-flash").with_base_url(server.url(""));
+    let request = LlmRequest {
+        model: "".to_string(),
+        messages: vec![Message {
+            role: Role::User,
+            content: "tell me a story".into(),
+            tool_call_id: None,
+            tool_calls: vec![],
+        }],
+        tools: vec![],
+        temperature: None,
+        max_tokens: Some(3),
+        stop_sequences: vec![],
+        response_format: None,
+    };
+
+    let response = client.invoke(request).await.unwrap();
+    assert_eq!(response.finish_reason.as_deref(), Some("MAX_TOKENS"));
+    assert_eq!(response.finish(), Some(FinishReason::Length));
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "google")]
+
+use httpmock::prelude::*;
+use serde_json::json;
+use wesichain_core::{ResponseFormat, Runnable};
+use wesichain_llm::{GoogleClient, LlmRequest, Message};
+
+#[tokio::test]
+async fn google_invoke_sends_json_schema_in_generation_config() {
+    let schema = json!({
+        "type": "OBJECT",
+        "properties": {"city": {"type": "STRING"}},
+        "required": ["city"]
+    });
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1beta/models/gemini-1.5-flash:generateContent")
+            .json_body(json!({
+                "contents": [{"role": "user", "parts": [{"text": "Where is the Louvre?"}]}],
+                "generationConfig": {
+                    "responseMimeType": "application/json",
+                    "responseSchema": schema
+                }
+            }));
+        then.status(200).json_body(json!({
+            "candidates": [{
+                "content": {"parts": [{"text": "{\"city\":\"Paris\"}"}]},
+                "finishReason": "STOP"
+            }]
+        }));
+    });
+
+    let client = GoogleClient::new("test-key", "gemini-1.5-flash").with_base_url(server.url(""));
+    let request = LlmRequest::builder()
+        .message(Message::user("Where is the Louvre?"))
+        .response_format(ResponseFormat::JsonSchema(schema.clone()))
+        .build();
+
+    let response = client.invoke(request).await.unwrap();
+
+    mock.assert();
+    assert_eq!(response.content, r#"{"city":"Paris"}"#);
+}
+
+#[tokio::test]
+async fn google_invoke_sends_json_mime_type_for_json_object() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1beta/models/gemini-1.5-flash:generateContent")
+            .json_body(json!({
+                "contents": [{"role": "user", "parts": [{"text": "hi"}]}],
+                "generationConfig": {"responseMimeType": "application/json"}
+            }));
+        then.status(200).json_body(json!({
+            "candidates": [{"content": {"parts": [{"text": "{}"}]}, "finishReason": "STOP"}]
+        }));
+    });
+
+    let client = GoogleClient::new("test-key", "gemini-1.5-flash").with_base_url(server.url(""));
+    let request = LlmRequest::builder()
+        .message(Message::user("hi"))
+        .response_format(ResponseFormat::JsonObject)
+        .build();
+
+    client.invoke(request).await.unwrap();
+
+    mock.assert();
+}
@@ -42,6 +42,7 @@ async fn google_stream_emits_text_chunks_and_final_answer() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        response_format: None,
     };
 
     let events: Vec<_> = client.stream(request).collect().await;
@@ -79,6 +80,7 @@ async fn google_stream_detects_late_function_call() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        response_format: None,
     };
 
     let events: Vec<_> = client.stream(request).collect().await;
@@ -115,6 +117,7 @@ async fn google_stream_surfaces_http_errors() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        response_format: None,
     };
 
     let mut events = client.stream(request);
@@ -153,6 +156,7 @@ async fn google_stream_stops_after_parse_error() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        response_format: None,
     };
 
     let mut events = client.stream(request);
@@ -0,0 +1,107 @@
+use httpmock::prelude::*;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde_json::json;
+use wesichain_core::Runnable;
+use wesichain_llm::{LlmRequest, Message, OllamaClient, OpenAiCompatibleClient};
+
+fn proxy_client() -> reqwest::Client {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-proxy-auth", HeaderValue::from_static("corp-token"));
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap()
+}
+
+fn request(model: &str) -> LlmRequest {
+    LlmRequest {
+        model: model.to_string(),
+        messages: vec![Message::user("hi")],
+        tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
+    }
+}
+
+#[tokio::test]
+async fn openai_compatible_uses_injected_http_client() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/chat/completions")
+            .header("x-proxy-auth", "corp-token");
+        then.status(200).json_body(json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1,
+            "model": "gpt-4o-mini",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "hello"},
+                "finish_reason": "stop"
+            }]
+        }));
+    });
+
+    let client = OpenAiCompatibleClient::builder()
+        .base_url(server.url(""))
+        .unwrap()
+        .api_key("test-key")
+        .http_client(proxy_client())
+        .build()
+        .unwrap();
+
+    let response = client.invoke(request("gpt-4o-mini")).await.unwrap();
+    assert_eq!(response.content, "hello");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn ollama_uses_injected_http_client() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/api/chat")
+            .header("x-proxy-auth", "corp-token");
+        then.status(200).json_body(json!({
+            "message": {"content": "hello"},
+            "done": true,
+            "tool_calls": []
+        }));
+    });
+
+    let client = OllamaClient::new(server.url(""), "llama3.1".to_string())
+        .unwrap()
+        .with_http_client(proxy_client());
+
+    let response = client.invoke(request("")).await.unwrap();
+    assert_eq!(response.content, "hello");
+    mock.assert();
+}
+
+#[cfg(feature = "google")]
+#[tokio::test]
+async fn google_uses_injected_http_client() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1beta/models/gemini-1.5-flash:generateContent")
+            .header("x-proxy-auth", "corp-token");
+        then.status(200).json_body(json!({
+            "candidates": [{
+                "content": {"parts": [{"text": "hello"}]},
+                "finishReason": "STOP"
+            }]
+        }));
+    });
+
+    let client = wesichain_llm::GoogleClient::new("test-key", "gemini-1.5-flash")
+        .with_base_url(server.url(""))
+        .with_http_client(proxy_client());
+
+    let response = client.invoke(request("")).await.unwrap();
+    assert_eq!(response.content, "hello");
+    mock.assert();
+}
@@ -15,6 +15,7 @@ impl wesichain_core::Runnable<LlmRequest, LlmResponse> for DummyLlm {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
     }
 
@@ -1,6 +1,6 @@
 use httpmock::prelude::*;
 use serde_json::json;
-use wesichain_core::{Runnable, WesichainError};
+use wesichain_core::{FinishReason, ResponseFormat, Runnable, WesichainError};
 use wesichain_llm::{LlmRequest, Message, OllamaClient, Role};
 
 #[tokio::test]
@@ -28,11 +28,13 @@ async fn ollama_invoke_maps_response() {
             content: "hi".into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         }],
         tools: vec![],
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let resp = client.invoke(req).await.expect("invoke");
@@ -60,14 +62,80 @@ async fn ollama_invoke_surfaces_http_errors() {
             content: "hi".into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         }],
         tools: vec![],
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let err = client.invoke(req).await.expect_err("invoke should fail");
     assert!(matches!(err, WesichainError::LlmProvider(_)));
     mock.assert();
 }
+
+#[tokio::test]
+async fn ollama_invoke_maps_done_reason() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST).path("/api/chat");
+        then.status(200).json_body(json!({
+            "message": {"content": "Once upon a"},
+            "done": true,
+            "done_reason": "length",
+            "tool_calls": []
+        }));
+    });
+
+    let client = OllamaClient::new(server.url(""), "llama3.1".to_string()).expect("client");
+    let req = LlmRequest {
+        model: "llama3.1".to_string(),
+        messages: vec![Message {
+            role: Role::User,
+            content: "hi".into(),
+            tool_call_id: None,
+            tool_calls: Vec::new(),
+            cache_control: None,
+        }],
+        tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
+    };
+
+    let resp = client.invoke(req).await.expect("invoke");
+    assert_eq!(resp.finish_reason.as_deref(), Some("length"));
+    assert_eq!(resp.finish(), Some(FinishReason::Length));
+}
+
+#[tokio::test]
+async fn ollama_invoke_sends_response_format_as_format() {
+    let schema = json!({"type": "object", "properties": {"ok": {"type": "boolean"}}});
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/api/chat").json_body(json!({
+            "model": "llama3.1",
+            "messages": [{"role": "user", "content": "hi"}],
+            "tools": [],
+            "format": schema,
+            "stream": false
+        }));
+        then.status(200).json_body(json!({
+            "message": {"content": "{\"ok\":true}"},
+            "done": true
+        }));
+    });
+
+    let client = OllamaClient::new(server.url(""), "llama3.1".to_string()).expect("client");
+    let req = LlmRequest::builder()
+        .message(Message::user("hi"))
+        .response_format(ResponseFormat::JsonSchema(schema.clone()))
+        .build();
+
+    let resp = client.invoke(req).await.expect("invoke");
+    assert_eq!(resp.content, r#"{"ok":true}"#);
+    mock.assert();
+}
@@ -74,11 +74,13 @@ async fn ollama_stream_emits_events() {
             content: "hi".into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         }],
         tools: vec![],
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let events: Vec<_> = client.stream(req).collect().await;
@@ -104,11 +106,13 @@ async fn ollama_stream_surfaces_http_errors() {
             content: "hi".into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         }],
         tools: vec![],
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let mut events = client.stream(req);
@@ -134,11 +138,13 @@ async fn ollama_stream_stops_on_parse_error() {
             content: "hi".into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         }],
         tools: vec![],
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let mut events = client.stream(req);
@@ -0,0 +1,57 @@
+use httpmock::prelude::*;
+use serde_json::json;
+use wesichain_core::{FinishReason, Runnable};
+use wesichain_llm::{LlmRequest, Message, OpenAiCompatibleClient};
+
+fn request() -> LlmRequest {
+    LlmRequest {
+        model: "gpt-4o-mini".to_string(),
+        messages: vec![Message::user("write a story")],
+        tools: vec![],
+        temperature: None,
+        max_tokens: Some(3),
+        stop_sequences: vec![],
+        response_format: None,
+    }
+}
+
+async fn invoke_with_reason(reason: &str) -> wesichain_core::LlmResponse {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(POST).path("/v1/chat/completions");
+        then.status(200).json_body(json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1,
+            "model": "gpt-4o-mini",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "Once upon a"},
+                "finish_reason": reason
+            }]
+        }));
+    });
+
+    let client = OpenAiCompatibleClient::builder()
+        .base_url(server.url(""))
+        .unwrap()
+        .api_key("test-key")
+        .build()
+        .unwrap();
+    client.invoke(request()).await.unwrap()
+}
+
+#[tokio::test]
+async fn openai_compatible_invoke_maps_finish_reasons() {
+    let cases = [
+        ("stop", FinishReason::Stop),
+        ("length", FinishReason::Length),
+        ("tool_calls", FinishReason::ToolCalls),
+        ("content_filter", FinishReason::ContentFilter),
+    ];
+    for (raw, expected) in cases {
+        let response = invoke_with_reason(raw).await;
+        assert_eq!(response.finish_reason.as_deref(), Some(raw));
+        assert_eq!(response.finish(), Some(expected));
+    }
+}
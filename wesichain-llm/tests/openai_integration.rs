@@ -21,6 +21,7 @@ async fn test_openai_simple_completion() {
             tool_calls: vec![],
         }],
         tools: vec![],
+        response_format: None,
     };
 
     let response = client.invoke(request).await.expect("Request failed");
@@ -0,0 +1,146 @@
+use httpmock::prelude::*;
+use serde_json::json;
+use wesichain_core::{CacheControl, Runnable};
+use wesichain_llm::openai_compatible::ChatCompletionRequest;
+use wesichain_llm::{LlmRequest, Message, OllamaClient, OpenAiCompatibleClient};
+
+fn cached_messages() -> Vec<Message> {
+    vec![
+        Message::system("Long shared instructions.").with_cache_control(CacheControl::Ephemeral),
+        Message::user("hi"),
+    ]
+}
+
+fn request() -> LlmRequest {
+    LlmRequest {
+        model: "gpt-4o-mini".to_string(),
+        messages: cached_messages(),
+        tools: vec![],
+        temperature: None,
+        max_tokens: None,
+        stop_sequences: vec![],
+        response_format: None,
+    }
+}
+
+fn completion() -> serde_json::Value {
+    json!({
+        "id": "chatcmpl-1",
+        "object": "chat.completion",
+        "created": 1,
+        "model": "gpt-4o-mini",
+        "choices": [{
+            "index": 0,
+            "message": {"role": "assistant", "content": "hello"},
+            "finish_reason": "stop"
+        }]
+    })
+}
+
+fn openai_compatible(server: &MockServer, prompt_caching: bool) -> OpenAiCompatibleClient {
+    OpenAiCompatibleClient::builder()
+        .base_url(server.url(""))
+        .unwrap()
+        .api_key("test-key")
+        .prompt_caching(prompt_caching)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn chat_completion_request_puts_cache_control_on_last_part() {
+    let request = ChatCompletionRequest {
+        model: "gpt-4o-mini".to_string(),
+        messages: cached_messages(),
+        tools: None,
+        temperature: None,
+        max_tokens: None,
+        response_format: None,
+        stream: false,
+    };
+
+    let body = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        body["messages"],
+        json!([
+            {
+                "role": "system",
+                "content": [{
+                    "type": "text",
+                    "text": "Long shared instructions.",
+                    "cache_control": {"type": "ephemeral"}
+                }]
+            },
+            {"role": "user", "content": "hi"}
+        ])
+    );
+}
+
+#[tokio::test]
+async fn openai_compatible_forwards_cache_control_when_enabled() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/chat/completions")
+            .body_contains("\"cache_control\":{\"type\":\"ephemeral\"}");
+        then.status(200).json_body(completion());
+    });
+
+    let response = openai_compatible(&server, true)
+        .invoke(request())
+        .await
+        .unwrap();
+    assert_eq!(response.content, "hello");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn openai_compatible_drops_cache_control_by_default() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/chat/completions")
+            .json_body(json!({
+                "model": "gpt-4o-mini",
+                "messages": [
+                    {"role": "system", "content": "Long shared instructions."},
+                    {"role": "user", "content": "hi"}
+                ],
+                "stream": false
+            }));
+        then.status(200).json_body(completion());
+    });
+
+    let response = openai_compatible(&server, false)
+        .invoke(request())
+        .await
+        .unwrap();
+    assert_eq!(response.content, "hello");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn ollama_drops_cache_control() {
+    let server = MockServer::start();
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/api/chat").json_body(json!({
+            "model": "gpt-4o-mini",
+            "messages": [
+                {"role": "system", "content": "Long shared instructions."},
+                {"role": "user", "content": "hi"}
+            ],
+            "tools": [],
+            "stream": false
+        }));
+        then.status(200).json_body(json!({
+            "message": {"content": "hello"},
+            "done": true,
+            "tool_calls": []
+        }));
+    });
+
+    let client = OllamaClient::new(server.url(""), "llama3.1".to_string()).unwrap();
+    let response = client.invoke(request()).await.unwrap();
+    assert_eq!(response.content, "hello");
+    mock.assert();
+}
@@ -0,0 +1,48 @@
+use serde_json::json;
+use wesichain_core::ResponseFormat;
+use wesichain_llm::openai_compatible::ChatCompletionRequest;
+use wesichain_llm::Message;
+
+fn request(response_format: Option<ResponseFormat>) -> serde_json::Value {
+    let request = ChatCompletionRequest {
+        model: "gpt-4o-mini".to_string(),
+        messages: vec![Message::user("List three colors.")],
+        tools: None,
+        temperature: None,
+        max_tokens: None,
+        response_format,
+        stream: false,
+    };
+    serde_json::to_value(&request).unwrap()
+}
+
+#[test]
+fn openai_request_omits_response_format_by_default() {
+    assert!(request(None).get("response_format").is_none());
+}
+
+#[test]
+fn openai_request_serializes_json_object_mode() {
+    let body = request(Some(ResponseFormat::JsonObject));
+
+    assert_eq!(body["response_format"], json!({"type": "json_object"}));
+}
+
+#[test]
+fn openai_request_wraps_json_schema() {
+    let schema = json!({
+        "type": "object",
+        "properties": {"colors": {"type": "array", "items": {"type": "string"}}},
+        "required": ["colors"]
+    });
+
+    let body = request(Some(ResponseFormat::JsonSchema(schema.clone())));
+
+    assert_eq!(
+        body["response_format"],
+        json!({
+            "type": "json_schema",
+            "json_schema": {"name": "response", "schema": schema}
+        })
+    );
+}
@@ -10,6 +10,7 @@ fn test_chat_completion_request_serialization() {
         tools: None,
         temperature: Some(0.7),
         max_tokens: Some(100),
+        response_format: None,
         stream: false,
     };
 
@@ -10,6 +10,7 @@ fn llm_request_serializes_with_tools() {
             content: "hi".into(),
             tool_call_id: None,
             tool_calls: Vec::new(),
+            cache_control: None,
         }],
         tools: vec![ToolSpec {
             name: "calculator".to_string(),
@@ -19,6 +20,7 @@ fn llm_request_serializes_with_tools() {
         temperature: None,
         max_tokens: None,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     let value = serde_json::to_value(req).expect("serialize");
@@ -31,6 +33,7 @@ fn llm_request_serializes_with_tools() {
         content: "ok".into(),
         tool_call_id: Some("call-1".to_string()),
         tool_calls: Vec::new(),
+        cache_control: None,
     };
     let tool_value = serde_json::to_value(tool_msg).expect("serialize tool msg");
     assert_eq!(tool_value["tool_call_id"], "call-1");
@@ -60,6 +63,7 @@ fn llm_response_serializes_with_content_and_tool_calls_only() {
         }],
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let value = serde_json::to_value(response).expect("serialize response");
@@ -74,6 +78,7 @@ fn llm_response_omits_tool_calls_when_empty() {
         tool_calls: Vec::new(),
         usage: None,
         model: String::new(),
+        finish_reason: None,
     };
 
     let value = serde_json::to_value(response).expect("serialize response");
@@ -37,22 +37,25 @@ impl StateSchema for SummaryMemoryState {
 ///
 /// This provides bounded memory regardless of conversation length.
 /// Recent messages are kept in a buffer, and older messages are summarized.
+/// The summarizer may be any runnable LLM, including an
+/// `Arc<dyn Runnable<LlmRequest, LlmResponse>>`.
 pub struct ConversationSummaryMemory<C, L>
 where
     C: Checkpointer<SummaryMemoryState> + Send + Sync,
-    L: Runnable<LlmRequest, LlmResponse> + Send + Sync,
+    L: Runnable<LlmRequest, LlmResponse> + Send + Sync + ?Sized,
 {
     checkpointer: Arc<C>,
     llm: Arc<L>,
     memory_key: String,
     buffer_size: usize,
     summarization_prompt: String,
+    max_summary_tokens: Option<u32>,
 }
 
 impl<C, L> ConversationSummaryMemory<C, L>
 where
     C: Checkpointer<SummaryMemoryState> + Send + Sync,
-    L: Runnable<LlmRequest, LlmResponse> + Send + Sync,
+    L: Runnable<LlmRequest, LlmResponse> + Send + Sync + ?Sized,
 {
     pub fn new(checkpointer: Arc<C>, llm: Arc<L>) -> Self {
         Self {
@@ -61,6 +64,7 @@ where
             memory_key: "history".to_string(),
             buffer_size: 4,
             summarization_prompt: DEFAULT_SUMMARIZATION_PROMPT.to_string(),
+            max_summary_tokens: None,
         }
     }
 
@@ -79,6 +83,13 @@ where
         self
     }
 
+    /// Cap the length of each generated summary, sent as the request's
+    /// `max_tokens`.
+    pub fn with_max_summary_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_summary_tokens = Some(max_tokens);
+        self
+    }
+
     async fn summarize(
         &self,
         current_summary: &str,
@@ -109,11 +120,13 @@ where
                 content: prompt.into(),
                 tool_call_id: None,
                 tool_calls: Vec::new(),
+                cache_control: None,
             }],
             tools: Vec::new(),
             temperature: None,
-            max_tokens: None,
+            max_tokens: self.max_summary_tokens,
             stop_sequences: vec![],
+            response_format: None,
         };
 
         let response = self.llm.invoke(request).await?;
@@ -125,7 +138,7 @@ where
 impl<C, L> Memory for ConversationSummaryMemory<C, L>
 where
     C: Checkpointer<SummaryMemoryState> + Send + Sync,
-    L: Runnable<LlmRequest, LlmResponse> + Send + Sync,
+    L: Runnable<LlmRequest, LlmResponse> + Send + Sync + ?Sized,
 {
     async fn load_memory_variables(
         &self,
@@ -196,12 +209,14 @@ where
                 content: input_text.to_string().into(),
                 tool_call_id: None,
                 tool_calls: Vec::new(),
+                cache_control: None,
             },
             Message {
                 role: Role::Assistant,
                 content: output_text.to_string().into(),
                 tool_call_id: None,
                 tool_calls: Vec::new(),
+                cache_control: None,
             },
         ];
 
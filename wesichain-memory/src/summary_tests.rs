@@ -30,6 +30,7 @@ mod tests {
                 tool_calls: Vec::new(),
                 usage: None,
                 model: String::new(),
+                finish_reason: None,
             })
         }
 
@@ -117,4 +118,63 @@ mod tests {
 
         assert!(history.is_empty());
     }
+
+    /// Returns a deterministic summary naming the call count and records the
+    /// requested token budget.
+    #[derive(Default)]
+    struct CountingSummarizer {
+        calls: std::sync::atomic::AtomicUsize,
+        max_tokens: std::sync::Mutex<Option<u32>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Runnable<LlmRequest, LlmResponse> for CountingSummarizer {
+        async fn invoke(
+            &self,
+            request: LlmRequest,
+        ) -> Result<LlmResponse, wesichain_core::WesichainError> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            *self.max_tokens.lock().unwrap() = request.max_tokens;
+            Ok(LlmResponse {
+                content: format!("summary #{call}"),
+                ..Default::default()
+            })
+        }
+
+        fn stream(
+            &self,
+            _input: LlmRequest,
+        ) -> futures::stream::BoxStream<'static, Result<StreamEvent, wesichain_core::WesichainError>>
+        {
+            Box::pin(futures::stream::empty())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summary_memory_with_dyn_llm_and_token_budget() {
+        use crate::summary::SummaryMemoryState;
+
+        let checkpointer = Arc::new(InMemoryCheckpointer::<SummaryMemoryState>::default());
+        let summarizer = Arc::new(CountingSummarizer::default());
+        let llm: Arc<dyn Runnable<LlmRequest, LlmResponse>> = summarizer.clone();
+        let memory = crate::summary::ConversationSummaryMemory::new(checkpointer, llm)
+            .with_buffer_size(0)
+            .with_max_summary_tokens(64);
+
+        let thread_id = "test_dyn";
+        for (input, output) in [("Hello", "Hi there!"), ("Bye", "Goodbye!")] {
+            let inputs = HashMap::from([("input".to_string(), serde_json::json!(input))]);
+            let outputs = HashMap::from([("output".to_string(), serde_json::json!(output))]);
+            memory
+                .save_context(thread_id, &inputs, &outputs)
+                .await
+                .unwrap();
+        }
+
+        let vars = memory.load_memory_variables(thread_id).await.unwrap();
+        let history = vars.get("history").unwrap().as_str().unwrap();
+
+        assert_eq!(history, "Summary of earlier conversation:\nsummary #2");
+        assert_eq!(*summarizer.max_tokens.lock().unwrap(), Some(64));
+    }
 }
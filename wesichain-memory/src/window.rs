@@ -96,12 +96,14 @@ where
                 content: input_text.to_string().into(),
                 tool_call_id: None,
                 tool_calls: Vec::new(),
+                cache_control: None,
             },
             Message {
                 role: Role::Assistant,
                 content: output_text.to_string().into(),
                 tool_call_id: None,
                 tool_calls: Vec::new(),
+                cache_control: None,
             },
         ];
 
@@ -17,15 +17,22 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use dashmap::DashMap;
 use opentelemetry::{
-    global::{self, BoxedSpan},
-    trace::{Span, SpanKind, Status, Tracer},
-    KeyValue,
+    global::{self, BoxedSpan, BoxedTracer},
+    trace::{
+        Span, SpanBuilder, SpanContext, SpanId, SpanKind, Status, TraceContextExt, TraceFlags,
+        TraceId, TraceState, Tracer,
+    },
+    Array, Context, KeyValue, StringValue,
 };
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+use regex::Regex;
 use uuid::Uuid;
 
 use wesichain_core::{CallbackHandler, LlmResult, RunContext, Value};
+use wesichain_sanitize::{sanitize_value, truncate_value};
+
+const DEFAULT_MAX_ATTRIBUTE_BYTES: usize = 4096;
 
 /// OpenTelemetry callback handler that creates one span per Wesichain run.
 ///
@@ -33,17 +40,119 @@ use wesichain_core::{CallbackHandler, LlmResult, RunContext, Value};
 /// `on_error` closes it with the correct status and duration.  Previously the
 /// handler opened *and* closed the span inside `on_start` (zero-duration
 /// orphaned spans) and created a separate unrelated span in `on_end`.
+///
+/// Span ids are derived from run ids (`run_id` → span id, `trace_id` → trace
+/// id), so a child run's span is parented under its `parent_run_id` even when
+/// the parent span was opened by another handler instance.  Run type, tags
+/// and metadata become `wesichain.*` attributes; string values are redacted
+/// and truncated by `wesichain-sanitize`, the same rules the LangSmith
+/// exporter applies.
 #[derive(Clone)]
 pub struct OtelCallbackHandler {
+    /// Tracer to open spans with; `None` uses the global tracer provider.
+    tracer: Option<Arc<BoxedTracer>>,
     /// Live spans keyed by run ID.  Removed (and ended) in on_end / on_error.
     active_spans: Arc<DashMap<Uuid, BoxedSpan>>,
+    redact_regex: Option<Regex>,
+    max_attribute_bytes: usize,
 }
 
 impl OtelCallbackHandler {
     pub fn new() -> Self {
         Self {
+            tracer: None,
             active_spans: Arc::new(DashMap::new()),
+            redact_regex: None,
+            max_attribute_bytes: DEFAULT_MAX_ATTRIBUTE_BYTES,
+        }
+    }
+
+    /// Open spans with `tracer` instead of the global tracer provider's.
+    pub fn with_tracer<T>(mut self, tracer: T) -> Self
+    where
+        T: Tracer + Send + Sync + 'static,
+        T::Span: Send + Sync + 'static,
+    {
+        self.tracer = Some(Arc::new(BoxedTracer::new(Box::new(tracer))));
+        self
+    }
+
+    /// Redact matches of `regex` in string attribute values.
+    pub fn with_redact_regex(mut self, regex: Regex) -> Self {
+        self.redact_regex = Some(regex);
+        self
+    }
+
+    /// Truncate string attribute values to `max_bytes`. Defaults to 4096.
+    pub fn with_max_attribute_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_attribute_bytes = max_bytes;
+        self
+    }
+
+    fn sanitize(&self, value: Value) -> Value {
+        let value = sanitize_value(value, self.redact_regex.as_ref());
+        truncate_value(value, self.max_attribute_bytes)
+    }
+
+    fn attribute(&self, key: String, value: Value) -> KeyValue {
+        let value = match self.sanitize(value) {
+            Value::Bool(flag) => opentelemetry::Value::Bool(flag),
+            Value::Number(number) => match number.as_i64() {
+                Some(int) => opentelemetry::Value::I64(int),
+                None => opentelemetry::Value::F64(number.as_f64().unwrap_or_default()),
+            },
+            Value::String(text) => opentelemetry::Value::String(text.into()),
+            other => opentelemetry::Value::String(other.to_string().into()),
+        };
+        KeyValue::new(key, value)
+    }
+
+    fn attributes(&self, ctx: &RunContext) -> Vec<KeyValue> {
+        let mut attributes = vec![
+            KeyValue::new("wesichain.run_id", ctx.run_id.to_string()),
+            KeyValue::new("wesichain.run_type", format!("{:?}", ctx.run_type)),
+            KeyValue::new("wesichain.name", ctx.name.clone()),
+        ];
+        if let Some(parent) = ctx.parent_run_id {
+            attributes.push(KeyValue::new("wesichain.parent_run_id", parent.to_string()));
+        }
+        if !ctx.tags.is_empty() {
+            let tags = self.sanitize(Value::from(ctx.tags.clone()));
+            let tags: Vec<StringValue> = tags
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(|tag| StringValue::from(tag.to_string()))
+                .collect();
+            attributes.push(KeyValue::new(
+                "wesichain.tags",
+                opentelemetry::Value::Array(Array::from(tags)),
+            ));
+        }
+        for (key, value) in &ctx.metadata {
+            attributes.push(self.attribute(format!("wesichain.metadata.{key}"), value.clone()));
         }
+        attributes
+    }
+
+    /// Parent under the open span of `parent_run_id`, or a context rebuilt
+    /// from the run ids when that span is not tracked here.
+    fn parent_context(&self, ctx: &RunContext) -> Context {
+        let Some(parent) = ctx.parent_run_id else {
+            return Context::current();
+        };
+        let parent_span = match self.active_spans.get(&parent) {
+            Some(span) => span.span_context().clone(),
+            None => SpanContext::new(
+                trace_id(ctx.trace_id),
+                span_id(parent),
+                TraceFlags::SAMPLED,
+                false,
+                TraceState::default(),
+            ),
+        };
+        Context::current().with_remote_span_context(parent_span)
     }
 }
 
@@ -53,22 +162,32 @@ impl Default for OtelCallbackHandler {
     }
 }
 
+fn trace_id(id: Uuid) -> TraceId {
+    TraceId::from_bytes(*id.as_bytes())
+}
+
+fn span_id(id: Uuid) -> SpanId {
+    let bytes = id.as_bytes();
+    let mut low = [0; 8];
+    low.copy_from_slice(&bytes[8..]);
+    SpanId::from_bytes(low)
+}
+
 #[async_trait]
 impl CallbackHandler for OtelCallbackHandler {
     /// Open a span for the run.  The span remains open until `on_end` or
     /// `on_error` is called.
     async fn on_start(&self, ctx: &RunContext, _inputs: &Value) {
-        let tracer = global::tracer("wesichain");
-        let mut span = tracer
-            .span_builder(ctx.name.clone())
+        let builder = SpanBuilder::from_name(ctx.name.clone())
             .with_kind(SpanKind::Internal)
-            .start(&tracer);
-        span.set_attribute(KeyValue::new("wesichain.run_id", ctx.run_id.to_string()));
-        span.set_attribute(KeyValue::new(
-            "wesichain.run_type",
-            format!("{:?}", ctx.run_type),
-        ));
-        span.set_attribute(KeyValue::new("wesichain.name", ctx.name.clone()));
+            .with_trace_id(trace_id(ctx.trace_id))
+            .with_span_id(span_id(ctx.run_id))
+            .with_attributes(self.attributes(ctx));
+        let parent_cx = self.parent_context(ctx);
+        let span = match &self.tracer {
+            Some(tracer) => tracer.build_with_context(builder, &parent_cx),
+            None => global::tracer("wesichain").build_with_context(builder, &parent_cx),
+        };
         // Store the live span — do NOT call span.end() here.
         self.active_spans.insert(ctx.run_id, span);
     }
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use futures_util::future::BoxFuture;
+use opentelemetry::trace::{Status, TracerProvider as _};
+use opentelemetry::{Array, KeyValue, StringValue, Value as AttributeValue};
+use opentelemetry_sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+use opentelemetry_sdk::trace::TracerProvider;
+use regex::Regex;
+use serde_json::json;
+use wesichain_core::{CallbackHandler, RunContext, RunType};
+use wesichain_otel::OtelCallbackHandler;
+
+#[derive(Clone, Debug, Default)]
+struct InMemoryExporter {
+    spans: Arc<Mutex<Vec<SpanData>>>,
+}
+
+impl SpanExporter for InMemoryExporter {
+    fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+        self.spans.lock().unwrap().extend(batch);
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
+
+fn setup() -> (TracerProvider, InMemoryExporter, OtelCallbackHandler) {
+    let exporter = InMemoryExporter::default();
+    let provider = TracerProvider::builder()
+        .with_simple_exporter(exporter.clone())
+        .build();
+    let handler = OtelCallbackHandler::new().with_tracer(provider.tracer("test"));
+    (provider, exporter, handler)
+}
+
+fn finished(provider: &TracerProvider, exporter: &InMemoryExporter) -> Vec<SpanData> {
+    for result in provider.force_flush() {
+        result.expect("flush should succeed");
+    }
+    exporter.spans.lock().unwrap().clone()
+}
+
+fn attribute<'a>(span: &'a SpanData, key: &str) -> Option<&'a AttributeValue> {
+    span.attributes
+        .iter()
+        .find(|attribute| attribute.key.as_str() == key)
+        .map(|attribute| &attribute.value)
+}
+
+#[tokio::test]
+async fn child_runs_become_nested_spans() {
+    let (provider, exporter, handler) = setup();
+
+    let root = RunContext::root(
+        RunType::Chain,
+        "pipeline".to_string(),
+        vec![],
+        BTreeMap::new(),
+    );
+    let child = root.child(RunType::Llm, "model".to_string());
+
+    handler.on_start(&root, &json!({})).await;
+    handler.on_start(&child, &json!({})).await;
+    handler.on_end(&child, &json!({}), 5).await;
+    handler.on_end(&root, &json!({}), 10).await;
+
+    let spans = finished(&provider, &exporter);
+    assert_eq!(spans.len(), 2);
+    let child_span = spans.iter().find(|span| span.name == "model").unwrap();
+    let root_span = spans.iter().find(|span| span.name == "pipeline").unwrap();
+
+    assert_eq!(child_span.parent_span_id, root_span.span_context.span_id());
+    assert_eq!(
+        child_span.span_context.trace_id(),
+        root_span.span_context.trace_id()
+    );
+    assert_eq!(
+        root_span.span_context.trace_id().to_bytes(),
+        *root.trace_id.as_bytes()
+    );
+    assert_eq!(child_span.status, Status::Ok);
+    assert_eq!(
+        attribute(child_span, "wesichain.parent_run_id"),
+        Some(&AttributeValue::from(root.run_id.to_string()))
+    );
+    assert_eq!(
+        attribute(child_span, "wesichain.run_type"),
+        Some(&AttributeValue::from("Llm"))
+    );
+}
+
+#[tokio::test]
+async fn tags_and_metadata_become_sanitized_attributes() {
+    let (provider, exporter, handler) = setup();
+    let handler = handler
+        .with_redact_regex(Regex::new("sk-[a-z0-9]+").unwrap())
+        .with_max_attribute_bytes(16);
+
+    let metadata = BTreeMap::from([
+        ("user".to_string(), json!("key sk-abc123")),
+        ("attempt".to_string(), json!(2)),
+        (
+            "prompt".to_string(),
+            json!("a very long prompt that will be cut"),
+        ),
+        ("flags".to_string(), json!({"beta": true})),
+    ]);
+    let run = RunContext::root(
+        RunType::Agent,
+        "agent".to_string(),
+        vec!["prod".to_string()],
+        metadata,
+    );
+
+    handler.on_start(&run, &json!({})).await;
+    handler.on_error(&run, &json!("boom"), 3).await;
+
+    let spans = finished(&provider, &exporter);
+    let span = &spans[0];
+    assert!(matches!(span.status, Status::Error { .. }));
+    assert_eq!(
+        attribute(span, "wesichain.tags"),
+        Some(&AttributeValue::Array(Array::String(vec![
+            StringValue::from("prod")
+        ])))
+    );
+    assert_eq!(
+        attribute(span, "wesichain.metadata.user"),
+        Some(&AttributeValue::from("key [REDACTED]"))
+    );
+    assert_eq!(
+        attribute(span, "wesichain.metadata.attempt"),
+        Some(&AttributeValue::I64(2))
+    );
+    assert_eq!(
+        attribute(span, "wesichain.metadata.prompt"),
+        Some(&AttributeValue::from("a very long prom"))
+    );
+    assert_eq!(
+        attribute(span, "wesichain.metadata.flags"),
+        Some(&AttributeValue::from(r#"{"beta":true}"#))
+    );
+    assert!(span
+        .attributes
+        .contains(&KeyValue::new("wesichain.name", "agent")));
+}
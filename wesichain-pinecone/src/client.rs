@@ -73,6 +73,47 @@ impl PineconeHttpClient {
             .await
             .map_err(|err| PineconeStoreError::Transport(err.to_string()))?;
 
+        Self::decode_response(response, namespace, batch_size).await
+    }
+
+    pub async fn get_typed<Resp>(&self, path: &str) -> Result<Resp, PineconeStoreError>
+    where
+        Resp: DeserializeOwned,
+    {
+        self.get_typed_with_query(path, &[], None).await
+    }
+
+    /// GET `path` with `query` appended as URL query parameters.
+    pub async fn get_typed_with_query<Resp>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        namespace: Option<&str>,
+    ) -> Result<Resp, PineconeStoreError>
+    where
+        Resp: DeserializeOwned,
+    {
+        let url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+        let response = self
+            .http
+            .get(url)
+            .header("Api-Key", &self.api_key)
+            .query(query)
+            .send()
+            .await
+            .map_err(|err| PineconeStoreError::Transport(err.to_string()))?;
+
+        Self::decode_response(response, namespace, None).await
+    }
+
+    async fn decode_response<Resp>(
+        response: reqwest::Response,
+        namespace: Option<&str>,
+        batch_size: Option<usize>,
+    ) -> Result<Resp, PineconeStoreError>
+    where
+        Resp: DeserializeOwned,
+    {
         let status = response.status();
         if status.is_success() {
             let value = response
@@ -1,9 +1,12 @@
 use crate::client::PineconeHttpClient;
 use crate::store::PineconeVectorStore;
-use crate::PineconeStoreError;
+use crate::types::IndexDescription;
+use crate::{PineconeMetric, PineconeStoreError};
 use std::sync::Arc;
 use wesichain_core::Embedding;
 
+const DEFAULT_CONTROL_PLANE_URL: &str = "https://api.pinecone.io";
+
 pub struct PineconeStoreBuilder {
     embedder: Arc<dyn Embedding>,
     base_url: Option<String>,
@@ -11,8 +14,10 @@ pub struct PineconeStoreBuilder {
     namespace: Option<String>,
     text_key: String,
     index_name: Option<String>,
+    control_plane_url: String,
     validate_dimension: bool,
     max_batch_size: usize,
+    metric: Option<PineconeMetric>,
 }
 
 impl PineconeStoreBuilder {
@@ -24,8 +29,10 @@ impl PineconeStoreBuilder {
             namespace: None,
             text_key: "text".to_string(),
             index_name: None,
+            control_plane_url: DEFAULT_CONTROL_PLANE_URL.to_string(),
             validate_dimension: false,
             max_batch_size: 1000,
+            metric: None,
         }
     }
 
@@ -54,6 +61,20 @@ impl PineconeStoreBuilder {
         self
     }
 
+    /// Index to connect to. When no `base_url` is set, `build` resolves the
+    /// index's data-plane host and dimension with a control-plane
+    /// `describe_index` call; an explicit `base_url` skips the lookup.
+    pub fn index(self, name: impl Into<String>) -> Self {
+        self.index_name(name)
+    }
+
+    /// Control-plane endpoint used to resolve an [`index`](Self::index).
+    /// Defaults to `https://api.pinecone.io`.
+    pub fn control_plane_url(mut self, value: impl Into<String>) -> Self {
+        self.control_plane_url = value.into();
+        self
+    }
+
     pub fn validate_dimension(mut self, value: bool) -> Self {
         self.validate_dimension = value;
         self
@@ -64,6 +85,13 @@ impl PineconeStoreBuilder {
         self
     }
 
+    /// Metric the index was created with, used to normalize scores. On build
+    /// it is checked against `describe_index_stats` and a mismatch is logged.
+    pub fn metric(mut self, value: PineconeMetric) -> Self {
+        self.metric = Some(value);
+        self
+    }
+
     pub fn base_url_from_env(mut self, var_name: &str) -> Self {
         if let Ok(value) = std::env::var(var_name) {
             self.base_url = Some(value);
@@ -79,12 +107,21 @@ impl PineconeStoreBuilder {
     }
 
     pub async fn build(self) -> Result<PineconeVectorStore, PineconeStoreError> {
-        let base_url = self
-            .base_url
-            .ok_or_else(|| PineconeStoreError::Config("base_url is required".to_string()))?;
         let api_key = self
             .api_key
             .ok_or_else(|| PineconeStoreError::Config("api_key is required".to_string()))?;
+        let (base_url, index_dimension) = match (self.base_url, self.index_name.as_deref()) {
+            (Some(base_url), _) => (base_url, None),
+            (None, Some(index)) => {
+                let description = describe_index(&self.control_plane_url, &api_key, index).await?;
+                (data_plane_url(&description.host), description.dimension)
+            }
+            (None, None) => {
+                return Err(PineconeStoreError::Config(
+                    "base_url or index is required".to_string(),
+                ))
+            }
+        };
 
         let client = PineconeHttpClient::new(base_url, api_key)?;
         if self.max_batch_size == 0 {
@@ -101,8 +138,28 @@ impl PineconeStoreBuilder {
             self.index_name,
             self.validate_dimension,
             self.max_batch_size,
+            self.metric,
+            index_dimension,
         );
-        store.validate_dimension_on_init().await;
+        store.validate_index_on_init().await;
         Ok(store)
     }
 }
+
+async fn describe_index(
+    control_plane_url: &str,
+    api_key: &str,
+    index: &str,
+) -> Result<IndexDescription, PineconeStoreError> {
+    let client = PineconeHttpClient::new(control_plane_url.to_string(), api_key.to_string())?;
+    client.get_typed(&format!("/indexes/{index}")).await
+}
+
+/// The control plane reports bare hosts; data-plane calls need a full URL.
+fn data_plane_url(host: &str) -> String {
+    if host.contains("://") {
+        host.to_string()
+    } else {
+        format!("https://{host}")
+    }
+}
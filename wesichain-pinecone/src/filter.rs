@@ -41,5 +41,8 @@ fn metadata_filter_to_json(filter: &MetadataFilter) -> Result<Value, PineconeSto
             let list: Result<Vec<_>, _> = filters.iter().map(metadata_filter_to_json).collect();
             json!({ "$or": list? })
         }
+        MetadataFilter::Exists(key) => json!({ key: { "$exists": true } }),
+        MetadataFilter::NotExists(key) => json!({ key: { "$exists": false } }),
+        MetadataFilter::Raw(value) => value.clone(),
     })
 }
@@ -15,9 +15,12 @@ mod config;
 mod error;
 pub mod filter;
 pub mod mapper;
+mod metric;
 mod store;
 mod types;
 
 pub use config::PineconeStoreBuilder;
 pub use error::PineconeStoreError;
+pub use metric::PineconeMetric;
 pub use store::PineconeVectorStore;
+pub use types::IndexStats;
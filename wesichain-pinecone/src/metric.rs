@@ -0,0 +1,45 @@
+/// Similarity metric a Pinecone index was created with.
+///
+/// Pinecone reports raw scores whose meaning depends on the metric: cosine
+/// similarity in `[-1, 1]`, an unbounded dot product, or a squared euclidean
+/// distance where lower is better. [`normalized_score`](Self::normalized_score)
+/// maps each onto a `[0, 1]` similarity so thresholds work across indexes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PineconeMetric {
+    Cosine,
+    DotProduct,
+    Euclidean,
+}
+
+impl PineconeMetric {
+    /// Parse the metric name used by the Pinecone API (`"cosine"`,
+    /// `"dotproduct"`, `"euclidean"`).
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "cosine" => Some(Self::Cosine),
+            "dotproduct" => Some(Self::DotProduct),
+            "euclidean" => Some(Self::Euclidean),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cosine => "cosine",
+            Self::DotProduct => "dotproduct",
+            Self::Euclidean => "euclidean",
+        }
+    }
+
+    /// Convert a raw score from an index using this metric into a similarity
+    /// in `[0, 1]`, higher meaning closer.
+    ///
+    /// Dot products are treated as cosine similarities, which holds for
+    /// unit-normalized embeddings; out-of-range values are clamped.
+    pub fn normalized_score(self, score: f32) -> f32 {
+        match self {
+            Self::Cosine | Self::DotProduct => ((score + 1.0) / 2.0).clamp(0.0, 1.0),
+            Self::Euclidean => 1.0 / (1.0 + score.max(0.0)),
+        }
+    }
+}
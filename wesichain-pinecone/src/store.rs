@@ -1,4 +1,5 @@
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use uuid::Uuid;
 use wesichain_core::{Document, Embedding, MetadataFilter, SearchResult, StoreError, VectorStore};
@@ -8,9 +9,10 @@ use crate::config::PineconeStoreBuilder;
 use crate::filter::{to_pinecone_filter_json, PineconeFilter};
 use crate::mapper::{doc_to_metadata, match_to_document};
 use crate::types::{
-    DeleteRequest, IndexStatsResponse, PineconeVector, QueryRequest, QueryResponse, UpsertRequest,
+    DeleteRequest, IndexStats, IndexStatsResponse, ListResponse, PineconeVector, QueryRequest,
+    QueryResponse, UpsertRequest,
 };
-use crate::PineconeStoreError;
+use crate::{PineconeMetric, PineconeStoreError};
 
 pub struct PineconeVectorStore {
     pub(crate) embedder: Arc<dyn Embedding>,
@@ -20,6 +22,8 @@ pub struct PineconeVectorStore {
     pub(crate) index_name: Option<String>,
     pub(crate) validate_dimension: bool,
     pub(crate) max_batch_size: usize,
+    pub(crate) metric: Option<PineconeMetric>,
+    pub(crate) index_dimension: Option<usize>,
 }
 
 impl PineconeVectorStore {
@@ -27,6 +31,7 @@ impl PineconeVectorStore {
         PineconeStoreBuilder::new(embedder)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         embedder: Arc<dyn Embedding>,
         client: PineconeHttpClient,
@@ -35,6 +40,8 @@ impl PineconeVectorStore {
         index_name: Option<String>,
         validate_dimension: bool,
         max_batch_size: usize,
+        metric: Option<PineconeMetric>,
+        index_dimension: Option<usize>,
     ) -> Self {
         Self {
             embedder,
@@ -44,6 +51,8 @@ impl PineconeVectorStore {
             index_name,
             validate_dimension,
             max_batch_size,
+            metric,
+            index_dimension,
         }
     }
 
@@ -51,8 +60,97 @@ impl PineconeVectorStore {
         &self.text_key
     }
 
-    pub(crate) async fn validate_dimension_on_init(&self) {
-        if !self.validate_dimension {
+    pub fn metric(&self) -> Option<PineconeMetric> {
+        self.metric
+    }
+
+    /// Dimension reported by `describe_index` when the host was resolved
+    /// from [`PineconeStoreBuilder::index`]; `None` with an explicit base URL.
+    pub fn index_dimension(&self) -> Option<usize> {
+        self.index_dimension
+    }
+
+    /// Vector counts per namespace and the index dimension, from
+    /// `describe_index_stats`.
+    pub async fn index_stats(&self) -> Result<IndexStats, StoreError> {
+        let stats: IndexStatsResponse = self
+            .client
+            .post_typed_with_context(
+                "/describe_index_stats",
+                &Value::Object(serde_json::Map::new()),
+                self.namespace.as_deref(),
+                None,
+            )
+            .await
+            .map_err(StoreError::from)?;
+
+        let namespaces: BTreeMap<String, u64> = stats
+            .namespaces
+            .into_iter()
+            .map(|(name, summary)| (name, summary.vector_count))
+            .collect();
+        let namespace_vector_count = namespaces
+            .get(self.namespace.as_deref().unwrap_or(""))
+            .copied()
+            .unwrap_or(0);
+
+        Ok(IndexStats {
+            dimension: stats.dimension,
+            total_vector_count: stats.total_vector_count,
+            namespaces,
+            namespace_vector_count,
+        })
+    }
+
+    /// One page of vector ids in the store's namespace, via `/vectors/list`.
+    ///
+    /// Only ids starting with `prefix` are returned when it is set. Pass the
+    /// returned token back as `pagination_token` for the next page; it is
+    /// `None` on the last page. Pinecone only supports listing on serverless
+    /// indexes.
+    pub async fn list_ids(
+        &self,
+        prefix: Option<String>,
+        limit: usize,
+        pagination_token: Option<String>,
+    ) -> Result<(Vec<String>, Option<String>), StoreError> {
+        if limit == 0 {
+            return Err(
+                PineconeStoreError::Config("limit must be greater than 0".to_string()).into(),
+            );
+        }
+
+        let mut query = vec![("limit", limit.to_string())];
+        if let Some(namespace) = &self.namespace {
+            query.push(("namespace", namespace.clone()));
+        }
+        if let Some(prefix) = prefix {
+            query.push(("prefix", prefix));
+        }
+        if let Some(token) = pagination_token {
+            query.push(("paginationToken", token));
+        }
+
+        let response: ListResponse = self
+            .client
+            .get_typed_with_query("/vectors/list", &query, self.namespace.as_deref())
+            .await
+            .map_err(StoreError::from)?;
+
+        let ids = response
+            .vectors
+            .into_iter()
+            .map(|vector| vector.id)
+            .collect();
+        let next = response
+            .pagination
+            .and_then(|pagination| pagination.next)
+            .filter(|token| !token.is_empty());
+        Ok((ids, next))
+    }
+
+    pub(crate) async fn validate_index_on_init(&self) {
+        if !self.validate_dimension && self.metric.is_none() {
             return;
         }
 
@@ -66,6 +164,12 @@ impl PineconeVectorStore {
 
         match response {
             Ok(stats) => {
+                if let Some(metric) = self.metric {
+                    self.check_index_metric(metric, stats.metric.as_deref());
+                }
+                if !self.validate_dimension {
+                    return;
+                }
                 if let Some(index_dim) = stats.dimension {
                     let embedder_dim = self.embedder.dimension();
                     if index_dim != embedder_dim {
@@ -82,7 +186,24 @@ impl PineconeVectorStore {
                 }
             }
             Err(err) => {
-                tracing::warn!(error = %err, "failed to validate pinecone index dimension");
+                tracing::warn!(error = %err, "failed to validate pinecone index settings");
+            }
+        }
+    }
+
+    fn check_index_metric(&self, metric: PineconeMetric, index_metric: Option<&str>) {
+        match index_metric {
+            Some(raw) if PineconeMetric::from_api_name(raw) != Some(metric) => {
+                tracing::warn!(
+                    index_name = ?self.index_name,
+                    configured_metric = metric.as_str(),
+                    index_metric = raw,
+                    "configured metric differs from pinecone index metric; normalized scores will be wrong"
+                );
+            }
+            Some(_) => {}
+            None => {
+                tracing::debug!("pinecone describe_index_stats response missing 'metric'");
             }
         }
     }
@@ -144,7 +265,7 @@ impl PineconeVectorStore {
         let texts: Vec<String> = docs.iter().map(|doc| doc.content.clone()).collect();
         let embeddings = self
             .embedder
-            .embed_batch(&texts)
+            .embed_documents(&texts)
             .await
             .map_err(|err| StoreError::Internal(Box::new(err)))?;
 
@@ -245,7 +366,7 @@ impl PineconeVectorStore {
     ) -> Result<Vec<Document>, StoreError> {
         let query_embedding = self
             .embedder
-            .embed(query)
+            .embed_query(query)
             .await
             .map_err(|err| StoreError::Internal(Box::new(err)))?;
 
@@ -288,7 +409,7 @@ impl PineconeVectorStore {
     ) -> Result<Vec<(Document, f32)>, StoreError> {
         let query_embedding = self
             .embedder
-            .embed(query)
+            .embed_query(query)
             .await
             .map_err(|err| StoreError::Internal(Box::new(err)))?;
 
@@ -311,6 +432,27 @@ impl PineconeVectorStore {
             .await
     }
 
+    /// Like [`similarity_search_with_score`](Self::similarity_search_with_score),
+    /// but with scores mapped to `[0, 1]` via [`PineconeMetric::normalized_score`].
+    /// Requires a metric set on the builder.
+    pub async fn similarity_search_with_normalized_score(
+        &self,
+        query: &str,
+        k: usize,
+        filter: Option<MetadataFilter>,
+    ) -> Result<Vec<(Document, f32)>, StoreError> {
+        let metric = self.metric.ok_or_else(|| {
+            StoreError::from(PineconeStoreError::Config(
+                "metric must be set on the builder to normalize scores".to_string(),
+            ))
+        })?;
+        let matches = self.similarity_search_with_score(query, k, filter).await?;
+        Ok(matches
+            .into_iter()
+            .map(|(doc, score)| (doc, metric.normalized_score(score)))
+            .collect())
+    }
+
     pub async fn similarity_search_with_score_raw_filter(
         &self,
         query: &str,
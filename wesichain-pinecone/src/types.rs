@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -59,6 +61,57 @@ pub struct DeleteRequest {
 #[derive(Clone, Debug, Deserialize)]
 pub struct IndexStatsResponse {
     pub dimension: Option<usize>,
+    #[serde(default)]
+    pub metric: Option<String>,
+    #[serde(default, rename = "totalVectorCount")]
+    pub total_vector_count: u64,
+    #[serde(default)]
+    pub namespaces: HashMap<String, NamespaceSummary>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NamespaceSummary {
+    #[serde(default, rename = "vectorCount")]
+    pub vector_count: u64,
+}
+
+/// Index statistics from `describe_index_stats`, as returned by
+/// [`PineconeVectorStore::index_stats`](crate::PineconeVectorStore::index_stats).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IndexStats {
+    pub dimension: Option<usize>,
+    pub total_vector_count: u64,
+    /// Vector count per namespace. The default namespace is `""`.
+    pub namespaces: BTreeMap<String, u64>,
+    /// Vector count in the store's own namespace; `0` if it has none yet.
+    pub namespace_vector_count: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    #[serde(default)]
+    pub vectors: Vec<ListedVector>,
+    #[serde(default)]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListedVector {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Pagination {
+    #[serde(default)]
+    pub next: Option<String>,
+}
+
+/// Control-plane `describe_index` response; `host` is the data-plane host.
+#[derive(Clone, Debug, Deserialize)]
+pub struct IndexDescription {
+    pub host: String,
+    #[serde(default)]
+    pub dimension: Option<usize>,
 }
 
 #[cfg(test)]
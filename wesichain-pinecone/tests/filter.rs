@@ -26,9 +26,36 @@ fn converts_nested_all_any_filter() {
     assert!(out.get("$and").is_some());
 }
 
+#[test]
+fn converts_exists_and_not_exists_filters() {
+    let exists = PineconeFilter::Typed(MetadataFilter::Exists("author".to_string()));
+    let missing = PineconeFilter::Typed(MetadataFilter::NotExists("author".to_string()));
+
+    assert_eq!(
+        to_pinecone_filter_json(&exists).unwrap(),
+        json!({"author": {"$exists": true}})
+    );
+    assert_eq!(
+        to_pinecone_filter_json(&missing).unwrap(),
+        json!({"author": {"$exists": false}})
+    );
+}
+
 #[test]
 fn raw_filter_passthrough() {
     let raw = json!({"$or": [{"source": {"$eq": "tweet"}}]});
     let out = to_pinecone_filter_json(&PineconeFilter::Raw(raw.clone())).unwrap();
     assert_eq!(out, raw);
 }
+
+#[test]
+fn typed_raw_filter_passthrough_inside_composites() {
+    let raw = json!({"genre": {"$nin": ["comedy", "drama"]}});
+    let filter = PineconeFilter::Typed(MetadataFilter::All(vec![
+        MetadataFilter::Eq("source".to_string(), json!("tweet")),
+        MetadataFilter::Raw(raw.clone()),
+    ]));
+
+    let out = to_pinecone_filter_json(&filter).unwrap();
+    assert_eq!(out, json!({"$and": [{"source": {"$eq": "tweet"}}, raw]}));
+}
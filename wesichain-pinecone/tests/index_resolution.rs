@@ -0,0 +1,108 @@
+use serde_json::json;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use wesichain_core::{Embedding, EmbeddingError};
+use wesichain_pinecone::{PineconeStoreError, PineconeVectorStore};
+
+#[derive(Clone)]
+struct FixedEmbedding;
+
+#[async_trait::async_trait]
+impl Embedding for FixedEmbedding {
+    async fn embed(&self, _text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        Ok(vec![0.9, 0.1])
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        Ok(texts.iter().map(|_| vec![0.9, 0.1]).collect())
+    }
+
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+#[tokio::test]
+async fn build_resolves_data_plane_host_from_describe_index() {
+    let control_plane = MockServer::start().await;
+    let data_plane = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/indexes/docs"))
+        .and(header("Api-Key", "key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": "docs",
+            "dimension": 2,
+            "metric": "cosine",
+            "host": data_plane.uri(),
+            "status": {"ready": true, "state": "Ready"}
+        })))
+        .expect(1)
+        .mount(&control_plane)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/query"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "matches": [{"id": "doc-1", "score": 0.5, "metadata": {"text": "hello"}}]
+        })))
+        .expect(1)
+        .mount(&data_plane)
+        .await;
+
+    let store = PineconeVectorStore::builder(FixedEmbedding)
+        .index("docs")
+        .api_key("key")
+        .control_plane_url(control_plane.uri())
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(store.index_dimension(), Some(2));
+    let docs = store.similarity_search("query", 1, None).await.unwrap();
+    assert_eq!(docs[0].content, "hello");
+}
+
+#[tokio::test]
+async fn explicit_base_url_skips_describe_index() {
+    let control_plane = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&control_plane)
+        .await;
+
+    let store = PineconeVectorStore::builder(FixedEmbedding)
+        .index("docs")
+        .base_url("https://docs-abc.svc.pinecone.io")
+        .api_key("key")
+        .control_plane_url(control_plane.uri())
+        .build()
+        .await
+        .unwrap();
+
+    assert_eq!(store.index_dimension(), None);
+}
+
+#[tokio::test]
+async fn describe_index_errors_surface_from_build() {
+    let control_plane = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/indexes/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "index not found"
+        })))
+        .mount(&control_plane)
+        .await;
+
+    let result = PineconeVectorStore::builder(FixedEmbedding)
+        .index("missing")
+        .api_key("key")
+        .control_plane_url(control_plane.uri())
+        .build()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(PineconeStoreError::Api { status: 404, ref message, .. }) if message == "index not found"
+    ));
+}
@@ -0,0 +1,137 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use wesichain_core::{Embedding, EmbeddingError, StoreError};
+use wesichain_pinecone::PineconeVectorStore;
+
+#[derive(Clone)]
+struct FixedEmbedding;
+
+#[async_trait::async_trait]
+impl Embedding for FixedEmbedding {
+    async fn embed(&self, _text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        Ok(vec![0.9, 0.1])
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        Ok(texts.iter().map(|_| vec![0.9, 0.1]).collect())
+    }
+
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+async fn store(server: &MockServer, namespace: Option<&str>) -> PineconeVectorStore {
+    let builder = PineconeVectorStore::builder(FixedEmbedding)
+        .base_url(server.uri())
+        .api_key("key")
+        .validate_dimension(false);
+    let builder = match namespace {
+        Some(namespace) => builder.namespace(namespace),
+        None => builder,
+    };
+    builder.build().await.unwrap()
+}
+
+#[tokio::test]
+async fn index_stats_reports_namespace_counts() {
+    let server = MockServer::start().await;
+    // Recorded from a serverless index with two namespaces.
+    Mock::given(method("POST"))
+        .and(path("/describe_index_stats"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "namespaces": {
+                "": {"vectorCount": 3},
+                "prod": {"vectorCount": 42}
+            },
+            "dimension": 1536,
+            "indexFullness": 0.0,
+            "totalVectorCount": 45,
+            "metric": "cosine",
+            "vectorType": "dense"
+        })))
+        .mount(&server)
+        .await;
+
+    let stats = store(&server, Some("prod"))
+        .await
+        .index_stats()
+        .await
+        .unwrap();
+    assert_eq!(stats.dimension, Some(1536));
+    assert_eq!(stats.total_vector_count, 45);
+    assert_eq!(stats.namespaces.get("prod"), Some(&42));
+    assert_eq!(stats.namespaces.get(""), Some(&3));
+    assert_eq!(stats.namespace_vector_count, 42);
+
+    let default_ns = store(&server, None).await.index_stats().await.unwrap();
+    assert_eq!(default_ns.namespace_vector_count, 3);
+
+    let empty_ns = store(&server, Some("staging"))
+        .await
+        .index_stats()
+        .await
+        .unwrap();
+    assert_eq!(empty_ns.namespace_vector_count, 0);
+}
+
+#[tokio::test]
+async fn list_ids_pages_through_the_store_namespace() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/vectors/list"))
+        .and(query_param("namespace", "prod"))
+        .and(query_param("prefix", "doc#"))
+        .and(query_param("limit", "2"))
+        .and(query_param("paginationToken", "page-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "vectors": [{"id": "doc#3"}],
+            "namespace": "prod",
+            "usage": {"readUnits": 1}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/vectors/list"))
+        .and(query_param("namespace", "prod"))
+        .and(query_param("prefix", "doc#"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "vectors": [{"id": "doc#1"}, {"id": "doc#2"}],
+            "pagination": {"next": "page-2"},
+            "namespace": "prod",
+            "usage": {"readUnits": 1}
+        })))
+        .mount(&server)
+        .await;
+
+    let store = store(&server, Some("prod")).await;
+    let (first, token) = store
+        .list_ids(Some("doc#".to_string()), 2, None)
+        .await
+        .unwrap();
+    assert_eq!(first, vec!["doc#1", "doc#2"]);
+    assert_eq!(token.as_deref(), Some("page-2"));
+
+    let (second, token) = store
+        .list_ids(Some("doc#".to_string()), 2, token)
+        .await
+        .unwrap();
+    assert_eq!(second, vec!["doc#3"]);
+    assert_eq!(token, None);
+}
+
+#[tokio::test]
+async fn list_ids_rejects_zero_limit() {
+    let server = MockServer::start().await;
+    let err = store(&server, None)
+        .await
+        .list_ids(None, 0, None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, StoreError::Internal(_)), "{err:?}");
+    assert!(err.to_string().contains("limit"), "{err}");
+}
@@ -0,0 +1,130 @@
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use wesichain_core::{Embedding, EmbeddingError};
+use wesichain_pinecone::{PineconeMetric, PineconeVectorStore};
+
+#[derive(Clone)]
+struct FixedEmbedding;
+
+#[async_trait::async_trait]
+impl Embedding for FixedEmbedding {
+    async fn embed(&self, _text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        Ok(vec![0.9, 0.1])
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        Ok(texts.iter().map(|_| vec![0.9, 0.1]).collect())
+    }
+
+    fn dimension(&self) -> usize {
+        2
+    }
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn cosine_scores_map_from_minus_one_one_to_zero_one() {
+    assert_close(PineconeMetric::Cosine.normalized_score(1.0), 1.0);
+    assert_close(PineconeMetric::Cosine.normalized_score(0.0), 0.5);
+    assert_close(PineconeMetric::Cosine.normalized_score(-1.0), 0.0);
+}
+
+#[test]
+fn dot_product_scores_are_treated_as_cosine_and_clamped() {
+    assert_close(PineconeMetric::DotProduct.normalized_score(0.5), 0.75);
+    assert_close(PineconeMetric::DotProduct.normalized_score(3.0), 1.0);
+    assert_close(PineconeMetric::DotProduct.normalized_score(-3.0), 0.0);
+}
+
+#[test]
+fn euclidean_distances_become_similarities() {
+    assert_close(PineconeMetric::Euclidean.normalized_score(0.0), 1.0);
+    assert_close(PineconeMetric::Euclidean.normalized_score(1.0), 0.5);
+    assert_close(PineconeMetric::Euclidean.normalized_score(3.0), 0.25);
+    assert!(
+        PineconeMetric::Euclidean.normalized_score(0.2)
+            > PineconeMetric::Euclidean.normalized_score(0.8)
+    );
+}
+
+#[test]
+fn metric_parses_api_names() {
+    assert_eq!(
+        PineconeMetric::from_api_name("dotproduct"),
+        Some(PineconeMetric::DotProduct)
+    );
+    assert_eq!(
+        PineconeMetric::from_api_name("Euclidean"),
+        Some(PineconeMetric::Euclidean)
+    );
+    assert_eq!(PineconeMetric::from_api_name("manhattan"), None);
+}
+
+#[tokio::test]
+async fn normalized_search_uses_configured_metric() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/describe_index_stats"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"dimension": 2, "metric": "cosine"})),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/query"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "matches": [{"id": "doc-1", "score": 1.0, "metadata": {"text": "hello"}}]
+        })))
+        .mount(&server)
+        .await;
+
+    // The index reports cosine; the mismatch is only logged and the
+    // configured metric still drives normalization.
+    let store = PineconeVectorStore::builder(FixedEmbedding)
+        .base_url(server.uri())
+        .api_key("key")
+        .metric(PineconeMetric::Euclidean)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(store.metric(), Some(PineconeMetric::Euclidean));
+
+    let raw = store
+        .similarity_search_with_score("query", 1, None)
+        .await
+        .unwrap();
+    let normalized = store
+        .similarity_search_with_normalized_score("query", 1, None)
+        .await
+        .unwrap();
+
+    assert_close(raw[0].1, 1.0);
+    assert_close(normalized[0].1, 0.5);
+    assert_eq!(normalized[0].0.content, "hello");
+}
+
+#[tokio::test]
+async fn normalized_search_requires_a_metric() {
+    let server = MockServer::start().await;
+
+    let store = PineconeVectorStore::builder(FixedEmbedding)
+        .base_url(server.uri())
+        .api_key("key")
+        .build()
+        .await
+        .unwrap();
+
+    let result = store
+        .similarity_search_with_normalized_score("query", 1, None)
+        .await;
+    assert!(result.is_err());
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
@@ -40,18 +40,21 @@ impl MessagePromptTemplate {
                 content: t.render(vars)?.into(),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             }]),
             MessagePromptTemplate::AI(t) => Ok(vec![Message {
                 role: Role::Assistant,
                 content: t.render(vars)?.into(),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             }]),
             MessagePromptTemplate::System(t) => Ok(vec![Message {
                 role: Role::System,
                 content: t.render(vars)?.into(),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             }]),
             MessagePromptTemplate::Placeholder { variable_name } => {
                 if let Some(val) = vars.get(variable_name) {
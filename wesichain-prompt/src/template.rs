@@ -6,15 +6,69 @@ use wesichain_core::{Value, WesichainError};
 #[derive(Debug, Clone)]
 pub struct PromptTemplate {
     template: String,
+    partials: HashMap<String, String>,
 }
 
+const VARIABLE_PATTERN: &str = r"\{\{\s*([\w.-]+)\s*\}\}";
+
 impl PromptTemplate {
     pub fn new(template: String) -> Self {
-        Self { template }
+        Self {
+            template,
+            partials: HashMap::new(),
+        }
+    }
+
+    /// Return a copy of this template with `vars` pre-bound, so later calls
+    /// to [`render`](Self::render) only need the remaining variables.
+    ///
+    /// Values supplied at render time take precedence over partials. Fails
+    /// with [`WesichainError::InvalidConfig`] if a variable is not declared
+    /// in the template.
+    pub fn partial(&self, vars: HashMap<String, String>) -> Result<Self, WesichainError> {
+        let pattern = Regex::new(VARIABLE_PATTERN)
+            .map_err(|e| WesichainError::InvalidConfig(e.to_string()))?;
+        let declared: Vec<&str> = pattern
+            .captures_iter(&self.template)
+            .filter_map(|caps| caps.get(1).map(|m| m.as_str()))
+            .collect();
+
+        let mut undeclared: Vec<&String> = vars
+            .keys()
+            .filter(|key| !declared.contains(&key.as_str()))
+            .collect();
+        undeclared.sort();
+        if let Some(key) = undeclared.first() {
+            return Err(WesichainError::InvalidConfig(format!(
+                "partial variable '{key}' is not declared in the template"
+            )));
+        }
+
+        let mut partial = self.clone();
+        partial.partials.extend(vars);
+        Ok(partial)
+    }
+
+    /// Insert output format instructions, such as those from
+    /// `StructuredOutputParser::format_instructions`, into the template.
+    ///
+    /// Replaces a `{{format_instructions}}` placeholder when the template has
+    /// one, otherwise appends the instructions after a blank line.
+    pub fn with_format_instructions(mut self, instructions: impl AsRef<str>) -> Self {
+        let instructions = instructions.as_ref();
+        let pattern = Regex::new(r"\{\{\s*format_instructions\s*\}\}").expect("valid regex");
+        if pattern.is_match(&self.template) {
+            self.template = pattern
+                .replace_all(&self.template, regex::NoExpand(instructions))
+                .into_owned();
+        } else {
+            self.template = format!("{}\n\n{}", self.template.trim_end(), instructions);
+        }
+        self
     }
 
     pub fn render(&self, vars: &HashMap<String, Value>) -> Result<String, WesichainError> {
-        let pattern = Regex::new(r"\{\{\s*([\w.-]+)\s*\}\}")
+        let pattern = Regex::new(VARIABLE_PATTERN)
             .map_err(|e| WesichainError::InvalidConfig(e.to_string()))?;
         let rendered = pattern.replace_all(&self.template, |caps: &regex::Captures| {
             let key = &caps[1];
@@ -23,10 +77,13 @@ impl PromptTemplate {
                     .as_str()
                     .map(|s| s.to_string())
                     .unwrap_or_else(|| value.to_string()),
-                None => caps
-                    .get(0)
-                    .map(|m| m.as_str().to_string())
-                    .unwrap_or_default(),
+                None => match self.partials.get(key) {
+                    Some(value) => value.clone(),
+                    None => caps
+                        .get(0)
+                        .map(|m| m.as_str().to_string())
+                        .unwrap_or_default(),
+                },
             }
         });
         Ok(rendered.to_string())
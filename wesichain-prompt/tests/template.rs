@@ -47,3 +47,55 @@ fn renders_non_string_value() {
     let rendered = tmpl.render(&vars).expect("render");
     assert_eq!(rendered, "Count 42");
 }
+
+#[test]
+fn format_instructions_fill_placeholder() {
+    let tmpl =
+        PromptTemplate::new("Extract {{item}}.\n{{ format_instructions }}\nDone.".to_string())
+            .with_format_instructions("Reply as JSON: {\"name\": \"string\"}");
+    let mut vars = HashMap::new();
+    vars.insert("item".to_string(), Value::from("the user"));
+    let rendered = tmpl.render(&vars).expect("render");
+    assert_eq!(
+        rendered,
+        "Extract the user.\nReply as JSON: {\"name\": \"string\"}\nDone."
+    );
+}
+
+#[test]
+fn format_instructions_appended_without_placeholder() {
+    let tmpl = PromptTemplate::new("Extract {{item}}.\n".to_string())
+        .with_format_instructions("Reply as JSON.");
+    let mut vars = HashMap::new();
+    vars.insert("item".to_string(), Value::from("the user"));
+    let rendered = tmpl.render(&vars).expect("render");
+    assert_eq!(rendered, "Extract the user.\n\nReply as JSON.");
+}
+
+#[test]
+fn partial_prefills_variables_for_later_render() {
+    let tmpl = PromptTemplate::new("You are {{persona}}. Answer: {{question}}".to_string());
+    let partial = tmpl
+        .partial(HashMap::from([(
+            "persona".to_string(),
+            "a terse assistant".to_string(),
+        )]))
+        .expect("partial");
+    let mut vars = HashMap::new();
+    vars.insert("question".to_string(), Value::from("why?"));
+    let rendered = partial.render(&vars).expect("render");
+    assert_eq!(rendered, "You are a terse assistant. Answer: why?");
+
+    vars.insert("persona".to_string(), Value::from("a pirate"));
+    let rendered = partial.render(&vars).expect("render");
+    assert_eq!(rendered, "You are a pirate. Answer: why?");
+}
+
+#[test]
+fn partial_rejects_undeclared_variable() {
+    let tmpl = PromptTemplate::new("Hi {{name}}".to_string());
+    let err = tmpl
+        .partial(HashMap::from([("persona".to_string(), "x".to_string())]))
+        .expect_err("undeclared variable");
+    assert!(err.to_string().contains("persona"), "{err}");
+}
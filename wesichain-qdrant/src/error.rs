@@ -29,8 +29,13 @@ pub enum QdrantStoreError {
     UnsupportedFilter,
     #[error("unsupported metadata filter value for key '{key}': {reason}")]
     UnsupportedFilterValue { key: String, reason: String },
+    #[error("refusing to delete by an empty filter")]
+    EmptyFilter,
     #[error("qdrant request failed: {0}")]
     Request(#[from] reqwest::Error),
+    #[cfg(feature = "grpc")]
+    #[error("qdrant gRPC request failed: {0}")]
+    Grpc(#[from] qdrant_client::QdrantError),
     #[error("collection '{collection}' not found: {message}")]
     CollectionNotFound { collection: String, message: String },
     #[error("qdrant returned HTTP {status}: {message}")]
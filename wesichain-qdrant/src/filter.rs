@@ -1,6 +1,6 @@
 use qdrant_client::qdrant::{
-    condition, r#match, Condition, FieldCondition, Filter, Match, Range, RepeatedIntegers,
-    RepeatedStrings,
+    condition, r#match, Condition, FieldCondition, Filter, IsEmptyCondition, Match, Range,
+    RepeatedIntegers, RepeatedStrings,
 };
 use serde_json::{Map as JsonMap, Value};
 use wesichain_core::MetadataFilter;
@@ -15,6 +15,17 @@ pub fn qdrant_filter_to_payload(filter: &Filter) -> Result<Value, QdrantStoreErr
     filter_payload(filter)
 }
 
+/// Translate a [`MetadataFilter`] into the JSON filter sent to the REST API.
+///
+/// A top-level [`MetadataFilter::Raw`] is sent as-is; raw filters nested
+/// inside `All`/`Any` are rejected since they have no typed equivalent.
+pub fn to_qdrant_payload(filter: &MetadataFilter) -> Result<Value, QdrantStoreError> {
+    match filter {
+        MetadataFilter::Raw(value) => Ok(value.clone()),
+        filter => qdrant_filter_to_payload(&to_qdrant_filter(filter)?),
+    }
+}
+
 fn filter_to_filter(filter: &MetadataFilter) -> Result<Filter, QdrantStoreError> {
     match filter {
         MetadataFilter::Eq(key, value) => Ok(Filter {
@@ -65,6 +76,18 @@ fn filter_to_filter(filter: &MetadataFilter) -> Result<Filter, QdrantStoreError>
                 ..Filter::default()
             })
         }
+        MetadataFilter::Exists(key) => Ok(Filter {
+            must_not: vec![is_empty_condition(key)],
+            ..Filter::default()
+        }),
+        MetadataFilter::NotExists(key) => Ok(Filter {
+            must: vec![is_empty_condition(key)],
+            ..Filter::default()
+        }),
+        MetadataFilter::Raw(_) => Err(QdrantStoreError::UnsupportedFilterValue {
+            key: "raw".to_string(),
+            reason: "raw filters must be the top-level filter".to_string(),
+        }),
     }
 }
 
@@ -74,6 +97,16 @@ fn filter_condition(filter: &MetadataFilter) -> Result<Condition, QdrantStoreErr
     })
 }
 
+/// Qdrant's `is_empty` also matches keys holding `null` or `[]`, which is the
+/// closest payload condition to key absence.
+fn is_empty_condition(key: &str) -> Condition {
+    Condition {
+        condition_one_of: Some(condition::ConditionOneOf::IsEmpty(IsEmptyCondition {
+            key: key.to_string(),
+        })),
+    }
+}
+
 fn eq_condition(key: &str, value: &Value) -> Result<Condition, QdrantStoreError> {
     let field = match value {
         Value::Bool(value) => FieldCondition {
@@ -310,6 +343,9 @@ fn condition_payload(condition: &Condition) -> Result<Value, QdrantStoreError> {
     match condition.condition_one_of.as_ref() {
         Some(condition::ConditionOneOf::Field(field)) => field_payload(field),
         Some(condition::ConditionOneOf::Filter(filter)) => filter_payload(filter),
+        Some(condition::ConditionOneOf::IsEmpty(is_empty)) => Ok(serde_json::json!({
+            "is_empty": { "key": is_empty.key }
+        })),
         _ => Err(QdrantStoreError::UnsupportedFilterValue {
             key: "<condition>".to_string(),
             reason: "unsupported qdrant condition generated".to_string(),
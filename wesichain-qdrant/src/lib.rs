@@ -3,20 +3,28 @@
 mod config;
 mod error;
 pub mod filter;
+#[cfg(feature = "grpc")]
+mod grpc;
 pub mod mapper;
 
 use std::fmt;
 
 pub use config::QdrantStoreBuilder;
 pub use error::QdrantStoreError;
-use filter::{qdrant_filter_to_payload, to_qdrant_filter};
+use filter::to_qdrant_payload;
+#[cfg(feature = "grpc")]
+pub use grpc::{QdrantGrpcStore, QdrantGrpcStoreBuilder};
+pub use mapper::QdrantSearchParams;
 use mapper::{
-    doc_to_point, scored_point_to_result, ApiResponse, DeletePointsRequest, PointId, ScoredPoint,
+    delete_by_filter_request, doc_to_point, scored_point_to_result, ApiResponse,
+    DeletePointsRequest, PointId, RetrievePointsRequest, RetrievedPoint, ScoredPoint,
     SearchPointsRequest, UpsertPointsRequest,
 };
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
-use wesichain_core::{Document, MetadataFilter, SearchResult, StoreError, VectorStore};
+use wesichain_core::{
+    find_duplicate_id, Document, MetadataFilter, SearchResult, StoreError, VectorStore, WriteMode,
+};
 
 #[derive(Clone)]
 pub struct QdrantVectorStore {
@@ -68,6 +76,39 @@ impl QdrantVectorStore {
         <Self as VectorStore>::search(self, query_embedding, top_k, filter).await
     }
 
+    /// Like [`VectorStore::search`], but sends `params` with the query to
+    /// tune HNSW recall (`hnsw_ef`) or force a full scan (`exact`).
+    pub async fn search_with_params(
+        &self,
+        query_embedding: &[f32],
+        top_k: usize,
+        filter: Option<&MetadataFilter>,
+        params: QdrantSearchParams,
+    ) -> Result<Vec<SearchResult>, StoreError> {
+        self.search_points(query_embedding, top_k, filter, Some(params))
+            .await
+    }
+
+    /// Delete every point whose payload matches `filter`.
+    ///
+    /// The filter is translated like a search filter; one that yields no
+    /// conditions is rejected rather than clearing the collection.
+    pub async fn delete_by_filter(&self, filter: &MetadataFilter) -> Result<(), StoreError> {
+        let request = delete_by_filter_request(filter).map_err(StoreError::from)?;
+        let _: ApiResponse<JsonValue> = self
+            .send_and_decode(
+                self.request_builder(
+                    reqwest::Method::POST,
+                    &format!("collections/{}/points/delete?wait=true", self.collection),
+                )
+                .json(&request),
+            )
+            .await
+            .map_err(StoreError::from)?;
+
+        Ok(())
+    }
+
     fn endpoint(&self, path: &str) -> String {
         format!(
             "{}/{}",
@@ -103,6 +144,84 @@ impl QdrantVectorStore {
         })
     }
 
+    /// Returns the subset of `ids` that already exist in the collection.
+    async fn existing_ids(&self, ids: &[String]) -> Result<Vec<String>, StoreError> {
+        let ids = ids
+            .iter()
+            .cloned()
+            .map(PointId::from_document_id)
+            .collect::<Result<Vec<PointId>, QdrantStoreError>>()
+            .map_err(StoreError::from)?;
+
+        let request = RetrievePointsRequest {
+            ids,
+            with_payload: false,
+            with_vector: false,
+        };
+        let response: ApiResponse<Vec<RetrievedPoint>> = self
+            .send_and_decode(
+                self.request_builder(
+                    reqwest::Method::POST,
+                    &format!("collections/{}/points", self.collection),
+                )
+                .json(&request),
+            )
+            .await
+            .map_err(StoreError::from)?;
+
+        Ok(response
+            .result
+            .into_iter()
+            .map(|point| point.id.as_string())
+            .collect())
+    }
+
+    async fn search_points(
+        &self,
+        query_embedding: &[f32],
+        top_k: usize,
+        filter: Option<&MetadataFilter>,
+        params: Option<QdrantSearchParams>,
+    ) -> Result<Vec<SearchResult>, StoreError> {
+        if query_embedding.is_empty() || top_k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let qdrant_filter = filter
+            .map(to_qdrant_payload)
+            .transpose()
+            .map_err(StoreError::from)?;
+
+        let request = SearchPointsRequest {
+            vector: query_embedding.to_vec(),
+            limit: top_k,
+            with_payload: true,
+            filter: qdrant_filter,
+            params,
+        };
+
+        let response: ApiResponse<Vec<ScoredPoint>> = self
+            .send_and_decode(
+                self.request_builder(
+                    reqwest::Method::POST,
+                    &format!("collections/{}/points/search", self.collection),
+                )
+                .json(&request),
+            )
+            .await
+            .map_err(StoreError::from)?;
+
+        let mut results = response
+            .result
+            .into_iter()
+            .map(scored_point_to_result)
+            .collect::<Result<Vec<SearchResult>, QdrantStoreError>>()
+            .map_err(StoreError::from)?;
+
+        results.sort_by(|left, right| right.score.total_cmp(&left.score));
+        Ok(results)
+    }
+
     fn http_error_from_response(&self, status: u16, body: &str) -> QdrantStoreError {
         let message = qdrant_error_message(body);
         if status == 404 && message.to_lowercase().contains("collection") {
@@ -156,51 +275,29 @@ impl VectorStore for QdrantVectorStore {
         Ok(())
     }
 
+    async fn add_mode(&self, docs: Vec<Document>, mode: WriteMode) -> Result<(), StoreError> {
+        if mode == WriteMode::InsertOnly && !docs.is_empty() {
+            if let Some(id) = find_duplicate_id(&docs) {
+                return Err(StoreError::DuplicateId(id.to_string()));
+            }
+
+            let ids = docs.iter().map(|doc| doc.id.clone()).collect::<Vec<_>>();
+            if let Some(id) = self.existing_ids(&ids).await?.into_iter().next() {
+                return Err(StoreError::DuplicateId(id));
+            }
+        }
+
+        self.add(docs).await
+    }
+
     async fn search(
         &self,
         query_embedding: &[f32],
         top_k: usize,
         filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>, StoreError> {
-        if query_embedding.is_empty() || top_k == 0 {
-            return Ok(Vec::new());
-        }
-
-        let qdrant_filter = match filter {
-            Some(filter) => {
-                let translated = to_qdrant_filter(filter).map_err(StoreError::from)?;
-                Some(qdrant_filter_to_payload(&translated).map_err(StoreError::from)?)
-            }
-            None => None,
-        };
-
-        let request = SearchPointsRequest {
-            vector: query_embedding.to_vec(),
-            limit: top_k,
-            with_payload: true,
-            filter: qdrant_filter,
-        };
-
-        let response: ApiResponse<Vec<ScoredPoint>> = self
-            .send_and_decode(
-                self.request_builder(
-                    reqwest::Method::POST,
-                    &format!("collections/{}/points/search", self.collection),
-                )
-                .json(&request),
-            )
+        self.search_points(query_embedding, top_k, filter, None)
             .await
-            .map_err(StoreError::from)?;
-
-        let mut results = response
-            .result
-            .into_iter()
-            .map(scored_point_to_result)
-            .collect::<Result<Vec<SearchResult>, QdrantStoreError>>()
-            .map_err(StoreError::from)?;
-
-        results.sort_by(|left, right| right.score.total_cmp(&left.score));
-        Ok(results)
     }
 
     async fn delete(&self, ids: &[String]) -> Result<(), StoreError> {
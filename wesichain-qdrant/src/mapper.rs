@@ -2,8 +2,9 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 use serde_json::{Map as JsonMap, Value as JsonValue};
-use wesichain_core::{Document, SearchResult, Value};
+use wesichain_core::{Document, MetadataFilter, SearchResult, Value};
 
+use crate::filter::to_qdrant_payload;
 use crate::QdrantStoreError;
 
 pub const CONTENT_PAYLOAD_KEY: &str = "__wesichain_content";
@@ -18,6 +19,37 @@ pub struct DeletePointsRequest {
     pub points: Vec<PointId>,
 }
 
+/// Body of a `points/delete` call that removes every point matching `filter`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeletePointsByFilterRequest {
+    pub filter: JsonValue,
+}
+
+/// Build the delete body for `filter`, rejecting filters that translate to no
+/// conditions, since Qdrant would treat those as matching every point.
+pub fn delete_by_filter_request(
+    filter: &MetadataFilter,
+) -> Result<DeletePointsByFilterRequest, QdrantStoreError> {
+    let filter = to_qdrant_payload(filter)?;
+    if filter.as_object().is_some_and(JsonMap::is_empty) {
+        return Err(QdrantStoreError::EmptyFilter);
+    }
+
+    Ok(DeletePointsByFilterRequest { filter })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RetrievePointsRequest {
+    pub ids: Vec<PointId>,
+    pub with_payload: bool,
+    pub with_vector: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RetrievedPoint {
+    pub id: PointId,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct SearchPointsRequest {
     pub vector: Vec<f32>,
@@ -25,6 +57,37 @@ pub struct SearchPointsRequest {
     pub with_payload: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<QdrantSearchParams>,
+}
+
+/// Per-query HNSW tuning, sent as the search request's `params` object.
+///
+/// `exact: true` bypasses the index for a full scan; a higher `hnsw_ef`
+/// widens the beam for better recall at the cost of latency. Unset fields
+/// fall back to the collection's configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct QdrantSearchParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hnsw_ef: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exact: Option<bool>,
+}
+
+impl QdrantSearchParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hnsw_ef(mut self, hnsw_ef: usize) -> Self {
+        self.hnsw_ef = Some(hnsw_ef);
+        self
+    }
+
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = Some(exact);
+        self
+    }
 }
 
 #[derive(Debug, Deserialize)]
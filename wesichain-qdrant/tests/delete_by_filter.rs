@@ -0,0 +1,45 @@
+use serde_json::json;
+use wesichain_core::MetadataFilter;
+use wesichain_qdrant::mapper::delete_by_filter_request;
+use wesichain_qdrant::QdrantStoreError;
+
+#[test]
+fn delete_by_filter_request_serializes_filter_body() {
+    let filter = MetadataFilter::Eq("source".to_string(), json!("old"));
+    let request = delete_by_filter_request(&filter).expect("filter should translate");
+    let body = serde_json::to_value(request).expect("request should serialize");
+
+    assert_eq!(
+        body,
+        json!({"filter": {"must": [{"key": "source", "match": {"value": "old"}}]}})
+    );
+}
+
+#[test]
+fn delete_by_filter_request_rejects_empty_filter() {
+    let err = delete_by_filter_request(&MetadataFilter::All(vec![]))
+        .expect_err("empty filter should be rejected");
+
+    assert!(matches!(
+        err,
+        QdrantStoreError::UnsupportedFilterValue { key, .. } if key == "all"
+    ));
+}
+
+#[test]
+fn delete_by_filter_request_rejects_empty_raw_filter() {
+    let err = delete_by_filter_request(&MetadataFilter::Raw(json!({})))
+        .expect_err("empty raw filter should be rejected");
+
+    assert!(matches!(err, QdrantStoreError::EmptyFilter));
+}
+
+#[test]
+fn delete_by_filter_request_sends_raw_filter_verbatim() {
+    let raw = json!({"must": [{"key": "tags", "values_count": {"gt": 2}}]});
+    let request =
+        delete_by_filter_request(&MetadataFilter::Raw(raw.clone())).expect("raw filter is valid");
+    let body = serde_json::to_value(request).expect("request should serialize");
+
+    assert_eq!(body, json!({ "filter": raw }));
+}
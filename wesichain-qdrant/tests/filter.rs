@@ -2,7 +2,7 @@ use qdrant_client::qdrant::{condition, r#match, Condition};
 use serde_json::json;
 use wesichain_core::MetadataFilter;
 use wesichain_qdrant::{
-    filter::{qdrant_filter_to_payload, to_qdrant_filter},
+    filter::{qdrant_filter_to_payload, to_qdrant_filter, to_qdrant_payload},
     QdrantStoreError,
 };
 
@@ -194,6 +194,23 @@ fn rejects_in_u64_values_above_i64_max() {
     ));
 }
 
+#[test]
+fn converts_exists_and_not_exists_to_is_empty_conditions() {
+    let exists = to_qdrant_filter(&MetadataFilter::Exists("author".to_string()))
+        .expect("exists filter should convert");
+    let missing = to_qdrant_filter(&MetadataFilter::NotExists("author".to_string()))
+        .expect("not-exists filter should convert");
+
+    assert_eq!(
+        qdrant_filter_to_payload(&exists).expect("payload serialization should work"),
+        json!({ "must_not": [{ "is_empty": { "key": "author" } }] })
+    );
+    assert_eq!(
+        qdrant_filter_to_payload(&missing).expect("payload serialization should work"),
+        json!({ "must": [{ "is_empty": { "key": "author" } }] })
+    );
+}
+
 #[test]
 fn serializes_nested_all_any_payload_for_search_wiring() {
     let metadata_filter = MetadataFilter::All(vec![
@@ -248,3 +265,28 @@ fn serializes_nested_all_any_payload_for_search_wiring() {
         })
     );
 }
+
+#[test]
+fn raw_filter_passes_through_to_payload() {
+    let raw = json!({
+        "must": [{
+            "key": "location",
+            "geo_radius": {"center": {"lat": 52.37, "lon": 4.89}, "radius": 2000.0}
+        }]
+    });
+
+    let payload = to_qdrant_payload(&MetadataFilter::Raw(raw.clone()))
+        .expect("raw filter should pass through");
+    assert_eq!(payload, raw);
+}
+
+#[test]
+fn rejects_raw_filter_nested_in_composite() {
+    let filter = MetadataFilter::All(vec![MetadataFilter::Raw(json!({"must": []}))]);
+
+    let err = to_qdrant_payload(&filter).expect_err("nested raw filter should be rejected");
+    assert!(matches!(
+        err,
+        QdrantStoreError::UnsupportedFilterValue { key, .. } if key == "raw"
+    ));
+}
@@ -0,0 +1,187 @@
+#![cfg(feature = "grpc")]
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use qdrant_client::qdrant::{CreateCollectionBuilder, Distance, VectorParamsBuilder};
+use qdrant_client::Qdrant;
+use serde_json::{json, Value as JsonValue};
+use wesichain_core::{Document, MetadataFilter, StoreError, Value, VectorStore};
+use wesichain_qdrant::{QdrantGrpcStore, QdrantStoreError};
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// Opt-in like the REST contract tests, since it needs a live Qdrant.
+fn qdrant_contract_enabled() -> bool {
+    std::env::var("RUN_QDRANT_CONTRACT").ok().as_deref() == Some("1")
+}
+
+fn unique_suffix() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time should move forward")
+        .as_nanos();
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{now}-{count}")
+}
+
+fn qdrant_grpc_url() -> String {
+    std::env::var("QDRANT_GRPC_URL").unwrap_or_else(|_| "http://127.0.0.1:6334".to_string())
+}
+
+fn qdrant_api_key() -> Option<String> {
+    std::env::var("QDRANT_API_KEY")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+fn build_doc(id: &str, content: &str, embedding: Vec<f32>, metadata: JsonValue) -> Document {
+    let metadata = metadata
+        .as_object()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .collect::<HashMap<String, Value>>();
+
+    Document {
+        id: id.to_string(),
+        content: content.to_string(),
+        metadata,
+        embedding: Some(embedding),
+    }
+}
+
+fn build_store(collection: &str) -> QdrantGrpcStore {
+    let builder = QdrantGrpcStore::builder()
+        .base_url(qdrant_grpc_url())
+        .collection(collection);
+
+    let builder = match qdrant_api_key() {
+        Some(api_key) => builder.api_key(api_key),
+        None => builder,
+    };
+
+    builder.build().expect("store should build")
+}
+
+async fn ensure_collection(collection: &str, dimension: u64) {
+    let client = Qdrant::from_url(&qdrant_grpc_url())
+        .api_key(qdrant_api_key())
+        .build()
+        .expect("client should build");
+
+    client
+        .create_collection(
+            CreateCollectionBuilder::new(collection)
+                .vectors_config(VectorParamsBuilder::new(dimension, Distance::Cosine)),
+        )
+        .await
+        .expect("collection create should succeed");
+}
+
+#[test]
+fn grpc_builder_validates_config() {
+    let err = QdrantGrpcStore::builder()
+        .collection("docs")
+        .build()
+        .expect_err("base_url is required");
+    assert!(matches!(err, QdrantStoreError::MissingBaseUrl));
+
+    let store = QdrantGrpcStore::builder()
+        .base_url("http://127.0.0.1:6334")
+        .collection("docs")
+        .api_key("secret")
+        .build()
+        .expect("store should build without connecting");
+    assert_eq!(store.collection(), "docs");
+    assert!(!format!("{store:?}").contains("secret"));
+}
+
+#[tokio::test]
+async fn grpc_add_search_delete_roundtrip() {
+    if !qdrant_contract_enabled() {
+        return;
+    }
+
+    let collection = format!("grpc_roundtrip_{}", unique_suffix());
+    ensure_collection(&collection, 3).await;
+
+    let store = build_store(&collection);
+    let first = "6f1b0f5e-8d5f-4b7a-9f43-0d7f3d6b2a01";
+    let second = "6f1b0f5e-8d5f-4b7a-9f43-0d7f3d6b2a02";
+    store
+        .add(vec![
+            build_doc(
+                first,
+                "alpha",
+                vec![0.99, 0.01, 0.0],
+                json!({"source": "grpc", "rank": 1}),
+            ),
+            build_doc(
+                second,
+                "beta",
+                vec![0.6, 0.4, 0.0],
+                json!({"source": "grpc", "rank": 2}),
+            ),
+        ])
+        .await
+        .expect("add should succeed");
+
+    let results = store
+        .search(&[1.0, 0.0, 0.0], 2, None)
+        .await
+        .expect("search should succeed");
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].document.id, first);
+    assert_eq!(results[0].document.content, "alpha");
+    assert_eq!(
+        results[0].document.metadata.get("source"),
+        Some(&json!("grpc"))
+    );
+
+    let filtered = store
+        .search(
+            &[1.0, 0.0, 0.0],
+            2,
+            Some(&MetadataFilter::Eq("rank".to_string(), json!(2))),
+        )
+        .await
+        .expect("filtered search should succeed");
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].document.id, second);
+
+    store
+        .delete(&[first.to_string(), second.to_string()])
+        .await
+        .expect("delete should succeed");
+    let after_delete = store
+        .search(&[1.0, 0.0, 0.0], 2, None)
+        .await
+        .expect("search after delete should succeed");
+    assert!(after_delete.is_empty());
+}
+
+#[tokio::test]
+async fn grpc_collection_not_found_returns_clear_error() {
+    if !qdrant_contract_enabled() {
+        return;
+    }
+
+    let store = build_store(&format!("grpc_missing_{}", unique_suffix()));
+    let err = store
+        .search(&[1.0, 0.0, 0.0], 1, None)
+        .await
+        .expect_err("search should fail for missing collection");
+
+    match err {
+        StoreError::Internal(inner) => {
+            let message = inner.to_string().to_lowercase();
+            assert!(
+                message.contains("collection") && message.contains("not found"),
+                "error should mention missing collection, got: {message}"
+            );
+        }
+        other => panic!("expected internal store error, got: {other:?}"),
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use wesichain_core::{Document, StoreError, VectorStore, WriteMode};
+use wesichain_qdrant::QdrantVectorStore;
+
+fn spawn_single_response_server(response_body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind test server");
+    let addr = listener.local_addr().expect("get local addr");
+
+    thread::spawn(move || {
+        let (mut socket, _) = listener.accept().expect("accept socket");
+        let mut request = Vec::new();
+        let mut buf = [0_u8; 1024];
+
+        loop {
+            let read = socket.read(&mut buf).expect("read request");
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..read]);
+            if request.windows(4).any(|window| window == b"\r\n\r\n") {
+                break;
+            }
+        }
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response_body.len(),
+            response_body
+        );
+
+        socket
+            .write_all(response.as_bytes())
+            .expect("write response");
+    });
+
+    format!("http://{addr}")
+}
+
+fn doc(id: &str) -> Document {
+    Document {
+        id: id.to_string(),
+        content: format!("content {id}"),
+        metadata: HashMap::new(),
+        embedding: Some(vec![1.0, 0.0]),
+    }
+}
+
+fn store(base_url: String) -> QdrantVectorStore {
+    QdrantVectorStore::builder()
+        .base_url(base_url)
+        .collection("docs")
+        .build()
+        .expect("store should build")
+}
+
+#[tokio::test]
+async fn insert_only_rejects_ids_already_in_collection() {
+    // Only the existence check is served; an upsert attempt would fail to connect.
+    let base_url = spawn_single_response_server(r#"{"result":[{"id":"doc-2"}]}"#);
+
+    let err = store(base_url)
+        .add_mode(vec![doc("doc-1"), doc("doc-2")], WriteMode::InsertOnly)
+        .await
+        .expect_err("existing id should conflict");
+
+    assert!(matches!(err, StoreError::DuplicateId(ref id) if id == "doc-2"));
+}
+
+#[tokio::test]
+async fn insert_only_rejects_duplicate_ids_within_batch() {
+    let err = store("http://127.0.0.1:1".to_string())
+        .add_mode(vec![doc("doc-1"), doc("doc-1")], WriteMode::InsertOnly)
+        .await
+        .expect_err("repeated id should conflict");
+
+    assert!(matches!(err, StoreError::DuplicateId(ref id) if id == "doc-1"));
+}
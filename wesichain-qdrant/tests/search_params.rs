@@ -0,0 +1,39 @@
+use serde_json::json;
+use wesichain_qdrant::mapper::SearchPointsRequest;
+use wesichain_qdrant::QdrantSearchParams;
+
+fn request(params: Option<QdrantSearchParams>) -> SearchPointsRequest {
+    SearchPointsRequest {
+        vector: vec![0.5, 0.25],
+        limit: 3,
+        with_payload: true,
+        filter: None,
+        params,
+    }
+}
+
+#[test]
+fn search_request_omits_params_by_default() {
+    let body = serde_json::to_value(request(None)).expect("request should serialize");
+
+    assert_eq!(
+        body,
+        json!({"vector": [0.5, 0.25], "limit": 3, "with_payload": true})
+    );
+}
+
+#[test]
+fn search_request_serializes_hnsw_ef_and_exact() {
+    let params = QdrantSearchParams::new().hnsw_ef(256).exact(false);
+    let body = serde_json::to_value(request(Some(params))).expect("request should serialize");
+
+    assert_eq!(body["params"], json!({"hnsw_ef": 256, "exact": false}));
+}
+
+#[test]
+fn search_request_serializes_only_set_params() {
+    let params = QdrantSearchParams::new().exact(true);
+    let body = serde_json::to_value(request(Some(params))).expect("request should serialize");
+
+    assert_eq!(body["params"], json!({"exact": true}));
+}
@@ -34,6 +34,7 @@ async fn ingest_and_query(
                         step: 999,
                         recoverable: false,
                         source: Some("simple-rag-stream".to_string()),
+                        trace_id: None,
                     })
                 );
                 break;
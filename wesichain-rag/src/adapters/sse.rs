@@ -13,6 +13,7 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
             message,
             step,
             thread_id,
+            trace_id,
         } => format_sse(
             "status",
             json!({
@@ -20,6 +21,7 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
                 "message": message,
                 "step": step,
                 "thread_id": thread_id,
+                "trace_id": trace_id,
             }),
         ),
         AgentEvent::Thought {
@@ -39,6 +41,7 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
             tool_name,
             input,
             step,
+            trace_id,
         } => format_sse(
             "trace",
             json!({
@@ -46,6 +49,7 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
                 "call_id": id,
                 "tool": tool_name,
                 "input": input,
+                "trace_id": trace_id,
             }),
         ),
         AgentEvent::Observation {
@@ -53,6 +57,7 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
             tool_name,
             output,
             step,
+            trace_id,
         } => format_sse(
             "trace",
             json!({
@@ -60,13 +65,33 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
                 "call_id": id,
                 "tool": tool_name,
                 "observation": output,
+                "trace_id": trace_id,
             }),
         ),
-        AgentEvent::Final { content, step } => format_sse(
+        AgentEvent::Token {
+            content,
+            step,
+            thread_id,
+            trace_id,
+        } => format_sse(
+            "token",
+            json!({
+                "content": content,
+                "step": step,
+                "thread_id": thread_id,
+                "trace_id": trace_id,
+            }),
+        ),
+        AgentEvent::Final {
+            content,
+            step,
+            trace_id,
+        } => format_sse(
             "answer",
             json!({
                 "content": content,
                 "step": step,
+                "trace_id": trace_id,
             }),
         ),
         AgentEvent::Error {
@@ -74,6 +99,7 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
             step,
             recoverable,
             source,
+            trace_id,
         } => format_sse(
             "error",
             json!({
@@ -81,6 +107,7 @@ pub fn to_sse_event(event: &AgentEvent) -> String {
                 "step": step,
                 "recoverable": recoverable,
                 "source": source,
+                "trace_id": trace_id,
             }),
         ),
         AgentEvent::Metadata { key, value } => format_sse(
@@ -6,14 +6,17 @@ use tokio::sync::{mpsc, oneshot};
 use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
 use wesichain_core::{
-    AgentEvent, Document, Embedding, LlmRequest, Message, Runnable, Role, StreamEvent,
-    ToolCallingLlm, VectorStore, WesichainError,
+    current_run_context, AgentEvent, CallbackManager, Document, Embedding, LlmRequest, Message,
+    Role, RunConfig, Runnable, StreamEvent, ToolCallingLlm, VectorStore, WesichainError,
 };
 use wesichain_graph::{
     Checkpoint, Checkpointer, ExecutionOptions, GraphBuilder, GraphError, GraphState,
     InMemoryCheckpointer, StateSchema, StateUpdate,
 };
-use wesichain_retrieval::{Indexer, RecursiveCharacterTextSplitter, Retriever};
+use wesichain_retrieval::{
+    DocumentSplitter, InMemoryVectorStore, Indexer, RecursiveCharacterTextSplitter, RetrievalError,
+    Retriever,
+};
 
 pub mod adapters;
 
@@ -58,8 +61,10 @@ pub struct WesichainRag {
     checkpointer: Arc<dyn Checkpointer<RagRuntimeState>>,
     indexer: Arc<dyn IndexerTrait>,
     retriever: Arc<dyn RetrieverTrait>,
-    splitter: RecursiveCharacterTextSplitter,
+    splitter: Arc<dyn DocumentSplitter>,
     llm: Option<Arc<dyn ToolCallingLlm>>,
+    callbacks: Option<CallbackManager>,
+    in_memory_store: Option<InMemoryVectorStore>,
 }
 
 #[derive(Clone)]
@@ -69,8 +74,11 @@ pub struct WesichainRagBuilder {
     checkpointer: Arc<dyn Checkpointer<RagRuntimeState>>,
     embedder: Option<Arc<dyn Embedding>>,
     vector_store: Option<Arc<dyn VectorStore>>,
-    splitter: RecursiveCharacterTextSplitter,
+    in_memory_store: Option<InMemoryVectorStore>,
+    splitter: Arc<dyn DocumentSplitter>,
     llm: Option<Arc<dyn ToolCallingLlm>>,
+    callbacks: Option<CallbackManager>,
+    validate_dimensions: bool,
 }
 
 // Trait to allow storing Indexer<dyn Embedding, dyn VectorStore>
@@ -159,6 +167,50 @@ impl StateSchema for RagRuntimeState {
 #[derive(Clone)]
 struct GenerateAnswerNode {
     llm: Option<Arc<dyn ToolCallingLlm>>,
+    /// When set, the answer is streamed from the LLM and each content chunk is
+    /// forwarded as an [`AgentEvent::Token`].
+    token_sender: Option<mpsc::Sender<AgentEvent>>,
+    thread_id: String,
+}
+
+impl GenerateAnswerNode {
+    /// Stream `request`, forwarding content chunks as tokens, and return the
+    /// full answer. Falls back to the `FinalAnswer` text when the LLM streams
+    /// no chunks.
+    async fn stream_answer(
+        &self,
+        llm: &dyn ToolCallingLlm,
+        request: LlmRequest,
+        sender: &mpsc::Sender<AgentEvent>,
+    ) -> Result<String, WesichainError> {
+        let mut stream = llm.stream(request);
+        let mut answer = String::new();
+        let mut final_answer = None;
+
+        while let Some(event) = stream.next().await {
+            match event? {
+                StreamEvent::ContentChunk(chunk) if !chunk.is_empty() => {
+                    answer.push_str(&chunk);
+                    // Step 0 is raised to the current step by `query_stream`.
+                    let _ = sender
+                        .send(AgentEvent::Token {
+                            content: chunk,
+                            step: 0,
+                            thread_id: self.thread_id.clone(),
+                            trace_id: current_run_context().map(|(_, run)| run.trace_id),
+                        })
+                        .await;
+                }
+                StreamEvent::FinalAnswer(text) => final_answer = Some(text),
+                _ => {}
+            }
+        }
+
+        if answer.is_empty() {
+            answer = final_answer.unwrap_or_default();
+        }
+        Ok(answer)
+    }
 }
 
 #[async_trait::async_trait]
@@ -185,15 +237,19 @@ impl Runnable<GraphState<RagRuntimeState>, StateUpdate<RagRuntimeState>> for Gen
                             content: next.current_query.clone().into(),
                             tool_call_id: None,
                             tool_calls: vec![],
+                            cache_control: None,
                         },
                     ],
                     tools: vec![],
                     temperature: None,
                     max_tokens: None,
                     stop_sequences: vec![],
+                    response_format: None,
                 };
-                let response = llm.invoke(request).await?;
-                response.content
+                match &self.token_sender {
+                    Some(sender) => self.stream_answer(llm.as_ref(), request, sender).await?,
+                    None => llm.invoke(request).await?.content,
+                }
             }
             None => {
                 return Err(WesichainError::InvalidConfig(
@@ -223,6 +279,7 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
             message,
             step,
             thread_id,
+            trace_id,
         } => {
             let normalized = step.max(last_step.saturating_add(1));
             *last_step = normalized;
@@ -231,6 +288,7 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
                 message,
                 step: normalized,
                 thread_id,
+                trace_id,
             }
         }
         AgentEvent::Thought {
@@ -251,6 +309,7 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
             tool_name,
             input,
             step,
+            trace_id,
         } => {
             let normalized = step.max(last_step.saturating_add(1));
             *last_step = normalized;
@@ -259,6 +318,7 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
                 tool_name,
                 input,
                 step: normalized,
+                trace_id,
             }
         }
         AgentEvent::Observation {
@@ -266,6 +326,7 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
             tool_name,
             output,
             step,
+            trace_id,
         } => {
             let normalized = step.max(last_step.saturating_add(1));
             *last_step = normalized;
@@ -274,14 +335,32 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
                 tool_name,
                 output,
                 step: normalized,
+                trace_id,
             }
         }
-        AgentEvent::Final { content, step } => {
+        AgentEvent::Token {
+            content,
+            step,
+            thread_id,
+            trace_id,
+        } => AgentEvent::Token {
+            content,
+            // Tokens belong to the step that produced them.
+            step: step.max(*last_step),
+            thread_id,
+            trace_id,
+        },
+        AgentEvent::Final {
+            content,
+            step,
+            trace_id,
+        } => {
             let normalized = step.max(last_step.saturating_add(1));
             *last_step = normalized;
             AgentEvent::Final {
                 content,
                 step: normalized,
+                trace_id,
             }
         }
         AgentEvent::Error {
@@ -289,6 +368,7 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
             step,
             recoverable,
             source,
+            trace_id,
         } => {
             let normalized = step.max(last_step.saturating_add(1));
             *last_step = normalized;
@@ -297,6 +377,7 @@ fn normalize_agent_event_step(event: AgentEvent, last_step: &mut usize) -> Agent
                 step: normalized,
                 recoverable,
                 source,
+                trace_id,
             }
         }
         AgentEvent::Metadata { key, value } => AgentEvent::Metadata { key, value },
@@ -311,13 +392,18 @@ impl WesichainRag {
             checkpointer: Arc::new(InMemoryCheckpointer::<RagRuntimeState>::default()),
             embedder: None,
             vector_store: None,
+            in_memory_store: None,
             llm: None,
-            splitter: RecursiveCharacterTextSplitter::builder()
-                .chunk_size(1000)
-                .chunk_overlap(200)
-                .separators(vec!["\n\n", "\n", ". ", " ", ""])
-                .build()
-                .expect("default splitter config should be valid"),
+            callbacks: None,
+            validate_dimensions: false,
+            splitter: Arc::new(
+                RecursiveCharacterTextSplitter::builder()
+                    .chunk_size(1000)
+                    .chunk_overlap(200)
+                    .separators(vec!["\n\n", "\n", ". ", " ", ""])
+                    .build()
+                    .expect("default splitter config should be valid"),
+            ),
         }
     }
 
@@ -350,6 +436,22 @@ impl WesichainRag {
         Ok(prompt)
     }
 
+    /// Persist the default in-memory index (documents, metadata and embeddings) to `path`.
+    ///
+    /// Returns [`RagError::NotImplemented`] when an external vector store was configured.
+    pub async fn save_index(&self, path: &Path) -> Result<(), RagError> {
+        let store = self
+            .in_memory_store
+            .as_ref()
+            .ok_or(RagError::NotImplemented(
+                "save_index for external vector stores",
+            ))?;
+        store
+            .save(path)
+            .await
+            .map_err(|err| RagError::Retrieval(RetrievalError::Store(err)))
+    }
+
     pub async fn process_file(&self, path: &Path) -> Result<(), RagError> {
         // Load the file
         let documents = wesichain_retrieval::load_file_async(path.to_path_buf()).await?;
@@ -412,14 +514,20 @@ impl WesichainRag {
             .await?;
 
         let mut answer = String::new();
+        let mut streamed = String::new();
         while let Some(event) = stream.next().await {
             match event? {
+                AgentEvent::Token { content, .. } => streamed.push_str(&content),
                 AgentEvent::Final { content, .. } => answer = content,
                 AgentEvent::Error { message, .. } => return Err(RagError::Runtime(message)),
                 _ => {}
             }
         }
 
+        if answer.is_empty() {
+            answer = streamed;
+        }
+
         Ok(RagQueryResponse { answer, thread_id })
     }
 
@@ -438,8 +546,16 @@ impl WesichainRag {
         state.thread_id = thread_id.clone();
         state.current_query = self.build_prompt(&request.query).await?;
 
+        let (graph_event_tx, mut graph_event_rx) =
+            mpsc::channel::<AgentEvent>(self.event_buffer_size);
+
+        let generate = GenerateAnswerNode {
+            llm: self.llm.clone(),
+            token_sender: Some(graph_event_tx.clone()),
+            thread_id: thread_id.clone(),
+        };
         let graph = GraphBuilder::new()
-            .add_node("generate", GenerateAnswerNode { llm: self.llm.clone() })
+            .add_node("generate", generate)
             .with_checkpointer(
                 SharedCheckpointer::new(self.checkpointer.clone()),
                 &thread_id,
@@ -449,8 +565,6 @@ impl WesichainRag {
 
         let max_retries = self.max_retries;
 
-        let (graph_event_tx, mut graph_event_rx) =
-            mpsc::channel::<AgentEvent>(self.event_buffer_size);
         let (output_tx, output_rx) =
             mpsc::channel::<Result<AgentEvent, RagError>>(self.event_buffer_size);
         let (result_tx, result_rx) =
@@ -459,6 +573,10 @@ impl WesichainRag {
         let options = ExecutionOptions {
             agent_event_sender: Some(graph_event_tx),
             agent_event_thread_id: Some(thread_id.clone()),
+            run_config: self.callbacks.clone().map(|callbacks| RunConfig {
+                callbacks: Some(callbacks),
+                ..RunConfig::default()
+            }),
             ..ExecutionOptions::default()
         };
 
@@ -486,6 +604,7 @@ impl WesichainRag {
                                     step: 0,
                                     recoverable: true,
                                     source: Some("graph".to_string()),
+                                    trace_id: None,
                                 })
                                 .await;
                         }
@@ -500,9 +619,25 @@ impl WesichainRag {
 
         tokio::spawn(async move {
             let mut last_step = 0usize;
+            // With retries enabled, tokens are held back until the run succeeds
+            // so a failed attempt's partial answer never reaches the stream.
+            let buffer_tokens = max_retries > 0;
+            let mut pending_tokens = Vec::new();
+            let mut trace_id = None;
 
             while let Some(event) = graph_event_rx.recv().await {
                 let event = normalize_agent_event_step(event, &mut last_step);
+                trace_id = event.trace_id().or(trace_id);
+                match &event {
+                    AgentEvent::Token { .. } if buffer_tokens => {
+                        pending_tokens.push(event);
+                        continue;
+                    }
+                    AgentEvent::Error {
+                        recoverable: true, ..
+                    } => pending_tokens.clear(),
+                    _ => {}
+                }
 
                 if output_tx.send(Ok(event)).await.is_err() {
                     return;
@@ -511,11 +646,17 @@ impl WesichainRag {
 
             match result_rx.await {
                 Ok(Ok(final_state)) => {
+                    for token in pending_tokens {
+                        if output_tx.send(Ok(token)).await.is_err() {
+                            return;
+                        }
+                    }
                     let content = final_state.data.last_answer.unwrap_or_default();
                     let _ = output_tx
                         .send(Ok(AgentEvent::Final {
                             content,
                             step: last_step.saturating_add(1),
+                            trace_id,
                         }))
                         .await;
                 }
@@ -558,9 +699,21 @@ impl WesichainRagBuilder {
         T: VectorStore + 'static,
     {
         self.vector_store = Some(Arc::new(vector_store));
+        self.in_memory_store = None;
         self
     }
 
+    /// Restore an index written by [`WesichainRag::save_index`] into the default
+    /// in-memory store. Replaces any vector store configured earlier.
+    pub async fn load_index(mut self, path: impl AsRef<Path>) -> Result<Self, RagError> {
+        let store = InMemoryVectorStore::load(path)
+            .await
+            .map_err(|err| RagError::Retrieval(RetrievalError::Store(err)))?;
+        self.in_memory_store = Some(store);
+        self.vector_store = None;
+        Ok(self)
+    }
+
     pub fn with_checkpointer<T>(mut self, checkpointer: T) -> Self
     where
         T: Checkpointer<RagRuntimeState> + 'static,
@@ -569,8 +722,13 @@ impl WesichainRagBuilder {
         self
     }
 
-    pub fn with_splitter(mut self, splitter: RecursiveCharacterTextSplitter) -> Self {
-        self.splitter = splitter;
+    /// Replace the default [`RecursiveCharacterTextSplitter`] used by
+    /// [`WesichainRag::add_documents`] and [`WesichainRag::process_file`].
+    pub fn with_splitter<T>(mut self, splitter: T) -> Self
+    where
+        T: DocumentSplitter + 'static,
+    {
+        self.splitter = Arc::new(splitter);
         self
     }
 
@@ -588,22 +746,50 @@ impl WesichainRagBuilder {
         self
     }
 
+    /// Rerun a failed query up to `max_retries` more times. When retries are
+    /// enabled, [`AgentEvent::Token`]s are delivered only once the run has
+    /// succeeded, just before its `Final` event.
     pub fn with_max_retries(mut self, max_retries: usize) -> Self {
         self.max_retries = max_retries;
         self
     }
 
+    /// Report each query's graph run to `callbacks`. Streamed events then
+    /// carry the run's trace id.
+    pub fn with_callbacks(mut self, callbacks: CallbackManager) -> Self {
+        self.callbacks = Some(callbacks);
+        self
+    }
+
+    /// Check in [`build`](Self::build) that the embedder's dimension matches
+    /// the vector store's, failing with [`RetrievalError::Store`] on mismatch.
+    ///
+    /// Off by default. Only stores that report their dimension locally (such as
+    /// a populated in-memory store) can be checked.
+    pub fn with_dimension_validation(mut self, enabled: bool) -> Self {
+        self.validate_dimensions = enabled;
+        self
+    }
+
     pub fn build(self) -> Result<WesichainRag, RagError> {
         // Use default embedder and vector store if not provided
         let embedder = self
             .embedder
             .unwrap_or_else(|| Arc::new(wesichain_retrieval::HashEmbedder::new(384)));
-        let vector_store = self
-            .vector_store
-            .unwrap_or_else(|| Arc::new(wesichain_retrieval::InMemoryVectorStore::new()));
+        let (vector_store, in_memory_store): (Arc<dyn VectorStore>, _) = match self.vector_store {
+            Some(store) => (store, None),
+            None => {
+                let store = self.in_memory_store.unwrap_or_default();
+                (Arc::new(store.clone()), Some(store))
+            }
+        };
 
         // Create indexer and retriever
-        let indexer = Arc::new(Indexer::new(embedder.clone(), vector_store.clone()));
+        let indexer = Indexer::new(embedder.clone(), vector_store.clone());
+        if self.validate_dimensions {
+            indexer.validate()?;
+        }
+        let indexer = Arc::new(indexer);
         let retriever = Arc::new(Retriever::new(embedder.clone(), vector_store.clone()));
 
         Ok(WesichainRag {
@@ -614,6 +800,8 @@ impl WesichainRagBuilder {
             retriever,
             splitter: self.splitter,
             llm: self.llm,
+            callbacks: self.callbacks,
+            in_memory_store,
         })
     }
 }
@@ -52,6 +52,7 @@ async fn stream_respects_backpressure_semantic_events_preserved() {
             AgentEvent::Thought { .. } => semantic_events += 1,
             AgentEvent::ToolCall { .. } => semantic_events += 1,
             AgentEvent::Observation { .. } => semantic_events += 1,
+            AgentEvent::Token { .. } => semantic_events += 1,
             AgentEvent::Final { .. } => {
                 semantic_events += 1;
                 final_received = true;
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
 use futures::StreamExt;
-use wesichain_core::AgentEvent;
+use wesichain_core::{AgentEvent, Document};
 use wesichain_rag::{RagQueryRequest, WesichainRag};
+use wesichain_retrieval::DocumentSplitter;
 
 #[tokio::test]
 async fn builder_defaults_to_bounded_event_buffer() {
@@ -11,6 +16,38 @@ async fn builder_defaults_to_bounded_event_buffer() {
     assert_eq!(rag.event_buffer_size(), 64);
 }
 
+#[tokio::test]
+async fn default_store_retrieves_added_documents() {
+    let rag = WesichainRag::builder()
+        .build()
+        .expect("facade should build");
+
+    rag.add_documents(vec![
+        Document {
+            id: "rust".to_string(),
+            content: "rust ownership and borrowing".to_string(),
+            metadata: HashMap::new(),
+            embedding: None,
+        },
+        Document {
+            id: "baking".to_string(),
+            content: "sourdough bread baking".to_string(),
+            metadata: HashMap::new(),
+            embedding: None,
+        },
+    ])
+    .await
+    .expect("indexing into the default store should succeed");
+
+    let results = rag
+        .similarity_search("rust ownership and borrowing", 2)
+        .await
+        .expect("search should succeed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].document.content, "rust ownership and borrowing");
+}
+
 #[tokio::test]
 async fn query_stream_uses_provided_thread_id_and_monotonic_steps() {
     let rag = WesichainRag::builder()
@@ -75,3 +112,72 @@ async fn query_stream_generates_thread_id_when_missing() {
         other => panic!("expected first event to be status, got {other:?}"),
     }
 }
+
+struct SentenceSplitter {
+    calls: Arc<AtomicUsize>,
+}
+
+impl DocumentSplitter for SentenceSplitter {
+    fn split_text(&self, text: &str) -> Vec<String> {
+        text.split(". ").map(str::to_string).collect()
+    }
+
+    fn split_documents(&self, documents: &[Document]) -> Vec<Document> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        documents
+            .iter()
+            .flat_map(|document| {
+                self.split_text(&document.content)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, sentence)| Document {
+                        id: format!("{}#sentence-{index}", document.id),
+                        content: sentence,
+                        metadata: document.metadata.clone(),
+                        embedding: None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[tokio::test]
+async fn custom_splitter_replaces_default_for_documents_and_files() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let rag = WesichainRag::builder()
+        .with_splitter(SentenceSplitter {
+            calls: calls.clone(),
+        })
+        .build()
+        .expect("facade should build");
+
+    rag.add_documents(vec![Document {
+        id: "guide".to_string(),
+        content: "rust ownership rules. sourdough bread baking".to_string(),
+        metadata: HashMap::new(),
+        embedding: None,
+    }])
+    .await
+    .expect("indexing should succeed");
+
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("notes.txt");
+    std::fs::write(&path, "async runtimes. tokio tasks").expect("write temp file");
+    rag.process_file(&path).await.expect("file should index");
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    let results = rag
+        .similarity_search("sourdough bread baking", 1)
+        .await
+        .expect("search should succeed");
+    assert_eq!(results[0].document.id, "guide#sentence-1");
+    assert_eq!(results[0].document.content, "sourdough bread baking");
+
+    let results = rag
+        .similarity_search("tokio tasks", 1)
+        .await
+        .expect("search should succeed");
+    assert!(results[0].document.id.ends_with("notes.txt#sentence-1"));
+}
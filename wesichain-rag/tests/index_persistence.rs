@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use wesichain_core::{Document, StoreError, Value};
+use wesichain_rag::{RagError, WesichainRag};
+use wesichain_retrieval::{HashEmbedder, InMemoryVectorStore, RetrievalError};
+
+fn sample_documents() -> Vec<Document> {
+    let mut metadata = HashMap::new();
+    metadata.insert("source".to_string(), Value::String("guide.md".to_string()));
+    vec![
+        Document {
+            id: "ownership".to_string(),
+            content: "rust ownership and borrowing rules".to_string(),
+            metadata: metadata.clone(),
+            embedding: None,
+        },
+        Document {
+            id: "async".to_string(),
+            content: "async runtimes schedule futures".to_string(),
+            metadata,
+            embedding: None,
+        },
+    ]
+}
+
+#[tokio::test]
+async fn saved_index_reloads_with_identical_search_results() {
+    let rag = WesichainRag::builder().build().unwrap();
+    rag.add_documents(sample_documents()).await.unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rag-index.json");
+    rag.save_index(&path).await.unwrap();
+
+    let restored = WesichainRag::builder()
+        .load_index(&path)
+        .await
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let query = "how does rust borrowing work";
+    let original = rag.similarity_search(query, 2).await.unwrap();
+    let reloaded = restored.similarity_search(query, 2).await.unwrap();
+
+    assert_eq!(original.len(), 2);
+    assert_eq!(original, reloaded);
+    assert_eq!(
+        reloaded[0].document.metadata.get("source"),
+        Some(&Value::String("guide.md".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn save_index_is_not_implemented_for_external_stores() {
+    let rag = WesichainRag::builder()
+        .with_vector_store(InMemoryVectorStore::new())
+        .build()
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let err = rag
+        .save_index(&dir.path().join("index.json"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RagError::NotImplemented(_)));
+}
+
+#[tokio::test]
+async fn dimension_validation_rejects_mismatched_embedder_for_loaded_index() {
+    let rag = WesichainRag::builder().build().unwrap();
+    rag.add_documents(sample_documents()).await.unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("rag-index.json");
+    rag.save_index(&path).await.unwrap();
+
+    let builder = WesichainRag::builder()
+        .with_embedder(HashEmbedder::new(8))
+        .load_index(&path)
+        .await
+        .unwrap();
+
+    assert!(builder.clone().build().is_ok());
+    let result = builder.with_dimension_validation(true).build();
+    assert!(matches!(
+        result,
+        Err(RagError::Retrieval(RetrievalError::Store(
+            StoreError::DimensionMismatch {
+                expected: 384,
+                got: 8
+            }
+        )))
+    ));
+}
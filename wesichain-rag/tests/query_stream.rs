@@ -1,5 +1,10 @@
-use futures::StreamExt;
-use wesichain_core::{AgentEvent, Document};
+use std::sync::{Arc, Mutex};
+
+use futures::stream::{self, BoxStream, StreamExt};
+use wesichain_core::{
+    AgentEvent, CallbackHandler, CallbackManager, Document, LlmRequest, LlmResponse, RunContext,
+    Runnable, StreamEvent, ToolCallingLlm, Value, WesichainError,
+};
 use wesichain_rag::{RagQueryRequest, WesichainRag};
 
 async fn collect_events(
@@ -107,3 +112,118 @@ async fn query_stream_resumes_state_for_same_thread_id() {
         "expected second answer to be turn #2"
     );
 }
+
+struct ChunkedLlm;
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, LlmResponse> for ChunkedLlm {
+    async fn invoke(&self, _input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        Ok(LlmResponse {
+            content: "Checkpoints save state.".to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn stream(&self, _input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(
+            ["Check", "points ", "save ", "state."]
+                .into_iter()
+                .map(|chunk| Ok(StreamEvent::ContentChunk(chunk.to_string())))
+                .chain([Ok(StreamEvent::FinalAnswer(
+                    "Checkpoints save state.".to_string(),
+                ))]),
+        )
+        .boxed()
+    }
+}
+
+impl ToolCallingLlm for ChunkedLlm {}
+
+#[tokio::test]
+async fn query_stream_emits_tokens_in_order_before_final_answer() {
+    let rag = WesichainRag::builder()
+        .with_llm(ChunkedLlm)
+        .build()
+        .expect("facade should build");
+    let events = collect_events(&rag, "What do checkpoints do?", Some("thread-tokens")).await;
+
+    let tokens: Vec<&str> = events
+        .iter()
+        .filter_map(|event| match event {
+            AgentEvent::Token {
+                content, thread_id, ..
+            } => {
+                assert_eq!(thread_id, "thread-tokens");
+                Some(content.as_str())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tokens, ["Check", "points ", "save ", "state."]);
+
+    let final_index = events
+        .iter()
+        .position(|event| matches!(event, AgentEvent::Final { .. }))
+        .expect("expected a final answer");
+    assert!(matches!(
+        &events[final_index],
+        AgentEvent::Final { content, .. } if *content == tokens.concat()
+    ));
+    assert!(events[final_index + 1..]
+        .iter()
+        .all(|event| !matches!(event, AgentEvent::Token { .. })));
+
+    let response = rag
+        .query(RagQueryRequest {
+            query: "What do checkpoints do?".to_string(),
+            thread_id: None,
+        })
+        .await
+        .expect("query should succeed");
+    assert_eq!(response.answer, "Checkpoints save state.");
+}
+
+#[derive(Default)]
+struct RootRecorder {
+    root: Mutex<Option<RunContext>>,
+}
+
+#[async_trait::async_trait]
+impl CallbackHandler for RootRecorder {
+    async fn on_start(&self, ctx: &RunContext, _inputs: &Value) {
+        if ctx.parent_run_id.is_none() {
+            *self.root.lock().unwrap() = Some(ctx.clone());
+        }
+    }
+
+    async fn on_end(&self, _ctx: &RunContext, _outputs: &Value, _duration_ms: u128) {}
+
+    async fn on_error(&self, _ctx: &RunContext, _error: &Value, _duration_ms: u128) {}
+}
+
+#[tokio::test]
+async fn query_stream_tokens_and_final_carry_trace_id_with_callbacks() {
+    let recorder = Arc::new(RootRecorder::default());
+    let rag = WesichainRag::builder()
+        .with_llm(ChunkedLlm)
+        .with_callbacks(CallbackManager::new(vec![recorder.clone()]))
+        .build()
+        .expect("facade should build");
+    let events = collect_events(&rag, "What do checkpoints do?", Some("thread-traced")).await;
+
+    let root = recorder.root.lock().unwrap().clone().expect("root run");
+    let tokens: Vec<_> = events
+        .iter()
+        .filter(|event| matches!(event, AgentEvent::Token { .. }))
+        .collect();
+    assert_eq!(tokens.len(), 4);
+    assert!(tokens
+        .iter()
+        .all(|event| event.trace_id() == Some(root.trace_id)));
+
+    let final_event = events
+        .iter()
+        .find(|event| matches!(event, AgentEvent::Final { .. }))
+        .expect("expected a final answer");
+    assert_eq!(final_event.trace_id(), Some(root.trace_id));
+}
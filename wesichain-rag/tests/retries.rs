@@ -1,7 +1,10 @@
 use std::sync::{Arc, Mutex};
 
-use futures::StreamExt;
-use wesichain_core::{AgentEvent, Document, WesichainError};
+use futures::stream::{self, BoxStream, StreamExt};
+use wesichain_core::{
+    AgentEvent, Document, LlmRequest, LlmResponse, Runnable, StreamEvent, ToolCallingLlm,
+    WesichainError,
+};
 use wesichain_graph::{Checkpoint, Checkpointer, InMemoryCheckpointer};
 use wesichain_rag::{RagQueryRequest, RagRuntimeState, WesichainRag};
 
@@ -147,3 +150,57 @@ async fn exhausted_retries_returns_stream_error() {
         "expected stream to return terminal error when retries exhausted"
     );
 }
+
+struct ChunkedLlm;
+
+#[async_trait::async_trait]
+impl Runnable<LlmRequest, LlmResponse> for ChunkedLlm {
+    async fn invoke(&self, _input: LlmRequest) -> Result<LlmResponse, WesichainError> {
+        Ok(LlmResponse {
+            content: "Retries recover.".to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn stream(&self, _input: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
+        stream::iter(["Retries ", "recover."])
+            .map(|chunk| Ok(StreamEvent::ContentChunk(chunk.to_string())))
+            .boxed()
+    }
+}
+
+impl ToolCallingLlm for ChunkedLlm {}
+
+#[tokio::test]
+async fn failed_attempt_tokens_are_not_streamed() {
+    let rag = WesichainRag::builder()
+        .with_llm(ChunkedLlm)
+        .with_checkpointer(FlakyCheckpointer::new(1))
+        .with_max_retries(1)
+        .build()
+        .expect("rag builder should succeed");
+
+    let events: Vec<_> = rag
+        .query_stream(RagQueryRequest {
+            query: "Will retries recover?".to_string(),
+            thread_id: Some("retry-token-thread".to_string()),
+        })
+        .await
+        .expect("query stream should start")
+        .map(|item| item.expect("stream should succeed after retry"))
+        .collect()
+        .await;
+
+    let tokens: Vec<&str> = events
+        .iter()
+        .filter_map(|event| match event {
+            AgentEvent::Token { content, .. } => Some(content.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(tokens, ["Retries ", "recover."]);
+    assert!(matches!(
+        events.last(),
+        Some(AgentEvent::Final { content, .. }) if content == "Retries recover."
+    ));
+}
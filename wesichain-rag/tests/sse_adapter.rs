@@ -25,6 +25,7 @@ fn sse_maps_status_to_status_event() {
         message: "Running vector search".to_string(),
         step: 1,
         thread_id: "thread-1".to_string(),
+        trace_id: None,
     };
 
     let frame = to_sse_event(&event);
@@ -41,6 +42,7 @@ fn sse_maps_final_to_answer_event() {
     let event = AgentEvent::Final {
         content: "Wesichain supports resumable graphs".to_string(),
         step: 4,
+        trace_id: None,
     };
 
     let frame = to_sse_event(&event);
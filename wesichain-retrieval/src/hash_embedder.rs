@@ -4,7 +4,7 @@ use wesichain_core::{Embedding, EmbeddingError};
 const FNV_OFFSET: u64 = 14695981039346656037;
 const FNV_PRIME: u64 = 1099511628211;
 
-fn fnv1a(bytes: &[u8], seed: u64) -> u64 {
+pub(crate) fn fnv1a(bytes: &[u8], seed: u64) -> u64 {
     let mut hash = FNV_OFFSET ^ seed;
     for byte in bytes {
         hash ^= *byte as u64;
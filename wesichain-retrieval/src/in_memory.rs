@@ -1,8 +1,14 @@
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
-use wesichain_core::{Document, MetadataFilter, SearchResult, StoreError, Value, VectorStore};
+use wesichain_core::{
+    find_duplicate_id, similarity, Document, MetadataFilter, SearchResult, StoreError, Value,
+    VectorStore, WriteMode,
+};
 
 #[derive(Default)]
 struct StoreInner {
@@ -12,21 +18,8 @@ struct StoreInner {
     dimension: Option<usize>,
 }
 
-#[derive(Clone, Default)]
-pub struct InMemoryVectorStore {
-    inner: Arc<RwLock<StoreInner>>,
-}
-
-impl InMemoryVectorStore {
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-
-#[async_trait::async_trait]
-impl VectorStore for InMemoryVectorStore {
-    async fn add(&self, docs: Vec<Document>) -> Result<(), StoreError> {
-        let mut inner = self.inner.write().await;
+impl StoreInner {
+    fn write(&mut self, docs: Vec<Document>) -> Result<(), StoreError> {
         for mut doc in docs {
             if doc.id.trim().is_empty() {
                 return Err(StoreError::InvalidId(doc.id));
@@ -36,29 +29,125 @@ impl VectorStore for InMemoryVectorStore {
                 StoreError::Internal(Box::new(std::io::Error::other("missing embedding")))
             })?;
             let dimension = embedding.len();
-            match inner.dimension {
+            match self.dimension {
                 Some(expected) if expected != dimension => {
                     return Err(StoreError::DimensionMismatch {
                         expected,
                         got: dimension,
                     });
                 }
-                None => inner.dimension = Some(dimension),
+                None => self.dimension = Some(dimension),
                 _ => {}
             }
 
-            if let Some(&index) = inner.id_map.get(&doc.id) {
-                inner.docs[index] = Some(doc);
-                inner.embeddings[index] = Some(embedding);
+            if let Some(&index) = self.id_map.get(&doc.id) {
+                self.docs[index] = Some(doc);
+                self.embeddings[index] = Some(embedding);
             } else {
-                let index = inner.docs.len();
-                inner.id_map.insert(doc.id.clone(), index);
-                inner.docs.push(Some(doc));
-                inner.embeddings.push(Some(embedding));
+                let index = self.docs.len();
+                self.id_map.insert(doc.id.clone(), index);
+                self.docs.push(Some(doc));
+                self.embeddings.push(Some(embedding));
             }
         }
         Ok(())
     }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryVectorStore {
+    inner: Arc<RwLock<StoreInner>>,
+    /// Mirror of `StoreInner::dimension` readable without the lock; `0`
+    /// while unset.
+    dimension: Arc<AtomicUsize>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoreSnapshot {
+    documents: Vec<Document>,
+}
+
+impl InMemoryVectorStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn write(&self, inner: &mut StoreInner, docs: Vec<Document>) -> Result<(), StoreError> {
+        let result = inner.write(docs);
+        if let Some(dimension) = inner.dimension {
+            self.dimension.store(dimension, Ordering::Release);
+        }
+        result
+    }
+
+    /// Returns all live documents, with embeddings attached, in insertion order.
+    pub async fn documents(&self) -> Vec<Document> {
+        let inner = self.inner.read().await;
+        inner
+            .docs
+            .iter()
+            .zip(inner.embeddings.iter())
+            .filter_map(|(doc, embedding)| {
+                let mut doc = doc.clone()?;
+                doc.embedding = embedding.clone();
+                Some(doc)
+            })
+            .collect()
+    }
+
+    /// Writes every stored document and its embedding to `path` as JSON.
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), StoreError> {
+        let snapshot = StoreSnapshot {
+            documents: self.documents().await,
+        };
+        let bytes =
+            serde_json::to_vec(&snapshot).map_err(|err| StoreError::Internal(Box::new(err)))?;
+        tokio::fs::write(path, bytes)
+            .await
+            .map_err(|err| StoreError::Internal(Box::new(err)))
+    }
+
+    /// Builds a store from a file previously written by [`save`](Self::save).
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, StoreError> {
+        let bytes = tokio::fs::read(path)
+            .await
+            .map_err(|err| StoreError::Internal(Box::new(err)))?;
+        let snapshot: StoreSnapshot =
+            serde_json::from_slice(&bytes).map_err(|err| StoreError::Internal(Box::new(err)))?;
+        let store = Self::new();
+        store.add(snapshot.documents).await?;
+        Ok(store)
+    }
+}
+
+#[async_trait::async_trait]
+impl VectorStore for InMemoryVectorStore {
+    async fn add(&self, docs: Vec<Document>) -> Result<(), StoreError> {
+        let mut inner = self.inner.write().await;
+        self.write(&mut inner, docs)
+    }
+
+    async fn add_mode(&self, docs: Vec<Document>, mode: WriteMode) -> Result<(), StoreError> {
+        let mut inner = self.inner.write().await;
+        if mode == WriteMode::InsertOnly {
+            if let Some(id) = find_duplicate_id(&docs) {
+                return Err(StoreError::DuplicateId(id.to_string()));
+            }
+            if let Some(doc) = docs.iter().find(|doc| inner.id_map.contains_key(&doc.id)) {
+                return Err(StoreError::DuplicateId(doc.id.clone()));
+            }
+        }
+        self.write(&mut inner, docs)
+    }
+
+    /// Dimension of the first embedding written, or `None` while the store is
+    /// empty.
+    fn dimension(&self) -> Option<usize> {
+        match self.dimension.load(Ordering::Acquire) {
+            0 => None,
+            dimension => Some(dimension),
+        }
+    }
 
     async fn search(
         &self,
@@ -66,6 +155,10 @@ impl VectorStore for InMemoryVectorStore {
         top_k: usize,
         filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>, StoreError> {
+        if let Some(filter) = filter {
+            ensure_typed(filter)?;
+        }
+
         let inner = self.inner.read().await;
         let expected = inner.dimension.unwrap_or(query_embedding.len());
         if expected != query_embedding.len() {
@@ -86,7 +179,7 @@ impl VectorStore for InMemoryVectorStore {
                     continue;
                 }
             }
-            let mut score = cosine_similarity(query_embedding, embedding);
+            let mut score = similarity::cosine(query_embedding, embedding)?;
             if score.is_nan() {
                 score = f32::NEG_INFINITY;
             }
@@ -115,21 +208,6 @@ impl VectorStore for InMemoryVectorStore {
     }
 }
 
-fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
-    let mut dot = 0.0;
-    let mut norm_a = 0.0;
-    let mut norm_b = 0.0;
-    for (x, y) in a.iter().zip(b.iter()) {
-        dot += x * y;
-        norm_a += x * x;
-        norm_b += y * y;
-    }
-    if norm_a == 0.0 || norm_b == 0.0 {
-        return 0.0;
-    }
-    dot / (norm_a.sqrt() * norm_b.sqrt())
-}
-
 fn metadata_matches(filter: &MetadataFilter, metadata: &HashMap<String, Value>) -> bool {
     match filter {
         MetadataFilter::Eq(key, value) => metadata.get(key) == Some(value),
@@ -167,5 +245,22 @@ fn metadata_matches(filter: &MetadataFilter, metadata: &HashMap<String, Value>)
         MetadataFilter::Any(filters) => filters
             .iter()
             .any(|filter| metadata_matches(filter, metadata)),
+        MetadataFilter::Exists(key) => metadata.get(key).is_some_and(|value| !value.is_null()),
+        MetadataFilter::NotExists(key) => metadata.get(key).map_or(true, Value::is_null),
+        MetadataFilter::Raw(_) => false,
+    }
+}
+
+/// Raw filters are written for a specific backend; there is no native
+/// filter language here to pass them to.
+fn ensure_typed(filter: &MetadataFilter) -> Result<(), StoreError> {
+    match filter {
+        MetadataFilter::Raw(_) => Err(StoreError::UnsupportedFilter(
+            "raw filters are not supported by the in-memory store".to_string(),
+        )),
+        MetadataFilter::All(filters) | MetadataFilter::Any(filters) => {
+            filters.iter().try_for_each(ensure_typed)
+        }
+        _ => Ok(()),
     }
 }
@@ -1,10 +1,26 @@
-use wesichain_core::{Document, Embedding, VectorStore};
+use std::collections::{HashMap, HashSet};
 
-use crate::RetrievalError;
+use wesichain_core::{Document, Embedding, StoreError, VectorStore};
+
+use crate::{content_hash, InMemoryIndexManifest, IndexManifest, RetrievalError};
+
+/// What [`Indexer::index_incremental`] did with each document.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexSummary {
+    /// Documents not seen in the previous run; embedded and written.
+    pub added: usize,
+    /// Documents whose content or metadata changed; re-embedded and written.
+    pub updated: usize,
+    /// Documents from the previous run that are gone; deleted from the store.
+    pub deleted: usize,
+    /// Documents identical to the previous run; left untouched.
+    pub unchanged: usize,
+}
 
 pub struct Indexer<E, S> {
     embedder: E,
     store: S,
+    manifest: Box<dyn IndexManifest>,
 }
 
 impl<E, S> Indexer<E, S>
@@ -13,22 +29,106 @@ where
     S: VectorStore,
 {
     pub fn new(embedder: E, store: S) -> Self {
-        Self { embedder, store }
+        Self {
+            embedder,
+            store,
+            manifest: Box::new(InMemoryIndexManifest::new()),
+        }
+    }
+
+    /// Where [`index_incremental`](Self::index_incremental) records what it
+    /// indexed. Defaults to an [`InMemoryIndexManifest`], which only
+    /// remembers runs made through this indexer; use a persistent manifest
+    /// such as [`FileIndexManifest`](crate::FileIndexManifest) to skip
+    /// unchanged documents across processes.
+    pub fn with_manifest(mut self, manifest: impl IndexManifest + 'static) -> Self {
+        self.manifest = Box::new(manifest);
+        self
+    }
+
+    /// Checks that the embedder produces vectors of the size the store expects.
+    ///
+    /// Passes when either side cannot report its dimension (an embedder
+    /// dimension of `0` or a store returning `None`), so this only catches
+    /// mismatches that are knowable without writing anything.
+    pub fn validate(&self) -> Result<(), RetrievalError> {
+        let embedder_dim = self.embedder.dimension();
+        match self.store.dimension() {
+            Some(store_dim) if embedder_dim != 0 && store_dim != embedder_dim => {
+                Err(RetrievalError::Store(StoreError::DimensionMismatch {
+                    expected: store_dim,
+                    got: embedder_dim,
+                }))
+            }
+            _ => Ok(()),
+        }
     }
 
     pub async fn index(&self, docs: Vec<Document>) -> Result<(), RetrievalError> {
         self.add_documents(docs).await
     }
 
-    pub async fn add_documents(&self, docs: Vec<Document>) -> Result<(), RetrievalError> {
-        for doc in &docs {
-            if doc.id.trim().is_empty() {
-                return Err(RetrievalError::InvalidId(doc.id.clone()));
+    /// Index `docs` as the full corpus, doing only the work that changed
+    /// since the last run recorded in the manifest.
+    ///
+    /// New and changed documents (by [`content_hash`]) are embedded and
+    /// upserted, documents missing from `docs` are deleted from the store,
+    /// and unchanged ones are skipped. The manifest is only saved once the
+    /// store writes succeed, so a failed run is retried in full.
+    pub async fn index_incremental(
+        &self,
+        docs: Vec<Document>,
+    ) -> Result<IndexSummary, RetrievalError> {
+        validate_ids(&docs)?;
+        let previous = self.manifest.load().await?;
+        let mut summary = IndexSummary::default();
+        let mut entries = HashMap::with_capacity(docs.len());
+        let mut changed = Vec::new();
+
+        for doc in docs {
+            let hash = content_hash(&doc);
+            let unchanged = match previous.get(&doc.id) {
+                Some(old) if *old == hash => true,
+                Some(_) => {
+                    summary.updated += 1;
+                    false
+                }
+                None => {
+                    summary.added += 1;
+                    false
+                }
+            };
+            entries.insert(doc.id.clone(), hash);
+            if unchanged {
+                summary.unchanged += 1;
+            } else {
+                changed.push(doc);
             }
         }
 
+        let current: HashSet<&String> = entries.keys().collect();
+        let vanished: Vec<String> = previous
+            .keys()
+            .filter(|id| !current.contains(id))
+            .cloned()
+            .collect();
+        summary.deleted = vanished.len();
+
+        if !changed.is_empty() {
+            self.add_documents(changed).await?;
+        }
+        if !vanished.is_empty() {
+            self.store.delete(&vanished).await?;
+        }
+        self.manifest.save(&entries).await?;
+        Ok(summary)
+    }
+
+    pub async fn add_documents(&self, docs: Vec<Document>) -> Result<(), RetrievalError> {
+        validate_ids(&docs)?;
+
         let texts: Vec<String> = docs.iter().map(|doc| doc.content.clone()).collect();
-        let embeddings = self.embedder.embed_batch(&texts).await?;
+        let embeddings = self.embedder.embed_documents(&texts).await?;
         let docs_with_embeddings = docs
             .into_iter()
             .zip(embeddings)
@@ -42,3 +142,10 @@ where
         Ok(())
     }
 }
+
+fn validate_ids(docs: &[Document]) -> Result<(), RetrievalError> {
+    match docs.iter().find(|doc| doc.id.trim().is_empty()) {
+        Some(doc) => Err(RetrievalError::InvalidId(doc.id.clone())),
+        None => Ok(()),
+    }
+}
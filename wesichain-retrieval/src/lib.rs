@@ -6,6 +6,7 @@ mod hash_embedder;
 mod in_memory;
 mod indexer;
 mod loader;
+mod manifest;
 mod multi_query;
 mod retriever;
 mod splitter;
@@ -19,12 +20,20 @@ pub use ensemble::EnsembleRetriever;
 pub use error::{IngestionError, RetrievalError};
 pub use hash_embedder::HashEmbedder;
 pub use in_memory::InMemoryVectorStore;
-pub use indexer::Indexer;
-pub use loader::{load_file_async, load_files_async, PdfLoader, TextLoader};
+pub use indexer::{IndexSummary, Indexer};
+pub use loader::{
+    load_file_async, load_files_async, load_files_concurrent_async,
+    load_files_concurrent_lenient_async, load_html_file_with_options_async, HtmlLoaderOptions,
+    PdfLoader, TextLoader,
+};
+pub use manifest::{content_hash, FileIndexManifest, InMemoryIndexManifest, IndexManifest};
 pub use multi_query::MultiQueryRetriever;
-pub use reranker::{CrossEncoderRetriever, KeywordReranker, Reranker};
+pub use reranker::{CrossEncoderRetriever, KeywordReranker, Reranker, ScoringReranker};
 pub use retriever::Retriever;
-pub use splitter::{RecursiveCharacterTextSplitter, SplitterConfigError, TextSplitter};
+pub use splitter::{
+    chunk_document, DocumentSplitter, RecursiveCharacterTextSplitter, SentenceTextSplitter,
+    SplitterConfigError, TextSplitter,
+};
 
 pub async fn load_and_split_recursive(
     paths: Vec<PathBuf>,
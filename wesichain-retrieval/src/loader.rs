@@ -3,6 +3,7 @@ use std::fs;
 use std::io::Read;
 use std::path::PathBuf;
 
+use futures::stream::{self, StreamExt};
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use wesichain_core::{Document, Value};
@@ -61,6 +62,46 @@ pub async fn load_files_async(paths: Vec<PathBuf>) -> Result<Vec<Document>, Inge
     Ok(documents)
 }
 
+/// Loads `paths` with up to `max_concurrency` files in flight at once.
+///
+/// Stops at the first error, abandoning loads still in progress. Documents are
+/// returned in completion order, not input order; use [`load_files_async`]
+/// when ordering must be deterministic. A `max_concurrency` of `0` is treated
+/// as `1`.
+pub async fn load_files_concurrent_async(
+    paths: Vec<PathBuf>,
+    max_concurrency: usize,
+) -> Result<Vec<Document>, IngestionError> {
+    let mut loads = stream::iter(paths)
+        .map(load_file_async)
+        .buffer_unordered(max_concurrency.max(1));
+    let mut documents = Vec::new();
+    while let Some(result) = loads.next().await {
+        documents.extend(result?);
+    }
+    Ok(documents)
+}
+
+/// Like [`load_files_concurrent_async`], but loads every file and returns the
+/// failures alongside the documents that did load instead of stopping early.
+pub async fn load_files_concurrent_lenient_async(
+    paths: Vec<PathBuf>,
+    max_concurrency: usize,
+) -> (Vec<Document>, Vec<IngestionError>) {
+    let mut loads = stream::iter(paths)
+        .map(load_file_async)
+        .buffer_unordered(max_concurrency.max(1));
+    let mut documents = Vec::new();
+    let mut errors = Vec::new();
+    while let Some(result) = loads.next().await {
+        match result {
+            Ok(docs) => documents.extend(docs),
+            Err(err) => errors.push(err),
+        }
+    }
+    (documents, errors)
+}
+
 async fn load_text_file_async(path: PathBuf) -> Result<Vec<Document>, IngestionError> {
     let content =
         tokio::fs::read_to_string(&path)
@@ -111,7 +152,29 @@ async fn load_docx_file_async(path: PathBuf) -> Result<Vec<Document>, IngestionE
     }])
 }
 
+/// Structure to keep as metadata when loading HTML. Both are off by default,
+/// which keeps only `source`, `title` and `lang`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HtmlLoaderOptions {
+    /// Collect `<a href>` targets, deduplicated in document order, into
+    /// `metadata["links"]` as an array of strings.
+    pub extract_links: bool,
+    /// Record the `<h1>`-`<h6>` outline into `metadata["headers"]` as
+    /// `{"level", "text"}` objects, like the Markdown loader's `headers`.
+    pub heading_metadata: bool,
+}
+
 async fn load_html_file_async(path: PathBuf) -> Result<Vec<Document>, IngestionError> {
+    load_html_file_with_options_async(path, HtmlLoaderOptions::default()).await
+}
+
+/// Loads an HTML file like [`load_file_async`], optionally keeping links and
+/// headings as metadata. Elements skipped by text extraction (`script`,
+/// `style`, `nav`, `header`, `footer`) are skipped here too.
+pub async fn load_html_file_with_options_async(
+    path: PathBuf,
+    options: HtmlLoaderOptions,
+) -> Result<Vec<Document>, IngestionError> {
     let html_content =
         tokio::fs::read_to_string(&path)
             .await
@@ -163,6 +226,18 @@ async fn load_html_file_async(path: PathBuf) -> Result<Vec<Document>, IngestionE
     if let Some(lang_value) = lang {
         metadata.insert("lang".to_string(), Value::String(lang_value));
     }
+    if options.extract_links {
+        let links = extract_html_links(&document);
+        if !links.is_empty() {
+            metadata.insert("links".to_string(), Value::Array(links));
+        }
+    }
+    if options.heading_metadata {
+        let headers = extract_html_headings(&document);
+        if !headers.is_empty() {
+            metadata.insert("headers".to_string(), Value::Array(headers));
+        }
+    }
 
     Ok(vec![Document {
         id: path.to_string_lossy().to_string(),
@@ -172,6 +247,54 @@ async fn load_html_file_async(path: PathBuf) -> Result<Vec<Document>, IngestionE
     }])
 }
 
+/// Whether `element` sits inside an element text extraction skips.
+fn in_skipped_html_element(element: scraper::ElementRef) -> bool {
+    element
+        .ancestors()
+        .filter_map(scraper::ElementRef::wrap)
+        .any(|ancestor| {
+            matches!(
+                ancestor.value().name(),
+                "script" | "style" | "nav" | "header" | "footer"
+            )
+        })
+}
+
+fn extract_html_links(document: &scraper::Html) -> Vec<Value> {
+    let selector = scraper::Selector::parse("a[href]").unwrap();
+    let mut links: Vec<Value> = Vec::new();
+    for anchor in document.select(&selector) {
+        if in_skipped_html_element(anchor) {
+            continue;
+        }
+        let href = anchor.value().attr("href").unwrap_or_default().trim();
+        if href.is_empty() || links.iter().any(|link| link.as_str() == Some(href)) {
+            continue;
+        }
+        links.push(Value::String(href.to_string()));
+    }
+    links
+}
+
+fn extract_html_headings(document: &scraper::Html) -> Vec<Value> {
+    let selector = scraper::Selector::parse("h1, h2, h3, h4, h5, h6").unwrap();
+    document
+        .select(&selector)
+        .filter(|heading| !in_skipped_html_element(*heading))
+        .filter_map(|heading| {
+            let text = normalize_whitespace(&heading.text().collect::<String>());
+            if text.is_empty() {
+                return None;
+            }
+            let level = heading.value().name()[1..].parse::<u64>().ok()?;
+            let mut header_meta = serde_json::Map::new();
+            header_meta.insert("level".to_string(), Value::from(level));
+            header_meta.insert("text".to_string(), Value::String(text));
+            Some(Value::Object(header_meta))
+        })
+        .collect()
+}
+
 fn extract_text_from_html_element(element: scraper::ElementRef, text_parts: &mut Vec<String>) {
     use scraper::node::Node;
 
@@ -503,31 +626,67 @@ fn invalid_docx<E: std::fmt::Display>(error: E) -> std::io::Error {
     )
 }
 
+/// Loads a PDF as one [`Document`] per page.
+///
+/// Each page document gets `metadata["page"]` (1-based) alongside `source`,
+/// and the id `{path}#page={page}`. Pages without any text are skipped, so
+/// page numbers always match the original PDF. Use
+/// [`with_one_document`](Self::with_one_document) to keep the whole file in a
+/// single document instead.
 #[cfg(feature = "pdf")]
 pub struct PdfLoader {
     path: PathBuf,
+    one_document: bool,
 }
 
 #[cfg(feature = "pdf")]
 impl PdfLoader {
     pub fn new(path: PathBuf) -> Self {
-        Self { path }
+        Self {
+            path,
+            one_document: false,
+        }
+    }
+
+    /// Load the whole file as a single document without page metadata.
+    pub fn with_one_document(mut self, one_document: bool) -> Self {
+        self.one_document = one_document;
+        self
     }
 
     pub fn load(&self) -> Result<Vec<Document>, std::io::Error> {
-        let content = pdf_extract::extract_text(&self.path).map_err(std::io::Error::other)?;
+        let source = self.path.to_string_lossy().to_string();
         let mut metadata = HashMap::new();
-        metadata.insert(
-            "source".to_string(),
-            Value::String(self.path.to_string_lossy().to_string()),
-        );
+        metadata.insert("source".to_string(), Value::String(source.clone()));
+
+        if self.one_document {
+            let content = pdf_extract::extract_text(&self.path).map_err(std::io::Error::other)?;
+            return Ok(vec![Document {
+                id: source,
+                content,
+                metadata,
+                embedding: None,
+            }]);
+        }
 
-        Ok(vec![Document {
-            id: self.path.to_string_lossy().to_string(),
-            content,
-            metadata,
-            embedding: None,
-        }])
+        let pages =
+            pdf_extract::extract_text_by_pages(&self.path).map_err(std::io::Error::other)?;
+        Ok(pages
+            .into_iter()
+            .enumerate()
+            .filter(|(_, content)| !content.trim().is_empty())
+            .map(|(index, content)| {
+                let page = index + 1;
+                let mut metadata = metadata.clone();
+                metadata.insert("page".to_string(), Value::from(page));
+                Document {
+                    id: format!("{source}#page={page}"),
+                    content,
+                    metadata,
+                    embedding: None,
+                }
+            })
+            .collect())
     }
 }
 
@@ -542,6 +701,10 @@ impl PdfLoader {
         Self { path }
     }
 
+    pub fn with_one_document(self, _one_document: bool) -> Self {
+        self
+    }
+
     pub fn load(&self) -> Result<Vec<Document>, std::io::Error> {
         let _ = &self.path;
         Err(std::io::Error::other("pdf feature disabled"))
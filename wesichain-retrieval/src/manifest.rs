@@ -0,0 +1,93 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use wesichain_core::Document;
+
+use crate::hash_embedder::fnv1a;
+use crate::RetrievalError;
+
+/// Remembers which documents [`Indexer::index_incremental`] last wrote, as a
+/// map from document id to [`content_hash`].
+///
+/// [`Indexer::index_incremental`]: crate::Indexer::index_incremental
+#[async_trait]
+pub trait IndexManifest: Send + Sync {
+    /// Returns the saved entries, or an empty map if nothing was saved yet.
+    async fn load(&self) -> Result<HashMap<String, String>, RetrievalError>;
+    /// Replaces the saved entries.
+    async fn save(&self, entries: &HashMap<String, String>) -> Result<(), RetrievalError>;
+}
+
+/// Hash of a document's content and metadata, used to detect changes
+/// between indexing runs. Stable across processes and platforms.
+pub fn content_hash(doc: &Document) -> String {
+    let metadata: BTreeMap<_, _> = doc.metadata.iter().collect();
+    let metadata = serde_json::to_string(&metadata).unwrap_or_default();
+    let hash = fnv1a(metadata.as_bytes(), fnv1a(doc.content.as_bytes(), 0));
+    format!("{hash:016x}")
+}
+
+/// Manifest kept in memory. Clones share the same entries.
+#[derive(Clone, Default)]
+pub struct InMemoryIndexManifest {
+    entries: Arc<RwLock<HashMap<String, String>>>,
+}
+
+impl InMemoryIndexManifest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IndexManifest for InMemoryIndexManifest {
+    async fn load(&self) -> Result<HashMap<String, String>, RetrievalError> {
+        Ok(self.entries.read().await.clone())
+    }
+
+    async fn save(&self, entries: &HashMap<String, String>) -> Result<(), RetrievalError> {
+        *self.entries.write().await = entries.clone();
+        Ok(())
+    }
+}
+
+/// Manifest stored as a JSON object in a single file. A missing file reads
+/// as an empty manifest.
+#[derive(Clone, Debug)]
+pub struct FileIndexManifest {
+    path: PathBuf,
+}
+
+impl FileIndexManifest {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl IndexManifest for FileIndexManifest {
+    async fn load(&self) -> Result<HashMap<String, String>, RetrievalError> {
+        let raw = match tokio::fs::read(&self.path).await {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(err) => return Err(manifest_error(&self.path, err)),
+        };
+        serde_json::from_slice(&raw).map_err(|err| manifest_error(&self.path, err))
+    }
+
+    async fn save(&self, entries: &HashMap<String, String>) -> Result<(), RetrievalError> {
+        let sorted: BTreeMap<_, _> = entries.iter().collect();
+        let raw =
+            serde_json::to_vec_pretty(&sorted).map_err(|err| manifest_error(&self.path, err))?;
+        tokio::fs::write(&self.path, raw)
+            .await
+            .map_err(|err| manifest_error(&self.path, err))
+    }
+}
+
+fn manifest_error(path: &std::path::Path, err: impl std::fmt::Display) -> RetrievalError {
+    RetrievalError::Other(format!("index manifest '{}': {err}", path.display()))
+}
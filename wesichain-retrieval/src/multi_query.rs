@@ -88,11 +88,13 @@ where
                 content: prompt.into(),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             }],
             tools: vec![],
             temperature: None,
             max_tokens: None,
             stop_sequences: vec![],
+            response_format: None,
         };
 
         let response =
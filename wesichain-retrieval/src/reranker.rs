@@ -24,7 +24,7 @@
 //! ```
 
 use async_trait::async_trait;
-use wesichain_core::{MetadataFilter, SearchResult};
+use wesichain_core::{Document, MetadataFilter, SearchResult, WesichainError};
 
 use crate::{BaseRetriever, RetrievalError};
 
@@ -172,6 +172,40 @@ impl Reranker for KeywordReranker {
     }
 }
 
+// ── ScoringReranker ──────────────────────────────────────────────────────────
+
+/// Adapts a per-document [`Reranker`] scorer to the batch
+/// [`wesichain_core::Reranker`] trait, so scorers such as [`KeywordReranker`]
+/// can drive a graph `RerankNode`.
+///
+/// Documents are returned best match first; ties keep their input order.
+pub struct ScoringReranker<S> {
+    scorer: S,
+}
+
+impl<S: Reranker> ScoringReranker<S> {
+    pub fn new(scorer: S) -> Self {
+        Self { scorer }
+    }
+}
+
+#[async_trait]
+impl<S: Reranker> wesichain_core::Reranker for ScoringReranker<S> {
+    async fn rerank(
+        &self,
+        query: &str,
+        docs: Vec<Document>,
+    ) -> Result<Vec<(Document, f32)>, WesichainError> {
+        let mut scored = Vec::with_capacity(docs.len());
+        for doc in docs {
+            let score = self.scorer.score(query, &doc.content).await;
+            scored.push((doc, score));
+        }
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        Ok(scored)
+    }
+}
+
 // ── tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -251,4 +285,19 @@ mod tests {
         let results = retriever.retrieve("hello", 2, None).await.unwrap();
         assert_eq!(results.len(), 2);
     }
+
+    #[tokio::test]
+    async fn scoring_reranker_orders_documents_by_score() {
+        let reranker = ScoringReranker::new(KeywordReranker::new());
+        let docs = vec![
+            result("a", "The quick brown fox jumps over the lazy dog", 0.9).document,
+            result("b", "Rust async programming is great for systems code", 0.5).document,
+        ];
+        let reranked = wesichain_core::Reranker::rerank(&reranker, "Rust async programming", docs)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = reranked.iter().map(|(doc, _)| doc.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "a"]);
+        assert!(reranked[0].1 > reranked[1].1);
+    }
 }
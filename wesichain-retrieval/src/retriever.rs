@@ -23,7 +23,7 @@ where
         top_k: usize,
         filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>, RetrievalError> {
-        let embedding = self.embedder.embed(query).await?;
+        let embedding = self.embedder.embed_query(query).await?;
         let results = self.store.search(&embedding, top_k, filter).await?;
         Ok(results)
     }
@@ -41,7 +41,7 @@ where
         top_k: usize,
         filter: Option<&MetadataFilter>,
     ) -> Result<Vec<SearchResult>, RetrievalError> {
-        let embedding = self.embedder.embed(query).await?;
+        let embedding = self.embedder.embed_query(query).await?;
         let results = self.store.search(&embedding, top_k, filter).await?;
         Ok(results)
     }
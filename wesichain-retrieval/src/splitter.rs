@@ -1,7 +1,54 @@
+use unicode_segmentation::UnicodeSegmentation;
 use wesichain_core::Document;
 
 const DEFAULT_SEPARATORS: [&str; 4] = ["\n\n", "\n", " ", ""];
 
+const DEFAULT_ABBREVIATIONS: [&str; 16] = [
+    "Mr.", "Mrs.", "Ms.", "Dr.", "Prof.", "Sr.", "Jr.", "St.", "vs.", "etc.", "e.g.", "i.e.",
+    "Inc.", "Ltd.", "Co.", "No.",
+];
+
+/// Splits documents into chunks; implemented by every document-level splitter
+/// so callers such as the RAG builder can accept any of them.
+pub trait DocumentSplitter: Send + Sync {
+    /// Split raw text into chunk contents.
+    fn split_text(&self, text: &str) -> Vec<String>;
+
+    /// Split each document with [`split_text`](Self::split_text) and stamp the
+    /// chunks with their provenance via [`chunk_document`].
+    fn split_documents(&self, documents: &[Document]) -> Vec<Document> {
+        documents
+            .iter()
+            .flat_map(|document| chunk_document(document, self.split_text(&document.content)))
+            .collect()
+    }
+}
+
+/// Turn `chunks` of `parent` into documents that record where they came from.
+///
+/// Each chunk keeps the parent's metadata plus `parent_id`, `chunk_index` and
+/// `chunk_total`, and gets the deterministic id `{parent_id}#{chunk_index}`.
+pub fn chunk_document(parent: &Document, chunks: Vec<String>) -> Vec<Document> {
+    let chunk_total = chunks.len();
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(chunk_index, content)| {
+            let mut metadata = parent.metadata.clone();
+            metadata.insert("parent_id".to_string(), serde_json::json!(parent.id));
+            metadata.insert("chunk_index".to_string(), serde_json::json!(chunk_index));
+            metadata.insert("chunk_total".to_string(), serde_json::json!(chunk_total));
+
+            Document {
+                id: format!("{}#{chunk_index}", parent.id),
+                content,
+                metadata,
+                embedding: None,
+            }
+        })
+        .collect()
+}
+
 pub struct TextSplitter;
 
 impl TextSplitter {
@@ -53,25 +100,10 @@ impl RecursiveCharacterTextSplitter {
         merged_chunks
     }
 
+    /// Split documents into chunks carrying `parent_id`, `chunk_index` and
+    /// `chunk_total` metadata; see [`chunk_document`].
     pub fn split_documents(&self, documents: &[Document]) -> Vec<Document> {
-        let mut chunked = Vec::new();
-
-        for document in documents {
-            for (chunk_index, content) in self.split_text(&document.content).into_iter().enumerate()
-            {
-                let mut metadata = document.metadata.clone();
-                metadata.insert("chunk_index".to_string(), serde_json::json!(chunk_index));
-
-                chunked.push(Document {
-                    id: format!("{}:{chunk_index}", document.id),
-                    content,
-                    metadata,
-                    embedding: None,
-                });
-            }
-        }
-
-        chunked
+        DocumentSplitter::split_documents(self, documents)
     }
 
     fn split_recursive(&self, text: &str, separator_index: usize) -> Vec<String> {
@@ -99,6 +131,12 @@ impl RecursiveCharacterTextSplitter {
     }
 }
 
+impl DocumentSplitter for RecursiveCharacterTextSplitter {
+    fn split_text(&self, text: &str) -> Vec<String> {
+        RecursiveCharacterTextSplitter::split_text(self, text)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RecursiveCharacterTextSplitterBuilder {
     chunk_size: usize,
@@ -163,6 +201,173 @@ impl RecursiveCharacterTextSplitterBuilder {
     }
 }
 
+/// Splits text into chunks of whole sentences.
+///
+/// Sentences come from Unicode sentence boundaries (UAX #29), except that a
+/// boundary right after a known abbreviation such as `Dr.` or a single-letter
+/// initial is ignored. Sentences are grouped greedily into chunks of at most
+/// `chunk_size` characters; a sentence longer than that becomes a chunk of
+/// its own rather than being cut. Each chunk after the first repeats up to
+/// `chunk_overlap` trailing sentences of the one before it.
+#[derive(Debug, Clone)]
+pub struct SentenceTextSplitter {
+    chunk_size: usize,
+    chunk_overlap: usize,
+    abbreviations: Vec<String>,
+}
+
+impl SentenceTextSplitter {
+    pub fn builder() -> SentenceTextSplitterBuilder {
+        SentenceTextSplitterBuilder::default()
+    }
+
+    pub fn split_text(&self, text: &str) -> Vec<String> {
+        let sentences = self.sentences(text);
+        let mut chunks = Vec::new();
+        let mut start = 0usize;
+
+        while start < sentences.len() {
+            let mut end = start + 1;
+            while end < sentences.len() && chunk_len(&sentences[start..=end]) <= self.chunk_size {
+                end += 1;
+            }
+            chunks.push(sentences[start..end].concat().trim().to_string());
+
+            if end == sentences.len() {
+                break;
+            }
+
+            let mut next = end.saturating_sub(self.chunk_overlap).max(start + 1);
+            while next < end && chunk_len(&sentences[next..=end]) > self.chunk_size {
+                next += 1;
+            }
+            start = next;
+        }
+
+        chunks
+    }
+
+    /// Split documents into chunks carrying `parent_id`, `chunk_index` and
+    /// `chunk_total` metadata; see [`chunk_document`].
+    pub fn split_documents(&self, documents: &[Document]) -> Vec<Document> {
+        DocumentSplitter::split_documents(self, documents)
+    }
+
+    fn sentences<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut sentences = Vec::new();
+        let mut pending = None;
+
+        for (offset, segment) in text.split_sentence_bound_indices() {
+            let start = pending.take().unwrap_or(offset);
+            let sentence = &text[start..offset + segment.len()];
+            if self.ends_with_abbreviation(sentence) {
+                pending = Some(start);
+            } else if !sentence.trim().is_empty() {
+                sentences.push(sentence);
+            }
+        }
+
+        if let Some(start) = pending {
+            sentences.push(&text[start..]);
+        }
+
+        sentences
+    }
+
+    fn ends_with_abbreviation(&self, sentence: &str) -> bool {
+        let Some(word) = sentence.split_whitespace().last() else {
+            return false;
+        };
+        let mut chars = word.chars();
+        let initial = matches!(
+            (chars.next(), chars.next(), chars.next()),
+            (Some(letter), Some('.'), None) if letter.is_uppercase()
+        );
+        initial
+            || self
+                .abbreviations
+                .iter()
+                .any(|abbreviation| abbreviation == word)
+    }
+}
+
+impl DocumentSplitter for SentenceTextSplitter {
+    fn split_text(&self, text: &str) -> Vec<String> {
+        SentenceTextSplitter::split_text(self, text)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SentenceTextSplitterBuilder {
+    chunk_size: usize,
+    chunk_overlap: usize,
+    abbreviations: Vec<String>,
+}
+
+impl Default for SentenceTextSplitterBuilder {
+    fn default() -> Self {
+        Self {
+            chunk_size: 1_000,
+            chunk_overlap: 0,
+            abbreviations: DEFAULT_ABBREVIATIONS
+                .iter()
+                .map(|abbreviation| abbreviation.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl SentenceTextSplitterBuilder {
+    /// Maximum chunk length in characters.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Number of trailing sentences each chunk shares with the next.
+    pub fn chunk_overlap(mut self, chunk_overlap: usize) -> Self {
+        self.chunk_overlap = chunk_overlap;
+        self
+    }
+
+    /// Replace the words, including their trailing period, after which a
+    /// sentence never ends.
+    pub fn abbreviations<I, S>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.abbreviations = abbreviations.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn build(self) -> Result<SentenceTextSplitter, SplitterConfigError> {
+        if self.chunk_size == 0 {
+            return Err(SplitterConfigError::ChunkSizeMustBeGreaterThanZero);
+        }
+
+        Ok(SentenceTextSplitter {
+            chunk_size: self.chunk_size,
+            chunk_overlap: self.chunk_overlap,
+            abbreviations: self.abbreviations,
+        })
+    }
+}
+
+/// Length in characters of `sentences` joined into a chunk, ignoring the
+/// whitespace trimmed from its end.
+fn chunk_len(sentences: &[&str]) -> usize {
+    match sentences.split_last() {
+        Some((last, rest)) => {
+            rest.iter()
+                .map(|sentence| sentence.chars().count())
+                .sum::<usize>()
+                + last.trim_end().chars().count()
+        }
+        None => 0,
+    }
+}
+
 fn split_by_chars(text: &str, chunk_size: usize) -> Vec<String> {
     let mut chunks = Vec::new();
     let mut current = String::new();
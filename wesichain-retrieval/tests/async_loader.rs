@@ -1,7 +1,10 @@
 use std::fs;
 
 use tempfile::tempdir;
-use wesichain_retrieval::{load_file_async, load_files_async, IngestionError, TextLoader};
+use wesichain_retrieval::{
+    load_file_async, load_files_async, load_files_concurrent_async,
+    load_files_concurrent_lenient_async, IngestionError, TextLoader,
+};
 
 #[tokio::test]
 async fn async_loader_reads_txt_document() {
@@ -58,6 +61,62 @@ async fn async_loader_is_additive_and_does_not_break_sync_text_loader() {
     assert_eq!(sync_docs[0].id, sync_path.to_string_lossy());
 }
 
+#[tokio::test]
+async fn concurrent_loader_returns_every_document() {
+    let dir = tempdir().expect("temp dir");
+    let paths: Vec<_> = (0..200)
+        .map(|i| {
+            let path = dir.path().join(format!("doc-{i}.txt"));
+            fs::write(&path, format!("content {i}")).expect("write temp file");
+            path
+        })
+        .collect();
+
+    let documents = load_files_concurrent_async(paths.clone(), 16)
+        .await
+        .expect("load files concurrently");
+
+    assert_eq!(documents.len(), paths.len());
+    let mut ids: Vec<_> = documents.iter().map(|doc| doc.id.clone()).collect();
+    let mut expected: Vec<_> = paths
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+    ids.sort();
+    expected.sort();
+    assert_eq!(ids, expected);
+}
+
+#[tokio::test]
+async fn concurrent_loader_surfaces_bad_file() {
+    let dir = tempdir().expect("temp dir");
+    let mut paths: Vec<_> = (0..20)
+        .map(|i| {
+            let path = dir.path().join(format!("doc-{i}.txt"));
+            fs::write(&path, "ok").expect("write temp file");
+            path
+        })
+        .collect();
+    let missing = dir.path().join("missing.txt");
+    paths.insert(10, missing.clone());
+
+    let error = load_files_concurrent_async(paths.clone(), 4)
+        .await
+        .expect_err("missing file should fail the batch");
+    assert!(matches!(
+        &error,
+        IngestionError::Read { path, .. } if path == &missing
+    ));
+
+    let (documents, errors) = load_files_concurrent_lenient_async(paths, 4).await;
+    assert_eq!(documents.len(), 20);
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        IngestionError::Read { path, .. } if path == &missing
+    ));
+}
+
 #[tokio::test]
 #[cfg(feature = "pdf")]
 async fn async_loader_routes_pdf_extension_when_feature_enabled() {
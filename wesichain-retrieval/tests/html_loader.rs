@@ -1,7 +1,8 @@
+use serde_json::json;
 use std::io::Write;
 use std::path::PathBuf;
 use tempfile::NamedTempFile;
-use wesichain_retrieval::load_file_async;
+use wesichain_retrieval::{load_file_async, load_html_file_with_options_async, HtmlLoaderOptions};
 
 #[tokio::test]
 async fn test_load_html_basic() {
@@ -164,3 +165,56 @@ async fn test_load_html_malformed_markup() {
     assert!(!doc.content.trim().is_empty());
     assert!(doc.content.len() > 50);
 }
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+#[tokio::test]
+async fn test_load_html_with_options_collects_links_and_headings() {
+    let options = HtmlLoaderOptions {
+        extract_links: true,
+        heading_metadata: true,
+    };
+
+    let documents = load_html_file_with_options_async(fixture_path("docs_page.html"), options)
+        .await
+        .unwrap();
+
+    let doc = &documents[0];
+    assert_eq!(
+        doc.metadata.get("links"),
+        Some(&json!([
+            "https://example.com/requirements",
+            "https://example.com/repo.git",
+            "#flags"
+        ]))
+    );
+    assert_eq!(
+        doc.metadata.get("headers"),
+        Some(&json!([
+            {"level": 1, "text": "Installing Widgets"},
+            {"level": 2, "text": "From source"},
+            {"level": 3, "text": "Build options"},
+            {"level": 2, "text": "From packages"}
+        ]))
+    );
+}
+
+#[tokio::test]
+async fn test_load_html_without_options_keeps_default_metadata() {
+    let path = fixture_path("docs_page.html");
+
+    let default = load_file_async(path.clone()).await.unwrap();
+    let explicit = load_html_file_with_options_async(path, HtmlLoaderOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(default[0].content, explicit[0].content);
+    assert_eq!(default[0].metadata, explicit[0].metadata);
+    assert!(!default[0].metadata.contains_key("links"));
+    assert!(!default[0].metadata.contains_key("headers"));
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use wesichain_core::{Document, MetadataFilter, Value, VectorStore};
+use wesichain_core::test_util::FakeEmbedding;
+use wesichain_core::{
+    Document, Embedding, MetadataFilter, StoreError, Value, VectorStore, WriteMode,
+};
 use wesichain_retrieval::InMemoryVectorStore;
 
 #[tokio::test]
@@ -26,6 +29,42 @@ async fn in_memory_store_ranks_by_cosine_similarity() {
     assert_eq!(results[0].document.id, "a");
 }
 
+#[tokio::test]
+async fn in_memory_store_returns_results_in_descending_score_order() {
+    let store = InMemoryVectorStore::new();
+    let docs = vec![
+        Document {
+            id: "far".to_string(),
+            content: "far".to_string(),
+            metadata: HashMap::new(),
+            embedding: Some(vec![0.0, 1.0]),
+        },
+        Document {
+            id: "near".to_string(),
+            content: "near".to_string(),
+            metadata: HashMap::new(),
+            embedding: Some(vec![1.0, 0.1]),
+        },
+        Document {
+            id: "middle".to_string(),
+            content: "middle".to_string(),
+            metadata: HashMap::new(),
+            embedding: Some(vec![1.0, 1.0]),
+        },
+    ];
+    store.add(docs).await.unwrap();
+
+    let results = store.search(&[1.0, 0.0], 3, None).await.unwrap();
+    let ids: Vec<&str> = results
+        .iter()
+        .map(|result| result.document.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["near", "middle", "far"]);
+    assert!(results
+        .windows(2)
+        .all(|pair| pair[0].score >= pair[1].score));
+}
+
 #[tokio::test]
 async fn in_memory_store_dimension_mismatch_on_add() {
     let store = InMemoryVectorStore::new();
@@ -343,6 +382,71 @@ async fn in_memory_store_filters_metadata_any() {
     assert!(ids.contains(&"score"));
 }
 
+#[tokio::test]
+async fn in_memory_store_filters_metadata_key_presence() {
+    let store = InMemoryVectorStore::new();
+    let mut tagged_metadata = HashMap::new();
+    tagged_metadata.insert("tag".to_string(), Value::String("alpha".to_string()));
+    let mut null_metadata = HashMap::new();
+    null_metadata.insert("tag".to_string(), Value::Null);
+    let docs = vec![
+        Document {
+            id: "tagged".to_string(),
+            content: "tagged".to_string(),
+            metadata: tagged_metadata,
+            embedding: Some(vec![1.0, 0.0, 0.0]),
+        },
+        Document {
+            id: "null".to_string(),
+            content: "null".to_string(),
+            metadata: null_metadata,
+            embedding: Some(vec![0.95, 0.05, 0.0]),
+        },
+        Document {
+            id: "untagged".to_string(),
+            content: "untagged".to_string(),
+            metadata: HashMap::new(),
+            embedding: Some(vec![0.9, 0.1, 0.0]),
+        },
+    ];
+    store.add(docs).await.unwrap();
+
+    let exists = MetadataFilter::Exists("tag".to_string());
+    let results = store
+        .search(&[1.0, 0.0, 0.0], 5, Some(&exists))
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].document.id, "tagged");
+
+    let missing = MetadataFilter::NotExists("tag".to_string());
+    let results = store
+        .search(&[1.0, 0.0, 0.0], 5, Some(&missing))
+        .await
+        .unwrap();
+    let ids: Vec<&str> = results.iter().map(|r| r.document.id.as_str()).collect();
+    assert_eq!(ids, vec!["null", "untagged"]);
+}
+
+#[tokio::test]
+async fn in_memory_store_rejects_raw_filters() {
+    let store = InMemoryVectorStore::new();
+    let raw = MetadataFilter::Raw(serde_json::json!({"tag": {"$eq": "alpha"}}));
+
+    let err = store
+        .search(&[1.0, 0.0, 0.0], 5, Some(&raw))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, StoreError::UnsupportedFilter(_)));
+
+    let nested = MetadataFilter::Any(vec![MetadataFilter::Exists("tag".to_string()), raw]);
+    let err = store
+        .search(&[1.0, 0.0, 0.0], 5, Some(&nested))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, StoreError::UnsupportedFilter(_)));
+}
+
 #[tokio::test]
 async fn in_memory_store_strips_embeddings_from_results() {
     let store = InMemoryVectorStore::new();
@@ -375,3 +479,98 @@ async fn in_memory_store_delete_is_idempotent() {
     let results = store.search(&[1.0, 0.0, 0.0], 5, None).await.unwrap();
     assert!(results.is_empty());
 }
+
+#[tokio::test]
+async fn in_memory_store_save_and_load_round_trips_documents() {
+    let store = InMemoryVectorStore::new();
+    let mut metadata = HashMap::new();
+    metadata.insert("source".to_string(), Value::String("notes.md".to_string()));
+    let docs = vec![
+        Document {
+            id: "a".to_string(),
+            content: "a".to_string(),
+            metadata,
+            embedding: Some(vec![0.1, -0.25, 0.333_333]),
+        },
+        Document {
+            id: "b".to_string(),
+            content: "b".to_string(),
+            metadata: HashMap::new(),
+            embedding: Some(vec![0.0, 1.0, 0.0]),
+        },
+    ];
+    store.add(docs.clone()).await.unwrap();
+    store.delete(&["b".to_string()]).await.unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("index.json");
+    store.save(&path).await.unwrap();
+
+    let restored = InMemoryVectorStore::load(&path).await.unwrap();
+    assert_eq!(restored.documents().await, vec![docs[0].clone()]);
+}
+
+#[tokio::test]
+async fn in_memory_store_insert_only_rejects_existing_and_repeated_ids() {
+    let store = InMemoryVectorStore::new();
+    let doc = |id: &str, content: &str| Document {
+        id: id.to_string(),
+        content: content.to_string(),
+        metadata: HashMap::new(),
+        embedding: Some(vec![1.0, 0.0]),
+    };
+    store
+        .add_mode(vec![doc("a", "original")], WriteMode::InsertOnly)
+        .await
+        .unwrap();
+
+    let err = store
+        .add_mode(
+            vec![doc("b", "new"), doc("a", "replacement")],
+            WriteMode::InsertOnly,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, StoreError::DuplicateId(ref id) if id == "a"));
+
+    let err = store
+        .add_mode(vec![doc("c", "x"), doc("c", "y")], WriteMode::InsertOnly)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, StoreError::DuplicateId(ref id) if id == "c"));
+
+    let docs = store.documents().await;
+    assert_eq!(docs.len(), 1);
+    assert_eq!(docs[0].content, "original");
+
+    store
+        .add_mode(vec![doc("a", "replacement")], WriteMode::Upsert)
+        .await
+        .unwrap();
+    assert_eq!(store.documents().await[0].content, "replacement");
+}
+
+#[tokio::test]
+async fn in_memory_store_ranks_fake_embeddings_in_controlled_order() {
+    let embedder = FakeEmbedding::new(2)
+        .with_mapping("cat", vec![1.0, 0.0])
+        .with_mapping("kitten", vec![0.9, 0.1])
+        .with_mapping("lion", vec![0.6, 0.4])
+        .with_mapping("car", vec![0.0, 1.0]);
+    let store = InMemoryVectorStore::new();
+    let mut docs = Vec::new();
+    for text in ["car", "lion", "kitten"] {
+        docs.push(Document {
+            id: text.to_string(),
+            content: text.to_string(),
+            metadata: HashMap::new(),
+            embedding: Some(embedder.embed(text).await.unwrap()),
+        });
+    }
+    store.add(docs).await.unwrap();
+
+    let query = embedder.embed("cat").await.unwrap();
+    let results = store.search(&query, 3, None).await.unwrap();
+    let ids: Vec<_> = results.iter().map(|r| r.document.id.as_str()).collect();
+    assert_eq!(ids, ["kitten", "lion", "car"]);
+}
@@ -1,7 +1,62 @@
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
-use wesichain_core::{Document, Embedding, VectorStore};
-use wesichain_retrieval::{HashEmbedder, InMemoryVectorStore, Indexer, RetrievalError};
+use wesichain_core::{Document, Embedding, EmbeddingError, StoreError, VectorStore};
+use wesichain_retrieval::{
+    FileIndexManifest, HashEmbedder, InMemoryVectorStore, IndexSummary, Indexer, RetrievalError,
+};
+
+/// Hash embedder that records every text it embeds.
+#[derive(Clone, Default)]
+struct RecordingEmbedder {
+    seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl RecordingEmbedder {
+    fn take_seen(&self) -> Vec<String> {
+        std::mem::take(&mut *self.seen.lock().unwrap())
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedding for RecordingEmbedder {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.seen.lock().unwrap().push(text.to_string());
+        HashEmbedder::new(8).embed(text).await
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let mut vectors = Vec::with_capacity(texts.len());
+        for text in texts {
+            vectors.push(self.embed(text).await?);
+        }
+        Ok(vectors)
+    }
+
+    fn dimension(&self) -> usize {
+        8
+    }
+}
+
+fn doc(id: &str, content: &str) -> Document {
+    Document {
+        id: id.to_string(),
+        content: content.to_string(),
+        metadata: HashMap::new(),
+        embedding: None,
+    }
+}
+
+async fn stored(store: &InMemoryVectorStore) -> Vec<(String, String)> {
+    let mut docs: Vec<_> = store
+        .documents()
+        .await
+        .into_iter()
+        .map(|doc| (doc.id, doc.content))
+        .collect();
+    docs.sort();
+    docs
+}
 
 #[tokio::test]
 async fn indexer_rejects_empty_id() {
@@ -52,3 +107,132 @@ async fn indexer_embeds_and_adds_documents() {
     assert_eq!(results[0].document.id, "doc-1");
     assert_eq!(results[0].document.content, "first document");
 }
+
+#[tokio::test]
+async fn indexer_validate_reports_dimension_mismatch() {
+    let store = InMemoryVectorStore::new();
+    Indexer::new(HashEmbedder::new(8), store.clone())
+        .index(vec![Document {
+            id: "doc-1".to_string(),
+            content: "first document".to_string(),
+            metadata: HashMap::new(),
+            embedding: None,
+        }])
+        .await
+        .unwrap();
+
+    assert_eq!(store.dimension(), Some(8));
+    assert!(Indexer::new(HashEmbedder::new(8), store.clone())
+        .validate()
+        .is_ok());
+
+    let error = Indexer::new(HashEmbedder::new(16), store)
+        .validate()
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        RetrievalError::Store(StoreError::DimensionMismatch {
+            expected: 8,
+            got: 16
+        })
+    ));
+}
+
+#[test]
+fn indexer_validate_passes_for_empty_store() {
+    let indexer = Indexer::new(HashEmbedder::new(16), InMemoryVectorStore::new());
+    assert!(indexer.validate().is_ok());
+}
+
+#[tokio::test]
+async fn index_incremental_only_writes_changes() {
+    let embedder = RecordingEmbedder::default();
+    let store = InMemoryVectorStore::new();
+    let indexer = Indexer::new(embedder.clone(), store.clone());
+
+    let first = indexer
+        .index_incremental(vec![
+            doc("a", "alpha"),
+            doc("b", "bravo"),
+            doc("c", "charlie"),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(
+        first,
+        IndexSummary {
+            added: 3,
+            ..Default::default()
+        }
+    );
+    embedder.take_seen();
+
+    let second = indexer
+        .index_incremental(vec![
+            doc("a", "alpha"),
+            doc("b", "bravo v2"),
+            doc("d", "delta"),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        second,
+        IndexSummary {
+            added: 1,
+            updated: 1,
+            deleted: 1,
+            unchanged: 1,
+        }
+    );
+    assert_eq!(embedder.take_seen(), ["bravo v2", "delta"]);
+    assert_eq!(
+        stored(&store).await,
+        [
+            ("a".to_string(), "alpha".to_string()),
+            ("b".to_string(), "bravo v2".to_string()),
+            ("d".to_string(), "delta".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn index_incremental_detects_metadata_changes() {
+    let indexer = Indexer::new(HashEmbedder::new(8), InMemoryVectorStore::new());
+    indexer
+        .index_incremental(vec![doc("a", "alpha")])
+        .await
+        .unwrap();
+
+    let mut tagged = doc("a", "alpha");
+    tagged
+        .metadata
+        .insert("lang".to_string(), serde_json::json!("en"));
+    let summary = indexer.index_incremental(vec![tagged]).await.unwrap();
+
+    assert_eq!(summary.updated, 1);
+}
+
+#[tokio::test]
+async fn file_manifest_persists_across_indexers() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("manifest.json");
+    let store = InMemoryVectorStore::new();
+    let corpus = || vec![doc("a", "alpha"), doc("b", "bravo")];
+
+    Indexer::new(HashEmbedder::new(8), store.clone())
+        .with_manifest(FileIndexManifest::new(&path))
+        .index_incremental(corpus())
+        .await
+        .unwrap();
+
+    let embedder = RecordingEmbedder::default();
+    let summary = Indexer::new(embedder.clone(), store)
+        .with_manifest(FileIndexManifest::new(&path))
+        .index_incremental(corpus())
+        .await
+        .unwrap();
+
+    assert_eq!(summary.unchanged, 2);
+    assert!(embedder.take_seen().is_empty());
+}
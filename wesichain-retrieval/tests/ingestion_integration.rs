@@ -50,7 +50,7 @@ async fn ingestion_integration_load_and_split_recursive_ingests_txt_and_docx_wit
             .and_then(|value| value.as_u64())
             .expect("chunk index metadata");
 
-        assert!(chunk.id.ends_with(&format!(":{chunk_index}")));
+        assert!(chunk.id.ends_with(&format!("#{chunk_index}")));
         assert!(!chunk.content.is_empty());
 
         if source.ends_with("notes.txt") {
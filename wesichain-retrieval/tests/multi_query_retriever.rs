@@ -33,6 +33,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockLlm {
                 tool_calls: vec![],
                 usage: None,
                 model: String::new(),
+                finish_reason: None,
             })
         } else {
             Err(WesichainError::Custom("No more responses".to_string()))
@@ -0,0 +1,51 @@
+#![cfg(feature = "pdf")]
+
+use std::path::PathBuf;
+
+use serde_json::json;
+use wesichain_retrieval::{load_file_async, PdfLoader};
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+#[tokio::test]
+async fn pdf_loader_emits_one_document_per_page_in_order() {
+    let path = fixture_path("three_pages.pdf");
+    let source = path.to_string_lossy().to_string();
+
+    let documents = load_file_async(path).await.expect("pdf should load");
+
+    assert_eq!(documents.len(), 3);
+    for (index, (document, word)) in documents
+        .iter()
+        .zip(["Alpha", "Bravo", "Charlie"])
+        .enumerate()
+    {
+        let page = index + 1;
+        assert!(document.content.contains(word), "{:?}", document.content);
+        assert_eq!(document.metadata.get("page"), Some(&json!(page)));
+        assert_eq!(document.metadata.get("source"), Some(&json!(source)));
+        assert_eq!(document.id, format!("{source}#page={page}"));
+    }
+}
+
+#[test]
+fn pdf_loader_one_document_keeps_whole_file() {
+    let path = fixture_path("three_pages.pdf");
+
+    let documents = PdfLoader::new(path.clone())
+        .with_one_document(true)
+        .load()
+        .expect("pdf should load");
+
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0].id, path.to_string_lossy());
+    assert!(!documents[0].metadata.contains_key("page"));
+    for word in ["Alpha", "Bravo", "Charlie"] {
+        assert!(documents[0].content.contains(word));
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde_json::json;
 use wesichain_core::Document;
-use wesichain_retrieval::{RecursiveCharacterTextSplitter, SplitterConfigError};
+use wesichain_retrieval::{DocumentSplitter, RecursiveCharacterTextSplitter, SplitterConfigError};
 
 #[test]
 fn recursive_splitter_respects_separator_priority() {
@@ -103,3 +103,67 @@ fn recursive_splitter_split_documents_propagates_metadata() {
         assert!(doc.content.chars().count() <= 5);
     }
 }
+
+#[test]
+fn recursive_splitter_links_chunks_to_parent_in_order() {
+    let splitter = RecursiveCharacterTextSplitter::builder()
+        .chunk_size(6)
+        .chunk_overlap(0)
+        .build()
+        .unwrap();
+    let parent = Document {
+        id: "manual".to_string(),
+        content: "alpha beta gamma delta".to_string(),
+        metadata: HashMap::new(),
+        embedding: None,
+    };
+
+    let chunks = splitter.split_documents(&[parent]);
+
+    assert!(chunks.len() > 2);
+    for (index, chunk) in chunks.iter().enumerate() {
+        assert_eq!(chunk.id, format!("manual#{index}"));
+        assert_eq!(chunk.metadata.get("parent_id"), Some(&json!("manual")));
+        assert_eq!(chunk.metadata.get("chunk_index"), Some(&json!(index)));
+        assert_eq!(
+            chunk.metadata.get("chunk_total"),
+            Some(&json!(chunks.len()))
+        );
+    }
+    let reassembled: String = chunks.iter().map(|chunk| chunk.content.as_str()).collect();
+    assert_eq!(reassembled, "alpha beta gamma delta");
+}
+
+struct LineSplitter;
+
+impl DocumentSplitter for LineSplitter {
+    fn split_text(&self, text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+}
+
+#[test]
+fn custom_splitters_inherit_chunk_provenance() {
+    let documents = vec![
+        Document {
+            id: "a".to_string(),
+            content: "one\ntwo".to_string(),
+            metadata: HashMap::new(),
+            embedding: None,
+        },
+        Document {
+            id: "b".to_string(),
+            content: "three".to_string(),
+            metadata: HashMap::new(),
+            embedding: None,
+        },
+    ];
+
+    let chunks = LineSplitter.split_documents(&documents);
+
+    let ids: Vec<&str> = chunks.iter().map(|chunk| chunk.id.as_str()).collect();
+    assert_eq!(ids, ["a#0", "a#1", "b#0"]);
+    assert_eq!(chunks[1].metadata.get("parent_id"), Some(&json!("a")));
+    assert_eq!(chunks[1].metadata.get("chunk_total"), Some(&json!(2)));
+    assert_eq!(chunks[2].metadata.get("chunk_total"), Some(&json!(1)));
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
-use wesichain_core::{Document, Embedding, VectorStore};
-use wesichain_retrieval::{HashEmbedder, InMemoryVectorStore, Retriever};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use wesichain_core::{Document, Embedding, EmbeddingError, VectorStore};
+use wesichain_retrieval::{HashEmbedder, InMemoryVectorStore, Indexer, Retriever};
 
 #[tokio::test]
 async fn retriever_returns_results() {
@@ -20,3 +23,70 @@ async fn retriever_returns_results() {
     let results = retriever.retrieve("hello", 1, None).await.unwrap();
     assert_eq!(results[0].document.id, "doc");
 }
+
+/// Records every text it embeds, prefixed the way instruction-tuned models
+/// expect.
+struct PrefixEmbedder {
+    inner: HashEmbedder,
+    seen: Mutex<Vec<String>>,
+}
+
+impl PrefixEmbedder {
+    fn record(&self, text: String) -> String {
+        self.seen.lock().unwrap().push(text.clone());
+        text
+    }
+}
+
+#[async_trait]
+impl Embedding for PrefixEmbedder {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.inner.embed(&self.record(text.to_string())).await
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let texts: Vec<String> = texts.iter().map(|t| self.record(t.clone())).collect();
+        self.inner.embed_batch(&texts).await
+    }
+
+    async fn embed_query(&self, text: &str) -> Result<Vec<f32>, EmbeddingError> {
+        self.embed(&format!("query: {text}")).await
+    }
+
+    async fn embed_documents(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, EmbeddingError> {
+        let texts: Vec<String> = texts.iter().map(|t| format!("passage: {t}")).collect();
+        self.embed_batch(&texts).await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+}
+
+#[tokio::test]
+async fn indexer_embeds_documents_and_retriever_embeds_queries() {
+    let embedder = Arc::new(PrefixEmbedder {
+        inner: HashEmbedder::new(8),
+        seen: Mutex::new(Vec::new()),
+    });
+    let store = Arc::new(InMemoryVectorStore::new());
+
+    Indexer::new(embedder.clone(), store.clone())
+        .add_documents(vec![Document {
+            id: "doc".to_string(),
+            content: "hello".to_string(),
+            metadata: HashMap::new(),
+            embedding: None,
+        }])
+        .await
+        .unwrap();
+    Retriever::new(embedder.clone(), store)
+        .retrieve("hello", 1, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *embedder.seen.lock().unwrap(),
+        vec!["passage: hello".to_string(), "query: hello".to_string()]
+    );
+}
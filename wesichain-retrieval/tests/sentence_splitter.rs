@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use wesichain_core::Document;
+use wesichain_retrieval::{DocumentSplitter, SentenceTextSplitter, SplitterConfigError};
+
+const TEXT: &str = "The cat sat on the mat. It was warm there! Was the dog jealous? \
+                    Nobody asked the dog. The end.";
+
+fn splitter(chunk_size: usize, chunk_overlap: usize) -> SentenceTextSplitter {
+    SentenceTextSplitter::builder()
+        .chunk_size(chunk_size)
+        .chunk_overlap(chunk_overlap)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn sentence_splitter_never_ends_chunks_mid_sentence() {
+    let chunks = splitter(50, 0).split_text(TEXT);
+
+    assert_eq!(
+        chunks,
+        vec![
+            "The cat sat on the mat. It was warm there!",
+            "Was the dog jealous? Nobody asked the dog.",
+            "The end.",
+        ]
+    );
+    for chunk in &chunks {
+        assert!(chunk.chars().count() <= 50);
+        assert!(chunk.ends_with(['.', '!', '?']), "{chunk:?}");
+    }
+}
+
+#[test]
+fn sentence_splitter_overlaps_whole_sentences() {
+    let chunks = splitter(45, 1).split_text(TEXT);
+
+    assert_eq!(
+        chunks,
+        vec![
+            "The cat sat on the mat. It was warm there!",
+            "It was warm there! Was the dog jealous?",
+            "Was the dog jealous? Nobody asked the dog.",
+            "Nobody asked the dog. The end.",
+        ]
+    );
+}
+
+#[test]
+fn sentence_splitter_keeps_unterminated_text_whole() {
+    let text = "no terminators here just a long run of words that exceeds the chunk size";
+    let chunks = splitter(10, 2).split_text(text);
+
+    assert_eq!(chunks, vec![text]);
+    assert!(splitter(10, 0).split_text("").is_empty());
+}
+
+#[test]
+fn sentence_splitter_does_not_break_after_abbreviations() {
+    let text = "Dr. Smith met J. R. Tolkien at 5 p.m. yesterday. They talked, e.g. about maps.";
+    let chunks = splitter(50, 0).split_text(text);
+
+    assert_eq!(
+        chunks,
+        vec![
+            "Dr. Smith met J. R. Tolkien at 5 p.m. yesterday.",
+            "They talked, e.g. about maps.",
+        ]
+    );
+}
+
+#[test]
+fn sentence_splitter_rejects_zero_chunk_size() {
+    let err = SentenceTextSplitter::builder()
+        .chunk_size(0)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, SplitterConfigError::ChunkSizeMustBeGreaterThanZero);
+}
+
+#[test]
+fn sentence_splitter_stamps_chunk_provenance() {
+    let document = Document {
+        id: "doc".to_string(),
+        content: TEXT.to_string(),
+        metadata: HashMap::from([("source".to_string(), json!("notes.txt"))]),
+        embedding: None,
+    };
+    let chunks = DocumentSplitter::split_documents(&splitter(50, 0), &[document]);
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[1].id, "doc#1");
+    assert_eq!(chunks[1].metadata["parent_id"], json!("doc"));
+    assert_eq!(chunks[1].metadata["chunk_index"], json!(1));
+    assert_eq!(chunks[1].metadata["chunk_total"], json!(3));
+    assert_eq!(chunks[1].metadata["source"], json!("notes.txt"));
+}
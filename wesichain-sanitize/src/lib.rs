@@ -1,8 +1,14 @@
+//! Redaction and size limits applied to trace payloads before export.
+//!
+//! Shared by the LangSmith and OpenTelemetry exporters so both redact and
+//! truncate values the same way.
+
 use regex::Regex;
 use serde_json::Value;
 
 const REDACTED: &str = "[REDACTED]";
 
+/// Wrap a non-object value as `{"value": value}`.
 pub fn ensure_object(value: Value) -> Value {
     match value {
         Value::Object(_) => value,
@@ -10,6 +16,8 @@ pub fn ensure_object(value: Value) -> Value {
     }
 }
 
+/// Replace every match of `regex` in the strings of `value` with
+/// `[REDACTED]`, recursing into arrays and objects.
 pub fn sanitize_value(value: Value, regex: Option<&Regex>) -> Value {
     match value {
         Value::String(text) => match regex {
@@ -31,18 +39,35 @@ pub fn sanitize_value(value: Value, regex: Option<&Regex>) -> Value {
     }
 }
 
+/// Cut every string in `value` to at most `max_bytes`, on a char boundary.
 pub fn truncate_value(value: Value, max_bytes: usize) -> Value {
+    truncate_value_checked(value, max_bytes).0
+}
+
+/// Like [`truncate_value`], also reporting whether any string was shortened.
+pub fn truncate_value_checked(value: Value, max_bytes: usize) -> (Value, bool) {
+    let mut truncated = false;
+    let value = truncate_into(value, max_bytes, &mut truncated);
+    (value, truncated)
+}
+
+fn truncate_into(value: Value, max_bytes: usize, truncated: &mut bool) -> Value {
     match value {
-        Value::String(text) => Value::String(truncate_string(&text, max_bytes)),
+        Value::String(text) => {
+            if text.len() > max_bytes {
+                *truncated = true;
+            }
+            Value::String(truncate_string(&text, max_bytes))
+        }
         Value::Array(items) => Value::Array(
             items
                 .into_iter()
-                .map(|item| truncate_value(item, max_bytes))
+                .map(|item| truncate_into(item, max_bytes, truncated))
                 .collect(),
         ),
         Value::Object(map) => Value::Object(
             map.into_iter()
-                .map(|(key, value)| (key, truncate_value(value, max_bytes)))
+                .map(|(key, value)| (key, truncate_into(value, max_bytes, truncated)))
                 .collect(),
         ),
         other => other,
@@ -101,6 +101,7 @@ where
                 content: MessageContent::Text(m.content),
                 tool_call_id: None,
                 tool_calls: vec![],
+                cache_control: None,
             }
         })
         .collect();
@@ -112,6 +113,7 @@ where
         temperature: req.temperature,
         max_tokens: req.max_tokens,
         stop_sequences: vec![],
+        response_format: None,
     };
 
     if req.stream {
@@ -32,6 +32,7 @@ impl Runnable<LlmRequest, LlmResponse> for MockLlm {
             tool_calls: vec![],
             usage: None,
             model: String::new(),
+            finish_reason: None,
         })
     }
 
@@ -82,6 +83,7 @@ async fn chat_endpoint_passes_model_to_llm() {
                 tool_calls: vec![],
                 usage: None,
                 model: String::new(),
+                finish_reason: None,
             })
         }
         fn stream(&self, _: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
@@ -116,6 +118,7 @@ async fn chat_endpoint_uses_default_model_when_omitted() {
                 tool_calls: vec![],
                 usage: None,
                 model: String::new(),
+                finish_reason: None,
             })
         }
         fn stream(&self, _: LlmRequest) -> BoxStream<'_, Result<StreamEvent, WesichainError>> {
@@ -14,6 +14,7 @@
 //!     content: "Hello".to_string(),
 //!     tool_calls: vec![],
 //!     usage: Some(TokenUsage { prompt_tokens: 100, completion_tokens: 50, total_tokens: 150 }),
+//!     finish_reason: None,
 //! };
 //!
 //! let cost = cost_for_response(&response);
@@ -153,6 +154,7 @@ mod tests {
             content: String::new(),
             tool_calls: vec![],
             usage: Some(TokenUsage { prompt_tokens: prompt, completion_tokens: completion, total_tokens: prompt + completion }),
+            finish_reason: None,
         }
     }
 
@@ -181,6 +183,7 @@ mod tests {
             content: String::new(),
             tool_calls: vec![],
             usage: Some(TokenUsage { prompt_tokens: 1_000, completion_tokens: 500, total_tokens: 1_500 }),
+            finish_reason: None,
         };
         assert_eq!(cost_for_response(&r), 0.0);
     }
@@ -1,6 +1,7 @@
 use std::fmt;
+use std::time::Duration;
 
-use crate::{WeaviateStoreError, WeaviateVectorStore};
+use crate::{ConsistencyLevel, Distance, WeaviateStoreError, WeaviateVectorStore};
 
 #[derive(Default, Clone)]
 pub struct WeaviateStoreBuilder {
@@ -8,6 +9,11 @@ pub struct WeaviateStoreBuilder {
     class_name: Option<String>,
     api_key: Option<String>,
     auto_create_class: bool,
+    vectorizer: Option<String>,
+    distance: Distance,
+    tenant: Option<String>,
+    consistency_level: Option<ConsistencyLevel>,
+    request_timeout: Option<Duration>,
 }
 
 impl fmt::Debug for WeaviateStoreBuilder {
@@ -23,6 +29,11 @@ impl fmt::Debug for WeaviateStoreBuilder {
             .field("class_name", &self.class_name)
             .field("api_key", &api_key)
             .field("auto_create_class", &self.auto_create_class)
+            .field("vectorizer", &self.vectorizer)
+            .field("distance", &self.distance)
+            .field("tenant", &self.tenant)
+            .field("consistency_level", &self.consistency_level)
+            .field("request_timeout", &self.request_timeout)
             .finish()
     }
 }
@@ -58,6 +69,41 @@ impl WeaviateStoreBuilder {
         self
     }
 
+    /// Vectorizer module for auto-created classes. Defaults to `"none"`.
+    pub fn vectorizer(mut self, value: impl Into<String>) -> Self {
+        self.vectorizer = Some(value.into());
+        self
+    }
+
+    /// Vector index distance for auto-created classes. Defaults to cosine.
+    pub fn distance(mut self, value: Distance) -> Self {
+        self.distance = value;
+        self
+    }
+
+    /// Tenant of a multi-tenant class. Every insert, query and delete is
+    /// scoped to it, and auto-created classes enable multi-tenancy.
+    pub fn tenant(mut self, value: Option<String>) -> Self {
+        self.tenant = value
+            .map(|tenant| tenant.trim().to_string())
+            .filter(|tenant| !tenant.is_empty());
+        self
+    }
+
+    /// Replica acknowledgement required for inserts and deletes. Unset by
+    /// default, which leaves the choice to the server.
+    pub fn consistency_level(mut self, value: ConsistencyLevel) -> Self {
+        self.consistency_level = Some(value);
+        self
+    }
+
+    /// Timeout for each HTTP request, from connecting until the response body
+    /// has been read. No timeout by default.
+    pub fn request_timeout(mut self, value: Duration) -> Self {
+        self.request_timeout = Some(value);
+        self
+    }
+
     pub fn build(self) -> Result<WeaviateVectorStore, WeaviateStoreError> {
         let base_url = self
             .base_url
@@ -86,12 +132,21 @@ impl WeaviateStoreBuilder {
             );
         }
 
+        let mut client = reqwest::Client::builder();
+        if let Some(timeout) = self.request_timeout {
+            client = client.timeout(timeout);
+        }
+
         Ok(WeaviateVectorStore {
-            client: reqwest::Client::new(),
+            client: client.build()?,
             base_url,
             class_name,
             api_key: self.api_key,
             auto_create_class: self.auto_create_class,
+            vectorizer: self.vectorizer,
+            distance: self.distance,
+            tenant: self.tenant,
+            consistency_level: self.consistency_level,
         })
     }
 }
@@ -1,34 +1,130 @@
+use serde_json::json;
 use wesichain_core::{MetadataFilter, Value};
 
 use crate::WeaviateStoreError;
 
+/// Translate a [`MetadataFilter`] into an inline GraphQL `where` argument.
 pub fn to_weaviate_filter(filter: &MetadataFilter) -> Result<String, WeaviateStoreError> {
-    filter_to_where(filter)
+    filter_to_where(filter).map(|clause| clause.to_graphql())
 }
 
-fn filter_to_where(filter: &MetadataFilter) -> Result<String, WeaviateStoreError> {
-    match filter {
-        MetadataFilter::Eq(key, value) => {
-            let path = graphql_path(path_segments(key)?);
-            let value = eq_value_clause(key, value)?;
-            Ok(format!("{{operator:Equal,path:{path},{value}}}"))
+/// Translate a [`MetadataFilter`] into the JSON `where` object accepted by the
+/// REST API (e.g. the `match` block of `DELETE /v1/batch/objects`).
+pub fn to_weaviate_where_json(filter: &MetadataFilter) -> Result<Value, WeaviateStoreError> {
+    filter_to_where(filter).map(|clause| clause.to_json())
+}
+
+enum WhereClause {
+    Condition {
+        operator: &'static str,
+        path: Vec<String>,
+        value_key: &'static str,
+        value: Value,
+    },
+    Logical {
+        operator: &'static str,
+        operands: Vec<WhereClause>,
+    },
+    /// A [`MetadataFilter::Raw`] `where` object, used as-is.
+    Raw(Value),
+}
+
+impl WhereClause {
+    fn condition(
+        operator: &'static str,
+        path: &[&str],
+        (value_key, value): (&'static str, Value),
+    ) -> Self {
+        Self::Condition {
+            operator,
+            path: path.iter().map(|segment| segment.to_string()).collect(),
+            value_key,
+            value,
         }
-        MetadataFilter::In(key, values) => {
-            let path = graphql_path(path_segments(key)?);
-            let value = in_value_clause(key, values)?;
-            Ok(format!("{{operator:ContainsAny,path:{path},{value}}}"))
+    }
+
+    fn to_graphql(&self) -> String {
+        match self {
+            Self::Condition {
+                operator,
+                path,
+                value_key,
+                value,
+            } => {
+                let path = path
+                    .iter()
+                    .map(|segment| graphql_string(segment))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!("{{operator:{operator},path:[{path}],{value_key}:{value}}}")
+            }
+            Self::Logical { operator, operands } => {
+                let operands = operands
+                    .iter()
+                    .map(WhereClause::to_graphql)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                format!("{{operator:{operator},operands:[{operands}]}}")
+            }
+            Self::Raw(value) => graphql_value(value),
         }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            Self::Condition {
+                operator,
+                path,
+                value_key,
+                value,
+            } => {
+                let mut clause = json!({ "operator": operator, "path": path });
+                clause[*value_key] = value.clone();
+                clause
+            }
+            Self::Logical { operator, operands } => json!({
+                "operator": operator,
+                "operands": operands.iter().map(WhereClause::to_json).collect::<Vec<_>>(),
+            }),
+            Self::Raw(value) => value.clone(),
+        }
+    }
+}
+
+fn filter_to_where(filter: &MetadataFilter) -> Result<WhereClause, WeaviateStoreError> {
+    match filter {
+        MetadataFilter::Eq(key, value) => Ok(WhereClause::condition(
+            "Equal",
+            &path_segments(key)?,
+            eq_value_clause(key, value)?,
+        )),
+        MetadataFilter::In(key, values) => Ok(WhereClause::condition(
+            "ContainsAny",
+            &path_segments(key)?,
+            in_value_clause(key, values)?,
+        )),
         MetadataFilter::Range { key, min, max } => range_clause(key, min.as_ref(), max.as_ref()),
         MetadataFilter::All(filters) => logical_clause("And", "all", filters),
         MetadataFilter::Any(filters) => logical_clause("Or", "any", filters),
+        MetadataFilter::Exists(key) => Ok(WhereClause::condition(
+            "IsNull",
+            &path_segments(key)?,
+            ("valueBoolean", Value::Bool(false)),
+        )),
+        MetadataFilter::NotExists(key) => Ok(WhereClause::condition(
+            "IsNull",
+            &path_segments(key)?,
+            ("valueBoolean", Value::Bool(true)),
+        )),
+        MetadataFilter::Raw(value) => Ok(WhereClause::Raw(value.clone())),
     }
 }
 
 fn logical_clause(
-    op: &str,
+    op: &'static str,
     key: &str,
     filters: &[MetadataFilter],
-) -> Result<String, WeaviateStoreError> {
+) -> Result<WhereClause, WeaviateStoreError> {
     if filters.is_empty() {
         return Err(WeaviateStoreError::UnsupportedFilterValue {
             key: key.to_string(),
@@ -41,31 +137,35 @@ fn logical_clause(
         operands.push(filter_to_where(filter)?);
     }
 
-    Ok(format!(
-        "{{operator:{op},operands:[{}]}}",
-        operands.join(",")
-    ))
+    Ok(WhereClause::Logical {
+        operator: op,
+        operands,
+    })
 }
 
 fn range_clause(
     key: &str,
     min: Option<&Value>,
     max: Option<&Value>,
-) -> Result<String, WeaviateStoreError> {
-    let path = graphql_path(path_segments(key)?);
+) -> Result<WhereClause, WeaviateStoreError> {
+    let path = path_segments(key)?;
     let mut operands = Vec::with_capacity(2);
 
     if let Some(min) = min {
         let min = numeric_value(key, "min", min)?;
-        operands.push(format!(
-            "{{operator:GreaterThanEqual,path:{path},valueNumber:{min}}}"
+        operands.push(WhereClause::condition(
+            "GreaterThanEqual",
+            &path,
+            ("valueNumber", min),
         ));
     }
 
     if let Some(max) = max {
         let max = numeric_value(key, "max", max)?;
-        operands.push(format!(
-            "{{operator:LessThanEqual,path:{path},valueNumber:{max}}}"
+        operands.push(WhereClause::condition(
+            "LessThanEqual",
+            &path,
+            ("valueNumber", max),
         ));
     }
 
@@ -75,10 +175,10 @@ fn range_clause(
             reason: "range requires at least one numeric bound".to_string(),
         }),
         1 => Ok(operands.into_iter().next().expect("single operand exists")),
-        _ => Ok(format!(
-            "{{operator:And,operands:[{}]}}",
-            operands.join(",")
-        )),
+        _ => Ok(WhereClause::Logical {
+            operator: "And",
+            operands,
+        }),
     }
 }
 
@@ -102,20 +202,11 @@ fn path_segments(key: &str) -> Result<Vec<&str>, WeaviateStoreError> {
     Ok(segments)
 }
 
-fn graphql_path(segments: Vec<&str>) -> String {
-    let encoded = segments
-        .into_iter()
-        .map(graphql_string)
-        .collect::<Vec<_>>()
-        .join(",");
-    format!("[{encoded}]")
-}
-
-fn eq_value_clause(key: &str, value: &Value) -> Result<String, WeaviateStoreError> {
+fn eq_value_clause(key: &str, value: &Value) -> Result<(&'static str, Value), WeaviateStoreError> {
     match value {
-        Value::String(value) => Ok(format!("valueText:{}", graphql_string(value))),
-        Value::Bool(value) => Ok(format!("valueBoolean:{value}")),
-        Value::Number(_) => Ok(format!("valueNumber:{}", numeric_value(key, "eq", value)?)),
+        Value::String(_) => Ok(("valueText", value.clone())),
+        Value::Bool(_) => Ok(("valueBoolean", value.clone())),
+        Value::Number(_) => Ok(("valueNumber", numeric_value(key, "eq", value)?)),
         Value::Null => Err(WeaviateStoreError::UnsupportedFilterValue {
             key: key.to_string(),
             reason: "null equality is not supported by weaviate filters".to_string(),
@@ -131,7 +222,10 @@ fn eq_value_clause(key: &str, value: &Value) -> Result<String, WeaviateStoreErro
     }
 }
 
-fn in_value_clause(key: &str, values: &[Value]) -> Result<String, WeaviateStoreError> {
+fn in_value_clause(
+    key: &str,
+    values: &[Value],
+) -> Result<(&'static str, Value), WeaviateStoreError> {
     if values.is_empty() {
         return Err(WeaviateStoreError::UnsupportedFilterValue {
             key: key.to_string(),
@@ -140,30 +234,19 @@ fn in_value_clause(key: &str, values: &[Value]) -> Result<String, WeaviateStoreE
     }
 
     if values.iter().all(Value::is_string) {
-        let values = values
-            .iter()
-            .map(|value| graphql_string(value.as_str().expect("validated as string")))
-            .collect::<Vec<_>>()
-            .join(",");
-        return Ok(format!("valueTextArray:[{values}]"));
+        return Ok(("valueTextArray", Value::Array(values.to_vec())));
     }
 
     if values.iter().all(Value::is_boolean) {
-        let values = values
-            .iter()
-            .map(|value| value.as_bool().expect("validated as bool").to_string())
-            .collect::<Vec<_>>()
-            .join(",");
-        return Ok(format!("valueBooleanArray:[{values}]"));
+        return Ok(("valueBooleanArray", Value::Array(values.to_vec())));
     }
 
     if values.iter().all(Value::is_number) {
         let values = values
             .iter()
             .map(|value| numeric_value(key, "in", value))
-            .collect::<Result<Vec<_>, _>>()?
-            .join(",");
-        return Ok(format!("valueNumberArray:[{values}]"));
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(("valueNumberArray", Value::Array(values)));
     }
 
     Err(WeaviateStoreError::UnsupportedFilterValue {
@@ -174,7 +257,7 @@ fn in_value_clause(key: &str, values: &[Value]) -> Result<String, WeaviateStoreE
     })
 }
 
-fn numeric_value(key: &str, op: &str, value: &Value) -> Result<String, WeaviateStoreError> {
+fn numeric_value(key: &str, op: &str, value: &Value) -> Result<Value, WeaviateStoreError> {
     let number = value
         .as_f64()
         .ok_or_else(|| WeaviateStoreError::UnsupportedFilterValue {
@@ -183,7 +266,7 @@ fn numeric_value(key: &str, op: &str, value: &Value) -> Result<String, WeaviateS
         })?;
 
     if number.is_finite() {
-        Ok(value.to_string())
+        Ok(value.clone())
     } else {
         Err(WeaviateStoreError::UnsupportedFilterValue {
             key: key.to_string(),
@@ -195,3 +278,30 @@ fn numeric_value(key: &str, op: &str, value: &Value) -> Result<String, WeaviateS
 fn graphql_string(value: &str) -> String {
     serde_json::to_string(value).expect("string escaping should not fail")
 }
+
+/// Render a JSON `where` object as an inline GraphQL argument: object keys
+/// are bare and `operator` values are enum literals, as in typed clauses.
+fn graphql_value(value: &Value) -> String {
+    match value {
+        Value::Object(fields) => {
+            let fields = fields
+                .iter()
+                .map(|(key, value)| match (key.as_str(), value) {
+                    ("operator", Value::String(operator)) => format!("{key}:{operator}"),
+                    _ => format!("{key}:{}", graphql_value(value)),
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("{{{fields}}}")
+        }
+        Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(graphql_value)
+                .collect::<Vec<_>>()
+                .join(",");
+            format!("[{items}]")
+        }
+        other => other.to_string(),
+    }
+}
@@ -9,17 +9,29 @@ use std::fmt;
 
 use mapper::{
     build_near_vector_query, class_schema_request, doc_to_object, graphql_hits_to_results,
-    GraphQlRequest, GraphQlResponse,
+    object_path, with_consistency_query, with_tenant_query, GraphQlRequest, GraphQlResponse,
 };
+pub use mapper::{ConsistencyLevel, Distance};
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
-use wesichain_core::{Document, MetadataFilter, SearchResult, StoreError, VectorStore};
+use wesichain_core::{
+    find_duplicate_id, Document, MetadataFilter, SearchResult, StoreError, VectorStore, WriteMode,
+};
 
-use crate::filter::to_weaviate_filter;
+use crate::filter::{to_weaviate_filter, to_weaviate_where_json};
 
 pub use config::WeaviateStoreBuilder;
 pub use error::WeaviateStoreError;
 
+/// Object counts reported by Weaviate for a [`WeaviateVectorStore::delete_by_filter`] call,
+/// summed across all pages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchDeleteSummary {
+    pub matches: u64,
+    pub successful: u64,
+    pub failed: u64,
+}
+
 #[derive(Clone)]
 pub struct WeaviateVectorStore {
     client: reqwest::Client,
@@ -27,6 +39,10 @@ pub struct WeaviateVectorStore {
     class_name: String,
     api_key: Option<String>,
     auto_create_class: bool,
+    vectorizer: Option<String>,
+    distance: Distance,
+    tenant: Option<String>,
+    consistency_level: Option<ConsistencyLevel>,
 }
 
 impl fmt::Debug for WeaviateVectorStore {
@@ -42,6 +58,10 @@ impl fmt::Debug for WeaviateVectorStore {
             .field("class_name", &self.class_name)
             .field("api_key", &api_key)
             .field("auto_create_class", &self.auto_create_class)
+            .field("vectorizer", &self.vectorizer)
+            .field("distance", &self.distance)
+            .field("tenant", &self.tenant)
+            .field("consistency_level", &self.consistency_level)
             .finish()
     }
 }
@@ -63,6 +83,10 @@ impl WeaviateVectorStore {
         self.api_key.as_deref()
     }
 
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
     pub async fn scored_search(
         &self,
         query_embedding: &[f32],
@@ -72,10 +96,78 @@ impl WeaviateVectorStore {
         <Self as VectorStore>::search(self, query_embedding, top_k, filter).await
     }
 
+    /// Delete every object in the class whose metadata matches `filter`.
+    ///
+    /// Weaviate caps how many objects a single batch delete may remove
+    /// (`QUERY_MAXIMUM_RESULTS`, 10 000 by default), so requests are repeated
+    /// until a page comes back below that limit. A missing class is treated as
+    /// having nothing to delete.
+    pub async fn delete_by_filter(
+        &self,
+        filter: &MetadataFilter,
+    ) -> Result<BatchDeleteSummary, StoreError> {
+        let where_clause = to_weaviate_where_json(filter).map_err(StoreError::from)?;
+        let body = serde_json::json!({
+            "match": {
+                "class": self.class_name,
+                "where": where_clause,
+            },
+            "output": "minimal",
+        });
+
+        let path = self.write_path(with_tenant_query(
+            "v1/batch/objects".to_string(),
+            self.tenant(),
+        ));
+        let mut summary = BatchDeleteSummary::default();
+        loop {
+            let response = match self
+                .send_json(
+                    self.request_builder(reqwest::Method::DELETE, &path)
+                        .json(&body),
+                )
+                .await
+            {
+                Ok(response) => response,
+                Err(WeaviateStoreError::ClassNotFound { .. }) => return Ok(summary),
+                Err(err) => return Err(StoreError::from(err)),
+            };
+
+            let page: BatchDeleteResponse = serde_json::from_value(response).map_err(|err| {
+                StoreError::from(WeaviateStoreError::InvalidResponse {
+                    message: format!("failed to decode batch delete response: {err}"),
+                })
+            })?;
+            let results = page.results;
+            summary.matches += results.matches;
+            summary.successful += results.successful;
+            summary.failed += results.failed;
+
+            // Stop once a page is not capped by the server limit, or when nothing
+            // was removed (avoids spinning on objects that keep failing).
+            let capped = results.limit.is_some_and(|limit| results.matches >= limit);
+            if !capped || results.successful == 0 {
+                return Ok(summary);
+            }
+        }
+    }
+
+    pub fn consistency_level(&self) -> Option<ConsistencyLevel> {
+        self.consistency_level
+    }
+
     pub fn auto_create_class(&self) -> bool {
         self.auto_create_class
     }
 
+    pub fn vectorizer(&self) -> &str {
+        self.vectorizer.as_deref().unwrap_or("none")
+    }
+
+    pub fn distance(&self) -> Distance {
+        self.distance
+    }
+
     fn endpoint(&self, path: &str) -> String {
         format!(
             "{}/{}",
@@ -93,6 +185,11 @@ impl WeaviateVectorStore {
         }
     }
 
+    /// `path` with the configured consistency level, for writes and deletes.
+    fn write_path(&self, path: String) -> String {
+        with_consistency_query(path, self.consistency_level)
+    }
+
     async fn send_json(
         &self,
         request: reqwest::RequestBuilder,
@@ -115,7 +212,12 @@ impl WeaviateVectorStore {
     }
 
     async fn create_class_schema(&self) -> Result<(), WeaviateStoreError> {
-        let schema = class_schema_request(&self.class_name);
+        let schema = class_schema_request(
+            &self.class_name,
+            self.vectorizer.as_deref(),
+            self.distance,
+            self.tenant.is_some(),
+        );
         match self
             .send_json(
                 self.request_builder(reqwest::Method::POST, "v1/schema")
@@ -138,7 +240,7 @@ impl WeaviateVectorStore {
         let mut objects = Vec::with_capacity(docs.len());
         let mut expected_dimension: Option<usize> = None;
         for doc in docs {
-            let object = doc_to_object(doc, &self.class_name)?;
+            let object = doc_to_object(doc, &self.class_name, self.tenant())?;
             match expected_dimension {
                 Some(expected) if expected != object.vector.len() => {
                     return Err(WeaviateStoreError::InvalidResponse {
@@ -154,9 +256,10 @@ impl WeaviateVectorStore {
             objects.push(object);
         }
 
+        let path = self.write_path("v1/objects".to_string());
         for object in objects {
             let request = self
-                .request_builder(reqwest::Method::POST, "v1/objects")
+                .request_builder(reqwest::Method::POST, &path)
                 .json(&object);
 
             let _ = self.send_json(request).await?;
@@ -165,6 +268,26 @@ impl WeaviateVectorStore {
         Ok(())
     }
 
+    async fn object_exists(&self, id: &str) -> Result<bool, WeaviateStoreError> {
+        let path = object_path(&self.class_name, id, self.tenant());
+        let response = self
+            .request_builder(reqwest::Method::HEAD, &path)
+            .send()
+            .await
+            .map_err(WeaviateStoreError::from)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(true);
+        }
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+
+        let body = response.text().await.map_err(WeaviateStoreError::from)?;
+        Err(self.http_error_from_response(status.as_u16(), &body))
+    }
+
     fn http_error_from_response(&self, status: u16, body: &str) -> WeaviateStoreError {
         let message = weaviate_error_message(body);
         if is_class_not_found_message(&message) {
@@ -195,6 +318,29 @@ impl VectorStore for WeaviateVectorStore {
         }
     }
 
+    async fn add_mode(&self, docs: Vec<Document>, mode: WriteMode) -> Result<(), StoreError> {
+        if mode == WriteMode::InsertOnly {
+            if let Some(id) = find_duplicate_id(&docs) {
+                return Err(StoreError::DuplicateId(id.to_string()));
+            }
+
+            for doc in &docs {
+                if doc.id.trim().is_empty() {
+                    return Err(StoreError::InvalidId(doc.id.clone()));
+                }
+                if self
+                    .object_exists(&doc.id)
+                    .await
+                    .map_err(StoreError::from)?
+                {
+                    return Err(StoreError::DuplicateId(doc.id.clone()));
+                }
+            }
+        }
+
+        self.add(docs).await
+    }
+
     async fn search(
         &self,
         query_embedding: &[f32],
@@ -215,6 +361,7 @@ impl VectorStore for WeaviateVectorStore {
             query_embedding,
             top_k,
             where_clause.as_deref(),
+            self.tenant(),
         );
         let response = self
             .send_json(
@@ -250,8 +397,8 @@ impl VectorStore for WeaviateVectorStore {
             })
         })?;
 
-        let mut results =
-            graphql_hits_to_results(data, &self.class_name).map_err(StoreError::from)?;
+        let mut results = graphql_hits_to_results(data, &self.class_name, self.distance)
+            .map_err(StoreError::from)?;
         results.sort_by(|left, right| right.score.total_cmp(&left.score));
         Ok(results)
     }
@@ -266,8 +413,7 @@ impl VectorStore for WeaviateVectorStore {
                 return Err(StoreError::InvalidId(id.clone()));
             }
 
-            let encoded_id = urlencoding::encode(id);
-            let path = format!("v1/objects/{}/{}", self.class_name, encoded_id);
+            let path = self.write_path(object_path(&self.class_name, id, self.tenant()));
             let _ = self
                 .send_json(self.request_builder(reqwest::Method::DELETE, &path))
                 .await
@@ -278,6 +424,24 @@ impl VectorStore for WeaviateVectorStore {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct BatchDeleteResponse {
+    #[serde(default)]
+    results: BatchDeleteResults,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BatchDeleteResults {
+    #[serde(default)]
+    matches: u64,
+    #[serde(default)]
+    limit: Option<u64>,
+    #[serde(default)]
+    successful: u64,
+    #[serde(default)]
+    failed: u64,
+}
+
 #[derive(Debug, Deserialize)]
 struct WeaviateErrorEnvelope {
     #[serde(default)]
@@ -15,6 +15,8 @@ pub struct WeaviateObject {
     pub id: String,
     pub vector: Vec<f32>,
     pub properties: JsonMap<String, JsonValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -27,8 +29,82 @@ pub struct SchemaCreateRequest {
     pub class: String,
     #[serde(rename = "vectorizer")]
     pub vectorizer: String,
+    #[serde(rename = "vectorIndexConfig")]
+    pub vector_index_config: VectorIndexConfig,
     #[serde(rename = "properties")]
     pub properties: Vec<SchemaProperty>,
+    #[serde(rename = "multiTenancyConfig", skip_serializing_if = "Option::is_none")]
+    pub multi_tenancy_config: Option<MultiTenancyConfig>,
+}
+
+/// Multi-tenancy settings of an auto-created class.
+#[derive(Debug, Clone, Serialize)]
+pub struct MultiTenancyConfig {
+    pub enabled: bool,
+    /// Create tenants on first insert instead of requiring them up front.
+    #[serde(rename = "autoTenantCreation")]
+    pub auto_tenant_creation: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VectorIndexConfig {
+    pub distance: Distance,
+}
+
+/// Distance metric of the class's vector index (`vectorIndexConfig.distance`).
+///
+/// Search scores are derived from the `_additional.distance` Weaviate reports
+/// for each hit; see [`Distance::score`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Distance {
+    #[default]
+    Cosine,
+    Dot,
+    L2Squared,
+    Hamming,
+    Manhattan,
+}
+
+impl Distance {
+    /// Convert a distance reported under this metric into a higher-is-better
+    /// score.
+    ///
+    /// Cosine maps to Weaviate's `certainty` (`1 - distance / 2`, in `[0, 1]`),
+    /// dot product to the raw dot product (`-distance`), and the remaining
+    /// metrics to `1 / (1 + distance)`.
+    pub fn score(self, distance: f32) -> f32 {
+        match self {
+            Distance::Cosine => 1.0 - distance / 2.0,
+            Distance::Dot => -distance,
+            Distance::L2Squared | Distance::Hamming | Distance::Manhattan => {
+                1.0 / (1.0 + distance.max(0.0))
+            }
+        }
+    }
+}
+
+/// How many replicas must acknowledge a write or delete before Weaviate
+/// responds, sent as the `consistency_level` query parameter.
+///
+/// Only meaningful on replicated classes. Use [`ConsistencyLevel::All`] (or
+/// `Quorum` for both reads and writes) when a read must observe a preceding
+/// write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyLevel {
+    One,
+    Quorum,
+    All,
+}
+
+impl ConsistencyLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::One => "ONE",
+            Self::Quorum => "QUORUM",
+            Self::All => "ALL",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -54,6 +130,7 @@ pub struct GraphQlError {
 pub fn doc_to_object(
     mut doc: Document,
     class_name: &str,
+    tenant: Option<&str>,
 ) -> Result<WeaviateObject, WeaviateStoreError> {
     if doc.id.trim().is_empty() {
         return Err(WeaviateStoreError::InvalidDocumentId(doc.id));
@@ -84,13 +161,23 @@ pub fn doc_to_object(
         id: doc.id,
         vector,
         properties,
+        tenant: tenant.map(str::to_string),
     })
 }
 
-pub fn class_schema_request(class_name: &str) -> SchemaCreateRequest {
+/// Schema for an auto-created class. `vectorizer` defaults to `"none"`, since
+/// documents arrive with their own embeddings. `multi_tenant` enables
+/// multi-tenancy with tenants created on first insert.
+pub fn class_schema_request(
+    class_name: &str,
+    vectorizer: Option<&str>,
+    distance: Distance,
+    multi_tenant: bool,
+) -> SchemaCreateRequest {
     SchemaCreateRequest {
         class: class_name.to_string(),
-        vectorizer: "none".to_string(),
+        vectorizer: vectorizer.unwrap_or("none").to_string(),
+        vector_index_config: VectorIndexConfig { distance },
         properties: vec![
             SchemaProperty {
                 name: CONTENT_PAYLOAD_KEY.to_string(),
@@ -101,6 +188,36 @@ pub fn class_schema_request(class_name: &str) -> SchemaCreateRequest {
                 data_type: vec!["text".to_string()],
             },
         ],
+        multi_tenancy_config: multi_tenant.then_some(MultiTenancyConfig {
+            enabled: true,
+            auto_tenant_creation: true,
+        }),
+    }
+}
+
+/// REST path of a single object, scoped to `tenant` when one is set.
+pub fn object_path(class_name: &str, id: &str, tenant: Option<&str>) -> String {
+    let path = format!("v1/objects/{class_name}/{}", urlencoding::encode(id));
+    with_tenant_query(path, tenant)
+}
+
+/// Append `?tenant=` to a REST path when a tenant is set.
+pub fn with_tenant_query(path: String, tenant: Option<&str>) -> String {
+    match tenant {
+        Some(tenant) => format!("{path}?tenant={}", urlencoding::encode(tenant)),
+        None => path,
+    }
+}
+
+/// Append `consistency_level=` to a REST path when a level is set, after any
+/// existing query string.
+pub fn with_consistency_query(path: String, level: Option<ConsistencyLevel>) -> String {
+    match level {
+        Some(level) => {
+            let separator = if path.contains('?') { '&' } else { '?' };
+            format!("{path}{separator}consistency_level={}", level.as_str())
+        }
+        None => path,
     }
 }
 
@@ -109,6 +226,7 @@ pub fn build_near_vector_query(
     query_embedding: &[f32],
     top_k: usize,
     where_clause: Option<&str>,
+    tenant: Option<&str>,
 ) -> String {
     let embedding = query_embedding
         .iter()
@@ -125,15 +243,20 @@ pub fn build_near_vector_query(
     let where_clause = where_clause
         .map(|clause| format!(",where:{clause}"))
         .unwrap_or_default();
+    // A JSON string literal is also a valid GraphQL string literal.
+    let tenant = tenant
+        .map(|tenant| format!(",tenant:{}", JsonValue::String(tenant.to_string())))
+        .unwrap_or_default();
 
     format!(
-        "{{Get{{{class_name}(nearVector:{{vector:[{embedding}]}},limit:{top_k}{where_clause}){{_additional{{id certainty}} {CONTENT_PAYLOAD_KEY} {METADATA_PAYLOAD_KEY}}}}}}}"
+        "{{Get{{{class_name}(nearVector:{{vector:[{embedding}]}},limit:{top_k}{where_clause}{tenant}){{_additional{{id distance}} {CONTENT_PAYLOAD_KEY} {METADATA_PAYLOAD_KEY}}}}}}}"
     )
 }
 
 pub fn graphql_hits_to_results(
     data: JsonValue,
     class_name: &str,
+    distance: Distance,
 ) -> Result<Vec<SearchResult>, WeaviateStoreError> {
     let hits = data
         .get("Get")
@@ -156,10 +279,12 @@ pub fn graphql_hits_to_results(
 
         let score = hit
             .get("_additional")
-            .and_then(|additional| additional.get("certainty"))
+            .and_then(|additional| additional.get("distance"))
             .and_then(JsonValue::as_f64)
-            .map(|value| value as f32)
-            .unwrap_or(0.0);
+            .map(|value| distance.score(value as f32))
+            .ok_or_else(|| WeaviateStoreError::InvalidResponse {
+                message: "missing _additional.distance in GraphQL hit".to_string(),
+            })?;
 
         let content = hit
             .get(CONTENT_PAYLOAD_KEY)
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use httpmock::prelude::*;
+use serde_json::json;
+use wesichain_core::{Document, VectorStore};
+use wesichain_weaviate::mapper::with_consistency_query;
+use wesichain_weaviate::{ConsistencyLevel, WeaviateVectorStore};
+
+fn doc(id: &str) -> Document {
+    Document {
+        id: id.to_string(),
+        content: "hello".to_string(),
+        metadata: HashMap::new(),
+        embedding: Some(vec![0.1, 0.2]),
+    }
+}
+
+#[test]
+fn consistency_query_is_appended_after_existing_params() {
+    assert_eq!(
+        with_consistency_query("v1/objects".to_string(), Some(ConsistencyLevel::Quorum)),
+        "v1/objects?consistency_level=QUORUM"
+    );
+    assert_eq!(
+        with_consistency_query(
+            "v1/objects/Doc/doc-1?tenant=acme".to_string(),
+            Some(ConsistencyLevel::All)
+        ),
+        "v1/objects/Doc/doc-1?tenant=acme&consistency_level=ALL"
+    );
+    assert_eq!(
+        with_consistency_query("v1/objects".to_string(), None),
+        "v1/objects"
+    );
+}
+
+#[tokio::test]
+async fn insert_and_delete_carry_consistency_level() {
+    let server = MockServer::start();
+    let store = WeaviateVectorStore::builder()
+        .base_url(server.base_url())
+        .class_name("Doc")
+        .consistency_level(ConsistencyLevel::All)
+        .request_timeout(Duration::from_secs(5))
+        .build()
+        .expect("store should build");
+    assert_eq!(store.consistency_level(), Some(ConsistencyLevel::All));
+
+    let insert = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/objects")
+            .query_param("consistency_level", "ALL");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"id": "doc-1"}));
+    });
+    let delete = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/v1/objects/Doc/doc-1")
+            .query_param("consistency_level", "ALL");
+        then.status(204);
+    });
+
+    store
+        .add(vec![doc("doc-1")])
+        .await
+        .expect("add should succeed");
+    store
+        .delete(&["doc-1".to_string()])
+        .await
+        .expect("delete should succeed");
+
+    insert.assert();
+    delete.assert();
+}
+
+#[tokio::test]
+async fn insert_omits_consistency_level_by_default() {
+    let server = MockServer::start();
+    let store = WeaviateVectorStore::builder()
+        .base_url(server.base_url())
+        .class_name("Doc")
+        .build()
+        .expect("store should build");
+    assert_eq!(store.consistency_level(), None);
+
+    let insert = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/objects")
+            .query_param_exists("consistency_level");
+        then.status(500);
+    });
+    let plain_insert = server.mock(|when, then| {
+        when.method(POST).path("/v1/objects");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"id": "doc-1"}));
+    });
+
+    store
+        .add(vec![doc("doc-1")])
+        .await
+        .expect("add should succeed");
+
+    insert.assert_hits(0);
+    plain_insert.assert();
+}
+
+#[tokio::test]
+async fn request_timeout_applies_to_the_client() {
+    let server = MockServer::start();
+    let store = WeaviateVectorStore::builder()
+        .base_url(server.base_url())
+        .class_name("Doc")
+        .request_timeout(Duration::from_millis(50))
+        .build()
+        .expect("store should build");
+
+    server.mock(|when, then| {
+        when.method(POST).path("/v1/objects");
+        then.status(200).delay(Duration::from_secs(2));
+    });
+
+    let err = store
+        .add(vec![doc("doc-1")])
+        .await
+        .expect_err("slow response should time out");
+    assert!(
+        err.to_string().contains("request to weaviate failed"),
+        "{err}"
+    );
+}
@@ -4,8 +4,9 @@ use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use httpmock::prelude::*;
+use httpmock::Method::HEAD;
 use serde_json::{json, Value as JsonValue};
-use wesichain_core::{Document, StoreError, Value, VectorStore};
+use wesichain_core::{Document, StoreError, Value, VectorStore, WriteMode};
 use wesichain_weaviate::WeaviateVectorStore;
 
 static COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -306,12 +307,12 @@ async fn contract_add_search_delete_roundtrip_with_httpmock() {
                     "Get": {
                         "Doc": [
                             {
-                                "_additional": {"id": "doc-2", "certainty": 0.31},
+                                "_additional": {"id": "doc-2", "distance": 1.38},
                                 "__wesichain_content": "beta",
                                 "__wesichain_metadata": "{\"source\":\"contract\",\"rank\":2}"
                             },
                             {
-                                "_additional": {"id": "doc-1", "certainty": 0.98},
+                                "_additional": {"id": "doc-1", "distance": 0.04},
                                 "__wesichain_content": "alpha",
                                 "__wesichain_metadata": "{\"source\":\"contract\",\"rank\":1}"
                             }
@@ -382,3 +383,68 @@ async fn contract_add_search_delete_roundtrip_with_httpmock() {
     delete_doc_1.assert();
     delete_doc_2.assert();
 }
+
+#[tokio::test]
+async fn contract_insert_only_rejects_existing_object_without_writing() {
+    let server = MockServer::start();
+    let store = build_store(&server.base_url(), "Doc", false);
+
+    let head_doc_1 = server.mock(|when, then| {
+        when.method(HEAD).path("/v1/objects/Doc/doc-1");
+        then.status(404);
+    });
+    let head_doc_2 = server.mock(|when, then| {
+        when.method(HEAD).path("/v1/objects/Doc/doc-2");
+        then.status(204);
+    });
+    let add = server.mock(|when, then| {
+        when.method(POST).path("/v1/objects");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({}));
+    });
+
+    let docs = vec![
+        build_doc("doc-1", "alpha", vec![1.0, 0.0], json!({})),
+        build_doc("doc-2", "beta", vec![0.0, 1.0], json!({})),
+    ];
+    let err = store
+        .add_mode(docs, WriteMode::InsertOnly)
+        .await
+        .expect_err("existing object should conflict");
+
+    assert!(matches!(err, StoreError::DuplicateId(ref id) if id == "doc-2"));
+    head_doc_1.assert();
+    head_doc_2.assert();
+    add.assert_hits(0);
+}
+
+#[tokio::test]
+async fn contract_insert_only_writes_when_ids_are_new() {
+    let server = MockServer::start();
+    let store = build_store(&server.base_url(), "Doc", false);
+
+    let head = server.mock(|when, then| {
+        when.method(HEAD).path("/v1/objects/Doc/doc-1");
+        then.status(404);
+    });
+    let add = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/objects")
+            .body_contains("\"id\":\"doc-1\"");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"id": "doc-1"}));
+    });
+
+    store
+        .add_mode(
+            vec![build_doc("doc-1", "alpha", vec![1.0, 0.0], json!({}))],
+            WriteMode::InsertOnly,
+        )
+        .await
+        .expect("new id should be inserted");
+
+    head.assert();
+    add.assert();
+}
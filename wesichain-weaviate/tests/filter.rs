@@ -3,8 +3,9 @@ use serde_json::json;
 use wesichain_core::MetadataFilter;
 use wesichain_core::VectorStore;
 use wesichain_weaviate::{
-    filter::to_weaviate_filter, mapper::build_near_vector_query, WeaviateStoreError,
-    WeaviateVectorStore,
+    filter::{to_weaviate_filter, to_weaviate_where_json},
+    mapper::build_near_vector_query,
+    BatchDeleteSummary, WeaviateStoreError, WeaviateVectorStore,
 };
 
 #[test]
@@ -151,6 +152,7 @@ fn query_builder_includes_optional_where_clause() {
         &[1.0, 0.0],
         3,
         Some("{operator:Equal,path:[\"source\",\"env\"],valueText:\"prod\"}"),
+        None,
     );
 
     assert!(
@@ -158,3 +160,220 @@ fn query_builder_includes_optional_where_clause() {
         "query should include translated where clause"
     );
 }
+
+#[test]
+fn converts_filter_to_rest_where_json() {
+    let filter = MetadataFilter::All(vec![
+        MetadataFilter::Eq("source".to_string(), json!("old.pdf")),
+        MetadataFilter::Range {
+            key: "page".to_string(),
+            min: Some(json!(2)),
+            max: None,
+        },
+    ]);
+
+    let out = to_weaviate_where_json(&filter).expect("filter should convert to json");
+
+    assert_eq!(
+        out,
+        json!({
+            "operator": "And",
+            "operands": [
+                {"operator": "Equal", "path": ["source"], "valueText": "old.pdf"},
+                {"operator": "GreaterThanEqual", "path": ["page"], "valueNumber": 2}
+            ]
+        })
+    );
+}
+
+#[test]
+fn converts_exists_and_not_exists_to_is_null() {
+    let exists = to_weaviate_filter(&MetadataFilter::Exists("meta.author".to_string()))
+        .expect("exists filter should convert");
+    let missing = to_weaviate_where_json(&MetadataFilter::NotExists("author".to_string()))
+        .expect("not-exists filter should convert");
+
+    assert_eq!(
+        exists,
+        "{operator:IsNull,path:[\"meta\",\"author\"],valueBoolean:false}"
+    );
+    assert_eq!(
+        missing,
+        json!({"operator": "IsNull", "path": ["author"], "valueBoolean": true})
+    );
+}
+
+fn geo_filter() -> serde_json::Value {
+    json!({
+        "operator": "WithinGeoRange",
+        "path": ["location"],
+        "valueGeoRange": {
+            "geoCoordinates": {"latitude": 52.37, "longitude": 4.89},
+            "distance": {"max": 2000}
+        }
+    })
+}
+
+#[test]
+fn raw_filter_passes_through_unchanged() {
+    let filter = MetadataFilter::Raw(geo_filter());
+
+    let rest = to_weaviate_where_json(&filter).expect("raw filter should convert");
+    assert_eq!(rest, geo_filter());
+
+    let graphql = to_weaviate_filter(&filter).expect("raw filter should convert");
+    assert_eq!(
+        graphql,
+        "{operator:WithinGeoRange,path:[\"location\"],valueGeoRange:{distance:{max:2000},geoCoordinates:{latitude:52.37,longitude:4.89}}}"
+    );
+}
+
+#[tokio::test]
+async fn search_sends_raw_where_clause() {
+    let server = MockServer::start();
+    let store = WeaviateVectorStore::builder()
+        .base_url(server.base_url())
+        .class_name("Doc")
+        .build()
+        .expect("store should build");
+
+    let filter = MetadataFilter::All(vec![
+        MetadataFilter::Eq("source".to_string(), json!("prod")),
+        MetadataFilter::Raw(geo_filter()),
+    ]);
+
+    let search = server.mock(|when, then| {
+        when.method(POST)
+            .path("/v1/graphql")
+            .body_contains("operator:WithinGeoRange,path:[\\\"location\\\"]");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({"data": {"Get": {"Doc": []}}}));
+    });
+
+    let results = store
+        .search(&[1.0, 0.0, 0.0], 2, Some(&filter))
+        .await
+        .expect("search with raw filter should succeed");
+
+    assert!(results.is_empty());
+    search.assert();
+}
+
+fn delete_store(server: &MockServer) -> WeaviateVectorStore {
+    WeaviateVectorStore::builder()
+        .base_url(server.base_url())
+        .class_name("Doc")
+        .build()
+        .expect("store should build")
+}
+
+#[tokio::test]
+async fn delete_by_filter_sends_match_block_and_parses_counts() {
+    let server = MockServer::start();
+    let store = delete_store(&server);
+    let filter = MetadataFilter::Eq("source".to_string(), json!("old.pdf"));
+
+    let delete = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/v1/batch/objects")
+            .json_body_partial(
+                json!({
+                    "match": {
+                        "class": "Doc",
+                        "where": {"operator": "Equal", "path": ["source"], "valueText": "old.pdf"}
+                    }
+                })
+                .to_string(),
+            );
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(json!({
+                "results": {"matches": 3, "limit": 10000, "successful": 3, "failed": 0}
+            }));
+    });
+
+    let summary = store
+        .delete_by_filter(&filter)
+        .await
+        .expect("delete by filter should succeed");
+
+    assert_eq!(
+        summary,
+        BatchDeleteSummary {
+            matches: 3,
+            successful: 3,
+            failed: 0,
+        }
+    );
+    delete.assert_hits(1);
+}
+
+#[tokio::test]
+async fn delete_by_filter_pages_until_below_server_limit() {
+    let server = MockServer::start_async().await;
+    let store = delete_store(&server);
+    let filter = MetadataFilter::Eq("source".to_string(), json!("old.pdf"));
+
+    // Both pages match the same request, and the mock created first wins. The
+    // capped page is delayed so it can be removed once hit; the follow-up then
+    // falls through to the final page.
+    let capped = server
+        .mock_async(|when, then| {
+            when.method(DELETE).path("/v1/batch/objects");
+            then.status(200)
+                .delay(std::time::Duration::from_millis(200))
+                .json_body(json!({
+                    "results": {"matches": 2, "limit": 2, "successful": 2, "failed": 0}
+                }));
+        })
+        .await;
+    let last = server
+        .mock_async(|when, then| {
+            when.method(DELETE).path("/v1/batch/objects");
+            then.status(200).json_body(json!({
+                "results": {"matches": 1, "limit": 2, "successful": 1, "failed": 0}
+            }));
+        })
+        .await;
+
+    let remove_capped = async {
+        while capped.hits_async().await == 0 {
+            tokio::task::yield_now().await;
+        }
+        capped.delete_async().await;
+    };
+    let (summary, ()) = tokio::join!(store.delete_by_filter(&filter), remove_capped);
+
+    assert_eq!(
+        summary.expect("delete by filter should succeed"),
+        BatchDeleteSummary {
+            matches: 3,
+            successful: 3,
+            failed: 0,
+        }
+    );
+    last.assert_hits_async(1).await;
+}
+
+#[tokio::test]
+async fn delete_by_filter_treats_missing_class_as_empty() {
+    let server = MockServer::start();
+    let store = delete_store(&server);
+    let filter = MetadataFilter::Eq("source".to_string(), json!("old.pdf"));
+
+    let delete = server.mock(|when, then| {
+        when.method(DELETE).path("/v1/batch/objects");
+        then.status(422).json_body(json!({
+            "error": [{"message": "class \"Doc\" not found in schema"}]
+        }));
+    });
+
+    let summary = store
+        .delete_by_filter(&filter)
+        .await
+        .expect("missing class should not be an error");
+
+    assert_eq!(summary, BatchDeleteSummary::default());
+    delete.assert();
+}
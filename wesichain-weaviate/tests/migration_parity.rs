@@ -116,12 +116,12 @@ fn spawn_weaviate_core_flow_server() -> (String, Arc<Mutex<RecordedRequests>>) {
                             "Get": {
                                 "Docs": [
                                     {
-                                        "_additional": {"id": "doc-2", "certainty": 0.44},
+                                        "_additional": {"id": "doc-2", "distance": 1.12},
                                         "__wesichain_content": "Weaviate stores vectors and metadata for similarity retrieval.",
                                         "__wesichain_metadata": "{\"source\":\"guide\"}"
                                     },
                                     {
-                                        "_additional": {"id": "doc-1", "certainty": 0.93},
+                                        "_additional": {"id": "doc-1", "distance": 0.14},
                                         "__wesichain_content": "Wesichain is a Rust-native LLM framework focused on graph and agent workflows.",
                                         "__wesichain_metadata": "{\"source\":\"guide\"}"
                                     }
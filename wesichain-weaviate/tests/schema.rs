@@ -0,0 +1,94 @@
+use serde_json::json;
+use wesichain_weaviate::mapper::{
+    build_near_vector_query, class_schema_request, graphql_hits_to_results, CONTENT_PAYLOAD_KEY,
+};
+use wesichain_weaviate::{Distance, WeaviateVectorStore};
+
+#[test]
+fn class_schema_defaults_to_external_vectors_and_cosine() {
+    let schema = serde_json::to_value(class_schema_request(
+        "Doc",
+        None,
+        Distance::default(),
+        false,
+    ))
+    .expect("schema should serialize");
+
+    assert_eq!(schema["class"], "Doc");
+    assert_eq!(schema["vectorizer"], "none");
+    assert_eq!(schema["vectorIndexConfig"], json!({"distance": "cosine"}));
+    assert_eq!(schema["properties"][0]["name"], CONTENT_PAYLOAD_KEY);
+}
+
+#[test]
+fn class_schema_emits_configured_vectorizer_and_distance() {
+    let schema = serde_json::to_value(class_schema_request(
+        "Doc",
+        Some("text2vec-openai"),
+        Distance::L2Squared,
+        false,
+    ))
+    .expect("schema should serialize");
+
+    assert_eq!(schema["vectorizer"], "text2vec-openai");
+    assert_eq!(schema["vectorIndexConfig"]["distance"], "l2-squared");
+}
+
+#[test]
+fn builder_threads_vectorizer_and_distance_into_store() {
+    let default_store = WeaviateVectorStore::builder()
+        .base_url("http://localhost:8080")
+        .class_name("Doc")
+        .build()
+        .expect("store should build");
+    assert_eq!(default_store.vectorizer(), "none");
+    assert_eq!(default_store.distance(), Distance::Cosine);
+
+    let store = WeaviateVectorStore::builder()
+        .base_url("http://localhost:8080")
+        .class_name("Doc")
+        .vectorizer("text2vec-openai")
+        .distance(Distance::Dot)
+        .build()
+        .expect("store should build");
+    assert_eq!(store.vectorizer(), "text2vec-openai");
+    assert_eq!(store.distance(), Distance::Dot);
+}
+
+#[test]
+fn near_vector_query_requests_distance() {
+    let query = build_near_vector_query("Doc", &[1.0, 0.0], 3, None, None);
+
+    assert!(query.contains("_additional{id distance}"));
+}
+
+#[test]
+fn hits_are_scored_higher_is_better_for_every_distance() {
+    let data = json!({
+        "Get": {
+            "Doc": [
+                {"_additional": {"id": "near", "distance": 0.5}, "__wesichain_content": "a", "__wesichain_metadata": "{}"},
+                {"_additional": {"id": "far", "distance": 4.0}, "__wesichain_content": "b", "__wesichain_metadata": "{}"}
+            ]
+        }
+    });
+
+    for distance in [
+        Distance::Cosine,
+        Distance::Dot,
+        Distance::L2Squared,
+        Distance::Hamming,
+        Distance::Manhattan,
+    ] {
+        let results =
+            graphql_hits_to_results(data.clone(), "Doc", distance).expect("hits should map");
+        assert!(
+            results[0].score > results[1].score,
+            "{distance:?} should score the nearer hit higher"
+        );
+    }
+
+    assert_eq!(Distance::Cosine.score(0.5), 0.75);
+    assert_eq!(Distance::Dot.score(-3.0), 3.0);
+    assert_eq!(Distance::L2Squared.score(1.0), 0.5);
+}
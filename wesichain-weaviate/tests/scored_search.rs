@@ -41,7 +41,7 @@ fn spawn_single_response_server(response_body: &'static str) -> String {
 #[tokio::test]
 async fn scored_search_returns_results_sorted_descending_by_score() {
     let base_url = spawn_single_response_server(
-        r#"{"data":{"Get":{"Doc":[{"_additional":{"id":"doc-1","certainty":0.1},"__wesichain_content":"first","__wesichain_metadata":"{}"},{"_additional":{"id":"doc-2","certainty":0.9},"__wesichain_content":"second","__wesichain_metadata":"{}"},{"_additional":{"id":"doc-3","certainty":0.4},"__wesichain_content":"third","__wesichain_metadata":"{}"}]}}}"#,
+        r#"{"data":{"Get":{"Doc":[{"_additional":{"id":"doc-1","distance":1.8},"__wesichain_content":"first","__wesichain_metadata":"{}"},{"_additional":{"id":"doc-2","distance":0.2},"__wesichain_content":"second","__wesichain_metadata":"{}"},{"_additional":{"id":"doc-3","distance":1.2},"__wesichain_content":"third","__wesichain_metadata":"{}"}]}}}"#,
     );
 
     let store = WeaviateVectorStore::builder()
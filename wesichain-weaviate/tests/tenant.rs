@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use httpmock::prelude::*;
+use serde_json::json;
+use wesichain_core::{Document, VectorStore};
+use wesichain_weaviate::mapper::{
+    build_near_vector_query, class_schema_request, doc_to_object, object_path,
+};
+use wesichain_weaviate::{Distance, WeaviateVectorStore};
+
+fn doc(id: &str) -> Document {
+    Document {
+        id: id.to_string(),
+        content: "hello".to_string(),
+        metadata: HashMap::new(),
+        embedding: Some(vec![0.1, 0.2]),
+    }
+}
+
+#[test]
+fn object_insert_carries_tenant() {
+    let object = doc_to_object(doc("doc-1"), "Doc", Some("acme")).expect("doc should map");
+    let body = serde_json::to_value(&object).expect("object should serialize");
+
+    assert_eq!(body["class"], "Doc");
+    assert_eq!(body["tenant"], "acme");
+}
+
+#[test]
+fn object_insert_omits_tenant_when_unset() {
+    let object = doc_to_object(doc("doc-1"), "Doc", None).expect("doc should map");
+    let body = serde_json::to_value(&object).expect("object should serialize");
+
+    assert!(body.get("tenant").is_none());
+}
+
+#[test]
+fn near_vector_query_passes_tenant_argument() {
+    let query = build_near_vector_query("Doc", &[1.0, 0.0], 3, None, Some("acme \"eu\""));
+
+    assert!(
+        query.contains(r#"limit:3,tenant:"acme \"eu\"")"#),
+        "query should scope the search to the tenant: {query}"
+    );
+    assert!(!build_near_vector_query("Doc", &[1.0], 3, None, None).contains("tenant"));
+}
+
+#[test]
+fn object_path_adds_tenant_query_string() {
+    assert_eq!(
+        object_path("Doc", "doc 1", Some("acme co")),
+        "v1/objects/Doc/doc%201?tenant=acme%20co"
+    );
+    assert_eq!(object_path("Doc", "doc-1", None), "v1/objects/Doc/doc-1");
+}
+
+#[test]
+fn class_schema_enables_multi_tenancy_for_tenant_stores() {
+    let schema = serde_json::to_value(class_schema_request("Doc", None, Distance::Cosine, true))
+        .expect("schema should serialize");
+    assert_eq!(
+        schema["multiTenancyConfig"],
+        json!({"enabled": true, "autoTenantCreation": true})
+    );
+
+    let schema = serde_json::to_value(class_schema_request("Doc", None, Distance::Cosine, false))
+        .expect("schema should serialize");
+    assert!(schema.get("multiTenancyConfig").is_none());
+}
+
+#[tokio::test]
+async fn delete_is_scoped_to_tenant() {
+    let server = MockServer::start();
+    let store = WeaviateVectorStore::builder()
+        .base_url(server.base_url())
+        .class_name("Doc")
+        .tenant(Some("acme".to_string()))
+        .build()
+        .expect("store should build");
+    assert_eq!(store.tenant(), Some("acme"));
+
+    let delete = server.mock(|when, then| {
+        when.method(DELETE)
+            .path("/v1/objects/Doc/doc-1")
+            .query_param("tenant", "acme");
+        then.status(204);
+    });
+
+    store
+        .delete(&["doc-1".to_string()])
+        .await
+        .expect("delete should succeed");
+
+    delete.assert();
+}